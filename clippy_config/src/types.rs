@@ -32,6 +32,49 @@ impl DisallowedPath {
     }
 }
 
+/// A pair of types allowed in binary operations by `arithmetic-side-effects-allowed-binary`,
+/// optionally restricted to a set of operators.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ArithmeticSideEffectsAllowedBinary {
+    Pair([String; 2]),
+    WithOperators(String, String, Vec<ArithmeticOperator>),
+}
+
+impl ArithmeticSideEffectsAllowedBinary {
+    pub fn types(&self) -> [&str; 2] {
+        match self {
+            Self::Pair([lhs, rhs]) | Self::WithOperators(lhs, rhs, _) => [lhs, rhs],
+        }
+    }
+
+    /// The operators this pair is allowed in, or `None` if it is allowed in all of them.
+    pub fn operators(&self) -> Option<&[ArithmeticOperator]> {
+        match self {
+            Self::Pair(_) => None,
+            Self::WithOperators(_, _, operators) => Some(operators),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum ArithmeticOperator {
+    #[serde(rename = "+")]
+    Add,
+    #[serde(rename = "-")]
+    Sub,
+    #[serde(rename = "*")]
+    Mul,
+    #[serde(rename = "/")]
+    Div,
+    #[serde(rename = "%")]
+    Rem,
+    #[serde(rename = "<<")]
+    Shl,
+    #[serde(rename = ">>")]
+    Shr,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum MatchLintBehaviour {
     AllTypes,
@@ -122,6 +165,7 @@ macro_rules! unimplemented_serialize {
 }
 
 unimplemented_serialize! {
+    ArithmeticSideEffectsAllowedBinary,
     DisallowedPath,
     Rename,
     MacroMatcher,
@@ -3,8 +3,8 @@ use indoc::{formatdoc, writedoc};
 use std::fmt;
 use std::fmt::Write as _;
 use std::fs::{self, OpenOptions};
+use std::io;
 use std::io::prelude::*;
-use std::io::{self, ErrorKind};
 use std::path::{Path, PathBuf};
 
 struct LintData<'a> {
@@ -25,7 +25,7 @@ impl<T> Context for io::Result<T> {
             Ok(t) => Ok(t),
             Err(e) => {
                 let message = format!("{}: {e}", text.as_ref());
-                Err(io::Error::new(ErrorKind::Other, message))
+                Err(io::Error::other(message))
             },
         }
     }
@@ -8,6 +8,8 @@ use std::{env, thread};
 /// # Panics
 ///
 /// Panics if the python commands could not be spawned
+// The http server is meant to run for as long as this process does
+#[allow(clippy::zombie_processes)]
 pub fn run(port: u16, lint: Option<String>) -> ! {
     let mut url = Some(match lint {
         None => format!("http://localhost:{port}"),
@@ -733,7 +733,6 @@ fn gather_all() -> (Vec<Lint>, Vec<DeprecatedLint>, Vec<RenamedLint>) {
         let module = rel_path
             .components()
             .map(|c| c.as_os_str().to_str().unwrap())
-            .collect::<Vec<_>>()
             .join("::");
 
         // If the lints are stored in mod.rs, we get the module name from
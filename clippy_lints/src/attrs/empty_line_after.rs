@@ -27,6 +27,8 @@ pub(super) fn check(cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
             );
 
             if let Some(snippet) = snippet_opt(cx, end_of_attr_to_next_attr_or_item) {
+                // the empty item after a trailing newline is counted as an empty line
+                #[expect(clippy::split_newline)]
                 let lines = snippet.split('\n').collect::<Vec<_>>();
                 let lines = without_block_comments(lines);
 
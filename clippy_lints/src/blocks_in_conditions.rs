@@ -80,27 +80,8 @@ impl<'tcx> LateLintPass<'tcx> for BlocksInConditions {
                 return;
             }
             if block.rules == BlockCheckMode::DefaultBlock {
-                if block.stmts.is_empty() {
-                    if let Some(ex) = &block.expr {
-                        // don't dig into the expression here, just suggest that they remove
-                        // the block
-                        if expr.span.from_expansion() || ex.span.from_expansion() {
-                            return;
-                        }
-                        let mut applicability = Applicability::MachineApplicable;
-                        span_lint_and_sugg(
-                            cx,
-                            BLOCKS_IN_CONDITIONS,
-                            cond.span,
-                            BRACED_EXPR_MESSAGE,
-                            "try",
-                            snippet_block_with_applicability(cx, ex.span, "..", Some(expr.span), &mut applicability)
-                                .to_string(),
-                            applicability,
-                        );
-                    }
-                } else {
-                    let span = block.expr.as_ref().map_or_else(|| block.stmts[0].span, |e| e.span);
+                if let Some(first_stmt) = block.stmts.first() {
+                    let span = block.expr.as_ref().map_or_else(|| first_stmt.span, |e| e.span);
                     if span.from_expansion() || expr.span.from_expansion() || is_from_proc_macro(cx, cond) {
                         return;
                     }
@@ -118,6 +99,23 @@ impl<'tcx> LateLintPass<'tcx> for BlocksInConditions {
                         ),
                         applicability,
                     );
+                } else if let Some(ex) = &block.expr {
+                    // don't dig into the expression here, just suggest that they remove
+                    // the block
+                    if expr.span.from_expansion() || ex.span.from_expansion() {
+                        return;
+                    }
+                    let mut applicability = Applicability::MachineApplicable;
+                    span_lint_and_sugg(
+                        cx,
+                        BLOCKS_IN_CONDITIONS,
+                        cond.span,
+                        BRACED_EXPR_MESSAGE,
+                        "try",
+                        snippet_block_with_applicability(cx, ex.span, "..", Some(expr.span), &mut applicability)
+                            .to_string(),
+                        applicability,
+                    );
                 }
             }
         }
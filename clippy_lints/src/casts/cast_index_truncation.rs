@@ -0,0 +1,214 @@
+use clippy_utils::comparisons::{normalize_comparison, Rel};
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_isize_or_usize;
+use clippy_utils::{higher, is_guarded_by_condition, is_lint_allowed, path_to_local, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, IntTy, Ty, UintTy};
+
+use super::cast_possible_truncation::apply_reductions;
+use super::{utils, CAST_INDEX_TRUNCATION, CAST_POSSIBLE_TRUNCATION};
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+    min_target_pointer_width: u64,
+) {
+    let min_target_pointer_width = min_target_pointer_width.clamp(8, 128);
+    let msg = match (cast_from.kind(), cast_to.kind()) {
+        (ty::Int(_) | ty::Uint(_), ty::Uint(UintTy::Usize))
+            if !is_isize_or_usize(cast_from)
+                && utils::int_ty_to_nbits(cast_from, cx.tcx) > min_target_pointer_width
+                && is_used_as_index(cx, expr) =>
+        {
+            format!(
+                "casting `{cast_from}` to `usize` may truncate the index on targets with \
+                {min_target_pointer_width}-bit wide pointers"
+            )
+        },
+        (ty::Uint(UintTy::Usize), ty::Uint(UintTy::U32) | ty::Int(IntTy::I32)) => {
+            let what = match cast_expr.kind {
+                ExprKind::MethodCall(method, _, [], _) if method.ident.as_str() == "len" => "length",
+                ExprKind::MethodCall(method, _, [], _) if method.ident.as_str() == "count" => "count",
+                _ => return,
+            };
+            format!("casting the {what} to `{cast_to}` may truncate it on targets with 64-bit wide pointers")
+        },
+        _ => return,
+    };
+    if !is_lint_allowed(cx, CAST_POSSIBLE_TRUNCATION, expr.hir_id)
+        || is_bounded(cx, cast_expr, cast_from, cast_to, min_target_pointer_width)
+        || is_guarded(cx, expr, cast_expr, cast_to, min_target_pointer_width)
+    {
+        return;
+    }
+
+    span_lint_and_then(cx, CAST_INDEX_TRUNCATION, expr.span, msg, |diag| {
+        let mut app = Applicability::MaybeIncorrect;
+        let value = Sugg::hir_with_context(cx, cast_expr, expr.span.ctxt(), "..", &mut app);
+        diag.span_suggestion(
+            expr.span,
+            format!("use `{cast_to}::try_from` to check the conversion"),
+            format!("{cast_to}::try_from({value}).unwrap()"),
+            app,
+        );
+        diag.help("alternatively, keep the value as a `usize` throughout");
+    });
+}
+
+/// Checks if `expr` is used as an index, as the argument of `get` or `split_at`, or as a bound of
+/// a range.
+fn is_used_as_index(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let mut parents = cx.tcx.hir().parent_iter(expr.hir_id).map(|(_, node)| node);
+    match parents.next() {
+        Some(Node::Expr(parent)) => match parent.kind {
+            ExprKind::Index(_, index, _) => index.hir_id == expr.hir_id,
+            ExprKind::MethodCall(method, _, [arg], _) => {
+                arg.hir_id == expr.hir_id
+                    && matches!(method.ident.as_str(), "get" | "get_mut" | "split_at" | "split_at_mut")
+            },
+            // `start..=end` is lowered to `RangeInclusive::new(start, end)`
+            ExprKind::Call(..) => higher::Range::hir(parent).is_some(),
+            _ => false,
+        },
+        // The other ranges are struct expressions
+        Some(Node::ExprField(_)) => {
+            matches!(parents.next(), Some(Node::Expr(range)) if higher::Range::hir(range).is_some())
+        },
+        _ => false,
+    }
+}
+
+/// Checks if the value is bounded to the range of the target type, e.g. `x.min(u32::MAX) as usize`.
+fn is_bounded(
+    cx: &LateContext<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+    min_target_pointer_width: u64,
+) -> bool {
+    let from_nbits = apply_reductions(
+        cx,
+        utils::int_ty_to_nbits(cast_from, cx.tcx),
+        cast_expr,
+        cast_from.is_signed(),
+    );
+    let to_nbits = if is_isize_or_usize(cast_to) {
+        min_target_pointer_width
+    } else {
+        utils::int_ty_to_nbits(cast_to, cx.tcx)
+    };
+    from_nbits <= to_nbits
+}
+
+/// Checks if the cast is in a branch of an `if` checking that the value fits in the target type,
+/// e.g. `if n < values.len() as u64 { values[n as usize] } else { 0 }`.
+fn is_guarded(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_to: Ty<'_>,
+    min_target_pointer_width: u64,
+) -> bool {
+    // The value is either a local or the length of a local
+    let local = match cast_expr.kind {
+        ExprKind::MethodCall(_, receiver, [], _) => path_to_local(receiver),
+        _ => path_to_local(cast_expr),
+    };
+    let Some(local) = local else {
+        return false;
+    };
+    is_guarded_by_condition(cx, expr, &[local], |cond, is_then| {
+        is_upper_bound_check(cx, cond, is_then, cast_expr, cast_to, min_target_pointer_width)
+    })
+}
+
+/// Checks if `cond`, evaluated to `is_then`, guarantees that `value` fits in `cast_to`.
+fn is_upper_bound_check<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'tcx>,
+    is_then: bool,
+    value: &Expr<'_>,
+    cast_to: Ty<'_>,
+    min_target_pointer_width: u64,
+) -> bool {
+    let cond = if let ExprKind::DropTemps(inner) = cond.kind {
+        inner
+    } else {
+        cond
+    };
+    let ExprKind::Binary(op, lhs, rhs) = cond.kind else {
+        return false;
+    };
+    if op.node == BinOpKind::And {
+        return is_then
+            && [lhs, rhs]
+                .into_iter()
+                .any(|cond| is_upper_bound_check(cx, cond, is_then, value, cast_to, min_target_pointer_width));
+    }
+    let op = if is_then {
+        op.node
+    } else {
+        match op.node {
+            BinOpKind::Lt => BinOpKind::Ge,
+            BinOpKind::Le => BinOpKind::Gt,
+            BinOpKind::Gt => BinOpKind::Le,
+            BinOpKind::Ge => BinOpKind::Lt,
+            _ => return false,
+        }
+    };
+    let Some((rel, small, bound)) = normalize_comparison(op, lhs, rhs) else {
+        return false;
+    };
+    let Some(max) = int_max(cast_to, min_target_pointer_width) else {
+        return false;
+    };
+    // `x < bound` allows one more than `x <= bound`
+    let max = match rel {
+        Rel::Lt => max.saturating_add(1),
+        Rel::Le => max,
+        Rel::Eq | Rel::Ne => return false,
+    };
+    if !SpanlessEq::new(cx).eq_expr(small, value) {
+        return false;
+    }
+
+    if let Some(bound) = constant_full_int(cx, cx.typeck_results(), bound) {
+        match bound {
+            FullInt::S(bound) => bound < 0 || bound.unsigned_abs() <= max,
+            FullInt::U(bound) => bound <= max,
+        }
+    } else if let ExprKind::Cast(inner, _) = bound.kind {
+        // `values.len() as u64` fits in `usize`, `u32::MAX as usize` fits in `u32`
+        let inner_ty = cx.typeck_results().expr_ty(inner);
+        if is_isize_or_usize(inner_ty) {
+            inner_ty == cast_to
+        } else {
+            int_max(inner_ty, min_target_pointer_width).is_some_and(|inner_max| inner_max <= max)
+        }
+    } else {
+        false
+    }
+}
+
+/// Returns the maximum value of an integer type, `usize` and `isize` being as wide as the
+/// narrowest pointers of the targets.
+fn int_max(ty: Ty<'_>, min_target_pointer_width: u64) -> Option<u128> {
+    let nbits = match ty.kind() {
+        ty::Int(IntTy::Isize) | ty::Uint(UintTy::Usize) => min_target_pointer_width,
+        ty::Int(ity) => ity.bit_width()?,
+        ty::Uint(uty) => uty.bit_width()?,
+        _ => return None,
+    };
+    if ty.is_signed() {
+        Some(u128::MAX >> (129 - nbits))
+    } else {
+        Some(u128::MAX >> (128 - nbits))
+    }
+}
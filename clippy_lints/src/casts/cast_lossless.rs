@@ -2,6 +2,7 @@ use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::in_constant;
 use clippy_utils::source::{snippet_opt, snippet_with_applicability};
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_isize_or_usize;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, QPath, TyKind};
@@ -10,16 +11,22 @@ use rustc_middle::ty::{self, FloatTy, Ty, UintTy};
 
 use super::{utils, CAST_LOSSLESS};
 
-pub(super) fn check(
-    cx: &LateContext<'_>,
-    expr: &Expr<'_>,
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
     cast_op: &Expr<'_>,
     cast_from: Ty<'_>,
     cast_to: Ty<'_>,
     cast_to_hir: &rustc_hir::Ty<'_>,
     msrv: &Msrv,
 ) {
-    if !should_lint(cx, expr, cast_from, cast_to, msrv) {
+    if !should_lint(cx, cast_from, cast_to, msrv) {
+        return;
+    }
+    // None of the `From` impls between primitive types can be called in a const context on a stable
+    // version, whatever the MSRV is, so there is nothing to suggest in consts, statics and const fns
+    // (see #2267 and #3656)
+    if in_constant(cx, expr.hir_id) {
         return;
     }
 
@@ -64,23 +71,23 @@ pub(super) fn check(
         format!("casting `{cast_from}` to `{cast_to_fmt}` may become silently lossy if you later change the type")
     };
 
-    span_lint_and_sugg(
-        cx,
-        CAST_LOSSLESS,
-        expr.span,
-        message,
-        "try",
-        format!("{cast_to_fmt}::from({sugg})"),
-        app,
-    );
+    // `x as _` can become `x.into()` if the type is fixed by the use site, `T::from(x)` works anywhere
+    let sugg = if let TyKind::Infer = cast_to_hir.kind
+        && utils::is_target_ty_inferable(cx, expr)
+    {
+        let sugg = Sugg::hir_with_context(cx, cast_op, expr.span.ctxt(), "..", &mut app).maybe_par();
+        format!("{sugg}.into()")
+    } else {
+        format!("{cast_to_fmt}::from({sugg})")
+    };
+
+    span_lint_and_sugg(cx, CAST_LOSSLESS, expr.span, message, "try", sugg, app);
 }
 
-fn should_lint(cx: &LateContext<'_>, expr: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>, msrv: &Msrv) -> bool {
-    // Do not suggest using From in consts/statics until it is valid to do so (see #2267).
-    //
+fn should_lint(cx: &LateContext<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>, msrv: &Msrv) -> bool {
     // If destination is u128, do not lint because source type cannot be larger
     // If source is bool, still lint due to the lint message differing (refers to style)
-    if in_constant(cx, expr.hir_id) || (!cast_from.is_bool() && matches!(cast_to.kind(), ty::Uint(UintTy::U128))) {
+    if !cast_from.is_bool() && matches!(cast_to.kind(), ty::Uint(UintTy::U128)) {
         return false;
     }
 
@@ -1,4 +1,4 @@
-use clippy_utils::consts::{constant, Constant};
+use clippy_utils::consts::{constant, constant_full_int, Constant, FullInt};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
 use clippy_utils::expr_or_init;
 use clippy_utils::source::snippet;
@@ -22,11 +22,30 @@ fn constant_int(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u128> {
     }
 }
 
+fn constant_signed(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<i128> {
+    if let Some(FullInt::S(c)) = constant_full_int(cx, cx.typeck_results(), expr) {
+        Some(c)
+    } else {
+        None
+    }
+}
+
 fn get_constant_bits(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u64> {
     constant_int(cx, expr).map(|c| u64::from(128 - c.leading_zeros()))
 }
 
-fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: bool) -> u64 {
+/// Returns the number of bits needed to represent every value in `lo..=hi`, counting the sign bit
+/// only if the range contains negative values.
+fn get_range_bits(lo: i128, hi: i128) -> u64 {
+    let (lo, hi) = (lo.min(hi), lo.max(hi));
+    if lo < 0 {
+        u64::from(129 - (!lo).leading_zeros().min(hi.max(0).leading_zeros()))
+    } else {
+        u64::from(128 - hi.leading_zeros())
+    }
+}
+
+pub(super) fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: bool) -> u64 {
     match expr_or_init(cx, expr).kind {
         ExprKind::Cast(inner, _) => apply_reductions(cx, nbits, inner, signed),
         ExprKind::Block(block, _) => block.expr.map_or(nbits, |e| apply_reductions(cx, nbits, e, signed)),
@@ -40,9 +59,20 @@ fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: b
                     get_constant_bits(cx, right).map_or(0, |b| b.saturating_sub(1))
                 })
             },
-            BinOpKind::Rem => get_constant_bits(cx, right)
-                .unwrap_or(u64::MAX)
-                .min(apply_reductions(cx, nbits, left, signed)),
+            BinOpKind::Rem => {
+                // `x % d` has the sign of `x` and is smaller than `d` in absolute value
+                let max_bits = if signed {
+                    constant_signed(cx, right)
+                        .and_then(i128::checked_abs)
+                        .filter(|&d| d != 0)
+                        .map(|d| get_range_bits(1 - d, d - 1))
+                } else {
+                    constant_int(cx, right)
+                        .and_then(|d| d.checked_sub(1))
+                        .map(|d| u64::from(128 - d.leading_zeros()))
+                };
+                max_bits.unwrap_or(u64::MAX).min(apply_reductions(cx, nbits, left, signed))
+            },
             BinOpKind::BitAnd => get_constant_bits(cx, right)
                 .unwrap_or(u64::MAX)
                 .min(get_constant_bits(cx, left).unwrap_or(u64::MAX))
@@ -54,6 +84,17 @@ fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: b
         },
         ExprKind::MethodCall(method, left, [right], _) => {
             if signed {
+                // a signed value needs both a `min` and a `max` bound, e.g. `x.max(-128).min(127)`
+                if let ExprKind::MethodCall(inner_method, _, [inner_right], _) = expr_or_init(cx, left).kind
+                    && let Some(bound) = constant_signed(cx, right)
+                    && let Some(inner_bound) = constant_signed(cx, inner_right)
+                    && matches!(
+                        (method.ident.as_str(), inner_method.ident.as_str()),
+                        ("min", "max") | ("max", "min")
+                    )
+                {
+                    return get_range_bits(bound, inner_bound).min(nbits);
+                }
                 return nbits;
             }
             let max_bits = if method.ident.as_str() == "min" {
@@ -66,7 +107,11 @@ fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: b
         ExprKind::MethodCall(method, _, [lo, hi], _) => {
             if method.ident.as_str() == "clamp" {
                 //FIXME: make this a diagnostic item
-                if let (Some(lo_bits), Some(hi_bits)) = (get_constant_bits(cx, lo), get_constant_bits(cx, hi)) {
+                if signed {
+                    if let (Some(lo), Some(hi)) = (constant_signed(cx, lo), constant_signed(cx, hi)) {
+                        return get_range_bits(lo, hi);
+                    }
+                } else if let (Some(lo_bits), Some(hi_bits)) = (get_constant_bits(cx, lo), get_constant_bits(cx, hi)) {
                     return lo_bits.max(hi_bits);
                 }
             }
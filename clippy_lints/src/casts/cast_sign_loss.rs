@@ -1,11 +1,12 @@
 use std::convert::Infallible;
 use std::ops::ControlFlow;
 
+use clippy_utils::comparisons::{normalize_comparison, Rel};
 use clippy_utils::consts::{constant, Constant};
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_note};
 use clippy_utils::visitors::{for_each_expr_without_closures, Descend};
-use clippy_utils::{method_chain_args, sext};
-use rustc_hir::{BinOpKind, Expr, ExprKind};
+use clippy_utils::{int_bits, is_guarded_by_condition, method_chain_args, path_to_local, path_to_local_id, sext};
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId};
 use rustc_lint::LateContext;
 use rustc_middle::ty::{self, Ty};
 
@@ -42,7 +43,23 @@ pub(super) fn check<'cx>(
     cast_from: Ty<'cx>,
     cast_to: Ty<'_>,
 ) {
-    if should_lint(cx, cast_op, cast_from, cast_to) {
+    if !should_lint(cx, expr, cast_op, cast_from, cast_to) {
+        return;
+    }
+
+    if cast_from.is_integral()
+        && let ExprKind::MethodCall(path, ..) = cast_op.kind
+        && path.ident.name.as_str() == "abs"
+    {
+        span_lint_and_note(
+            cx,
+            CAST_SIGN_LOSS,
+            expr.span,
+            format!("casting the result of `{cast_from}::abs()` to `{cast_to}` may lose the sign of the value"),
+            None,
+            format!("`{cast_from}::MIN.abs()` overflows to `{cast_from}::MIN` without overflow checks"),
+        );
+    } else {
         span_lint(
             cx,
             CAST_SIGN_LOSS,
@@ -52,7 +69,13 @@ pub(super) fn check<'cx>(
     }
 }
 
-fn should_lint<'cx>(cx: &LateContext<'cx>, cast_op: &Expr<'_>, cast_from: Ty<'cx>, cast_to: Ty<'_>) -> bool {
+fn should_lint<'cx>(
+    cx: &LateContext<'cx>,
+    expr: &Expr<'_>,
+    cast_op: &Expr<'_>,
+    cast_from: Ty<'cx>,
+    cast_to: Ty<'_>,
+) -> bool {
     match (cast_from.is_integral(), cast_to.is_integral()) {
         (true, true) => {
             if !cast_from.is_signed() || cast_to.is_signed() {
@@ -72,7 +95,7 @@ fn should_lint<'cx>(cx: &LateContext<'cx>, cast_op: &Expr<'_>, cast_from: Ty<'cx
                 return false;
             }
 
-            true
+            !is_guarded_non_negative(cx, expr, cast_op)
         },
 
         (false, true) => !cast_to.is_signed(),
@@ -142,18 +165,82 @@ fn expr_sign<'cx, 'tcx>(cx: &LateContext<'cx>, mut expr: &'tcx Expr<'tcx>, ty: i
             expr = recv;
         }
 
-        if METHODS_POW.iter().any(|&name| method_name == name)
+        if METHODS_POW.contains(&method_name)
             && let [arg] = args
         {
             return pow_call_result_sign(cx, caller, arg);
-        } else if METHODS_RET_POSITIVE.iter().any(|&name| method_name == name) {
+        } else if METHODS_RET_POSITIVE.contains(&method_name) {
             return Sign::ZeroOrPositive;
+        } else if method_name == "max"
+            && let [arg] = args
+            && expr_sign(cx, arg, None) == Sign::ZeroOrPositive
+        {
+            return Sign::ZeroOrPositive;
+        } else if method_name == "abs" {
+            // `MIN.abs()` overflows, any other value is fine
+            return if let ty::Int(ity) = *cx.typeck_results().expr_ty(caller).kind()
+                && let Some(val) = get_const_signed_int_eval(cx, caller, None)
+                && val != i128::MIN >> (128 - int_bits(cx.tcx, ity))
+            {
+                Sign::ZeroOrPositive
+            } else {
+                Sign::Uncertain
+            };
         }
     }
 
     Sign::Uncertain
 }
 
+/// Checks if the cast is in a branch of an `if` checking that the value is non-negative, e.g.
+/// `if x >= 0 { x as u32 } else { 0 }`.
+fn is_guarded_non_negative(cx: &LateContext<'_>, expr: &Expr<'_>, cast_op: &Expr<'_>) -> bool {
+    let Some(local) = path_to_local(cast_op) else {
+        return false;
+    };
+    is_guarded_by_condition(cx, expr, &[local], |cond, is_then| {
+        is_non_negative_check(cx, cond, is_then, local)
+    })
+}
+
+/// Checks if `cond`, evaluated to `is_then`, guarantees that `local` is non-negative.
+fn is_non_negative_check<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'tcx>, is_then: bool, local: HirId) -> bool {
+    let cond = if let ExprKind::DropTemps(inner) = cond.kind {
+        inner
+    } else {
+        cond
+    };
+    let ExprKind::Binary(op, lhs, rhs) = cond.kind else {
+        return false;
+    };
+    if op.node == BinOpKind::And {
+        return is_then && [lhs, rhs].into_iter().any(|cond| is_non_negative_check(cx, cond, is_then, local));
+    }
+    let op = if is_then {
+        op.node
+    } else {
+        match op.node {
+            BinOpKind::Lt => BinOpKind::Ge,
+            BinOpKind::Le => BinOpKind::Gt,
+            BinOpKind::Gt => BinOpKind::Le,
+            BinOpKind::Ge => BinOpKind::Lt,
+            _ => return false,
+        }
+    };
+    let Some((rel, bound, value)) = normalize_comparison(op, lhs, rhs) else {
+        return false;
+    };
+    if !path_to_local_id(value, local) {
+        return false;
+    }
+    // `-1 < x` is as good as `0 <= x`
+    match (rel, get_const_signed_int_eval(cx, bound, None)) {
+        (Rel::Le, Some(bound)) => bound >= 0,
+        (Rel::Lt, Some(bound)) => bound >= -1,
+        _ => false,
+    }
+}
+
 /// Return the sign of the `pow` call's result, ignoring overflow.
 ///
 /// If the base is positive, the result is always positive.
@@ -3,6 +3,7 @@ mod as_underscore;
 mod borrow_as_ptr;
 mod cast_abs_to_unsigned;
 mod cast_enum_constructor;
+mod cast_index_truncation;
 mod cast_lossless;
 mod cast_nan_to_int;
 mod cast_possible_truncation;
@@ -67,6 +68,10 @@ declare_clippy_lint! {
     /// Possibly surprising results. You can activate this lint
     /// as a one-time check to see where numerical wrapping can arise.
     ///
+    /// Values known to be non-negative are not linted, like the results of `x.max(0)`,
+    /// `x.rem_euclid(3)` or `x.pow(2)`, and locals checked with `if x >= 0`. `x.abs()` is still
+    /// linted, since `MIN.abs()` overflows.
+    ///
     /// ### Example
     /// ```no_run
     /// let y: i8 = -1;
@@ -169,6 +174,9 @@ declare_clippy_lint! {
     ///     u64::from(x)
     /// }
     /// ```
+    ///
+    /// Casts to `_` are replaced with `x.into()` when the target type is declared where the
+    /// value is used. Casts in const contexts are not linted, as `From` can't be called there.
     #[clippy::version = "pre 1.29.0"]
     pub CAST_LOSSLESS,
     pedantic,
@@ -374,6 +382,10 @@ declare_clippy_lint! {
     /// Checks for `as` casts between raw pointers without changing its mutability,
     /// namely `*const T` to `*const U` and `*mut T` to `*mut U`.
     ///
+    /// Casts changing both the type and the constness, like `*const T` to `*mut U`, and chains
+    /// of casts like `ptr as *const u8 as *mut U` are linted too, once per chain, if
+    /// `pointer::cast_mut` and `pointer::cast_const` are available.
+    ///
     /// ### Why is this bad?
     /// Though `as` casts between raw pointers are not terrible, `pointer::cast` is safer because
     /// it cannot accidentally change the pointer's mutability nor cast the pointer to other types like `usize`.
@@ -403,6 +415,9 @@ declare_clippy_lint! {
     /// Checks for `as` casts between raw pointers which change its constness, namely `*const T` to
     /// `*mut T` and `*mut T` to `*const T`.
     ///
+    /// Chains of casts ending with the same pointee type, like `ptr as *const u8 as *mut T`, are
+    /// linted as a whole. Chains also changing the type are left to `ptr_as_ptr`.
+    ///
     /// ### Why is this bad?
     /// Though `as` casts between raw pointers are not terrible, `pointer::cast_mut` and
     /// `pointer::cast_const` are safer because they cannot accidentally cast the pointer to another
@@ -714,14 +729,53 @@ declare_clippy_lint! {
     "using `as` to cast a reference to pointer"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `as usize` casts of integers wider than the pointers of some targets, like `u64`,
+    /// used directly as an index, as the argument of `get` or `split_at`, or as a bound of a range.
+    /// Also checks for `as u32` and `as i32` casts of the `len()` or `count()` of a collection.
+    ///
+    /// Casts in a branch of an `if` checking that the value is in range are not linted. This is a
+    /// subset of `cast_possible_truncation`, and is not emitted where that lint is enabled.
+    ///
+    /// ### Why is this bad?
+    /// The index is silently truncated on targets with 32-bit wide pointers, which goes unnoticed
+    /// when only testing on 64-bit targets. Lengths and counts cast to 32 bits are truncated on
+    /// 64-bit targets, and are likely to be used as an index later on.
+    ///
+    /// Crates which are never built for targets with 32-bit wide pointers can set
+    /// `min-target-pointer-width` to 64 in `clippy.toml` to allow `u64` and `i64` indices.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn nth(values: &[u8], n: u64) -> u8 {
+    ///     values[n as usize]
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn nth(values: &[u8], n: u64) -> u8 {
+    ///     values[usize::try_from(n).unwrap()]
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CAST_INDEX_TRUNCATION,
+    pedantic,
+    "casts of 64-bit integers used as an index, or of lengths to 32-bit integers, which truncate on some targets"
+}
+
 pub struct Casts {
     msrv: Msrv,
+    min_target_pointer_width: u64,
 }
 
 impl Casts {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
-        Self { msrv }
+    pub fn new(msrv: Msrv, min_target_pointer_width: u64) -> Self {
+        Self {
+            msrv,
+            min_target_pointer_width,
+        }
     }
 }
 
@@ -750,6 +804,7 @@ impl_lint_pass!(Casts => [
     CAST_NAN_TO_INT,
     ZERO_PTR,
     REF_AS_PTR,
+    CAST_INDEX_TRUNCATION,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
@@ -771,7 +826,7 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
                 return;
             }
             cast_slice_from_raw_parts::check(cx, expr, cast_expr, cast_to, &self.msrv);
-            ptr_cast_constness::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
+            ptr_cast_constness::check(cx, expr, &self.msrv);
             as_ptr_cast_mut::check(cx, expr, cast_expr, cast_to);
             fn_to_numeric_cast_any::check(cx, expr, cast_expr, cast_from, cast_to);
             fn_to_numeric_cast::check(cx, expr, cast_expr, cast_from, cast_to);
@@ -780,6 +835,7 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
 
             if cast_to.is_numeric() {
                 cast_possible_truncation::check(cx, expr, cast_expr, cast_from, cast_to, cast_to_hir.span);
+                cast_index_truncation::check(cx, expr, cast_expr, cast_from, cast_to, self.min_target_pointer_width);
                 if cast_from.is_numeric() {
                     cast_possible_wrap::check(cx, expr, cast_from, cast_to);
                     cast_precision_loss::check(cx, expr, cast_from, cast_to);
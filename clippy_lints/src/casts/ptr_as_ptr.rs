@@ -2,100 +2,193 @@ use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::sugg::Sugg;
+use clippy_utils::{get_parent_expr, is_lint_allowed};
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, Mutability, QPath, TyKind};
 use rustc_hir_pretty::qpath_to_string;
-use rustc_lint::LateContext;
-use rustc_middle::ty;
+use rustc_lint::{LateContext, Lint};
+use rustc_middle::ty::{self, Ty};
 use rustc_span::sym;
+use std::fmt::Write;
 
-use super::PTR_AS_PTR;
+use super::{utils, PTR_AS_PTR, PTR_CAST_CONSTNESS};
 
-enum OmitFollowedCastReason<'a> {
-    None,
-    Null(&'a QPath<'a>),
-    NullMut(&'a QPath<'a>),
+/// A chain of `as` casts between raw pointers, e.g. `p as *const u8 as *mut T`, which is linted
+/// once as a whole by either `ptr_as_ptr` or `ptr_cast_constness`.
+pub(super) struct PtrCastChain<'tcx> {
+    /// The outermost cast.
+    expr: &'tcx Expr<'tcx>,
+    /// The pointer the innermost cast is applied to.
+    base: &'tcx Expr<'tcx>,
+    /// The type written in the outermost cast.
+    to_hir_ty: &'tcx rustc_hir::Ty<'tcx>,
+    from_pointee: Ty<'tcx>,
+    from_mutbl: Mutability,
+    to_pointee: Ty<'tcx>,
+    to_mutbl: Mutability,
+    /// The `ptr::null` or `ptr::null_mut` path if the base is a call to it whose type argument
+    /// is inferred from the cast.
+    null_fn: Option<&'tcx QPath<'tcx>>,
 }
 
-impl OmitFollowedCastReason<'_> {
-    fn corresponding_item(&self) -> Option<&QPath<'_>> {
-        match self {
-            OmitFollowedCastReason::None => None,
-            OmitFollowedCastReason::Null(x) | OmitFollowedCastReason::NullMut(x) => Some(*x),
+impl<'tcx> PtrCastChain<'tcx> {
+    pub(super) fn new(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Self> {
+        let ExprKind::Cast(mut base, to_hir_ty) = expr.kind else {
+            return None;
+        };
+        let typeck = cx.typeck_results();
+        let ty::RawPtr(to_pointee, to_mutbl) = *typeck.expr_ty(expr).kind() else {
+            return None;
+        };
+        // Intermediate casts are collapsed, only the innermost pointer and the final type matter
+        while let ExprKind::Cast(inner, _) = base.kind
+            && base.span.ctxt() == expr.span.ctxt()
+            && typeck.expr_ty(inner).is_unsafe_ptr()
+        {
+            base = inner;
         }
+        let ty::RawPtr(from_pointee, from_mutbl) = *typeck.expr_ty(base).kind() else {
+            return None;
+        };
+
+        // `ptr::null() as *const T` infers the type argument from the cast, following it with
+        // `cast` would not compile
+        let null_fn = if let ExprKind::Call(func, []) = base.kind
+            && let ExprKind::Path(ref qpath @ QPath::Resolved(None, path)) = func.kind
+            && path.segments.last().is_some_and(|segment| segment.args.is_none())
+            && let Some(def_id) = path.res.opt_def_id()
+            && matches!(
+                cx.tcx.get_diagnostic_name(def_id),
+                Some(sym::ptr_null | sym::ptr_null_mut)
+            )
+        {
+            Some(qpath)
+        } else {
+            None
+        };
+
+        Some(Self {
+            expr,
+            base,
+            to_hir_ty,
+            from_pointee,
+            from_mutbl,
+            to_pointee,
+            to_mutbl,
+            null_fn,
+        })
     }
-}
 
-pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, msrv: &Msrv) {
-    if !msrv.meets(msrvs::POINTER_CAST) {
-        return;
+    /// Checks if `pointer::cast` is part of the suggestion, which is also the case for a chain
+    /// which doesn't change the pointer at all.
+    fn changes_pointee(&self) -> bool {
+        self.from_pointee != self.to_pointee || self.null_fn.is_some() || !self.changes_constness()
     }
 
-    if let ExprKind::Cast(cast_expr, cast_to_hir_ty) = expr.kind
-        && let (cast_from, cast_to) = (cx.typeck_results().expr_ty(cast_expr), cx.typeck_results().expr_ty(expr))
-        && let ty::RawPtr(_, from_mutbl) = cast_from.kind()
-        && let ty::RawPtr(to_pointee_ty, to_mutbl) = cast_to.kind()
-        && matches!((from_mutbl, to_mutbl),
-            (Mutability::Not, Mutability::Not) | (Mutability::Mut, Mutability::Mut))
-        // The `U` in `pointer::cast` have to be `Sized`
-        // as explained here: https://github.com/rust-lang/rust/issues/60602.
-        && to_pointee_ty.is_sized(cx.tcx, cx.param_env)
-    {
-        let mut app = Applicability::MachineApplicable;
-        let turbofish = match &cast_to_hir_ty.kind {
-            TyKind::Infer => String::new(),
-            TyKind::Ptr(mut_ty) => {
-                if matches!(mut_ty.ty.kind, TyKind::Infer) {
-                    String::new()
-                } else {
-                    format!(
-                        "::<{}>",
-                        snippet_with_applicability(cx, mut_ty.ty.span, "/* type */", &mut app)
-                    )
-                }
-            },
-            _ => return,
-        };
+    fn changes_constness(&self) -> bool {
+        self.from_mutbl != self.to_mutbl
+    }
 
-        // following `cast` does not compile because it fails to infer what type is expected
-        // as type argument to `std::ptr::ptr_null` or `std::ptr::ptr_null_mut`, so
-        // we omit following `cast`:
-        let omit_cast = if let ExprKind::Call(func, []) = cast_expr.kind
-            && let ExprKind::Path(ref qpath @ QPath::Resolved(None, path)) = func.kind
-            && let Some(method_defid) = path.res.opt_def_id()
+    /// The constness of the final pointer, as in `pointer::cast_const` and `pointer::cast_mut`.
+    pub(super) fn constness(&self) -> &'static str {
+        match self.to_mutbl {
+            Mutability::Not => "const",
+            Mutability::Mut => "mut",
+        }
+    }
+
+    /// Returns the lint this chain is linted by, if the methods can replace it.
+    fn lint(&self, cx: &LateContext<'tcx>, msrv: &Msrv) -> Option<&'static Lint> {
+        if self.changes_pointee() {
+            // The `U` in `pointer::cast` have to be `Sized`
+            // as explained here: https://github.com/rust-lang/rust/issues/60602.
+            let lintable = msrv.meets(msrvs::POINTER_CAST)
+                && self.to_pointee.is_sized(cx.tcx, cx.param_env)
+                && matches!(self.to_hir_ty.kind, TyKind::Infer | TyKind::Ptr(_))
+                && (!self.changes_constness() || (msrv.meets(msrvs::POINTER_CAST_CONSTNESS) && self.null_fn.is_none()));
+            lintable.then_some(PTR_AS_PTR)
+        } else {
+            (self.changes_constness() && msrv.meets(msrvs::POINTER_CAST_CONSTNESS)).then_some(PTR_CAST_CONSTNESS)
+        }
+    }
+
+    /// Checks if the chain should be linted by `lint`, and not as a part of a longer chain.
+    pub(super) fn is_linted_by(&self, cx: &LateContext<'tcx>, lint: &'static Lint, msrv: &Msrv) -> bool {
+        if let Some(parent) = get_parent_expr(cx, self.expr)
+            && parent.span.ctxt() == self.expr.span.ctxt()
+            && let Some(parent_chain) = Self::new(cx, parent)
+            && let Some(parent_lint) = parent_chain.lint(cx, msrv)
+            && !is_lint_allowed(cx, parent_lint, parent.hir_id)
         {
-            if cx.tcx.is_diagnostic_item(sym::ptr_null, method_defid) {
-                OmitFollowedCastReason::Null(qpath)
-            } else if cx.tcx.is_diagnostic_item(sym::ptr_null_mut, method_defid) {
-                OmitFollowedCastReason::NullMut(qpath)
-            } else {
-                OmitFollowedCastReason::None
-            }
+            return false;
+        }
+        self.lint(cx, msrv).is_some_and(|chain_lint| std::ptr::eq(chain_lint, lint))
+    }
+
+    /// Returns the method calls replacing the chain, e.g. `p.cast::<T>().cast_mut()`.
+    pub(super) fn sugg(&self, cx: &LateContext<'tcx>, app: &mut Applicability) -> String {
+        let turbofish = if self.changes_pointee() {
+            self.turbofish(cx, app)
         } else {
-            OmitFollowedCastReason::None
+            String::new()
         };
-
-        let (help, final_suggestion) = if let Some(method) = omit_cast.corresponding_item() {
+        if let Some(null_fn) = self.null_fn {
             // don't force absolute path
-            let method = qpath_to_string(&cx.tcx, method);
-            ("try call directly", format!("{method}{turbofish}()"))
+            return format!("{}{turbofish}()", qpath_to_string(&cx.tcx, null_fn));
+        }
+
+        let mut sugg = Sugg::hir_with_applicability(cx, self.base, "_", app).maybe_par().to_string();
+        if self.changes_pointee() {
+            let _ = write!(sugg, ".cast{turbofish}()");
+        }
+        if self.changes_constness() {
+            let _ = write!(sugg, ".cast_{}()", self.constness());
+        }
+        sugg
+    }
+
+    fn turbofish(&self, cx: &LateContext<'tcx>, app: &mut Applicability) -> String {
+        if let TyKind::Ptr(mut_ty) = self.to_hir_ty.kind
+            && !matches!(mut_ty.ty.kind, TyKind::Infer)
+        {
+            format!("::<{}>", snippet_with_applicability(cx, mut_ty.ty.span, "/* type */", app))
+        } else if !self.changes_constness() && utils::is_target_ty_inferable(cx, self.expr) {
+            // `as *const _` can be left to inference only if the type is fixed by the use site, and
+            // `cast` is the last call
+            String::new()
         } else {
-            let cast_expr_sugg = Sugg::hir_with_applicability(cx, cast_expr, "_", &mut app);
+            *app = Applicability::MaybeIncorrect;
+            format!("::<{}>", self.to_pointee)
+        }
+    }
+}
 
-            (
-                "try `pointer::cast`, a safer alternative",
-                format!("{}.cast{turbofish}()", cast_expr_sugg.maybe_par()),
-            )
-        };
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, msrv: &Msrv) {
+    let Some(chain) = PtrCastChain::new(cx, expr) else {
+        return;
+    };
+    if !chain.is_linted_by(cx, PTR_AS_PTR, msrv) {
+        return;
+    }
 
-        span_lint_and_sugg(
-            cx,
-            PTR_AS_PTR,
-            expr.span,
+    let mut app = Applicability::MachineApplicable;
+    let sugg = chain.sugg(cx, &mut app);
+    let (msg, help) = if chain.null_fn.is_some() {
+        (
             "`as` casting between raw pointers without changing its mutability",
-            help,
-            final_suggestion,
-            app,
-        );
-    }
+            "try call directly".to_string(),
+        )
+    } else if chain.changes_constness() {
+        (
+            "`as` casting between raw pointers while changing both its type and its constness",
+            format!("try `pointer::cast` and `pointer::cast_{}`, safer alternatives", chain.constness()),
+        )
+    } else {
+        (
+            "`as` casting between raw pointers without changing its mutability",
+            "try `pointer::cast`, a safer alternative".to_string(),
+        )
+    };
+
+    span_lint_and_sugg(cx, PTR_AS_PTR, expr.span, msg, help, sugg, app);
 }
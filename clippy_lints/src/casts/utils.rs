@@ -1,5 +1,8 @@
 use clippy_utils::ty::{read_explicit_enum_value, EnumValue};
-use rustc_middle::ty::{self, AdtDef, IntTy, Ty, TyCtxt, UintTy, VariantDiscr};
+use clippy_utils::{expr_use_ctxt, DefinedTy};
+use rustc_hir::{Expr, TyKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, AdtDef, IntTy, Ty, TyCtxt, TypeVisitableExt, UintTy, VariantDiscr};
 
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
@@ -73,3 +76,23 @@ pub(super) fn enum_ty_to_nbits(adt: AdtDef<'_>, tcx: TyCtxt<'_>) -> u64 {
         neg_bits.max(pos_bits).into()
     }
 }
+
+/// Checks if the type of `expr` is declared where it is used, e.g. by the type of a `let` or a
+/// function parameter, so that a generic method returning it would infer it.
+pub(super) fn is_target_ty_inferable<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    let use_cx = expr_use_ctxt(cx, expr);
+    if use_cx.is_ty_unified || !use_cx.adjustments.is_empty() {
+        return false;
+    }
+    match use_cx.use_node(cx).defined_ty(cx) {
+        Some(DefinedTy::Hir(mut ty)) => {
+            // `*const _` leaves the pointee to the value as well
+            while let TyKind::Ptr(mut_ty) | TyKind::Ref(_, mut_ty) = ty.kind {
+                ty = mut_ty.ty;
+            }
+            !matches!(ty.kind, TyKind::Infer)
+        },
+        Some(DefinedTy::Mir(ty)) => !ty.value.skip_binder().has_param(),
+        None => false,
+    }
+}
@@ -79,7 +79,7 @@ fn is_union_with_two_non_zst_fields<'tcx>(cx: &LateContext<'tcx>, item: &Item<'t
     if let ItemKind::Union(..) = &item.kind
         && let ty::Adt(adt_def, args) = cx.tcx.type_of(item.owner_id).instantiate_identity().kind()
     {
-        adt_def.all_fields().filter(|f| !is_zst(cx, f, args)).count() >= 2
+        adt_def.all_fields().filter(|f| !is_zst(cx, f, args)).nth(1).is_some()
     } else {
         false
     }
@@ -89,7 +89,7 @@ fn check_word(cx: &LateContext<'_>, word: &str, span: Span, code_level: isize, b
 
         let s = if let Some(prefix) = s.strip_suffix("es")
             && prefix.chars().all(|c| c.is_ascii_uppercase())
-            && matches!(prefix.chars().last(), Some('S' | 'X'))
+            && matches!(prefix.chars().next_back(), Some('S' | 'X'))
         {
             prefix
         } else {
@@ -97,8 +97,8 @@ fn check_word(cx: &LateContext<'_>, word: &str, span: Span, code_level: isize, b
         };
 
         s.chars().all(char::is_alphanumeric)
-            && s.chars().filter(|&c| c.is_uppercase()).take(2).count() > 1
-            && s.chars().filter(|&c| c.is_lowercase()).take(1).count() > 0
+            && s.chars().filter(|&c| c.is_uppercase()).nth(1).is_some()
+            && s.chars().any(char::is_lowercase)
     }
 
     fn has_underscore(s: &str) -> bool {
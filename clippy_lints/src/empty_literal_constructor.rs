@@ -0,0 +1,99 @@
+use clippy_utils::consts::constant_is_empty;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::higher::VecArgs;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{is_path_diagnostic_item, path_res};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, ItemKind, Node, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `PathBuf`s, `OsString`s and `Vec`s converted from an empty literal, like
+    /// `PathBuf::from("")` or `Vec::from([])`. Empty `String`s are handled by `manual_string_new`.
+    ///
+    /// ### Why is this bad?
+    /// `new()` creates the same empty value without going through a conversion,
+    /// and states the intent more clearly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::path::PathBuf;
+    /// let path = PathBuf::from("");
+    /// let bytes: Vec<u8> = Vec::from([]);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::path::PathBuf;
+    /// let path = PathBuf::new();
+    /// let bytes: Vec<u8> = Vec::new();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub EMPTY_LITERAL_CONSTRUCTOR,
+    style,
+    "empty `PathBuf`, `OsString` or `Vec` created from an empty literal"
+}
+
+declare_lint_pass!(EmptyLiteralConstructor => [EMPTY_LITERAL_CONSTRUCTOR]);
+
+impl<'tcx> LateLintPass<'tcx> for EmptyLiteralConstructor {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // `String`s are left to `manual_string_new`
+        if !expr.span.from_expansion()
+            && let ExprKind::Call(callee, [arg]) = expr.kind
+            && is_path_diagnostic_item(cx, callee, sym::from_fn)
+            && let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).kind()
+            && let Some(ty_name) = cx.tcx.get_diagnostic_name(adt.did())
+            && (matches!(ty_name, sym::PathBuf | sym::OsString) && is_empty_str(cx, arg)
+                || ty_name == sym::Vec && is_empty_array_or_vec(cx, arg))
+        {
+            let mut applicability = Applicability::MachineApplicable;
+            // Only `Vec` is known to be in scope for `From::from([])`
+            let ty_path = match callee.kind {
+                ExprKind::Path(QPath::TypeRelative(ty_path, _)) => {
+                    snippet_with_applicability(cx, ty_path.span, "..", &mut applicability)
+                },
+                _ if ty_name == sym::Vec => "Vec".into(),
+                _ => return,
+            };
+            span_lint_and_sugg(
+                cx,
+                EMPTY_LITERAL_CONSTRUCTOR,
+                expr.span,
+                "empty value created from an empty literal",
+                "use `new` instead",
+                format!("{ty_path}::new()"),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Checks for `""`, and for constants which are empty strings unless their value comes from a
+/// macro like `concat!`, which might produce something different depending on the configuration.
+fn is_empty_str(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if expr.span.from_expansion() || !cx.typeck_results().expr_ty(expr).peel_refs().is_str() {
+        return false;
+    }
+    if let Res::Def(DefKind::Const, def_id) = path_res(cx, expr)
+        && let Some(Node::Item(item)) = cx.tcx.hir().get_if_local(def_id)
+        && let ItemKind::Const(.., body_id) = item.kind
+        && cx.tcx.hir().body(body_id).value.span.from_expansion()
+    {
+        return false;
+    }
+    constant_is_empty(cx, expr) == Some(true)
+}
+
+/// Checks for `[]` and `vec![]`.
+fn is_empty_array_or_vec(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let Some(VecArgs::Vec([])) = VecArgs::hir(cx, expr) {
+        true
+    } else {
+        !expr.span.from_expansion() && matches!(expr.kind, ExprKind::Array([]))
+    }
+}
@@ -8,6 +8,7 @@ use rustc_middle::ty::Ty;
 use rustc_session::declare_lint_pass;
 use rustc_span::Symbol;
 use std::borrow::Cow;
+use std::fmt::Write;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -182,7 +183,7 @@ fn maybe_lint_endian_bytes(cx: &LateContext<'_>, expr: &Expr<'_>, prefix: Prefix
                 help_str.push_str("either of ");
             }
 
-            help_str.push_str(&format!("`{ty}::{}` ", lint.as_name(prefix)));
+            let _ = write!(help_str, "`{ty}::{}` ", lint.as_name(prefix));
 
             if i != len && !only_one {
                 help_str.push_str("or ");
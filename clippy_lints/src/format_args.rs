@@ -8,6 +8,7 @@ use clippy_utils::macros::{
 };
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::{implements_trait, is_type_lang_item};
+use clippy_utils::visitors::for_each_expr;
 use itertools::Itertools;
 use rustc_ast::{
     FormatArgPosition, FormatArgPositionKind, FormatArgsPiece, FormatArgumentKind, FormatCount, FormatOptions,
@@ -21,7 +22,9 @@ use rustc_middle::ty::adjustment::{Adjust, Adjustment};
 use rustc_middle::ty::Ty;
 use rustc_session::impl_lint_pass;
 use rustc_span::edition::Edition::Edition2021;
+use rustc_span::hygiene::MacroKind;
 use rustc_span::{sym, Span, Symbol};
+use std::ops::ControlFlow;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -52,7 +55,9 @@ declare_clippy_lint! {
     /// ### What it does
     /// Checks for [`ToString::to_string`](https://doc.rust-lang.org/std/string/trait.ToString.html#tymethod.to_string)
     /// applied to a type that implements [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
-    /// in a macro that does formatting.
+    /// in a macro that does formatting. Besides the standard library's formatting macros, this
+    /// includes macros marked with `#[clippy::format_args]` and other macros passing their
+    /// arguments on to `format_args!`.
     ///
     /// ### Why is this bad?
     /// Since the type implements `Display`, the use of `to_string` is
@@ -188,7 +193,6 @@ impl FormatArgs {
 impl<'tcx> LateLintPass<'tcx> for FormatArgs {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
         if let Some(macro_call) = root_macro_call_first_node(cx, expr)
-            && is_format_macro(cx, macro_call.def_id)
             && let Some(format_args) = self.format_args.get(cx, expr, macro_call.expn)
         {
             let linter = FormatArgsExpr {
@@ -199,10 +203,14 @@ impl<'tcx> LateLintPass<'tcx> for FormatArgs {
                 ignore_mixed: self.ignore_mixed,
             };
 
-            linter.check_templates();
+            if is_format_macro(cx, macro_call.def_id) {
+                linter.check_templates();
 
-            if self.msrv.meets(msrvs::FORMAT_ARGS_CAPTURE) {
-                linter.check_uninlined_args();
+                if self.msrv.meets(msrvs::FORMAT_ARGS_CAPTURE) {
+                    linter.check_uninlined_args();
+                }
+            } else if macro_call.kind == MacroKind::Bang {
+                linter.check_forwarded_args();
             }
         }
     }
@@ -236,12 +244,45 @@ impl<'a, 'tcx> FormatArgsExpr<'a, 'tcx> {
                 {
                     let name = self.cx.tcx.item_name(self.macro_call.def_id);
                     self.check_format_in_format_args(name, arg_expr);
-                    self.check_to_string_in_format_args(name, arg_expr);
+                    self.check_to_string_in_format_args(name, arg_expr, Applicability::MachineApplicable);
                 }
             }
         }
     }
 
+    /// Checks the arguments of a macro that isn't known to be `format!`-like, but passes its
+    /// arguments on to `format_args!`, e.g. a logging macro from another crate.
+    fn check_forwarded_args(&self) {
+        for piece in &self.format_args.template {
+            if let FormatArgsPiece::Placeholder(placeholder) = piece
+                && placeholder.format_trait == FormatTrait::Display
+                && placeholder.format_options == FormatOptions::default()
+                && let Ok(index) = placeholder.argument.index
+                && let Some(arg) = self.format_args.arguments.all_args().get(index)
+                && let Ok(arg_expr) = find_format_arg_expr(self.expr, arg)
+                && !self.is_aliased(index)
+                && self.is_only_used_for_formatting(arg_expr)
+            {
+                let name = self.cx.tcx.item_name(self.macro_call.def_id);
+                // The macro might still do something else with the formatted value
+                self.check_to_string_in_format_args(name, arg_expr, Applicability::MaybeIncorrect);
+            }
+        }
+    }
+
+    /// Checks that the macro doesn't also use `arg_expr` outside of `format_args!`, in which case
+    /// it's expanded more than once.
+    fn is_only_used_for_formatting(&self, arg_expr: &Expr<'_>) -> bool {
+        let mut uses = 0;
+        for_each_expr(self.cx, self.expr, |expr| {
+            if expr.span == arg_expr.span {
+                uses += 1;
+            }
+            ControlFlow::<()>::Continue(())
+        });
+        uses == 1
+    }
+
     fn check_unused_format_specifier(
         &self,
         placeholder: &FormatPlaceholder,
@@ -412,7 +453,7 @@ impl<'a, 'tcx> FormatArgsExpr<'a, 'tcx> {
         );
     }
 
-    fn check_to_string_in_format_args(&self, name: Symbol, value: &Expr<'_>) {
+    fn check_to_string_in_format_args(&self, name: Symbol, value: &Expr<'_>, applicability: Applicability) {
         let cx = self.cx;
         if !value.span.from_expansion()
             && let ExprKind::MethodCall(_, receiver, [], to_string_span) = value.kind
@@ -435,7 +476,7 @@ impl<'a, 'tcx> FormatArgsExpr<'a, 'tcx> {
                     format!("`to_string` applied to a type that implements `Display` in `{name}!` args"),
                     "remove this",
                     String::new(),
-                    Applicability::MachineApplicable,
+                    applicability,
                 );
             } else {
                 span_lint_and_sugg(
@@ -449,7 +490,7 @@ impl<'a, 'tcx> FormatArgsExpr<'a, 'tcx> {
                         if needs_ref { "&" } else { "" },
                         ""
                     ),
-                    Applicability::MachineApplicable,
+                    applicability,
                 );
             }
         }
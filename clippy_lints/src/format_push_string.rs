@@ -1,9 +1,13 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::macros::{format_args_inputs_span, root_macro_call_first_node, FormatArgsStorage};
+use clippy_utils::source::{indent_of, snippet_with_applicability, snippet_with_context};
 use clippy_utils::ty::is_type_lang_item;
 use clippy_utils::{higher, match_def_path, paths};
-use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem, MatchSource};
+use rustc_ast::{FormatArgsPiece, FormatOptions, FormatTrait};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem, MatchSource, Node, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::declare_lint_pass;
+use rustc_session::impl_lint_pass;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -11,8 +15,9 @@ declare_clippy_lint! {
     /// Detects cases where the result of a `format!` call is
     /// appended to an existing `String`.
     ///
-    /// ### Why restrict this?
-    /// Introduces an extra, avoidable heap allocation.
+    /// ### Why is this bad?
+    /// Introduces an extra, avoidable heap allocation for the temporary `String`,
+    /// whereas `write!` formats directly into the existing buffer.
     ///
     /// ### Known problems
     /// `format!` returns a `String` but `write!` returns a `Result`.
@@ -31,20 +36,44 @@ declare_clippy_lint! {
     /// use std::fmt::Write as _; // import without risk of name clashing
     ///
     /// let mut s = String::new();
-    /// let _ = write!(s, "0x{:X}", 1024);
+    /// write!(s, "0x{:X}", 1024).unwrap();
     /// ```
     #[clippy::version = "1.62.0"]
     pub FORMAT_PUSH_STRING,
-    restriction,
+    perf,
     "`format!(..)` appended to existing `String`"
 }
-declare_lint_pass!(FormatPushString => [FORMAT_PUSH_STRING]);
+
+pub struct FormatPushString {
+    format_args: FormatArgsStorage,
+}
+
+impl FormatPushString {
+    pub fn new(format_args: FormatArgsStorage) -> Self {
+        Self { format_args }
+    }
+}
+
+impl_lint_pass!(FormatPushString => [FORMAT_PUSH_STRING]);
 
 fn is_string(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
     is_type_lang_item(cx, cx.typeck_results().expr_ty(e).peel_refs(), LangItem::String)
 }
-fn is_format(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+
+/// Peels the borrow or `.as_str()` call turning the temporary `String` into a `&str`.
+fn peel_as_str<'tcx>(e: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
     let e = e.peel_blocks().peel_borrows();
+    if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+        && path.ident.name == sym::as_str
+    {
+        recv.peel_blocks()
+    } else {
+        e
+    }
+}
+
+fn is_format(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let e = peel_as_str(e);
 
     if e.span.from_expansion()
         && let Some(macro_def_id) = e.span.ctxt().outer_expn_data().macro_def_id
@@ -67,20 +96,28 @@ fn is_format(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
 
 impl<'tcx> LateLintPass<'tcx> for FormatPushString {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        let arg = match expr.kind {
-            ExprKind::MethodCall(_, _, [arg], _) => {
+        let (string, arg) = match expr.kind {
+            ExprKind::MethodCall(_, recv, [arg], _) => {
                 if let Some(fn_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
                     && match_def_path(cx, fn_def_id, &paths::PUSH_STR)
                 {
-                    arg
+                    (recv, arg)
                 } else {
                     return;
                 }
             },
-            ExprKind::AssignOp(op, left, arg) if op.node == BinOpKind::Add && is_string(cx, left) => arg,
+            ExprKind::AssignOp(op, left, arg) if op.node == BinOpKind::Add && is_string(cx, left) => (left, arg),
             _ => return,
         };
-        if is_format(cx, arg) {
+        if !is_format(cx, arg) {
+            return;
+        }
+
+        let format = peel_as_str(arg);
+        let Some(macro_call) = root_macro_call_first_node(cx, format)
+            .filter(|macro_call| cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id))
+        else {
+            // One of several `format!` calls in the branches of an `if` or `match`
             span_lint_and_help(
                 cx,
                 FORMAT_PUSH_STRING,
@@ -89,6 +126,53 @@ impl<'tcx> LateLintPass<'tcx> for FormatPushString {
                 None,
                 "consider using `write!` to avoid the extra allocation",
             );
+            return;
+        };
+        let Some(format_args) = self.format_args.get(cx, format, macro_call.expn) else {
+            return;
+        };
+        // `format!("{}", x)` is covered by `useless_format` and `to_string`-specific lints
+        if let ([_], [FormatArgsPiece::Placeholder(placeholder)]) =
+            (format_args.arguments.all_args(), &format_args.template[..])
+            && placeholder.format_trait == FormatTrait::Display
+            && placeholder.format_options == FormatOptions::default()
+        {
+            return;
         }
+
+        span_lint_and_then(
+            cx,
+            FORMAT_PUSH_STRING,
+            expr.span,
+            "`format!(..)` appended to existing `String`",
+            |diag| {
+                let mut app = Applicability::MaybeIncorrect;
+                let string = snippet_with_context(cx, string.span, expr.span.ctxt(), "..", &mut app).0;
+                let inputs = snippet_with_applicability(cx, format_args_inputs_span(format_args), "..", &mut app);
+                let write = format!("write!({string}, {inputs}).unwrap()");
+                // `write!` needs `std::fmt::Write` in scope, import it right before the statement
+                if let Node::Stmt(stmt) = cx.tcx.parent_hir_node(expr.hir_id)
+                    && let StmtKind::Semi(_) = stmt.kind
+                {
+                    let indent = " ".repeat(indent_of(cx, stmt.span).unwrap_or(0));
+                    diag.multipart_suggestion(
+                        "consider using `write!` to avoid the extra allocation",
+                        vec![
+                            (stmt.span.shrink_to_lo(), format!("use std::fmt::Write as _;\n{indent}")),
+                            (expr.span, write),
+                        ],
+                        app,
+                    );
+                } else {
+                    diag.span_suggestion(
+                        expr.span,
+                        "consider using `write!` to avoid the extra allocation",
+                        write,
+                        app,
+                    );
+                    diag.note("`write!` requires `std::fmt::Write` to be in scope");
+                }
+            },
+        );
     }
 }
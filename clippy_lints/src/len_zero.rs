@@ -320,10 +320,9 @@ fn extract_future_output<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<&
 
 fn is_first_generic_integral<'tcx>(segment: &'tcx PathSegment<'tcx>) -> bool {
     if let Some(generic_args) = segment.args {
-        if generic_args.args.is_empty() {
+        let Some(arg) = generic_args.args.first() else {
             return false;
-        }
-        let arg = &generic_args.args[0];
+        };
         if let GenericArg::Type(rustc_hir::Ty {
             kind: TyKind::Path(QPath::Resolved(_, path)),
             ..
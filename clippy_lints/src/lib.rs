@@ -121,6 +121,7 @@ mod duplicate_mod;
 mod else_if_without_else;
 mod empty_drop;
 mod empty_enum;
+mod empty_literal_constructor;
 mod empty_with_brackets;
 mod endian_bytes;
 mod entry;
@@ -192,6 +193,7 @@ mod let_if_seq;
 mod let_underscore;
 mod let_with_type_underscore;
 mod lifetimes;
+mod linear_search_in_loop;
 mod lines_filter_map_ok;
 mod literal_representation;
 mod loops;
@@ -202,6 +204,7 @@ mod manual_assert;
 mod manual_async_fn;
 mod manual_bits;
 mod manual_clamp;
+mod manual_collect;
 mod manual_float_methods;
 mod manual_hash_one;
 mod manual_is_ascii_check;
@@ -317,6 +320,7 @@ mod self_named_constructors;
 mod semicolon_block;
 mod semicolon_if_nothing_returned;
 mod serde_api;
+mod set_contains_or_insert;
 mod shadow;
 mod significant_drop_tightening;
 mod single_call_fn;
@@ -381,8 +385,10 @@ mod write;
 mod zero_div_zero;
 mod zero_repeat_side_effects;
 mod zero_sized_map_values;
+mod zombie_processes;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
+use clippy_config::types::ArithmeticSideEffectsAllowedBinary;
 use clippy_config::{get_configuration_metadata, Conf};
 use clippy_utils::macros::FormatArgsStorage;
 use rustc_data_structures::fx::FxHashSet;
@@ -545,6 +551,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         ref allowed_idents_below_min_chars,
         ref allowed_scripts,
         ref allowed_wildcard_imports,
+        arithmetic_side_effects_allow_in_tests,
         ref arithmetic_side_effects_allowed_binary,
         ref arithmetic_side_effects_allowed_unary,
         ref arithmetic_side_effects_allowed,
@@ -567,7 +574,9 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         future_size_threshold,
         ref ignore_interior_mutability,
         large_error_threshold,
+        linear_search_array_threshold,
         literal_representation_threshold,
+        local_exhaustive_enum_variant_threshold,
         matches_for_let_else,
         max_fn_params_bools,
         max_include_file_size,
@@ -575,6 +584,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         max_suggested_slice_pattern_length,
         max_trait_bounds,
         min_ident_chars_threshold,
+        min_target_pointer_width,
         missing_docs_in_crate_items,
         ref msrv,
         pass_by_value_size_limit,
@@ -661,6 +671,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
             arithmetic_side_effects_allowed
                 .iter()
                 .flat_map(|el| [[el.clone(), "*".to_string()], ["*".to_string(), el.clone()]])
+                .map(ArithmeticSideEffectsAllowedBinary::Pair)
                 .chain(arithmetic_side_effects_allowed_binary.clone())
                 .collect(),
             arithmetic_side_effects_allowed
@@ -668,6 +679,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
                 .chain(arithmetic_side_effects_allowed_unary.iter())
                 .cloned()
                 .collect(),
+            arithmetic_side_effects_allow_in_tests,
         ))
     });
     store.register_late_pass(|_| Box::new(utils::dump_hir::DumpHir));
@@ -722,7 +734,9 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
             format_args.clone(),
         ))
     });
-    store.register_late_pass(move |_| Box::new(matches::Matches::new(msrv())));
+    store.register_late_pass(move |_| {
+        Box::new(matches::Matches::new(msrv(), local_exhaustive_enum_variant_threshold))
+    });
     store.register_early_pass(move || Box::new(manual_non_exhaustive::ManualNonExhaustiveStruct::new(msrv())));
     store.register_late_pass(move |_| Box::new(manual_non_exhaustive::ManualNonExhaustiveEnum::new(msrv())));
     store.register_late_pass(move |_| Box::new(manual_strip::ManualStrip::new(msrv())));
@@ -735,7 +749,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(use_self::UseSelf::new(msrv())));
     store.register_late_pass(move |_| Box::new(missing_const_for_fn::MissingConstForFn::new(msrv())));
     store.register_late_pass(move |_| Box::new(needless_question_mark::NeedlessQuestionMark));
-    store.register_late_pass(move |_| Box::new(casts::Casts::new(msrv())));
+    store.register_late_pass(move |_| Box::new(casts::Casts::new(msrv(), min_target_pointer_width)));
     store.register_early_pass(move || Box::new(unnested_or_patterns::UnnestedOrPatterns::new(msrv())));
     store.register_late_pass(|_| Box::new(size_of_in_element_count::SizeOfInElementCount));
     store.register_late_pass(|_| Box::new(same_name_method::SameNameMethod));
@@ -1010,7 +1024,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_early_pass(|| Box::new(empty_with_brackets::EmptyWithBrackets));
     store.register_late_pass(|_| Box::new(unnecessary_owned_empty_strings::UnnecessaryOwnedEmptyStrings));
     store.register_early_pass(|| Box::new(pub_use::PubUse));
-    store.register_late_pass(|_| Box::new(format_push_string::FormatPushString));
+    let format_args = format_args_storage.clone();
+    store.register_late_pass(move |_| Box::new(format_push_string::FormatPushString::new(format_args.clone())));
     store.register_late_pass(move |_| Box::new(large_include_file::LargeIncludeFile::new(max_include_file_size)));
     store.register_late_pass(|_| Box::new(strings::TrimSplitWhitespace));
     store.register_late_pass(|_| Box::new(rc_clone_in_vec_init::RcCloneInVecInit));
@@ -1027,6 +1042,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         Box::new(operators::Operators::new(
             verbose_bit_mask_threshold,
             allow_comparison_to_zero,
+            msrv(),
         ))
     });
     store.register_late_pass(|_| Box::<std_instead_of_core::StdReexports>::default());
@@ -1171,6 +1187,13 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     });
     store.register_late_pass(move |_| Box::new(string_patterns::StringPatterns::new(msrv())));
     store.register_early_pass(|| Box::new(field_scoped_visibility_modifiers::FieldScopedVisibilityModifiers));
+    store.register_late_pass(|_| Box::new(zombie_processes::ZombieProcesses));
+    store.register_late_pass(|_| Box::new(manual_collect::ManualCollect));
+    store.register_late_pass(|_| Box::new(empty_literal_constructor::EmptyLiteralConstructor));
+    store.register_late_pass(|_| Box::new(set_contains_or_insert::SetContainsOrInsert));
+    store.register_late_pass(move |_| {
+        Box::new(linear_search_in_loop::LinearSearchInLoop::new(linear_search_array_threshold))
+    });
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
 use clippy_utils::trait_ref_of_method;
 use itertools::Itertools;
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexSet};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::nested_filter::{self as hir_nested_filter, NestedFilter};
 use rustc_hir::intravisit::{
@@ -92,12 +92,14 @@ declare_lint_pass!(Lifetimes => [NEEDLESS_LIFETIMES, EXTRA_UNUSED_LIFETIMES]);
 
 impl<'tcx> LateLintPass<'tcx> for Lifetimes {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
-        if let ItemKind::Fn(ref sig, generics, id) = item.kind {
-            check_fn_inner(cx, sig, Some(id), None, generics, item.span, true);
-        } else if let ItemKind::Impl(impl_) = item.kind {
-            if !item.span.from_expansion() {
+        match item.kind {
+            ItemKind::Fn(ref sig, generics, id) => {
+                check_fn_inner(cx, sig, Some(id), None, generics, item.span, true);
+            },
+            ItemKind::Impl(impl_) if !item.span.from_expansion() => {
                 report_extra_impl_lifetimes(cx, impl_);
-            }
+            },
+            _ => {},
         }
     }
 
@@ -197,7 +199,6 @@ fn check_fn_inner<'tcx>(
             // `Node::GenericParam`.
             .filter_map(|&def_id| cx.tcx.hir_node_by_def_id(def_id).ident())
             .map(|ident| ident.to_string())
-            .collect::<Vec<_>>()
             .join(", ");
 
         span_lint_and_then(
@@ -239,7 +240,7 @@ fn check_fn_inner<'tcx>(
 fn elision_suggestions(
     cx: &LateContext<'_>,
     generics: &Generics<'_>,
-    elidable_lts: &[LocalDefId],
+    elidable_lts: &FxIndexSet<LocalDefId>,
     usages: &[Lifetime],
 ) -> Option<Vec<(Span, String)>> {
     let explicit_params = generics
@@ -333,7 +334,7 @@ fn could_use_elision<'tcx>(
     body: Option<BodyId>,
     trait_sig: Option<&[Ident]>,
     named_generics: &'tcx [GenericParam<'_>],
-) -> Option<(Vec<LocalDefId>, Vec<Lifetime>)> {
+) -> Option<(FxIndexSet<LocalDefId>, Vec<Lifetime>)> {
     // There are two scenarios where elision works:
     // * no output references, all input references have different LT
     // * output references, exactly one input reference with same LT
@@ -428,7 +429,7 @@ fn could_use_elision<'tcx>(
                 None
             }
         })
-        .collect::<Vec<_>>();
+        .collect::<FxIndexSet<_>>();
 
     if elidable_lts.is_empty() {
         return None;
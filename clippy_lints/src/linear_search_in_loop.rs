@@ -0,0 +1,279 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{higher, is_trait_method, path_to_local, path_to_local_id, peel_blocks, peel_ref_operators};
+use rustc_ast::LitKind;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, LoopSource, Node, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for linear searches through a `Vec` or a slice, with `contains`, `iter().any(..)`
+    /// or `iter().position(..)`, inside a loop or an iterator adapter, when the searched
+    /// collection isn't modified by the loop.
+    ///
+    /// ### Why is this bad?
+    /// Each search goes through the whole collection, so the loop takes time proportional to
+    /// the product of the lengths of both collections. Collecting the searched collection into a
+    /// `HashSet` or a `BTreeSet` once before the loop makes each search cheap.
+    ///
+    /// ### Known problems
+    /// For small collections, the linear search may well be faster than building a set. Arrays
+    /// up to the length configured with `linear-search-array-threshold` aren't linted, and
+    /// neither are function parameters and borrowed slices stored in fields, like the lists of
+    /// the HIR, whose type is chosen by the caller and which are usually short.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn count_blocked(names: &[String], blocked: &str) -> usize {
+    ///     let blocked: Vec<&str> = blocked.lines().collect();
+    ///     names.iter().filter(|name| blocked.contains(&name.as_str())).count()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::HashSet;
+    /// fn count_blocked(names: &[String], blocked: &str) -> usize {
+    ///     let blocked: HashSet<&str> = blocked.lines().collect();
+    ///     names.iter().filter(|name| blocked.contains(name.as_str())).count()
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub LINEAR_SEARCH_IN_LOOP,
+    pedantic,
+    "searching a `Vec` or a slice linearly inside a loop"
+}
+
+pub struct LinearSearchInLoop {
+    array_threshold: u64,
+}
+
+impl LinearSearchInLoop {
+    #[must_use]
+    pub fn new(array_threshold: u64) -> Self {
+        Self { array_threshold }
+    }
+
+    /// Checks if `e` evaluates to an array or a range of literals no longer than the threshold,
+    /// looking through references and method calls on it.
+    fn is_small(&self, cx: &LateContext<'_>, mut e: &Expr<'_>) -> bool {
+        loop {
+            if let Some(higher::Range {
+                start: Some(start),
+                end: Some(end),
+                ..
+            }) = higher::Range::hir(e)
+                && let ExprKind::Lit(start) = start.kind
+                && let ExprKind::Lit(end) = end.kind
+                && let (LitKind::Int(start, _), LitKind::Int(end, _)) = (&start.node, &end.node)
+            {
+                return end.get().saturating_sub(start.get()) <= u128::from(self.array_threshold);
+            }
+            match e.kind {
+                ExprKind::MethodCall(_, recv, ..) => e = recv,
+                ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) => e = inner,
+                _ => return self.is_small_array(cx, cx.typeck_results().expr_ty(e).peel_refs()),
+            }
+        }
+    }
+
+    /// Checks if `ty` is an array whose length is unknown or no longer than the threshold.
+    fn is_small_array<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+        if let ty::Array(_, len) = ty.kind() {
+            !len.try_eval_target_usize(cx.tcx, cx.param_env)
+                .is_some_and(|len| len > self.array_threshold)
+        } else {
+            false
+        }
+    }
+}
+
+impl_lint_pass!(LinearSearchInLoop => [LINEAR_SEARCH_IN_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for LinearSearchInLoop {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !expr.span.from_expansion()
+            && let Some(haystack) = searched_slice(cx, expr)
+            && let Some(local) = base_local(haystack)
+            && !is_chosen_by_caller(cx, haystack)
+            && let haystack_ty = cx.typeck_results().expr_ty(haystack).peel_refs()
+            && !self.is_small_array(cx, haystack_ty)
+            && let Some(set) = elem_ty(cx, haystack_ty).and_then(|ty| set_type(cx, ty))
+            && let Some(repeated) = enclosing_repetition(cx, expr)
+            && !repeated.expr.span.contains(cx.tcx.hir().span(local))
+            && !repeated.iterated.is_some_and(|iterated| self.is_small(cx, iterated))
+            && mutated_variables(repeated.expr, cx).is_some_and(|mutated| !mutated.contains(&local))
+        {
+            span_lint_and_then(
+                cx,
+                LINEAR_SEARCH_IN_LOOP,
+                expr.span,
+                format!("searching `{}` linearly inside a {}", snippet(cx, haystack.span, ".."), repeated.kind),
+                |diag| {
+                    diag.span_note(repeated.span, format!("the {} is here", repeated.kind));
+                    let alternative = if set == "BTreeSet" {
+                        ", or sorting it and using `binary_search`"
+                    } else {
+                        ""
+                    };
+                    diag.help(format!("consider collecting it into a `{set}` beforehand{alternative}"));
+                },
+            );
+        }
+    }
+}
+
+/// Gets the searched slice of `slice.contains(x)`, `slice.iter().any(|y| y == x)` or
+/// `slice.iter().position(|y| y == x)`.
+fn searched_slice<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::MethodCall(method, recv, [arg], _) = expr.kind else {
+        return None;
+    };
+    let haystack = match method.ident.as_str() {
+        "contains" => recv,
+        "any" | "position"
+            if is_trait_method(cx, expr, sym::Iterator)
+                && let ExprKind::MethodCall(iter, haystack, [], _) = recv.kind
+                && iter.ident.name == sym::iter
+                && is_eq_closure(cx, arg) =>
+        {
+            haystack
+        },
+        _ => return None,
+    };
+    cx.typeck_results()
+        .expr_ty_adjusted(haystack)
+        .peel_refs()
+        .is_slice()
+        .then_some(haystack)
+}
+
+/// Checks if `e` is a closure comparing its parameter with something else for equality.
+fn is_eq_closure(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    if let ExprKind::Closure(closure) = e.kind
+        && let body = cx.tcx.hir().body(closure.body)
+        && let [param] = body.params
+        && let PatKind::Binding(_, id, _, None) = match param.pat.kind {
+            PatKind::Ref(inner, _) => inner.kind,
+            kind => kind,
+        }
+        && let ExprKind::Binary(op, lhs, rhs) = peel_blocks(body.value).kind
+        && op.node == BinOpKind::Eq
+    {
+        let is_param = |e: &Expr<'_>| path_to_local_id(peel_ref_operators(cx, e), id);
+        (is_param(lhs) && !is_local_used(cx, rhs, id)) || (is_param(rhs) && !is_local_used(cx, lhs, id))
+    } else {
+        false
+    }
+}
+
+/// Gets the local `e` refers to, possibly through fields and references.
+fn base_local(e: &Expr<'_>) -> Option<HirId> {
+    match e.kind {
+        ExprKind::Field(base, _) | ExprKind::AddrOf(_, _, base) | ExprKind::Unary(UnOp::Deref, base) => {
+            base_local(base)
+        },
+        _ => path_to_local(e),
+    }
+}
+
+/// Checks if `haystack` is a function parameter, or a borrowed slice stored in a field.
+fn is_chosen_by_caller(cx: &LateContext<'_>, haystack: &Expr<'_>) -> bool {
+    match haystack.kind {
+        ExprKind::Field(..) => {
+            if let ty::Ref(_, ty, _) = cx.typeck_results().expr_ty(haystack).kind() {
+                ty.is_slice()
+            } else {
+                false
+            }
+        },
+        _ => path_to_local(haystack).is_some_and(|local| matches!(cx.tcx.parent_hir_node(local), Node::Param(_))),
+    }
+}
+
+fn elem_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    match ty.kind() {
+        ty::Slice(elem) | ty::Array(elem, _) => Some(*elem),
+        ty::Adt(_, args) if is_type_diagnostic_item(cx, ty, sym::Vec) => Some(args.type_at(0)),
+        _ => None,
+    }
+}
+
+/// Gets the name of the set the elements could be collected into.
+fn set_type<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<&'static str> {
+    let implements = |item| {
+        cx.tcx
+            .get_diagnostic_item(item)
+            .is_some_and(|id| implements_trait(cx, ty, id, &[]))
+    };
+    if implements(sym::Hash) && implements(sym::Eq) {
+        Some("HashSet")
+    } else if implements(sym::Ord) {
+        Some("BTreeSet")
+    } else {
+        None
+    }
+}
+
+struct Repetition<'tcx> {
+    /// The loop, or the body of the closure.
+    expr: &'tcx Expr<'tcx>,
+    /// What is iterated over, if known.
+    iterated: Option<&'tcx Expr<'tcx>>,
+    span: Span,
+    kind: &'static str,
+}
+
+/// Finds the innermost loop or closure passed to an iterator adapter containing `expr`.
+fn enclosing_repetition<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<Repetition<'tcx>> {
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        let Node::Expr(e) = node else {
+            if matches!(node, Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_)) {
+                return None;
+            }
+            continue;
+        };
+        if let Some(for_loop) = higher::ForLoop::hir(e) {
+            if for_loop.body.span.contains(expr.span) {
+                return Some(Repetition {
+                    expr: e,
+                    iterated: Some(for_loop.arg),
+                    span: for_loop.span.with_hi(for_loop.arg.span.hi()),
+                    kind: "loop",
+                });
+            }
+        } else if let ExprKind::Loop(_, _, source, head) = e.kind
+            && source != LoopSource::ForLoop
+        {
+            return Some(Repetition {
+                expr: e,
+                iterated: None,
+                span: e.span.with_hi(head.hi()),
+                kind: "loop",
+            });
+        } else if let ExprKind::Closure(closure) = e.kind {
+            // Closures run once per element when they are passed to iterator adapters, but
+            // otherwise nothing is known about how often they are called.
+            return if let Node::Expr(call) = cx.tcx.parent_hir_node(e.hir_id)
+                && let ExprKind::MethodCall(_, recv, args, _) = call.kind
+                && args.iter().any(|arg| arg.hir_id == e.hir_id)
+                && is_trait_method(cx, call, sym::Iterator)
+            {
+                Some(Repetition {
+                    expr: cx.tcx.hir().body(closure.body).value,
+                    iterated: Some(recv),
+                    span: e.span,
+                    kind: "closure",
+                })
+            } else {
+                None
+            };
+        }
+    }
+    None
+}
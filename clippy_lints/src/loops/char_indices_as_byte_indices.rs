@@ -0,0 +1,217 @@
+use super::CHAR_INDICES_AS_BYTE_INDICES;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::usage::local_used_in;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_expr, peel_ref_operators, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_errors::{Applicability, Diag};
+use rustc_hir::{Expr, ExprKind, HirId, LangItem, MatchSource, Node, Pat, PatKind, TyKind};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+/// Methods of `str` and `String` taking a byte index, or a range of them, as their first argument.
+const BYTE_INDEX_METHODS: &[&str] = &[
+    "ceil_char_boundary",
+    "drain",
+    "floor_char_boundary",
+    "get",
+    "get_mut",
+    "get_unchecked",
+    "get_unchecked_mut",
+    "insert",
+    "insert_str",
+    "is_char_boundary",
+    "remove",
+    "replace_range",
+    "split_at",
+    "split_at_checked",
+    "split_at_mut",
+    "split_at_mut_checked",
+    "split_off",
+    "truncate",
+];
+
+const NOTE: &str = "a character can take up more than one byte, so they are not interchangeable";
+
+/// Checks for `for (i, c) in s.chars().enumerate()` loops using `i` as a byte index.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    if let PatKind::Tuple([idx_pat, _], _) = pat.kind
+        && let PatKind::Binding(_, idx_id, _, None) = idx_pat.kind
+        && let ExprKind::MethodCall(enumerate, chars_call, [], _) = arg.kind
+        && enumerate.ident.as_str() == "enumerate"
+        && let Some(string) = chars_recv(cx, chars_call)
+        && !arg.span.from_expansion()
+    {
+        let mut app = Applicability::MaybeIncorrect;
+        let string_snippet = snippet_with_applicability(cx, string.span, "..", &mut app);
+        lint_uses(cx, string, idx_id, body, |diag| {
+            diag.span_suggestion(
+                arg.span,
+                "consider using `.char_indices()` instead",
+                format!("{string_snippet}.char_indices()"),
+                app,
+            );
+        });
+    }
+}
+
+/// Checks for `s.chars().position(..)` whose result is later used as a byte index.
+pub(super) fn check_position<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    if let ExprKind::MethodCall(position, chars_call, [pred], _) = expr.kind
+        && position.ident.as_str() == "position"
+        && let Some(string) = chars_recv(cx, chars_call)
+        && let ExprKind::MethodCall(chars, ..) = chars_call.kind
+        && !expr.span.from_expansion()
+        && let Some(idx_id) = bound_index(cx, expr)
+        && let Some(body_id) = cx.enclosing_body
+    {
+        let body = cx.tcx.hir().body(body_id).value;
+        lint_uses(cx, string, idx_id, body, |diag| {
+            let mut sugg = vec![(chars.ident.span.to(position.ident.span), "find".to_string())];
+            // `str::find` can't infer the argument type of the closure by itself.
+            if let ExprKind::Closure(closure) = pred.kind
+                && let [input] = closure.fn_decl.inputs
+                && matches!(input.kind, TyKind::Infer)
+            {
+                let param = cx.tcx.hir().body(closure.body).params[0].pat;
+                sugg.push((param.span.shrink_to_hi(), ": char".to_string()));
+            }
+            diag.multipart_suggestion(
+                "consider using `str::find` to get a byte index instead",
+                sugg,
+                Applicability::MaybeIncorrect,
+            );
+        });
+    }
+}
+
+/// Gets the string `s` of a `s.chars()` call.
+fn chars_recv<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(chars, string, [], _) = e.kind
+        && chars.ident.as_str() == "chars"
+        && cx.typeck_results().expr_ty_adjusted(string).peel_refs().is_str()
+    {
+        Some(string)
+    } else {
+        None
+    }
+}
+
+/// Gets the local the index found by `position` is bound to, through `let i = ..?;`,
+/// `let i = ...unwrap();`, `let Some(i) = .. else { .. };` or `if let Some(i) = ..`.
+fn bound_index(cx: &LateContext<'_>, position: &Expr<'_>) -> Option<HirId> {
+    let mut e = position;
+    let mut unwrapped = false;
+    if let Some(parent) = get_parent_expr(cx, e)
+        && let ExprKind::MethodCall(method, recv, _, _) = parent.kind
+        && recv.hir_id == e.hir_id
+        && matches!(method.ident.as_str(), "unwrap" | "expect")
+    {
+        e = parent;
+        unwrapped = true;
+    } else if let Some(branch) = get_parent_expr(cx, e)
+        && let ExprKind::Call(_, [arg]) = branch.kind
+        && arg.hir_id == e.hir_id
+        && let Some(try_expr) = get_parent_expr(cx, branch)
+        && let ExprKind::Match(_, _, MatchSource::TryDesugar(_)) = try_expr.kind
+    {
+        e = try_expr;
+        unwrapped = true;
+    }
+
+    let pat = match cx.tcx.parent_hir_node(e.hir_id) {
+        Node::LetStmt(local) => local.pat,
+        Node::Expr(Expr {
+            kind: ExprKind::Let(let_expr),
+            ..
+        }) => let_expr.pat,
+        _ => return None,
+    };
+    let pat = if unwrapped {
+        pat
+    } else if let PatKind::TupleStruct(_, [inner], _) = pat.kind {
+        inner
+    } else {
+        return None;
+    };
+    if let PatKind::Binding(_, id, _, None) = pat.kind {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+/// Lints uses of `idx_id` as a byte index of a string within `body`. Only uses on `string` get the
+/// suggestion, as those are the ones switching to byte indices fixes.
+fn lint_uses<'tcx>(
+    cx: &LateContext<'tcx>,
+    string: &Expr<'_>,
+    idx_id: HirId,
+    body: &'tcx Expr<'tcx>,
+    sugg: impl FnOnce(&mut Diag<'_, ()>),
+) {
+    let mut same_string = Vec::new();
+    let mut other_string = Vec::new();
+    let _: Option<!> = for_each_expr(cx, body, |e| {
+        if let Some((indexed, span)) = byte_index_use(cx, e, idx_id) {
+            if SpanlessEq::new(cx).eq_expr(peel_ref_operators(cx, indexed), peel_ref_operators(cx, string)) {
+                same_string.push(span);
+            } else {
+                other_string.push(span);
+            }
+        }
+        ControlFlow::Continue(())
+    });
+
+    if !same_string.is_empty() {
+        span_lint_and_then(
+            cx,
+            CHAR_INDICES_AS_BYTE_INDICES,
+            same_string,
+            "using a character position of a string as a byte index into it",
+            |diag| {
+                diag.note(NOTE);
+                sugg(diag);
+            },
+        );
+    } else if !other_string.is_empty() {
+        span_lint_and_then(
+            cx,
+            CHAR_INDICES_AS_BYTE_INDICES,
+            other_string,
+            "using a character position of a string as a byte index into another string",
+            |diag| {
+                diag.note(NOTE);
+            },
+        );
+    }
+}
+
+/// Gets the string and the span of `e` if it uses the index as a byte index of a string, either
+/// by indexing it or by passing it to a method taking a byte index.
+fn byte_index_use<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'tcx>,
+    idx_id: HirId,
+) -> Option<(&'tcx Expr<'tcx>, Span)> {
+    match e.kind {
+        ExprKind::Index(indexed, idx, _) if is_string(cx, indexed) && local_used_in(cx, idx_id, idx) => {
+            Some((indexed, e.span))
+        },
+        ExprKind::MethodCall(method, recv, args, _)
+            if BYTE_INDEX_METHODS.contains(&method.ident.as_str())
+                && is_string(cx, recv)
+                && args.first().is_some_and(|arg| local_used_in(cx, idx_id, arg)) =>
+        {
+            Some((recv, e.span))
+        },
+        _ => None,
+    }
+}
+
+fn is_string(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(e).peel_refs();
+    ty.is_str() || is_type_lang_item(cx, ty, LangItem::String)
+}
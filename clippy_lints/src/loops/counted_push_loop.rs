@@ -0,0 +1,135 @@
+use super::utils::{get_single_push, is_invariant};
+use super::COUNTED_PUSH_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::usage::{contains_return_break_continue_macro, local_used_in};
+use clippy_utils::{
+    higher, is_expr_path_def_path, is_integer_const, is_lint_allowed, path_to_local, path_to_local_id, paths,
+};
+use rustc_ast::ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Expr, ExprKind, Node, Pat, PatKind, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+/// Checks for `for` loops over `0..n` pushing a single element per iteration into a `Vec` created
+/// by `Vec::new()` right before the loop. Returns whether the lint was emitted.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+) -> bool {
+    if is_lint_allowed(cx, COUNTED_PUSH_LOOP, expr.hir_id) {
+        return false;
+    }
+    let loop_var = match pat.kind {
+        PatKind::Wild => None,
+        PatKind::Binding(_, id, _, None) => Some(id),
+        _ => return false,
+    };
+    if let Some(higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits: RangeLimits::HalfOpen,
+    }) = higher::Range::hir(arg)
+        && is_integer_const(cx, start, 0)
+        && is_count(end)
+        // Both `vec!` and `Vec::with_capacity` take a `usize`
+        && cx.typeck_results().expr_ty(end).is_usize()
+        && let Some((vec, pushed)) = get_single_push(cx, body)
+        && let Some(vec_id) = path_to_local(vec)
+        && !local_used_in(cx, vec_id, pushed)
+        && !local_used_in(cx, vec_id, end)
+        && !contains_return_break_continue_macro(pushed)
+        // The loop must directly follow `let mut vec = Vec::new();`
+        && let Node::Stmt(loop_stmt) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let Node::Block(block) = cx.tcx.parent_hir_node(loop_stmt.hir_id)
+        && let Some(loop_idx) = block.stmts.iter().position(|stmt| stmt.hir_id == loop_stmt.hir_id)
+        && let Some(let_stmt) = loop_idx.checked_sub(1).map(|idx| &block.stmts[idx])
+        && let StmtKind::Let(local) = let_stmt.kind
+        && local.els.is_none()
+        && let PatKind::Binding(BindingMode::MUT, id, _, None) = local.pat.kind
+        && id == vec_id
+        && let Some(init) = local.init
+        && let ExprKind::Call(func, []) = init.kind
+        && is_expr_path_def_path(cx, func, &paths::VEC_NEW)
+        && !let_stmt.span.from_expansion()
+        && !loop_stmt.span.from_expansion()
+        && !pushed.span.from_expansion()
+    {
+        let span = let_stmt.span.to(loop_stmt.span);
+        let mut app = Applicability::MachineApplicable;
+        let count = snippet_with_applicability(cx, end.span, "..", &mut app);
+        let elem = snippet_with_applicability(cx, pushed.span, "..", &mut app);
+        let let_head = snippet_with_applicability(cx, let_stmt.span.until(init.span), "..", &mut app);
+        let uses_loop_var = loop_var.is_some_and(|id| local_used_in(cx, id, pushed));
+        let pushed_ty = cx.typeck_results().expr_ty(pushed);
+
+        span_lint_and_then(
+            cx,
+            COUNTED_PUSH_LOOP,
+            span,
+            "this `Vec` is filled by pushing one element at a time in a counted loop",
+            |diag| {
+                if !uses_loop_var
+                    && is_invariant(cx, pushed)
+                    && cx
+                        .tcx
+                        .lang_items()
+                        .clone_trait()
+                        .is_some_and(|id| implements_trait(cx, pushed_ty, id, &[]))
+                {
+                    diag.span_suggestion(
+                        span,
+                        "create the `Vec` with `vec!` instead",
+                        format!("{let_head}vec![{elem}; {count}];"),
+                        app,
+                    );
+                } else if !pushed.can_have_side_effects() {
+                    let iter = if loop_var.is_some_and(|id| path_to_local_id(pushed, id)) {
+                        format!("(0..{count})")
+                    } else {
+                        let pat = snippet_with_applicability(cx, pat.span, "..", &mut app);
+                        format!("(0..{count}).map(|{pat}| {elem})")
+                    };
+                    let collect = if local.ty.is_some() {
+                        "collect()"
+                    } else {
+                        "collect::<Vec<_>>()"
+                    };
+                    diag.span_suggestion(
+                        span,
+                        "collect the elements instead",
+                        format!("{let_head}{iter}.{collect};"),
+                        Applicability::MaybeIncorrect,
+                    );
+                } else {
+                    // Rewriting the loop would change when the side effects of the pushed value happen.
+                    diag.span_suggestion(
+                        init.span,
+                        "allocate the needed capacity up front",
+                        format!("Vec::with_capacity({count})"),
+                        app,
+                    );
+                }
+            },
+        );
+        true
+    } else {
+        false
+    }
+}
+
+/// Checks if the end of the range is a literal, a path or the length of a path.
+fn is_count(end: &Expr<'_>) -> bool {
+    match end.kind {
+        ExprKind::Lit(_) | ExprKind::Path(_) => true,
+        ExprKind::MethodCall(method, recv, [], _) => {
+            method.ident.name == sym::len && matches!(recv.kind, ExprKind::Path(_) | ExprKind::Field(..))
+        },
+        _ => false,
+    }
+}
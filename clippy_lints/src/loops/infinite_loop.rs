@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::{fn_def_id, is_from_proc_macro, is_lint_allowed};
+use clippy_utils::{fn_def_id, is_from_proc_macro, is_lint_allowed, trait_ref_of_method};
 use hir::intravisit::{walk_expr, Visitor};
 use hir::{Expr, ExprKind, FnRetTy, FnSig, Node};
 use rustc_ast::Label;
@@ -21,7 +21,7 @@ pub(super) fn check<'tcx>(
     }
 
     // Skip check if this loop is not in a function/method/closure. (In some weird case)
-    let Some(parent_fn_ret) = get_parent_fn_ret_ty(cx, expr) else {
+    let Some((parent_fn_ret, can_change_ret)) = get_parent_fn_ret_ty(cx, expr) else {
         return;
     };
     // Or, its parent function is already returning `Never`
@@ -51,7 +51,9 @@ pub(super) fn check<'tcx>(
 
     if !is_finite_loop {
         span_lint_and_then(cx, INFINITE_LOOP, expr.span, "infinite loop detected", |diag| {
-            if let FnRetTy::DefaultReturn(ret_span) = parent_fn_ret {
+            if let FnRetTy::DefaultReturn(ret_span) = parent_fn_ret
+                && can_change_ret
+            {
                 diag.span_suggestion(
                     ret_span,
                     "if this is intentional, consider specifying `!` as function return",
@@ -65,7 +67,9 @@ pub(super) fn check<'tcx>(
     }
 }
 
-fn get_parent_fn_ret_ty<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<FnRetTy<'tcx>> {
+/// Gets the return type of the function, method or closure containing `expr`, and whether it
+/// can be changed on its own.
+fn get_parent_fn_ret_ty<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<(FnRetTy<'tcx>, bool)> {
     for (_, parent_node) in cx.tcx.hir().parent_iter(expr.hir_id) {
         match parent_node {
             Node::Item(hir::Item {
@@ -75,15 +79,18 @@ fn get_parent_fn_ret_ty<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option
             | Node::TraitItem(hir::TraitItem {
                 kind: hir::TraitItemKind::Fn(FnSig { decl, .. }, _),
                 ..
-            })
-            | Node::ImplItem(hir::ImplItem {
+            }) => return Some((decl.output, true)),
+            // The signature of a trait method implementation has to match the one of the trait
+            Node::ImplItem(hir::ImplItem {
                 kind: hir::ImplItemKind::Fn(FnSig { decl, .. }, _),
+                owner_id,
                 ..
-            })
-            | Node::Expr(Expr {
-                kind: ExprKind::Closure(hir::Closure { fn_decl: decl, .. }),
+            }) => return Some((decl.output, trait_ref_of_method(cx, owner_id.def_id).is_none())),
+            // `async` functions and blocks return a future, which the loop is only a part of
+            Node::Expr(Expr {
+                kind: ExprKind::Closure(hir::Closure { fn_decl: decl, kind, .. }),
                 ..
-            }) => return Some(decl.output),
+            }) => return Some((decl.output, !matches!(kind, hir::ClosureKind::Coroutine(_)))),
             _ => (),
         }
     }
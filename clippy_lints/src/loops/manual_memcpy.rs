@@ -1,14 +1,17 @@
+use super::utils::{get_single_push, is_ordered_const_range};
 use super::{IncrementVisitor, InitializeVisitor, MANUAL_MEMCPY};
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::snippet;
+use clippy_utils::source::{snippet, snippet_with_applicability};
 use clippy_utils::sugg::Sugg;
-use clippy_utils::ty::is_copy;
+use clippy_utils::ty::{implements_trait, is_copy};
 use clippy_utils::usage::local_used_in;
-use clippy_utils::{get_enclosing_block, higher, path_to_local, sugg};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_enclosing_block, higher, is_integer_const, path_to_local, sugg, SpanlessEq};
+use core::ops::ControlFlow;
 use rustc_ast::ast;
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::walk_block;
-use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, Pat, PatKind, StmtKind};
+use rustc_hir::{BinOpKind, Block, BorrowKind, Expr, ExprKind, HirId, Mutability, Pat, PatKind, StmtKind, UnOp};
 use rustc_lint::LateContext;
 use rustc_middle::ty::{self, Ty};
 use rustc_span::symbol::sym;
@@ -23,6 +26,9 @@ pub(super) fn check<'tcx>(
     body: &'tcx Expr<'_>,
     expr: &'tcx Expr<'_>,
 ) -> bool {
+    if check_push(cx, pat, arg, body, expr) {
+        return true;
+    }
     if let Some(higher::Range {
         start: Some(start),
         end: Some(end),
@@ -109,6 +115,194 @@ pub(super) fn check<'tcx>(
     false
 }
 
+/// Checks for `for` loops that push the items of a slice-like object into a `Vec` one by one.
+fn check_push<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+) -> bool {
+    let Some((dst, pushed)) = get_single_push(cx, body) else {
+        return false;
+    };
+    let Some((binding, elem_pat)) = (match pat.kind {
+        PatKind::Binding(_, id, _, None) => Some((id, false)),
+        PatKind::Ref(inner, _) => match inner.kind {
+            PatKind::Binding(_, id, _, None) => Some((id, true)),
+            _ => None,
+        },
+        _ => None,
+    }) else {
+        return false;
+    };
+    if local_used_in(cx, binding, dst) {
+        return false;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let (src, src_slice, src_iter) = if !elem_pat
+        && let Some(higher::Range {
+            start: Some(start),
+            end: Some(end),
+            limits,
+        }) = higher::Range::hir(arg)
+    {
+        // `for i in a..b { dst.push(src[i]) }` or `for i in a..b { dst.push(f(src[i])) }`
+        let (indexed, map_fn) = match pushed.kind {
+            ExprKind::Call(callee, [call_arg]) if matches!(callee.kind, ExprKind::Path(_)) => {
+                (fetch_cloned_expr(call_arg), Some(callee))
+            },
+            _ => (fetch_cloned_expr(pushed), None),
+        };
+        let ExprKind::Index(src, idx, _) = indexed.kind else {
+            return false;
+        };
+        if path_to_local(idx) != Some(binding) || local_used_in(cx, binding, src) {
+            return false;
+        }
+        let is_full_range = limits == ast::RangeLimits::HalfOpen
+            && ((is_integer_const(cx, start, 0)
+                && matches!(end.kind, ExprKind::MethodCall(method, recv, [], _)
+                    if method.ident.name == sym::len && SpanlessEq::new(cx).eq_expr(recv, src)))
+                || is_array_length_equal_to_range(cx, start, end, src));
+        let range = if is_full_range {
+            None
+        } else {
+            if !is_ordered_const_range(cx, start, end) {
+                app = Applicability::MaybeIncorrect;
+            }
+            let start = if is_integer_const(cx, start, 0) {
+                String::new()
+            } else {
+                snippet_with_applicability(cx, start.span, "..", &mut app).into_owned()
+            };
+            let limits = if limits == ast::RangeLimits::Closed { "..=" } else { ".." };
+            let end = snippet_with_applicability(cx, end.span, "..", &mut app);
+            Some(format!("[{start}{limits}{end}]"))
+        };
+        let src_str = Sugg::hir_with_applicability(cx, src, "..", &mut app).maybe_par();
+        let src_slice = match &range {
+            Some(range) => format!("&{src_str}{range}"),
+            None if cx.typeck_results().expr_ty(src).is_ref() => src_str.to_string(),
+            None => format!("&{src_str}"),
+        };
+        let src_iter = map_fn.map(|callee| {
+            let elem_ty = cx.typeck_results().expr_ty(indexed);
+            let adapter = if is_copy(cx, elem_ty) { "copied" } else { "cloned" };
+            let callee = snippet_with_applicability(cx, callee.span, "..", &mut app);
+            format!("{src_str}{}.iter().{adapter}().map({callee})", range.unwrap_or_default())
+        });
+        (src, src_slice, src_iter)
+    } else {
+        // `for x in &src { dst.push(*x) }` or `for x in src.iter().cloned() { dst.push(x) }`
+        let (src, copied) = match arg.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, src) => (src, false),
+            ExprKind::MethodCall(method, src, [], _) if method.ident.name == sym::iter => (src, false),
+            ExprKind::MethodCall(method, iter, [], _)
+                if matches!(method.ident.name, sym::cloned | sym::copied)
+                    && let ExprKind::MethodCall(iter_method, src, [], _) = iter.kind
+                    && iter_method.ident.name == sym::iter =>
+            {
+                (src, true)
+            },
+            _ if cx.typeck_results().expr_ty(arg).ref_mutability() == Some(Mutability::Not) => (arg, false),
+            _ => return false,
+        };
+        if copied && elem_pat {
+            return false;
+        }
+        let src_str = Sugg::hir_with_applicability(cx, src, "..", &mut app).maybe_par();
+        let src_slice = if cx.typeck_results().expr_ty(src).is_ref() {
+            src_str.to_string()
+        } else {
+            format!("&{src_str}")
+        };
+        let is_elem = match pushed.kind {
+            ExprKind::Unary(UnOp::Deref, e) => !copied && !elem_pat && path_to_local(e) == Some(binding),
+            ExprKind::MethodCall(method, e, [], _) if method.ident.name == sym::clone => {
+                !elem_pat && path_to_local(e) == Some(binding)
+            },
+            _ => (copied || elem_pat) && path_to_local(pushed) == Some(binding),
+        };
+        let src_iter = if is_elem {
+            None
+        } else if !local_used_in(cx, binding, pushed) || uses_dst(cx, pushed, dst) {
+            return false;
+        } else {
+            let iter = if let ExprKind::MethodCall(..) = arg.kind {
+                snippet_with_applicability(cx, arg.span, "..", &mut app).into_owned()
+            } else {
+                format!("{src_str}.iter()")
+            };
+            if path_to_local(pushed) == Some(binding) {
+                Some(iter)
+            } else if !elem_pat
+                && let ExprKind::Call(callee, [call_arg]) = pushed.kind
+                && matches!(callee.kind, ExprKind::Path(_))
+                && path_to_local(call_arg) == Some(binding)
+            {
+                let callee = snippet_with_applicability(cx, callee.span, "..", &mut app);
+                Some(format!("{iter}.map({callee})"))
+            } else {
+                let pat = snippet_with_applicability(cx, pat.span, "..", &mut app);
+                let pushed = snippet_with_applicability(cx, pushed.span, "..", &mut app);
+                Some(format!("{iter}.map(|{pat}| {pushed})"))
+            }
+        };
+        (src, src_slice, src_iter)
+    };
+
+    let Some(elem_ty) = get_slice_like_element_ty(cx, cx.typeck_results().expr_ty(src)) else {
+        return false;
+    };
+    if SpanlessEq::new(cx).eq_expr(src, dst)
+        || path_to_local(src).is_some_and(|id| path_to_local(dst) == Some(id))
+        || (src_iter.is_none()
+            && (cx.typeck_results().expr_ty(pushed) != elem_ty
+                || !cx
+                    .tcx
+                    .lang_items()
+                    .clone_trait()
+                    .is_some_and(|id| implements_trait(cx, elem_ty, id, &[]))))
+    {
+        return false;
+    }
+
+    let dst_str = Sugg::hir_with_applicability(cx, dst, "..", &mut app).maybe_par();
+    let sugg = if let Some(src_iter) = src_iter {
+        app = Applicability::MaybeIncorrect;
+        format!("{dst_str}.extend({src_iter});")
+    } else {
+        format!("{dst_str}.extend_from_slice({src_slice});")
+    };
+    span_lint_and_sugg(
+        cx,
+        MANUAL_MEMCPY,
+        expr.span,
+        "it looks like you're manually copying a slice into a `Vec`",
+        "try replacing the loop by",
+        sugg,
+        app,
+    );
+    true
+}
+
+/// Checks if the `Vec` being pushed to is used in the pushed value.
+fn uses_dst<'tcx>(cx: &LateContext<'tcx>, pushed: &'tcx Expr<'tcx>, dst: &Expr<'_>) -> bool {
+    match path_to_local(dst) {
+        Some(id) => local_used_in(cx, id, pushed),
+        None => for_each_expr(cx, pushed, |e| {
+            if SpanlessEq::new(cx).eq_expr(e, dst) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_some(),
+    }
+}
+
 fn build_manual_memcpy_suggestion<'tcx>(
     cx: &LateContext<'tcx>,
     start: &Expr<'_>,
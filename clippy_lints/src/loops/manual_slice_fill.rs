@@ -0,0 +1,164 @@
+use super::utils::{is_invariant, is_ordered_const_range, single_block_expr};
+use super::MANUAL_SLICE_FILL;
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_copy, is_type_diagnostic_item};
+use clippy_utils::usage::local_used_in;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{higher, is_integer_const, path_to_local, path_to_local_id, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_ast::ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, Pat, PatKind, QPath, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+/// Checks for `for` loops assigning the same value to every element of a slice, either through
+/// `for x in &mut buf { *x = val; }` or `for i in 0..buf.len() { buf[i] = val; }`. Returns whether
+/// the lint was emitted.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    msrv: &Msrv,
+) -> bool {
+    let PatKind::Binding(_, loop_var, _, None) = pat.kind else {
+        return false;
+    };
+    let Some((lhs, value)) = single_assignment(body) else {
+        return false;
+    };
+    if expr.span.from_expansion() || local_used_in(cx, loop_var, value) || !msrv.meets(msrvs::SLICE_FILL) {
+        return false;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let (slice, range) = if let ExprKind::Unary(UnOp::Deref, elem) = lhs.kind
+        && path_to_local_id(elem, loop_var)
+        && let Some(slice) = iter_mut_recv(cx, arg)
+    {
+        (slice, String::new())
+    } else if let ExprKind::Index(slice, idx, _) = lhs.kind
+        && path_to_local_id(idx, loop_var)
+        && !local_used_in(cx, loop_var, slice)
+        && let Some(higher::Range {
+            start: Some(start),
+            end: Some(end),
+            limits,
+        }) = higher::Range::hir(arg)
+    {
+        let range = if is_integer_const(cx, start, 0)
+            && limits == RangeLimits::HalfOpen
+            && let ExprKind::MethodCall(path, len_recv, [], _) = end.kind
+            && path.ident.name == sym::len
+            && SpanlessEq::new(cx).eq_expr(len_recv, slice)
+        {
+            String::new()
+        } else {
+            if !is_ordered_const_range(cx, start, end) {
+                app = Applicability::MaybeIncorrect;
+            }
+            let start = if is_integer_const(cx, start, 0) {
+                String::new()
+            } else {
+                snippet_with_applicability(cx, start.span, "..", &mut app).into_owned()
+            };
+            let limits = if limits == RangeLimits::Closed { "..=" } else { ".." };
+            let end = snippet_with_applicability(cx, end.span, "..", &mut app);
+            format!("[{start}{limits}{end}]")
+        };
+        (slice, range)
+    } else {
+        return false;
+    };
+
+    if !is_slice_like(cx, slice) || uses_expr(cx, value, slice) {
+        return false;
+    }
+
+    let fill = if is_invariant(cx, value) {
+        let value_snippet = snippet_with_applicability(cx, value.span, "..", &mut app);
+        if is_copy(cx, cx.typeck_results().expr_ty(value)) {
+            format!("fill({value_snippet})")
+        } else if msrv.meets(msrvs::SLICE_FILL_WITH) {
+            format!("fill_with(|| {value_snippet})")
+        } else {
+            return false;
+        }
+    } else if let ExprKind::Call(callee, []) = value.kind
+        && let ExprKind::Path(ref qpath) = callee.kind
+        && msrv.meets(msrvs::SLICE_FILL_WITH)
+    {
+        // A value computed on each iteration by calling a function or a closure.
+        let callee_snippet = snippet_with_applicability(cx, callee.span, "..", &mut app);
+        if let QPath::Resolved(None, _) = qpath
+            && let Res::Local(_) = cx.qpath_res(qpath, callee.hir_id)
+        {
+            format!("fill_with(&mut {callee_snippet})")
+        } else {
+            format!("fill_with({callee_snippet})")
+        }
+    } else {
+        return false;
+    };
+
+    let slice_sugg = Sugg::hir_with_applicability(cx, slice, "..", &mut app).maybe_par();
+    span_lint_and_sugg(
+        cx,
+        MANUAL_SLICE_FILL,
+        expr.span,
+        "manually filling a slice",
+        "try",
+        format!("{slice_sugg}{range}.{fill};"),
+        app,
+    );
+    true
+}
+
+/// Gets the assignee and the assigned value if the loop body consists of a single assignment.
+fn single_assignment<'tcx>(body: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let assign = match body.kind {
+        ExprKind::Block(block, None) => single_block_expr(block)?,
+        _ => body,
+    };
+    if let ExprKind::Assign(lhs, value, _) = assign.kind {
+        Some((lhs, value))
+    } else {
+        None
+    }
+}
+
+/// Gets the slice iterated over by `&mut slice`, `slice.iter_mut()` or a mutable reference to a
+/// slice.
+fn iter_mut_recv<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match arg.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, slice) => Some(slice),
+        ExprKind::MethodCall(path, slice, [], _) if path.ident.name == sym::iter_mut => Some(slice),
+        _ if cx.typeck_results().expr_ty(arg).ref_mutability() == Some(Mutability::Mut) => Some(arg),
+        _ => None,
+    }
+}
+
+fn is_slice_like(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(e).peel_refs();
+    matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::Vec)
+}
+
+/// Checks if `expr` is used anywhere within `value`.
+fn uses_expr<'tcx>(cx: &LateContext<'tcx>, value: &'tcx Expr<'tcx>, expr: &Expr<'_>) -> bool {
+    let id = path_to_local(expr);
+    for_each_expr(cx, value, |e| {
+        if id.map_or_else(|| SpanlessEq::new(cx).eq_expr(e, expr), |id| path_to_local_id(e, id)) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
@@ -0,0 +1,100 @@
+use super::utils::single_block_expr;
+use super::MANUAL_WHILE_LOOP;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::visitors::for_each_expr_without_closures;
+use clippy_utils::{higher, span_contains_comment};
+use core::ops::ControlFlow;
+use rustc_ast::Label;
+use rustc_errors::Applicability;
+use rustc_hir::{Block, Expr, ExprKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LintContext};
+
+/// Checks for `loop`s starting with `if cond { break; }`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    loop_block: &'tcx Block<'_>,
+    label: Option<Label>,
+) {
+    let (first, first_span) = match (loop_block.stmts, loop_block.expr) {
+        ([stmt, ..], _) => match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => (e, stmt.span),
+            _ => return,
+        },
+        ([], Some(e)) => (e, e.span),
+        ([], None) => return,
+    };
+
+    if let Some(higher::If {
+        cond,
+        then,
+        r#else: None,
+    }) = higher::If::hir(first)
+        && is_break_of(then, expr)
+        && !first.span.from_expansion()
+        && !contains_let_or_control_flow(cond)
+    {
+        // Replace everything from the `loop` keyword to the end of the `if`.
+        let span = expr.span.with_hi(first_span.hi());
+        let mut app = if span_contains_comment(cx.sess().source_map(), span) {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        let while_cond = negate(cx, cond, &mut app);
+        let label = label.map_or(String::new(), |label| format!("{}: ", label.ident));
+        span_lint_and_sugg(
+            cx,
+            MANUAL_WHILE_LOOP,
+            span,
+            "this loop could be written as a `while` loop",
+            "try",
+            format!("{label}while {while_cond} {{"),
+            app,
+        );
+    }
+}
+
+/// Checks if the block consists of a single `break` without a value targeting the given loop.
+pub(super) fn is_break_of(then: &Expr<'_>, loop_expr: &Expr<'_>) -> bool {
+    if let ExprKind::Block(block, None) = then.kind
+        && let Some(e) = single_block_expr(block)
+    {
+        matches!(e.kind, ExprKind::Break(dest, None) if dest.target_id == Ok(loop_expr.hir_id))
+    } else {
+        false
+    }
+}
+
+/// Checks if the condition contains `let` expressions, which can't be negated, or control flow
+/// which would behave differently in the condition of a `while` loop.
+fn contains_let_or_control_flow(cond: &Expr<'_>) -> bool {
+    for_each_expr_without_closures(cond, |e| {
+        if matches!(
+            e.kind,
+            ExprKind::Let(_) | ExprKind::Break(..) | ExprKind::Continue(_) | ExprKind::Ret(_)
+        ) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+fn negate(cx: &LateContext<'_>, cond: &Expr<'_>, app: &mut Applicability) -> String {
+    if let ExprKind::Unary(UnOp::Not, inner) = cond.kind {
+        return Sugg::hir_with_applicability(cx, inner, "..", app).to_string();
+    }
+    let sugg = Sugg::hir_with_applicability(cx, cond, "..", app);
+    // Inverting the comparison of floats would change the result for NaN.
+    if let ExprKind::Binary(op, lhs, _) = cond.kind
+        && op.node.is_comparison()
+        && cx.typeck_results().expr_ty(lhs).peel_refs().is_floating_point()
+    {
+        format!("!{}", sugg.maybe_par())
+    } else {
+        (!sugg).to_string()
+    }
+}
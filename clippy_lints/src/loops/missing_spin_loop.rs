@@ -1,11 +1,15 @@
+use super::manual_while_loop::is_break_of;
 use super::MISSING_SPIN_LOOP;
-use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::is_no_std_crate;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::indent_of;
+use clippy_utils::visitors::{for_each_expr_without_closures, Visitable};
+use clippy_utils::{higher, is_no_std_crate};
+use core::ops::ControlFlow;
 use rustc_errors::Applicability;
-use rustc_hir::{Block, Expr, ExprKind};
-use rustc_lint::LateContext;
+use rustc_hir::{Block, Expr, ExprKind, StmtKind};
+use rustc_lint::{LateContext, LintContext};
 use rustc_middle::ty;
-use rustc_span::sym;
+use rustc_span::{sym, BytePos, Span};
 
 fn unpack_cond<'tcx>(cond: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
     match &cond.kind {
@@ -30,31 +34,124 @@ fn unpack_cond<'tcx>(cond: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
     }
 }
 
-pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
-    if let ExprKind::Block(
-        Block {
-            stmts: [], expr: None, ..
-        },
-        _,
-    ) = body.kind
-        && let ExprKind::MethodCall(method, callee, ..) = unpack_cond(cond).kind
+/// Checks if the condition polls an atomic with `load` or `compare_exchange`.
+fn polls_atomic(cx: &LateContext<'_>, cond: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(method, callee, ..) = unpack_cond(cond).kind
         && [sym::load, sym::compare_exchange, sym::compare_exchange_weak].contains(&method.ident.name)
-        && let ty::Adt(def, _args) = cx.typeck_results().expr_ty(callee).kind()
-        && cx.tcx.is_diagnostic_item(sym::AtomicBool, def.did())
+        && let ty::Adt(def, _args) = cx.typeck_results().expr_ty(callee).peel_refs().kind()
     {
-        span_lint_and_sugg(
-            cx,
-            MISSING_SPIN_LOOP,
-            body.span,
-            "busy-waiting loop should at least have a spin loop hint",
-            "try",
-            (if is_no_std_crate(cx) {
-                "{ core::hint::spin_loop() }"
-            } else {
-                "{ std::hint::spin_loop() }"
-            })
-            .into(),
-            Applicability::MachineApplicable,
-        );
+        cx.tcx
+            .get_diagnostic_name(def.did())
+            .is_some_and(|name| name.as_str().starts_with("Atomic"))
+    } else {
+        false
+    }
+}
+
+/// Checks if the node does no work besides computing values, i.e. it doesn't call any function,
+/// which could already wait or hint the processor, and doesn't await.
+fn is_trivial<'tcx>(node: impl Visitable<'tcx>) -> bool {
+    for_each_expr_without_closures(node, |e| {
+        if matches!(
+            e.kind,
+            ExprKind::Call(..) | ExprKind::MethodCall(..) | ExprKind::Yield(..) | ExprKind::InlineAsm(_)
+        ) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_none()
+}
+
+/// Gets the span of the first statement or the trailing expression of the block.
+fn first_span(block: &Block<'_>) -> Option<Span> {
+    block.stmts.first().map(|stmt| stmt.span).or(block.expr.map(|e| e.span))
+}
+
+/// Checks `while` loops polling an atomic whose body does nothing or only trivial work.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'_>, body: &'tcx Expr<'_>, span: Span) {
+    if let ExprKind::Block(block, _) = body.kind
+        && polls_atomic(cx, cond)
+    {
+        if let Some(first) = first_span(block) {
+            if block.stmts.iter().all(is_trivial) && is_trivial(block.expr) {
+                lint_with_insertion(cx, span, first);
+            }
+        } else {
+            span_lint_and_sugg(
+                cx,
+                MISSING_SPIN_LOOP,
+                body.span,
+                "busy-waiting loop should at least have a spin loop hint",
+                "try",
+                format!("{{ {}() }}", spin_loop_path(cx)),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Checks `loop`s which only wait for an atomic to be set with `if cond { break; }` and otherwise
+/// do trivial work.
+pub(super) fn check_loop<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, block: &'tcx Block<'_>, head: Span) {
+    let is_poll = |e: &Expr<'_>| {
+        higher::If::hir(e).is_some_and(|higher::If { cond, then, r#else }| {
+            r#else.is_none() && is_break_of(then, expr) && polls_atomic(cx, cond)
+        })
+    };
+    let mut polls = false;
+    for stmt in block.stmts {
+        if let StmtKind::Expr(e) | StmtKind::Semi(e) = stmt.kind
+            && is_poll(e)
+        {
+            polls = true;
+        } else if !is_trivial(stmt) {
+            return;
+        }
+    }
+    if let Some(e) = block.expr {
+        if is_poll(e) {
+            polls = true;
+        } else if !is_trivial(e) {
+            return;
+        }
+    }
+    if polls && let Some(first) = first_span(block) {
+        lint_with_insertion(cx, expr.span.with_hi(head.hi()), first);
+    }
+}
+
+/// Lints the loop whose header is `span`, suggesting to insert a spin loop hint before `first`,
+/// the first statement of the loop body.
+fn lint_with_insertion(cx: &LateContext<'_>, span: Span, first: Span) {
+    if span.from_expansion() || first.from_expansion() {
+        return;
+    }
+    let hint = format!("{}();", spin_loop_path(cx));
+    // Insert the hint on its own line if the statement starts its line.
+    let (at, sugg) = match indent_of(cx, first) {
+        Some(indent) if cx.sess().source_map().lookup_char_pos(first.lo()).col.0 == indent => (
+            first.with_lo(first.lo() - BytePos::from_usize(indent)).shrink_to_lo(),
+            format!("{}{hint}\n", " ".repeat(indent)),
+        ),
+        _ => (first.shrink_to_lo(), format!("{hint} ")),
+    };
+    span_lint_and_then(
+        cx,
+        MISSING_SPIN_LOOP,
+        span,
+        "busy-waiting loop should at least have a spin loop hint",
+        |diag| {
+            diag.span_suggestion(at, "insert a spin loop hint", sugg, Applicability::MachineApplicable);
+        },
+    );
+}
+
+fn spin_loop_path(cx: &LateContext<'_>) -> &'static str {
+    if is_no_std_crate(cx) {
+        "core::hint::spin_loop"
+    } else {
+        "std::hint::spin_loop"
     }
 }
@@ -1,3 +1,5 @@
+mod char_indices_as_byte_indices;
+mod counted_push_loop;
 mod empty_loop;
 mod explicit_counter_loop;
 mod explicit_into_iter_loop;
@@ -8,17 +10,21 @@ mod iter_next_loop;
 mod manual_find;
 mod manual_flatten;
 mod manual_memcpy;
+mod manual_slice_fill;
+mod manual_while_loop;
 mod manual_while_let_some;
 mod missing_spin_loop;
 mod mut_range_bound;
+mod needless_collect;
 mod needless_range_loop;
 mod never_loop;
 mod same_item_push;
 mod single_element_loop;
 mod unused_enumerate_index;
-mod utils;
+pub(crate) mod utils;
 mod while_float;
 mod while_immutable_condition;
+mod while_index_loop;
 mod while_let_loop;
 mod while_let_on_iterator;
 
@@ -33,10 +39,13 @@ use utils::{make_iterator_snippet, IncrementVisitor, InitializeVisitor};
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for for-loops that manually copy items between
-    /// slices that could be optimized by having a memcpy.
+    /// slices that could be optimized by having a memcpy, or that
+    /// push the items of a slice into a `Vec` one by one.
     ///
     /// ### Why is this bad?
-    /// It is not as fast as a memcpy.
+    /// It is not as fast as a memcpy. Pushing the items one by one
+    /// may also reallocate the `Vec` several times, while
+    /// `extend_from_slice` reserves the needed capacity once.
     ///
     /// ### Example
     /// ```no_run
@@ -548,7 +557,9 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for empty spin loops
+    /// Checks for spin loops polling an atomic, either as the condition of a `while` loop or
+    /// with `if cond { break; }` in a `loop`, whose body is empty or only does trivial work
+    /// without calling any function.
     ///
     /// ### Why is this bad?
     /// The loop body should have something like `thread::park()` or at least
@@ -615,7 +626,8 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for uses of the `enumerate` method where the index is unused (`_`)
+    /// Checks for uses of the `enumerate` method where the index is unused, either because it is
+    /// matched by `_` or because its binding is never used.
     ///
     /// ### Why is this bad?
     /// The index from `.enumerate()` is immediately dropped.
@@ -712,6 +724,205 @@ declare_clippy_lint! {
     "possibly unintended infinite loop"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops assigning the same value to every element of a slice, an array or
+    /// a `Vec`.
+    ///
+    /// ### Why is this bad?
+    /// `slice::fill` and `slice::fill_with` are more concise, and `fill` can be optimized into a
+    /// memset for byte-like types.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let mut buf = vec![1u8; 16];
+    /// for x in &mut buf {
+    ///     *x = 0;
+    /// }
+    /// for i in 4..8 {
+    ///     buf[i] = 0xff;
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// # let mut buf = vec![1u8; 16];
+    /// buf.fill(0);
+    /// buf[4..8].fill(0xff);
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_SLICE_FILL,
+    style,
+    "manually filling a slice with a value"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops over `0..n` pushing one element per iteration into a `Vec` that was
+    /// created with `Vec::new()` right before the loop.
+    ///
+    /// ### Why is this bad?
+    /// The `Vec` is reallocated several times while it grows, although its final length is known
+    /// up front. `vec![elem; n]` or collecting an iterator allocate the `Vec` once, and
+    /// `Vec::with_capacity(n)` does so when the loop has to stay.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let n = 16;
+    /// let mut zeros = Vec::new();
+    /// for _ in 0..n {
+    ///     zeros.push(0u8);
+    /// }
+    /// let mut squares = Vec::new();
+    /// for i in 0..n {
+    ///     squares.push(i * i);
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// # let n = 16;
+    /// let mut zeros = vec![0u8; n];
+    /// let mut squares = (0..n).map(|i| i * i).collect::<Vec<_>>();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub COUNTED_PUSH_LOOP,
+    perf,
+    "pushing into a new `Vec` in a loop with a known number of iterations"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for character positions obtained from `str::chars` through `enumerate` or `position`
+    /// which are used as byte indices, e.g. to index or slice a string or passed to `str::split_at`.
+    ///
+    /// ### Why is this bad?
+    /// A character can take up more than one byte in a string, so the position of a character is
+    /// only the same as its byte index if all the characters before it are ASCII. For any other
+    /// input, the index either panics by not being on a character boundary, or points to the wrong
+    /// character.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn key(line: &str) -> &str {
+    ///     for (i, c) in line.chars().enumerate() {
+    ///         if c == ':' {
+    ///             return &line[..i];
+    ///         }
+    ///     }
+    ///     line
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn key(line: &str) -> &str {
+    ///     for (i, c) in line.char_indices() {
+    ///         if c == ':' {
+    ///             return &line[..i];
+    ///         }
+    ///     }
+    ///     line
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CHAR_INDICES_AS_BYTE_INDICES,
+    correctness,
+    "using the position of a character in a string as a byte index"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `loop`s whose body starts with `if cond { break; }`.
+    ///
+    /// ### Why is this bad?
+    /// This is exactly what a `while` loop does, and `while` makes the exit condition visible
+    /// in the loop header.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let mut x = 0;
+    /// loop {
+    ///     if x >= 10 {
+    ///         break;
+    ///     }
+    ///     x += 1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let mut x = 0;
+    /// while x < 10 {
+    ///     x += 1;
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_WHILE_LOOP,
+    style,
+    "`loop` starting with a conditional `break` that could be a `while` loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for looping over a range of indices of a sequence to modify its elements through
+    /// indexing, when the index is used for nothing else than indexing that sequence.
+    ///
+    /// ### Why is this bad?
+    /// Iterating over mutable references to the elements with `iter_mut()` states the intent
+    /// directly and avoids the bounds checks of indexing. The index itself is still available
+    /// through `enumerate()`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut vec = vec![1, 2, 3];
+    /// for i in 0..vec.len() {
+    ///     vec[i] = vec[i] * 2 + i;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut vec = vec![1, 2, 3];
+    /// for (i, x) in vec.iter_mut().enumerate() {
+    ///     *x = *x * 2 + i;
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_ITER_MUT,
+    style,
+    "looping over indices of a sequence to modify its elements instead of using `iter_mut()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `while` loops over the indices of a sequence, with a counter compared against
+    /// its length and incremented at the end of the loop, like `while i < v.len() { ..; i += 1; }`.
+    ///
+    /// ### Why is this bad?
+    /// Iterating over the elements with a `for` loop is shorter, can't go out of bounds, and
+    /// doesn't need a mutable counter. The index is still available through `enumerate()` when it
+    /// is used for anything else.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let v = vec![1, 2, 3];
+    /// let mut i = 0;
+    /// while i < v.len() {
+    ///     println!("{}", v[i]);
+    ///     i += 1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let v = vec![1, 2, 3];
+    /// for x in &v {
+    ///     println!("{}", *x);
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub WHILE_INDEX_LOOP,
+    style,
+    "indexing a sequence with a counter in a `while` loop instead of iterating over it"
+}
+
 pub struct Loops {
     msrv: Msrv,
     enforce_iter_loop_reborrow: bool,
@@ -748,6 +959,12 @@ impl_lint_pass!(Loops => [
     MANUAL_WHILE_LET_SOME,
     UNUSED_ENUMERATE_INDEX,
     INFINITE_LOOP,
+    MANUAL_SLICE_FILL,
+    COUNTED_PUSH_LOOP,
+    CHAR_INDICES_AS_BYTE_INDICES,
+    MANUAL_WHILE_LOOP,
+    MANUAL_ITER_MUT,
+    WHILE_INDEX_LOOP,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -786,20 +1003,24 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
         // check for `loop { if let {} else break }` that could be `while let`
         // (also matches an explicit "match" instead of "if let")
         // (even if the "match" or "if let" is used for declaration)
-        if let ExprKind::Loop(block, label, LoopSource::Loop, _) = expr.kind {
+        if let ExprKind::Loop(block, label, LoopSource::Loop, head) = expr.kind {
             // also check for empty `loop {}` statements, skipping those in #[panic_handler]
             empty_loop::check(cx, expr, block);
             while_let_loop::check(cx, expr, block);
+            manual_while_loop::check(cx, expr, block, label);
             infinite_loop::check(cx, expr, block, label);
+            missing_spin_loop::check_loop(cx, expr, block, head);
         }
 
         while_let_on_iterator::check(cx, expr);
+        char_indices_as_byte_indices::check_position(cx, expr);
 
         if let Some(higher::While { condition, body, span }) = higher::While::hir(expr) {
             while_immutable_condition::check(cx, condition, body);
             while_float::check(cx, condition);
-            missing_spin_loop::check(cx, condition, body);
+            missing_spin_loop::check(cx, condition, body, span);
             manual_while_let_some::check(cx, condition, body, span);
+            while_index_loop::check(cx, condition, body, expr, span);
         }
     }
 
@@ -817,7 +1038,9 @@ impl Loops {
         span: Span,
     ) {
         let is_manual_memcpy_triggered = manual_memcpy::check(cx, pat, arg, body, expr);
-        if !is_manual_memcpy_triggered {
+        let is_manual_slice_fill_triggered =
+            !is_manual_memcpy_triggered && manual_slice_fill::check(cx, pat, arg, body, expr, &self.msrv);
+        if !is_manual_memcpy_triggered && !is_manual_slice_fill_triggered {
             needless_range_loop::check(cx, pat, arg, body, expr);
             explicit_counter_loop::check(cx, pat, arg, body, expr);
         }
@@ -825,10 +1048,14 @@ impl Loops {
         for_kv_map::check(cx, pat, arg, body);
         mut_range_bound::check(cx, arg, body);
         single_element_loop::check(cx, pat, arg, body, expr);
-        same_item_push::check(cx, pat, arg, body, expr);
+        if is_manual_memcpy_triggered || !counted_push_loop::check(cx, pat, arg, body, expr) {
+            same_item_push::check(cx, pat, arg, body, expr);
+        }
         manual_flatten::check(cx, pat, arg, body, span);
         manual_find::check(cx, pat, arg, body, span, expr);
         unused_enumerate_index::check(cx, pat, arg, body);
+        char_indices_as_byte_indices::check(cx, pat, arg, body);
+        needless_collect::check(cx, arg, body);
     }
 
     fn check_for_loop_arg(&self, cx: &LateContext<'_>, _: &Pat<'_>, arg: &Expr<'_>) {
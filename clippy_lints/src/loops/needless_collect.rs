@@ -0,0 +1,92 @@
+use crate::methods::NEEDLESS_COLLECT;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use clippy_utils::{is_trait_method, path_to_local};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, Mutability};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+/// Checks for `for` loops iterating over a collection which was just collected from an iterator,
+/// e.g. `for x in iter.collect::<Vec<_>>()` or `for x in &iter.collect::<Vec<_>>()`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    let (collect_expr, by_ref) = match arg.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) => (inner, true),
+        _ => (arg, false),
+    };
+    if let ExprKind::MethodCall(method, iter_expr, [], _) = collect_expr.kind
+        && method.ident.as_str() == "collect"
+        && is_trait_method(cx, collect_expr, sym::Iterator)
+        && !collect_expr.span.from_expansion()
+        && let ty = cx.typeck_results().expr_ty(collect_expr)
+        // Sets and maps remove duplicates and change the iteration order, so they can't be removed.
+        && let Some(keeps_order) = [sym::Vec, sym::VecDeque, sym::LinkedList, sym::BinaryHeap]
+            .into_iter()
+            .find(|&item| is_type_diagnostic_item(cx, ty, item))
+            .map(|item| item != sym::BinaryHeap)
+        // Iterating lazily keeps the locals the iterator uses borrowed for the whole loop.
+        && !uses_iter_locals(cx, iter_expr, body)
+    {
+        if !by_ref {
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_COLLECT,
+                collect_expr.span.with_lo(iter_expr.span.hi()),
+                "avoid using `collect()` when not needed",
+                "remove this call",
+                String::new(),
+                if keeps_order {
+                    Applicability::MachineApplicable
+                } else {
+                    Applicability::MaybeIncorrect
+                },
+            );
+            return;
+        }
+
+        // `&iter.cloned().collect::<Vec<_>>()` yields references to the cloned items, which iterating
+        // over the original references does as well.
+        let (iter_expr, yields_refs) = if let ExprKind::MethodCall(adapter, source, [], _) = iter_expr.kind
+            && matches!(adapter.ident.as_str(), "cloned" | "copied")
+            && is_trait_method(cx, iter_expr, sym::Iterator)
+            && keeps_order
+        {
+            (source, true)
+        } else {
+            (iter_expr, false)
+        };
+        span_lint_and_then(
+            cx,
+            NEEDLESS_COLLECT,
+            arg.span,
+            "avoid using `collect()` when not needed",
+            |diag| {
+                let mut app = if yields_refs {
+                    Applicability::MachineApplicable
+                } else {
+                    Applicability::MaybeIncorrect
+                };
+                let sugg = snippet_with_applicability(cx, iter_expr.span, "..", &mut app);
+                diag.span_suggestion(arg.span, "iterate over the iterator instead", sugg, app);
+                if !yields_refs {
+                    diag.note("the loop variable will hold the items instead of references to them");
+                }
+            },
+        );
+    }
+}
+
+/// Checks if any local used by the iterator is used in the loop body.
+fn uses_iter_locals<'tcx>(cx: &LateContext<'tcx>, iter_expr: &'tcx Expr<'_>, body: &'tcx Expr<'_>) -> bool {
+    let mut locals: Vec<HirId> = Vec::new();
+    let _: Option<!> = for_each_expr(cx, iter_expr, |e| {
+        if let Some(id) = path_to_local(e) {
+            locals.push(id);
+        }
+        ControlFlow::Continue(())
+    });
+    locals.into_iter().any(|id| is_local_used(cx, body, id))
+}
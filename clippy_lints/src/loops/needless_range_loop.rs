@@ -1,18 +1,23 @@
-use super::NEEDLESS_RANGE_LOOP;
-use clippy_utils::diagnostics::{multispan_sugg, span_lint_and_then};
+use super::{MANUAL_ITER_MUT, NEEDLESS_RANGE_LOOP};
+use clippy_utils::diagnostics::{multispan_sugg, multispan_sugg_with_applicability, span_lint_and_then};
 use clippy_utils::source::snippet;
 use clippy_utils::ty::has_iter_method;
-use clippy_utils::visitors::is_local_used;
-use clippy_utils::{contains_name, higher, is_integer_const, sugg, SpanlessEq};
+use clippy_utils::visitors::{for_each_expr, is_local_used, Descend, Visitable};
+use clippy_utils::{
+    contains_name, get_parent_expr, higher, is_integer_const, is_lint_allowed, path_to_local_id, sugg, SpanlessEq,
+};
+use core::ops::ControlFlow;
 use rustc_ast::ast;
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
+use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::intravisit::{walk_expr, Visitor};
-use rustc_hir::{BinOpKind, BorrowKind, Closure, Expr, ExprKind, HirId, Mutability, Pat, PatKind, QPath};
+use rustc_hir::{BinOpKind, BindingMode, BorrowKind, Closure, Expr, ExprKind, HirId, Mutability, Pat, PatKind, QPath};
 use rustc_lint::LateContext;
 use rustc_middle::middle::region;
 use rustc_middle::ty::{self, Ty};
-use rustc_span::symbol::{sym, Symbol};
+use rustc_span::symbol::{sym, Ident, Symbol};
+use std::fmt::Write;
 use std::{iter, mem};
 
 /// Checks for looping over a range and then indexing a sequence with it.
@@ -33,16 +38,7 @@ pub(super) fn check<'tcx>(
     {
         // the var must be a single name
         if let PatKind::Binding(_, canonical_id, ident, _) = pat.kind {
-            let mut visitor = VarVisitor {
-                cx,
-                var: canonical_id,
-                indexed_mut: FxHashSet::default(),
-                indexed_indirectly: FxHashMap::default(),
-                indexed_directly: FxHashMap::default(),
-                referenced: FxHashSet::default(),
-                nonindex: false,
-                prefer_mutable: false,
-            };
+            let mut visitor = VarVisitor::new(cx, canonical_id);
             walk_expr(&mut visitor, body);
 
             // linting condition: we only indexed one variable, and indexed it directly
@@ -54,13 +50,8 @@ pub(super) fn check<'tcx>(
                     .expect("already checked that we have exactly 1 element");
 
                 // ensure that the indexed variable was declared before the loop, see #601
-                if let Some(indexed_extent) = indexed_extent {
-                    let parent_def_id = cx.tcx.hir().get_parent_item(expr.hir_id);
-                    let region_scope_tree = cx.tcx.region_scope_tree(parent_def_id);
-                    let pat_extent = region_scope_tree.var_scope(pat.hir_id.local_id).unwrap();
-                    if region_scope_tree.is_subscope_of(indexed_extent, pat_extent) {
-                        return;
-                    }
+                if !is_declared_before_loop(cx, pat, expr, indexed_extent) {
+                    return;
                 }
 
                 // don't lint if the container that is indexed does not have .iter() method
@@ -138,6 +129,19 @@ pub(super) fn check<'tcx>(
                     mem::swap(&mut method_1, &mut method_2);
                 }
 
+                if visitor.indexed_mut.contains(&indexed) && !is_lint_allowed(cx, MANUAL_ITER_MUT, expr.hir_id) {
+                    let iter = if visitor.nonindex {
+                        format!("{indexed}.iter_mut().enumerate(){method_1}{method_2}")
+                    } else if starts_at_zero && take_is_empty && !indexed_ty.is_ref() {
+                        format!("&mut {indexed}")
+                    } else {
+                        format!("{indexed}.iter_mut(){method_1}{method_2}")
+                    };
+                    if check_iter_mut(cx, pat, arg, body, indexed, iter, visitor.nonindex) {
+                        return;
+                    }
+                }
+
                 if visitor.nonindex {
                     span_lint_and_then(
                         cx,
@@ -179,9 +183,256 @@ pub(super) fn check<'tcx>(
                         },
                     );
                 }
+            } else if visitor.indexed_indirectly.is_empty() && visitor.indexed_directly.len() > 1 && !visitor.nonindex {
+                check_zip(cx, pat, ident, arg, expr, &visitor);
+            }
+        }
+    }
+}
+
+/// Checks for loops writing to the only sequence they index, like
+/// `for i in 0..v.len() { v[i] *= 2 }`, and suggests iterating over mutable references to its
+/// elements instead, replacing each `v[i]` in the body. Returns whether the lint was emitted.
+fn check_iter_mut<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    indexed: Symbol,
+    iter: String,
+    enumerate: bool,
+) -> bool {
+    // Changing the loop variable would change which element is accessed.
+    let PatKind::Binding(BindingMode::NONE, var, ident, None) = pat.kind else {
+        return false;
+    };
+    let Some(elem) = elem_name(cx, body) else {
+        return false;
+    };
+    let Some(uses) = index_uses(cx, body, var, indexed) else {
+        return false;
+    };
+
+    let pat_sugg = if enumerate {
+        format!("({}, {elem})", ident.name)
+    } else {
+        elem.to_string()
+    };
+    let mut sugg = vec![(pat.span, pat_sugg), (arg.span, iter)];
+    sugg.extend(uses.iter().map(|e| (e.span, elem_sugg(cx, e, elem))));
+
+    span_lint_and_then(
+        cx,
+        MANUAL_ITER_MUT,
+        arg.span,
+        format!("`{indexed}` is modified by indexing it with the loop variable `{}`", ident.name),
+        |diag| {
+            diag.multipart_suggestion(
+                "consider iterating over mutable references to the elements",
+                sugg,
+                Applicability::MachineApplicable,
+            );
+        },
+    );
+    true
+}
+
+/// Gets a name for the binding of the element which isn't used in `body` yet.
+pub(super) fn elem_name(cx: &LateContext<'_>, body: &Expr<'_>) -> Option<Symbol> {
+    ["x", "item"]
+        .into_iter()
+        .map(Symbol::intern)
+        .find(|&name| !contains_name(name, body, cx))
+}
+
+/// Gets all the `indexed[var]` expressions in `body`, or `None` if there are none or if some
+/// indexing can't be rewritten in place.
+pub(super) fn index_uses<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: impl Visitable<'tcx>,
+    var: HirId,
+    indexed: Symbol,
+) -> Option<Vec<&'tcx Expr<'tcx>>> {
+    let mut uses = Vec::new();
+    let found_unsupported = for_each_expr(cx, body, |e| match e.kind {
+        ExprKind::Index(seq, idx, _) if path_to_local_id(idx, var) && is_path_to(seq, indexed) => {
+            if e.span.from_expansion() {
+                return ControlFlow::Break(());
             }
+            uses.push(e);
+            ControlFlow::Continue(Descend::No)
+        },
+        // Explicit calls to `Index::index` can't be rewritten in place.
+        ExprKind::MethodCall(method, ..) if matches!(method.ident.name, sym::index | sym::index_mut) => {
+            ControlFlow::Break(())
+        },
+        _ => ControlFlow::Continue(Descend::Yes),
+    })
+    .is_some();
+    (!found_unsupported && !uses.is_empty()).then_some(uses)
+}
+
+/// Gets the replacement of the indexing expression `e` using the reference to the element.
+pub(super) fn elem_sugg(cx: &LateContext<'_>, e: &Expr<'_>, elem: Symbol) -> String {
+    if let Some(parent) = get_parent_expr(cx, e) {
+        match parent.kind {
+            // Field accesses and indexing dereference the element by themselves.
+            ExprKind::Field(base, _) | ExprKind::Index(base, ..) if base.hir_id == e.hir_id => {
+                return elem.to_string();
+            },
+            // So do calls to inherent methods, but traits can also be implemented for the reference.
+            ExprKind::MethodCall(_, recv, ..) if recv.hir_id == e.hir_id => {
+                return if cx
+                    .typeck_results()
+                    .type_dependent_def_id(parent.hir_id)
+                    .is_some_and(|id| cx.tcx.trait_of_item(id).is_none())
+                {
+                    elem.to_string()
+                } else {
+                    format!("(*{elem})")
+                };
+            },
+            _ => {},
         }
     }
+    format!("*{elem}")
+}
+
+fn is_path_to(e: &Expr<'_>, name: Symbol) -> bool {
+    if let ExprKind::Path(QPath::Resolved(None, path)) = e.kind
+        && let [segment] = path.segments
+    {
+        segment.ident.name == name
+    } else {
+        false
+    }
+}
+
+/// Checks for loops only indexing several sequences with the loop variable, like
+/// `for i in 0..a.len() { sum += a[i] * b[i] }`, which can zip the iterators of the sequences
+/// instead.
+fn check_zip<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    ident: Ident,
+    arg: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    visitor: &VarVisitor<'_, 'tcx>,
+) {
+    let Some(higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits: ast::RangeLimits::HalfOpen,
+    }) = higher::Range::hir(arg)
+    else {
+        return;
+    };
+    if !is_integer_const(cx, start, 0)
+        || visitor.indexed_directly.iter().any(|(indexed, &(extent, ty))| {
+            visitor.referenced.contains(indexed)
+                || has_iter_method(cx, ty).is_none()
+                || !is_declared_before_loop(cx, pat, expr, extent)
+        })
+    {
+        return;
+    }
+    // The loop has to end at the length of one of the sequences, or the shortest of them
+    let Some(len_names) = min_len_names(end) else {
+        return;
+    };
+    if len_names.iter().any(|name| !visitor.indexed_directly.contains_key(name)) {
+        return;
+    }
+    let mut seen = FxHashSet::default();
+    let names: Vec<Symbol> = len_names
+        .iter()
+        .chain(visitor.indexed_directly.keys())
+        .copied()
+        .filter(|&name| seen.insert(name))
+        .collect();
+    // The lengths are all of indexed sequences, so they cover them all if there are as many
+    let is_exact = len_names.iter().collect::<FxHashSet<_>>().len() == visitor.indexed_directly.len();
+
+    let is_mut = |name: &Symbol| visitor.indexed_mut.contains(name);
+    let mut iter = format!("{}.{}()", names[0], if is_mut(&names[0]) { "iter_mut" } else { "iter" });
+    let mut item_pat = format!("<{}_item>", names[0]);
+    for name in &names[1..] {
+        let zipped = match (visitor.indexed_directly[name].1.is_ref(), is_mut(name)) {
+            (true, true) => format!("{name}.iter_mut()"),
+            (true, false) => format!("{name}.iter()"),
+            (false, true) => format!("&mut {name}"),
+            (false, false) => format!("&{name}"),
+        };
+        let _ = write!(iter, ".zip({zipped})");
+        item_pat = format!("({item_pat}, <{name}_item>)");
+    }
+
+    let (last, rest) = names.split_last().expect("more than one sequence is indexed");
+    let indexed = format!(
+        "{} and `{last}`",
+        rest.iter().map(|name| format!("`{name}`")).collect::<Vec<_>>().join(", ")
+    );
+    span_lint_and_then(
+        cx,
+        NEEDLESS_RANGE_LOOP,
+        arg.span,
+        format!("the loop variable `{}` is only used to index {indexed}", ident.name),
+        |diag| {
+            let applicability = if is_exact {
+                Applicability::HasPlaceholders
+            } else {
+                diag.note(format!(
+                    "`zip` stops at the end of the shortest sequence, while this loop would panic if one is \
+                     shorter than `{}`",
+                    names[0]
+                ));
+                Applicability::Unspecified
+            };
+            multispan_sugg_with_applicability(
+                diag,
+                "consider using iterators and zip()",
+                applicability,
+                vec![(pat.span, item_pat), (arg.span, iter)],
+            );
+        },
+    );
+}
+
+/// Returns whether the variable with the scope `extent` was declared before the loop, see #601.
+/// Statics and constants don't have a scope.
+fn is_declared_before_loop(
+    cx: &LateContext<'_>,
+    pat: &Pat<'_>,
+    expr: &Expr<'_>,
+    extent: Option<region::Scope>,
+) -> bool {
+    let Some(extent) = extent else {
+        return true;
+    };
+    let parent_def_id = cx.tcx.hir().get_parent_item(expr.hir_id);
+    let region_scope_tree = cx.tcx.region_scope_tree(parent_def_id);
+    let pat_extent = region_scope_tree.var_scope(pat.hir_id.local_id).unwrap();
+    !region_scope_tree.is_subscope_of(extent, pat_extent)
+}
+
+/// Returns the names of the sequences whose lengths `end` is the minimum of, e.g. `a` and `b` for
+/// `a.len().min(b.len())`
+fn min_len_names(end: &Expr<'_>) -> Option<Vec<Symbol>> {
+    match end.kind {
+        ExprKind::MethodCall(method, recv, [other], _) if method.ident.name == sym::min => {
+            let mut names = min_len_names(recv)?;
+            names.extend(min_len_names(other)?);
+            Some(names)
+        },
+        ExprKind::MethodCall(method, recv, [], _)
+            if method.ident.name == sym::len
+                && let ExprKind::Path(QPath::Resolved(None, path)) = recv.kind
+                && let [segment] = path.segments =>
+        {
+            Some(vec![segment.ident.name])
+        },
+        _ => None,
+    }
 }
 
 fn is_len_call(expr: &Expr<'_>, var: Symbol) -> bool {
@@ -217,30 +468,43 @@ fn is_end_eq_array_len<'tcx>(
     false
 }
 
-struct VarVisitor<'a, 'tcx> {
+pub(super) struct VarVisitor<'a, 'tcx> {
     /// context reference
     cx: &'a LateContext<'tcx>,
     /// var name to look for as index
     var: HirId,
     /// indexed variables that are used mutably
-    indexed_mut: FxHashSet<Symbol>,
+    pub(super) indexed_mut: FxHashSet<Symbol>,
     /// indirectly indexed variables (`v[(i + 4) % N]`), the extend is `None` for global
-    indexed_indirectly: FxHashMap<Symbol, Option<region::Scope>>,
+    pub(super) indexed_indirectly: FxHashMap<Symbol, Option<region::Scope>>,
     /// subset of `indexed` of vars that are indexed directly: `v[i]`
     /// this will not contain cases like `v[calc_index(i)]` or `v[(i + 4) % N]`
-    indexed_directly: FxHashMap<Symbol, (Option<region::Scope>, Ty<'tcx>)>,
+    pub(super) indexed_directly: FxIndexMap<Symbol, (Option<region::Scope>, Ty<'tcx>)>,
     /// Any names that are used outside an index operation.
     /// Used to detect things like `&mut vec` used together with `vec[i]`
-    referenced: FxHashSet<Symbol>,
+    pub(super) referenced: FxHashSet<Symbol>,
     /// has the loop variable been used in expressions other than the index of
     /// an index op?
-    nonindex: bool,
+    pub(super) nonindex: bool,
     /// Whether we are inside the `$` in `&mut $` or `$ = foo` or `$.bar`, where bar
     /// takes `&mut self`
     prefer_mutable: bool,
 }
 
 impl<'a, 'tcx> VarVisitor<'a, 'tcx> {
+    pub(super) fn new(cx: &'a LateContext<'tcx>, var: HirId) -> Self {
+        Self {
+            cx,
+            var,
+            indexed_mut: FxHashSet::default(),
+            indexed_indirectly: FxHashMap::default(),
+            indexed_directly: FxIndexMap::default(),
+            referenced: FxHashSet::default(),
+            nonindex: false,
+            prefer_mutable: false,
+        }
+    }
+
     fn check(&mut self, idx: &'tcx Expr<'_>, seqexpr: &'tcx Expr<'_>, expr: &'tcx Expr<'_>) -> bool {
         if let ExprKind::Path(ref seqpath) = seqexpr.kind
             // the indexed container is referenced by a name
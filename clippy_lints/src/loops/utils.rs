@@ -1,9 +1,10 @@
-use clippy_utils::ty::{has_iter_method, implements_trait};
-use clippy_utils::{get_parent_expr, is_integer_const, path_to_local, path_to_local_id, sugg};
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::ty::{has_iter_method, implements_trait, is_type_diagnostic_item};
+use clippy_utils::{get_parent_expr, is_integer_const, is_trait_method, path_to_local, path_to_local_id, sugg};
 use rustc_ast::ast::{LitIntType, LitKind};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{walk_expr, walk_local, Visitor};
-use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, HirId, HirIdMap, LetStmt, Mutability, PatKind};
+use rustc_hir::{BinOpKind, Block, BorrowKind, Expr, ExprKind, HirId, HirIdMap, LetStmt, Mutability, PatKind, StmtKind};
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter;
 use rustc_middle::ty::{self, Ty};
@@ -22,6 +23,7 @@ pub(super) struct IncrementVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,                  // context reference
     states: HirIdMap<IncrementVisitorVarState>, // incremented variables
     depth: u32,                                 // depth of conditional expressions
+    loop_depth: u32,                            // depth of nested loops
 }
 
 impl<'a, 'tcx> IncrementVisitor<'a, 'tcx> {
@@ -30,6 +32,7 @@ impl<'a, 'tcx> IncrementVisitor<'a, 'tcx> {
             cx,
             states: HirIdMap::default(),
             depth: 0,
+            loop_depth: 0,
         }
     }
 
@@ -81,14 +84,23 @@ impl<'a, 'tcx> Visitor<'tcx> for IncrementVisitor<'a, 'tcx> {
             }
 
             walk_expr(self, expr);
-        } else if is_loop(expr) || is_conditional(expr) {
+        } else if is_loop(expr) {
             self.depth += 1;
+            self.loop_depth += 1;
             walk_expr(self, expr);
+            self.loop_depth -= 1;
             self.depth -= 1;
-        } else if let ExprKind::Continue(_) = expr.kind {
+        } else if is_conditional(expr) {
+            self.depth += 1;
+            walk_expr(self, expr);
+            self.depth -= 1;
+        } else if let ExprKind::Continue(dest) = expr.kind {
             // If we see a `continue` block, then we increment depth so that the IncrementVisitor
             // state will be set to DontWarn if we see the variable being modified anywhere afterwards.
-            self.depth += 1;
+            // An unlabeled `continue` in a nested loop doesn't skip the rest of the checked loop.
+            if self.loop_depth == 0 || dest.label.is_some() {
+                self.depth += 1;
+            }
         } else {
             walk_expr(self, expr);
         }
@@ -290,3 +302,54 @@ pub(super) fn make_iterator_snippet(cx: &LateContext<'_>, arg: &Expr<'_>, applic
         }
     }
 }
+
+/// Checks if the value has no side effects besides being cloned, i.e. it is the same on each
+/// iteration as long as it doesn't use the loop variable.
+pub(super) fn is_invariant(cx: &LateContext<'_>, value: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(path, recv, [], _) = value.kind
+        && path.ident.name == sym::clone
+        && is_trait_method(cx, value, sym::Clone)
+    {
+        !recv.can_have_side_effects()
+    } else {
+        !value.can_have_side_effects()
+    }
+}
+
+/// Checks if the bounds of a range are constants, with the start not after the end. A loop over a
+/// decreasing range does nothing, while slicing with it panics.
+pub(super) fn is_ordered_const_range(cx: &LateContext<'_>, start: &Expr<'_>, end: &Expr<'_>) -> bool {
+    matches!(
+        (constant(cx, cx.typeck_results(), start), constant(cx, cx.typeck_results(), end)),
+        (Some(Constant::Int(start)), Some(Constant::Int(end))) if start <= end
+    )
+}
+
+/// Gets the only expression of a block consisting of a single statement or a trailing expression.
+pub(crate) fn single_block_expr<'hir>(block: &Block<'hir>) -> Option<&'hir Expr<'hir>> {
+    match (block.stmts, block.expr) {
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            _ => None,
+        },
+        ([], Some(e)) => Some(e),
+        _ => None,
+    }
+}
+
+/// Gets the `Vec` and the pushed value if the loop body consists of a single `push` call.
+pub(super) fn get_single_push<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::Block(block, _) = body.kind
+        && let Some(e) = single_block_expr(block)
+        && let ExprKind::MethodCall(method, vec, [pushed], _) = e.kind
+        && method.ident.name == sym::push
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(vec).peel_refs(), sym::Vec)
+    {
+        Some((vec, pushed))
+    } else {
+        None
+    }
+}
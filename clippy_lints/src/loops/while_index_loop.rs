@@ -0,0 +1,163 @@
+use super::needless_range_loop::{elem_name, elem_sugg, index_uses, VarVisitor};
+use super::WHILE_INDEX_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{with_leading_whitespace, SpanRange};
+use clippy_utils::ty::has_iter_method;
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use clippy_utils::{is_integer_const, path_to_local_id, span_contains_comment};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::Visitor;
+use rustc_hir::{
+    BinOpKind, BindingMode, BorrowKind, Expr, ExprKind, HirId, Mutability, Node, PatKind, QPath, Stmt, StmtKind,
+};
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::{sym, Span};
+
+/// Checks for `while i < v.len() { ..; i += 1; }` loops directly following `let mut i = 0;`,
+/// which only use the counter to index `v` or to number its elements.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    condition: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    header: Span,
+) {
+    if let ExprKind::Loop(_, label, ..) = expr.kind
+        && let ExprKind::Binary(op, lhs, rhs) = condition.kind
+        && op.node == BinOpKind::Lt
+        && let ExprKind::Path(QPath::Resolved(None, counter_path)) = lhs.kind
+        && let Res::Local(counter) = counter_path.res
+        && let ExprKind::MethodCall(len, seq, [], _) = rhs.kind
+        && len.ident.name == sym::len
+        && let ExprKind::Path(QPath::Resolved(None, seq_path)) = seq.kind
+        && let [seq_segment] = seq_path.segments
+        && matches!(seq_path.res, Res::Local(_))
+        && let indexed = seq_segment.ident.name
+        && let ExprKind::Block(block, None) = body.kind
+        && block.expr.is_none()
+        && let [stmts @ .., increment] = block.stmts
+        && is_increment_of(cx, increment, counter)
+        && !header.from_expansion()
+        && !increment.span.from_expansion()
+        // The loop must directly follow `let mut i = 0;`, and the counter must not be used after it.
+        && let Node::Stmt(loop_stmt) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let Node::Block(outer) = cx.tcx.parent_hir_node(loop_stmt.hir_id)
+        && let Some(loop_idx) = outer.stmts.iter().position(|stmt| stmt.hir_id == loop_stmt.hir_id)
+        && let Some(let_stmt) = loop_idx.checked_sub(1).map(|idx| &outer.stmts[idx])
+        && let StmtKind::Let(local) = let_stmt.kind
+        && local.els.is_none()
+        && let PatKind::Binding(BindingMode::MUT, id, _, None) = local.pat.kind
+        && id == counter
+        && local.init.is_some_and(|init| is_integer_const(cx, init, 0))
+        && !let_stmt.span.from_expansion()
+        && !is_local_used(cx, &outer.stmts[loop_idx + 1..], counter)
+        && !is_local_used(cx, outer.expr, counter)
+        // Skipping elements, or restarting an iteration without incrementing the counter, can't be
+        // expressed with an iterator.
+        && !modifies_or_continues(cx, stmts, counter, expr)
+    {
+        let mut visitor = VarVisitor::new(cx, counter);
+        for stmt in stmts {
+            visitor.visit_stmt(stmt);
+        }
+        // The sequence must only be indexed directly with the counter, and not be used otherwise.
+        if !visitor.indexed_indirectly.is_empty()
+            || visitor.indexed_directly.len() != 1
+            || visitor.referenced.contains(&indexed)
+        {
+            return;
+        }
+        let Some(&(_, indexed_ty)) = visitor.indexed_directly.get(&indexed) else {
+            return;
+        };
+        if has_iter_method(cx, indexed_ty).is_none() {
+            return;
+        }
+        let Some(elem) = elem_name(cx, body) else {
+            return;
+        };
+        let Some(uses) = index_uses(cx, stmts, counter, indexed) else {
+            return;
+        };
+
+        let (method, ref_mut) = if visitor.indexed_mut.contains(&indexed) {
+            ("iter_mut", "&mut ")
+        } else {
+            ("iter", "&")
+        };
+        let counter_name = counter_path.segments[0].ident.name;
+        let (pat, iter) = if visitor.nonindex {
+            (
+                format!("({counter_name}, {elem})"),
+                format!("{indexed}.{method}().enumerate()"),
+            )
+        } else if indexed_ty.is_ref() {
+            (elem.to_string(), format!("{indexed}.{method}()"))
+        } else {
+            (elem.to_string(), format!("{ref_mut}{indexed}"))
+        };
+        let label = label.map_or(String::new(), |label| format!("{}: ", label.ident));
+
+        // Remove the whole lines of the `let` statement and the increment, not just the statements.
+        let mut sugg = vec![
+            (let_stmt.span.until(loop_stmt.span), String::new()),
+            (header, format!("{label}for {pat} in {iter}")),
+            (
+                with_leading_whitespace(cx, increment.span).set_span_pos(increment.span),
+                String::new(),
+            ),
+        ];
+        sugg.extend(uses.iter().map(|e| (e.span, elem_sugg(cx, e, elem))));
+
+        let app = if span_contains_comment(cx.sess().source_map(), let_stmt.span.between(header)) {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        span_lint_and_then(
+            cx,
+            WHILE_INDEX_LOOP,
+            header,
+            format!("the counter `{counter_name}` is used to index `{indexed}` in a `while` loop"),
+            |diag| {
+                diag.multipart_suggestion("consider using a `for` loop over the elements", sugg, app);
+            },
+        );
+    }
+}
+
+/// Checks if the statement is `counter += 1;`.
+fn is_increment_of(cx: &LateContext<'_>, stmt: &Stmt<'_>, counter: HirId) -> bool {
+    if let StmtKind::Semi(e) = stmt.kind
+        && let ExprKind::AssignOp(op, lhs, rhs) = e.kind
+        && op.node == BinOpKind::Add
+        && path_to_local_id(lhs, counter)
+    {
+        is_integer_const(cx, rhs, 1)
+    } else {
+        false
+    }
+}
+
+/// Checks if the statements assign to the counter, borrow it mutably, or `continue` the loop.
+fn modifies_or_continues<'tcx>(
+    cx: &LateContext<'tcx>,
+    stmts: &'tcx [Stmt<'tcx>],
+    counter: HirId,
+    loop_expr: &Expr<'_>,
+) -> bool {
+    for_each_expr(cx, stmts, |e| match e.kind {
+        ExprKind::Assign(lhs, ..)
+        | ExprKind::AssignOp(_, lhs, _)
+        | ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, lhs)
+            if path_to_local_id(lhs, counter) =>
+        {
+            ControlFlow::Break(())
+        },
+        ExprKind::Continue(dest) if dest.target_id == Ok(loop_expr.hir_id) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
@@ -6,7 +6,7 @@ use clippy_utils::{get_enclosing_loop_or_multi_call_closure, higher, is_refutabl
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
 use rustc_hir::intravisit::{walk_expr, Visitor};
-use rustc_hir::{Closure, Expr, ExprKind, HirId, LangItem, LetStmt, Mutability, PatKind, UnOp};
+use rustc_hir::{BorrowKind, Closure, Expr, ExprKind, HirId, LangItem, LetStmt, Mutability, PatKind, UnOp};
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter::OnlyBodies;
 use rustc_middle::ty::adjustment::Adjust;
@@ -37,27 +37,34 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             "_".into()
         };
 
-        // If the iterator is a field or the iterator is accessed after the loop is complete it needs to be
-        // borrowed mutably. TODO: If the struct can be partially moved from and the struct isn't used
-        // afterwards a mutable borrow of a field isn't necessary.
-        let by_ref = if cx.typeck_results().expr_ty(iter_expr).ref_mutability() == Some(Mutability::Mut)
-            || !iter_expr_struct.can_move
-            || !iter_expr_struct.fields.is_empty()
-            || needs_mutable_borrow(cx, &iter_expr_struct, expr)
-        {
-            ".by_ref()"
+        let iterator = if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, inner) = iter_expr.kind {
+            // `(&mut iter).next()`, the iterator is already borrowed mutably.
+            format!("&mut {}", snippet_with_applicability(cx, inner.span, "_", &mut applicability))
         } else {
-            ""
+            let iterator = snippet_with_applicability(cx, iter_expr.span, "_", &mut applicability);
+            // If the iterator is accessed after the loop is complete it needs to be borrowed mutably. A
+            // field is always borrowed mutably, as the struct might not be movable. TODO: If the struct can
+            // be partially moved from and the struct isn't used afterwards a mutable borrow of a field
+            // isn't necessary.
+            if cx.typeck_results().expr_ty(iter_expr).ref_mutability() == Some(Mutability::Mut)
+                || !iter_expr_struct.can_move
+                || needs_mutable_borrow(cx, &iter_expr_struct, expr)
+            {
+                format!("{iterator}.by_ref()")
+            } else if !iter_expr_struct.fields.is_empty() {
+                format!("&mut {iterator}")
+            } else {
+                iterator.into_owned()
+            }
         };
 
-        let iterator = snippet_with_applicability(cx, iter_expr.span, "_", &mut applicability);
         span_lint_and_sugg(
             cx,
             WHILE_LET_ON_ITERATOR,
             expr.span.with_hi(let_expr.span.hi()),
             "this loop could be written as a `for` loop",
             "try",
-            format!("for {loop_var} in {iterator}{by_ref}"),
+            format!("for {loop_var} in {iterator}"),
             applicability,
         );
     }
@@ -132,7 +132,7 @@ impl<'tcx> LateLintPass<'tcx> for MacroUseImports {
     }
     fn check_crate_post(&mut self, cx: &LateContext<'_>) {
         let mut used = BTreeMap::new();
-        let mut check_dup = vec![];
+        let mut check_dup = FxHashSet::default();
         for (import, span, hir_id) in &self.imports {
             let found_idx = self.mac_refs.iter().position(|mac| import.ends_with(&mac.name));
 
@@ -149,7 +149,7 @@ impl<'tcx> LateLintPass<'tcx> for MacroUseImports {
                             used.entry(((*root).to_string(), span, hir_id))
                                 .or_insert_with(Vec::new)
                                 .push((*item).to_string());
-                            check_dup.push((*item).to_string());
+                            check_dup.insert((*item).to_string());
                         }
                     },
                     [root, rest @ ..] => {
@@ -136,15 +136,11 @@ fn create_sugg(cx: &LateContext<'_>, expr: &Expr<'_>, base_sugg: String) -> Stri
 }
 
 fn is_ty_conversion(expr: &Expr<'_>) -> bool {
-    if let ExprKind::Cast(..) = expr.kind {
-        true
-    } else if let ExprKind::MethodCall(path, _, [], _) = expr.kind
-        && path.ident.name == sym::try_into
-    {
+    match expr.kind {
+        ExprKind::Cast(..) => true,
         // This is only called for `usize` which implements `TryInto`. Therefore,
         // we don't have to check here if `self` implements the `TryInto` trait.
-        true
-    } else {
-        false
+        ExprKind::MethodCall(path, _, [], _) => path.ident.name == sym::try_into,
+        _ => false,
     }
 }
@@ -30,12 +30,12 @@ declare_clippy_lint! {
     ///
     /// ### Limitations
     ///
-    /// This lint will only trigger if max and min are known at compile time, and max is
-    /// greater than min.
+    /// This lint will only trigger if max and min are known at compile time. If max is
+    /// less than min, or either of them is NaN, the pattern is reported without a suggestion.
     ///
     /// ### Known issue(s)
-    /// If the clamped variable is NaN this suggestion will cause the code to propagate NaN
-    /// rather than returning either `max` or `min`.
+    /// If the clamped variable is NaN, replacing a chain of float `min` and `max` calls will
+    /// cause the code to propagate NaN rather than returning either `max` or `min`.
     ///
     /// `clamp` functions will panic if `max < min`, `max.is_nan()`, or `min.is_nan()`.
     /// Some may consider panicking in these situations to be desirable, but it also may
@@ -108,24 +108,25 @@ struct ClampSuggestion<'tcx> {
     span: Span,
     make_assignment: Option<&'tcx Expr<'tcx>>,
     hir_with_ignore_attr: Option<HirId>,
+    /// Whether the pattern is a chain of `min` and `max` calls, which for floats differs from
+    /// `clamp` when the input is NaN.
+    is_min_max_chain: bool,
 }
 
 impl<'tcx> ClampSuggestion<'tcx> {
-    /// This function will return true if and only if you can demonstrate at compile time that min
-    /// is less than max.
-    fn min_less_than_max(&self, cx: &LateContext<'tcx>) -> bool {
+    /// Compares min and max if both of them can be evaluated at compile time. Returns `None` if
+    /// they can't be compared, and `Some(None)` if a float bound is NaN.
+    fn cmp_min_max(&self, cx: &LateContext<'tcx>) -> Option<Option<Ordering>> {
         let max_type = cx.typeck_results().expr_ty(self.params.max);
         let min_type = cx.typeck_results().expr_ty(self.params.min);
         if max_type != min_type {
-            return false;
+            return None;
         }
-        if let Some(max) = constant(cx, cx.typeck_results(), self.params.max)
-            && let Some(min) = constant(cx, cx.typeck_results(), self.params.min)
-            && let Some(ord) = Constant::partial_cmp(cx.tcx, max_type, &min, &max)
-        {
-            ord != Ordering::Greater
-        } else {
-            false
+        let max = constant(cx, cx.typeck_results(), self.params.max)?;
+        let min = constant(cx, cx.typeck_results(), self.params.min)?;
+        match Constant::partial_cmp(cx.tcx, max_type, &min, &max) {
+            None if !self.params.is_float => None,
+            ord => Some(ord),
         }
     }
 }
@@ -167,7 +168,25 @@ impl<'tcx> LateLintPass<'tcx> for ManualClamp {
 }
 
 fn maybe_emit_suggestion<'tcx>(cx: &LateContext<'tcx>, suggestion: &ClampSuggestion<'tcx>) {
-    if !suggestion.min_less_than_max(cx) {
+    let Some(ord) = suggestion.cmp_min_max(cx) else {
+        return;
+    };
+    let msg = "clamp-like pattern without using clamp function";
+    if !matches!(ord, Some(Ordering::Less | Ordering::Equal)) {
+        // `clamp` would be guaranteed to panic, so only point out the pattern
+        let note = if ord.is_some() {
+            "min is greater than max, so clamp would panic"
+        } else {
+            "min or max is NaN, so clamp would panic"
+        };
+        let lint_builder = |d: &mut Diag<'_, ()>| {
+            d.note(note);
+        };
+        if let Some(hir_id) = suggestion.hir_with_ignore_attr {
+            span_lint_hir_and_then(cx, MANUAL_CLAMP, hir_id, suggestion.span, msg, lint_builder);
+        } else {
+            span_lint_and_then(cx, MANUAL_CLAMP, suggestion.span, msg, lint_builder);
+        }
         return;
     }
     let ClampSuggestion {
@@ -180,6 +199,7 @@ fn maybe_emit_suggestion<'tcx>(cx: &LateContext<'tcx>, suggestion: &ClampSuggest
         span,
         make_assignment,
         hir_with_ignore_attr,
+        is_min_max_chain,
     } = suggestion;
     let input = Sugg::hir(cx, input, "..").maybe_par();
     let min = Sugg::hir(cx, min, "..");
@@ -192,10 +212,20 @@ fn maybe_emit_suggestion<'tcx>(cx: &LateContext<'tcx>, suggestion: &ClampSuggest
         String::new()
     };
     let suggestion = format!("{assignment}{input}.clamp({min}, {max}){semicolon}");
-    let msg = "clamp-like pattern without using clamp function";
+    // The bounds are known to be valid, so `clamp` behaves the same, except for a NaN input to a
+    // float `min`/`max` chain
+    let nan_input_differs = *is_float && *is_min_max_chain;
+    let applicability = if nan_input_differs {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
     let lint_builder = |d: &mut Diag<'_, ()>| {
-        d.span_suggestion(*span, "replace with clamp", suggestion, Applicability::MaybeIncorrect);
-        if *is_float {
+        d.span_suggestion(*span, "replace with clamp", suggestion, applicability);
+        if nan_input_differs {
+            d.note("clamp will panic if max < min, min.is_nan(), or max.is_nan()")
+                .note("clamp returns NaN if the input is NaN, unlike the `min` and `max` calls");
+        } else if *is_float {
             d.note("clamp will panic if max < min, min.is_nan(), or max.is_nan()")
                 .note("clamp returns NaN if the input is NaN");
         } else {
@@ -278,6 +308,7 @@ fn is_if_elseif_else_pattern<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx
             span: expr.span,
             make_assignment: None,
             hir_with_ignore_attr: None,
+            is_min_max_chain: false,
         })
     } else {
         None
@@ -314,6 +345,7 @@ fn is_max_min_pattern<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> O
             span: expr.span,
             make_assignment: None,
             hir_with_ignore_attr: None,
+            is_min_max_chain: true,
         })
     } else {
         None
@@ -394,6 +426,7 @@ fn is_call_max_min_pattern<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>)
                 span,
                 make_assignment: None,
                 hir_with_ignore_attr: None,
+                is_min_max_chain: true,
             })
         } else {
             None
@@ -461,6 +494,7 @@ fn is_match_pattern<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Opt
                 span: expr.span,
                 make_assignment: None,
                 hir_with_ignore_attr: None,
+                is_min_max_chain: false,
             });
         }
     }
@@ -518,6 +552,7 @@ fn is_two_if_pattern<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) ->
                     span: first_expr.span.to(second_expr.span),
                     make_assignment: Some(maybe_input_first_path),
                     hir_with_ignore_attr: Some(first_expr.hir_id()),
+                    is_min_max_chain: false,
                 })
             } else {
                 None
@@ -568,6 +603,7 @@ fn is_if_elseif_pattern<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) ->
             span: expr.span,
             make_assignment: Some(maybe_input_first_path),
             hir_with_ignore_attr: None,
+            is_min_max_chain: false,
         })
     } else {
         None
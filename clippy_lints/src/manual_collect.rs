@@ -0,0 +1,332 @@
+use crate::loops::utils::single_block_expr;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{get_type_diagnostic_name, has_iter_method, implements_trait, is_copy, is_type_lang_item};
+use clippy_utils::usage::contains_return_break_continue_macro;
+use clippy_utils::visitors::{for_each_local_use_after_expr, is_local_used};
+use clippy_utils::{get_parent_expr, higher, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{
+    BindingMode, Block, BorrowKind, Expr, ExprKind, HirId, LangItem, LetStmt, Mutability, Pat, PatKind, QPath, Stmt,
+    StmtKind,
+};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for empty std collections, like `Vec`s, `String`s, maps and sets, which
+    /// are filled right after being created, either with a single `extend` call or with a `for`
+    /// loop which only pushes or inserts one element per iteration.
+    ///
+    /// ### Why is this bad?
+    /// Collecting the elements says the same thing in one expression, and lets
+    /// `FromIterator` reserve capacity up front from the iterator's size hint.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let words = ["a", "b"];
+    /// let mut lengths = Vec::new();
+    /// for word in words {
+    ///     lengths.push(word.len());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let words = ["a", "b"];
+    /// let lengths: Vec<_> = words.into_iter().map(|word| word.len()).collect();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_COLLECT,
+    pedantic,
+    "empty collection filled by `extend` or a push loop right after creation"
+}
+
+declare_lint_pass!(ManualCollect => [MANUAL_COLLECT]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualCollect {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for [let_stmt, fill_stmt] in block.stmts.array_windows() {
+            if let StmtKind::Let(local) = let_stmt.kind
+                && let StmtKind::Expr(fill) | StmtKind::Semi(fill) = fill_stmt.kind
+                && !in_external_macro(cx.sess(), let_stmt.span)
+                && !fill_stmt.span.from_expansion()
+            {
+                check_init_then_fill(cx, let_stmt, local, fill_stmt, fill);
+            }
+        }
+    }
+}
+
+/// The std collections which can be built by adding the elements one by one.
+#[derive(Clone, Copy)]
+pub(crate) enum CollectionKind {
+    String,
+    /// A collection with one generic type and the method which adds an element to it
+    Sequence(&'static str),
+    Map,
+}
+
+impl CollectionKind {
+    pub(crate) fn new(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<Self> {
+        if is_type_lang_item(cx, ty, LangItem::String) {
+            return Some(Self::String);
+        }
+        match get_type_diagnostic_name(cx, ty)? {
+            sym::Vec | sym::BinaryHeap => Some(Self::Sequence("push")),
+            sym::VecDeque => Some(Self::Sequence("push_back")),
+            sym::HashSet | sym::BTreeSet => Some(Self::Sequence("insert")),
+            sym::HashMap | sym::BTreeMap => Some(Self::Map),
+            _ => None,
+        }
+    }
+
+    /// Returns the generic arguments to add to the type's name in a turbofish or an annotation
+    pub(crate) fn placeholders(self) -> &'static str {
+        match self {
+            Self::String => "",
+            Self::Sequence(_) => "<_>",
+            Self::Map => "<_, _>",
+        }
+    }
+}
+
+/// The element added by a `push` or `insert` call.
+#[derive(Clone, Copy)]
+pub(crate) enum Element<'tcx> {
+    Single(&'tcx Expr<'tcx>),
+    /// The key and value inserted into a map
+    Pair(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>),
+}
+
+fn check_init_then_fill<'tcx>(
+    cx: &LateContext<'tcx>,
+    let_stmt: &'tcx Stmt<'tcx>,
+    local: &'tcx LetStmt<'tcx>,
+    fill_stmt: &'tcx Stmt<'tcx>,
+    fill: &'tcx Expr<'tcx>,
+) {
+    let Some(init) = local.init else { return };
+    let PatKind::Binding(BindingMode::MUT, local_id, name, None) = local.pat.kind else {
+        return;
+    };
+    let Some((kind, ty_path)) = collection_init(cx, init) else {
+        return;
+    };
+
+    let mut app = Applicability::MachineApplicable;
+    let iter = if let ExprKind::MethodCall(method, recv, [arg], _) = fill.kind
+        && method.ident.as_str() == "extend"
+        && path_to_local_id(recv, local_id)
+        && !is_local_used(cx, arg, local_id)
+        && extend_item_matches(cx, fill, kind, cx.typeck_results().expr_ty(init))
+    {
+        iter_sugg(cx, arg, &mut app)
+    } else if let Some(for_loop) = higher::ForLoop::hir(fill)
+        && !is_local_used(cx, for_loop.arg, local_id)
+        && let ExprKind::Block(body, None) = for_loop.body.kind
+        && let Some(push) = single_block_expr(body)
+        && let Some((cond, elem)) = single_push(cx, push, local_id, kind)
+    {
+        let iter = iter_sugg(cx, for_loop.arg, &mut app);
+        let pat = snippet_with_applicability(cx, for_loop.pat.span, "..", &mut app);
+        let elem = match elem {
+            Element::Single(elem) => snippet_with_applicability(cx, elem.span, "..", &mut app).into_owned(),
+            Element::Pair(key, value) => format!(
+                "({}, {})",
+                snippet_with_applicability(cx, key.span, "..", &mut app),
+                snippet_with_applicability(cx, value.span, "..", &mut app),
+            ),
+        };
+        let is_identity = elem == pat;
+        match cond {
+            None if is_identity => iter,
+            None => format!("{iter}.map(|{pat}| {elem})"),
+            Some(cond) => {
+                // The condition may rely on the loop pattern being bound by value
+                app = Applicability::MaybeIncorrect;
+                let cond = snippet_with_applicability(cx, cond.span, "..", &mut app);
+                let filter = if is_copy_pat(cx, for_loop.pat) {
+                    format!("{iter}.filter(|&{pat}| {cond})")
+                } else {
+                    format!("{iter}.filter(|{pat}| {cond})")
+                };
+                if is_identity {
+                    filter
+                } else {
+                    format!("{filter}.map(|{pat}| {elem})")
+                }
+            },
+        }
+    } else {
+        return;
+    };
+
+    let ty = if let Some(ty) = local.ty {
+        snippet_with_applicability(cx, ty.span, "_", &mut app).into_owned()
+    } else {
+        let ty_path = snippet_with_applicability(cx, ty_path.span, "_", &mut app);
+        let placeholders = if ty_path.contains('<') {
+            ""
+        } else {
+            kind.placeholders()
+        };
+        format!("{ty_path}{placeholders}")
+    };
+    let mutability = if is_mutated_after(cx, local_id, fill) { "mut " } else { "" };
+
+    span_lint_and_sugg(
+        cx,
+        MANUAL_COLLECT,
+        let_stmt.span.to(fill_stmt.span),
+        format!("`{name}` is filled right after being created empty"),
+        "collect the elements instead",
+        format!("let {mutability}{name}: {ty} = {iter}.collect();"),
+        app,
+    );
+}
+
+/// Matches `new()` of the std collections, returning the path of the type being constructed.
+fn collection_init<'tcx>(
+    cx: &LateContext<'tcx>,
+    init: &'tcx Expr<'tcx>,
+) -> Option<(CollectionKind, &'tcx rustc_hir::Ty<'tcx>)> {
+    if let ExprKind::Call(callee, []) = init.kind
+        && let ExprKind::Path(QPath::TypeRelative(ty_path, segment)) = callee.kind
+        && segment.ident.name == sym::new
+        && !init.span.from_expansion()
+    {
+        Some((CollectionKind::new(cx, cx.typeck_results().expr_ty(init))?, ty_path))
+    } else {
+        None
+    }
+}
+
+/// Checks that `extend` is called with the element type itself, as e.g. `Vec<T>` can also be
+/// extended with `&T`, but can't be collected from it.
+fn extend_item_matches<'tcx>(
+    cx: &LateContext<'tcx>,
+    extend: &Expr<'_>,
+    kind: CollectionKind,
+    collection_ty: Ty<'tcx>,
+) -> bool {
+    let ty::Adt(_, collection_args) = collection_ty.kind() else {
+        return false;
+    };
+    let item_ty = cx.typeck_results().node_args(extend.hir_id).type_at(1);
+    let elem_ty = match kind {
+        // `String` can be collected from everything it can be extended with.
+        CollectionKind::String => return true,
+        CollectionKind::Sequence(_) => collection_args.type_at(0),
+        CollectionKind::Map => Ty::new_tup(cx.tcx, &[collection_args.type_at(0), collection_args.type_at(1)]),
+    };
+    cx.tcx.erase_regions(item_ty) == cx.tcx.erase_regions(elem_ty)
+}
+
+/// Matches a single `push` or `insert` call adding an element to the collection `local_id`,
+/// optionally wrapped in an `if` without an `else` branch. Returns the condition and the element
+/// being added.
+pub(crate) fn single_push<'tcx>(
+    cx: &LateContext<'tcx>,
+    push: &'tcx Expr<'tcx>,
+    local_id: HirId,
+    kind: CollectionKind,
+) -> Option<(Option<&'tcx Expr<'tcx>>, Element<'tcx>)> {
+    let (cond, push) = if let Some(higher::If {
+        cond,
+        then,
+        r#else: None,
+    }) = higher::If::hir(push)
+    {
+        if is_local_used(cx, cond, local_id) || contains_return_break_continue_macro(cond) {
+            return None;
+        }
+        let ExprKind::Block(then, None) = then.kind else {
+            return None;
+        };
+        (Some(cond), single_block_expr(then)?)
+    } else {
+        (None, push)
+    };
+
+    let ExprKind::MethodCall(method, recv, args, _) = push.kind else {
+        return None;
+    };
+    // Coercions of the arguments, like `&String` to `&str`, wouldn't happen when collecting.
+    if !path_to_local_id(recv, local_id)
+        || push.span.from_expansion()
+        || args.iter().any(|arg| {
+            is_local_used(cx, arg, local_id)
+                || contains_return_break_continue_macro(arg)
+                || !cx.typeck_results().expr_adjustments(arg).is_empty()
+        })
+    {
+        return None;
+    }
+    let elem = match (kind, args) {
+        (CollectionKind::String, [elem]) if matches!(method.ident.as_str(), "push" | "push_str") => {
+            Element::Single(elem)
+        },
+        (CollectionKind::Sequence(name), [elem]) if method.ident.as_str() == name => Element::Single(elem),
+        (CollectionKind::Map, [key, value]) if method.ident.as_str() == "insert" => Element::Pair(key, value),
+        _ => return None,
+    };
+    Some((cond, elem))
+}
+
+/// Builds the iterator to collect from the `IntoIterator` argument of `extend` or a `for` loop.
+fn iter_sugg(cx: &LateContext<'_>, arg: &Expr<'_>, app: &mut Applicability) -> String {
+    if let ExprKind::AddrOf(BorrowKind::Ref, mutbl, inner) = arg.kind
+        && has_iter_method(cx, cx.typeck_results().expr_ty(inner)).is_some()
+    {
+        let inner = Sugg::hir_with_applicability(cx, inner, "..", app).maybe_par();
+        return match mutbl {
+            Mutability::Not => format!("{inner}.iter()"),
+            Mutability::Mut => format!("{inner}.iter_mut()"),
+        };
+    }
+    let sugg = Sugg::hir_with_applicability(cx, arg, "..", app).maybe_par();
+    if let Some(iterator) = cx.tcx.get_diagnostic_item(sym::Iterator)
+        && implements_trait(cx, cx.typeck_results().expr_ty(arg), iterator, &[])
+    {
+        sugg.to_string()
+    } else {
+        format!("{sugg}.into_iter()")
+    }
+}
+
+/// Checks if all the bindings of the pattern are by value and of `Copy` types, so that it can
+/// also match the references `filter` passes to its closure.
+fn is_copy_pat(cx: &LateContext<'_>, pat: &Pat<'_>) -> bool {
+    let mut all_copy = true;
+    pat.walk_always(|pat| {
+        if let PatKind::Binding(mode, ..) = pat.kind {
+            all_copy &= mode == BindingMode::NONE && is_copy(cx, cx.typeck_results().pat_ty(pat));
+        }
+    });
+    all_copy
+}
+
+/// Checks whether the collection is still mutated after it has been filled, in which case the
+/// binding has to stay `mut`.
+fn is_mutated_after(cx: &LateContext<'_>, local_id: HirId, fill: &Expr<'_>) -> bool {
+    for_each_local_use_after_expr(cx, local_id, fill.hir_id, |e| {
+        let is_mut_use = cx.typeck_results().expr_ty_adjusted(e).ref_mutability() == Some(Mutability::Mut)
+            || get_parent_expr(cx, e).is_some_and(|parent| match parent.kind {
+                ExprKind::AddrOf(_, Mutability::Mut, _) => true,
+                ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) => lhs.hir_id == e.hir_id,
+                _ => false,
+            });
+        if is_mut_use {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_break()
+}
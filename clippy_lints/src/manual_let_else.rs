@@ -5,10 +5,13 @@ use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::higher::IfLetOrMatch;
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{is_lint_allowed, is_never_expr, pat_and_expr_can_be_question_mark, peel_blocks};
+use clippy_utils::{
+    is_lint_allowed, is_never_expr, pat_and_expr_can_be_question_mark, path_to_local_id, peel_blocks,
+    span_contains_comment,
+};
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, MatchSource, Pat, PatKind, QPath, Stmt, StmtKind};
+use rustc_hir::{BindingMode, ByRef, Expr, ExprKind, MatchSource, Pat, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::lint::in_external_macro;
 
@@ -62,13 +65,13 @@ impl<'tcx> QuestionMark {
         {
             match if_let_or_match {
                 IfLetOrMatch::IfLet(if_let_expr, let_pat, if_then, if_else, ..) => {
-                    if let Some(ident_map) = expr_simple_identity_map(local.pat, let_pat, if_then)
+                    if let Some(replacement) = pat_replacement(cx, local.pat, let_pat, if_then)
                         && let Some(if_else) = if_else
                         && is_never_expr(cx, if_else).is_some()
                         && let qm_allowed = is_lint_allowed(cx, QUESTION_MARK, stmt.hir_id)
                         && (qm_allowed || pat_and_expr_can_be_question_mark(cx, let_pat, if_else).is_none())
                     {
-                        emit_manual_let_else(cx, stmt.span, if_let_expr, &ident_map, let_pat, if_else);
+                        emit_manual_let_else(cx, stmt.span, if_let_expr, &replacement, let_pat, if_else);
                     }
                 },
                 IfLetOrMatch::Match(match_expr, arms, source) => {
@@ -102,22 +105,47 @@ impl<'tcx> QuestionMark {
                         return;
                     }
                     let pat_arm = &arms[1 - idx];
-                    let Some(ident_map) = expr_simple_identity_map(local.pat, pat_arm.pat, pat_arm.body) else {
+                    let Some(replacement) = pat_replacement(cx, local.pat, pat_arm.pat, pat_arm.body) else {
                         return;
                     };
 
-                    emit_manual_let_else(cx, stmt.span, match_expr, &ident_map, pat_arm.pat, diverging_arm.body);
+                    emit_manual_let_else(cx, stmt.span, match_expr, &replacement, pat_arm.pat, diverging_arm.body);
                 },
             }
         };
     }
 }
 
+/// How the pattern of the `if let` or of the binding arm is turned into the pattern of the
+/// `let...else` statement.
+enum PatReplacement<'a, 'hir> {
+    /// The bindings are replaced by the sub-patterns of the `let` statement they are mapped to.
+    Bindings(FxHashMap<Symbol, &'a Pat<'hir>>),
+    /// A tuple pattern whose bindings are re-tupled in the same order is replaced by the single
+    /// binding of the `let` statement, e.g. `Some((a, b)) => (a, b)` in `let v = match ..`.
+    Tuple {
+        tuple_pat: &'a Pat<'hir>,
+        local_pat: &'a Pat<'hir>,
+    },
+}
+
+fn pat_replacement<'a, 'hir>(
+    cx: &LateContext<'_>,
+    local_pat: &'a Pat<'hir>,
+    let_pat: &'a Pat<'hir>,
+    expr: &'_ Expr<'hir>,
+) -> Option<PatReplacement<'a, 'hir>> {
+    if let Some(ident_map) = expr_simple_identity_map(local_pat, let_pat, expr) {
+        return Some(PatReplacement::Bindings(ident_map));
+    }
+    retupled_pat(cx, local_pat, let_pat, expr).map(|tuple_pat| PatReplacement::Tuple { tuple_pat, local_pat })
+}
+
 fn emit_manual_let_else(
     cx: &LateContext<'_>,
     span: Span,
     expr: &Expr<'_>,
-    ident_map: &FxHashMap<Symbol, &Pat<'_>>,
+    replacement: &PatReplacement<'_, '_>,
     pat: &Pat<'_>,
     else_body: &Expr<'_>,
 ) {
@@ -133,6 +161,13 @@ fn emit_manual_let_else(
             // * unused binding collision detection with existing ones
             // for this to be machine applicable.
             let mut app = Applicability::HasPlaceholders;
+            // Comments outside of the diverging arm would be removed.
+            let sm = cx.sess().source_map();
+            if span_contains_comment(sm, span.with_lo(expr.span.hi()).with_hi(else_body.span.lo()))
+                || span_contains_comment(sm, span.with_lo(else_body.span.hi()))
+            {
+                app = Applicability::MaybeIncorrect;
+            }
             let (sn_expr, _) = snippet_with_context(cx, expr.span, span.ctxt(), "", &mut app);
             let (sn_else, else_is_mac_call) = snippet_with_context(cx, else_body.span, span.ctxt(), "", &mut app);
 
@@ -141,7 +176,22 @@ fn emit_manual_let_else(
             } else {
                 format!("{{ {sn_else} }}")
             };
-            let sn_bl = replace_in_pattern(cx, span, ident_map, pat, &mut app, true);
+            let sn_bl = match replacement {
+                PatReplacement::Bindings(ident_map) => replace_in_pattern(cx, span, ident_map, pat, &mut app, true),
+                PatReplacement::Tuple { tuple_pat, local_pat } => {
+                    let (sn_before, _) =
+                        snippet_with_context(cx, pat.span.until(tuple_pat.span), span.ctxt(), "", &mut app);
+                    let (sn_local, _) = snippet_with_context(cx, local_pat.span, span.ctxt(), "", &mut app);
+                    let (sn_after, _) = snippet_with_context(
+                        cx,
+                        pat.span.with_lo(tuple_pat.span.hi()),
+                        span.ctxt(),
+                        "",
+                        &mut app,
+                    );
+                    format!("{sn_before}{sn_local}{sn_after}")
+                },
+            };
             let sugg = format!("let {sn_bl} = {sn_expr} else {else_bl};");
             diag.span_suggestion(span, "consider writing", sugg, app);
         },
@@ -308,6 +358,60 @@ fn pat_allowed_for_else(cx: &LateContext<'_>, pat: &'_ Pat<'_>, check_types: boo
     !has_disallowed
 }
 
+/// Checks if the passed block re-tuples the bindings of a tuple in the pattern, in the same order,
+/// and if yes, returns that tuple pattern so that it can be replaced by the binding of the `let`
+/// statement, e.g.:
+///
+/// ```ignore
+/// let v = match opt { Some((a, b)) => (a, b), None => return };
+/// ```
+///
+/// Other bindings in the pattern aren't supported, as they would be introduced into the scope.
+/// Neither are bindings made by reference through match ergonomics, as the tuple of references
+/// has a different type than the one matched by the pattern.
+fn retupled_pat<'a, 'hir>(
+    cx: &LateContext<'_>,
+    local_pat: &'_ Pat<'hir>,
+    let_pat: &'a Pat<'hir>,
+    expr: &'_ Expr<'hir>,
+) -> Option<&'a Pat<'hir>> {
+    let PatKind::Binding(BindingMode(ByRef::No, _), _, _, None) = local_pat.kind else {
+        return None;
+    };
+    let tuple_expr = peel_blocks(expr);
+    let ExprKind::Tup(exprs) = tuple_expr.kind else {
+        return None;
+    };
+    let mut binding_count = 0;
+    let_pat.each_binding_or_first(&mut |_, _, _, _| binding_count += 1);
+    if binding_count != exprs.len() {
+        return None;
+    }
+    let typeck_results = cx.typeck_results();
+    find_retupled(let_pat, exprs)
+        .filter(|tuple_pat| typeck_results.pat_ty(tuple_pat) == typeck_results.expr_ty(tuple_expr))
+}
+
+fn find_retupled<'a, 'hir>(pat: &'a Pat<'hir>, exprs: &[Expr<'_>]) -> Option<&'a Pat<'hir>> {
+    match pat.kind {
+        PatKind::Tuple(pats, dot_dot)
+            if dot_dot.as_opt_usize().is_none()
+                && pats.len() == exprs.len()
+                && pats.iter().zip(exprs).all(|(pat, e)| {
+                    matches!(pat.kind, PatKind::Binding(BindingMode::NONE, id, _, None) if path_to_local_id(e, id))
+                }) =>
+        {
+            Some(pat)
+        },
+        PatKind::Tuple(pats, _) | PatKind::TupleStruct(_, pats, _) => {
+            pats.iter().find_map(|pat| find_retupled(pat, exprs))
+        },
+        PatKind::Struct(_, fields, _) => fields.iter().find_map(|field| find_retupled(field.pat, exprs)),
+        PatKind::Box(pat) | PatKind::Ref(pat, _) => find_retupled(pat, exprs),
+        _ => None,
+    }
+}
+
 /// Checks if the passed block is a simple identity referring to bindings created by the pattern,
 /// and if yes, returns a mapping between the relevant sub-pattern and the identifier it corresponds
 /// to.
@@ -0,0 +1,168 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::{indent_of, reindent_multiline, snippet_with_applicability};
+use clippy_utils::{is_else_clause, is_refutable, peel_hir_pat_refs, recurse_or_patterns, SpanlessEq};
+use itertools::Itertools;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BinOpKind, Expr, ExprKind, Pat, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use std::fmt::Write;
+
+use super::IF_LET_CHAIN_SAME_SCRUTINEE;
+
+/// A branch of the chain: `if let <pat> = <scrutinee> && <guard>.. { <body> }`
+struct Branch<'tcx> {
+    pat: &'tcx Pat<'tcx>,
+    guard: Vec<&'tcx Expr<'tcx>>,
+    body: &'tcx Expr<'tcx>,
+}
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+    if is_else_clause(cx.tcx, expr) {
+        return;
+    }
+
+    let mut scrutinee = None;
+    let mut branches = Vec::new();
+    let mut els = None;
+    let mut cur = expr;
+    while let ExprKind::If(cond, then, r#else) = cur.kind
+        && let Some((init, branch)) = split_let_cond(cond, then)
+    {
+        if let Some(scrutinee) = scrutinee
+            && !SpanlessEq::new(cx).deny_side_effects().eq_expr(scrutinee, init)
+        {
+            break;
+        }
+        scrutinee = Some(init);
+        branches.push(branch);
+        els = r#else;
+        match r#else {
+            Some(r#else) => cur = r#else,
+            None => break,
+        }
+    }
+
+    let Some(scrutinee) = scrutinee else {
+        return;
+    };
+    if branches.len() < 2 || !is_side_effect_free(cx, scrutinee) {
+        return;
+    }
+
+    let mut app = Applicability::MaybeIncorrect;
+    let indent = indent_of(cx, expr.span).unwrap_or(0);
+    let arm_indent = " ".repeat(indent + 4);
+    let arm_body = |body: &Expr<'_>, app: &mut Applicability| {
+        reindent_multiline(snippet_with_applicability(cx, body.span, "..", app), true, Some(indent + 4))
+    };
+
+    let mut sugg = format!("match {} {{", snippet_with_applicability(cx, scrutinee.span, "..", &mut app));
+    for branch in &branches {
+        let _ = write!(sugg, "\n{arm_indent}{}", snippet_with_applicability(cx, branch.pat.span, "..", &mut app));
+        if !branch.guard.is_empty() {
+            let guard = branch
+                .guard
+                .iter()
+                .map(|cond| snippet_with_applicability(cx, cond.span, "..", &mut app))
+                .join(" && ");
+            let _ = write!(sugg, " if {guard}");
+        }
+        let _ = write!(sugg, " => {}", arm_body(branch.body, &mut app));
+    }
+    // The trailing `else` is unreachable if the patterns cover all the variants
+    if !is_exhaustive(cx, scrutinee, &branches) {
+        let body = els.map_or_else(|| "{}".into(), |els| arm_body(els, &mut app));
+        let _ = write!(sugg, "\n{arm_indent}_ => {body}");
+    }
+    let _ = write!(sugg, "\n{}}}", " ".repeat(indent));
+
+    span_lint_and_sugg(
+        cx,
+        IF_LET_CHAIN_SAME_SCRUTINEE,
+        expr.span,
+        "this `if let` chain matches the same value repeatedly",
+        "consider using a `match`",
+        sugg,
+        app,
+    );
+}
+
+/// Splits the condition `let <pat> = <scrutinee> && <cond> && ..` of a branch into the scrutinee
+/// and the rest of the branch.
+fn split_let_cond<'tcx>(
+    mut cond: &'tcx Expr<'tcx>,
+    body: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, Branch<'tcx>)> {
+    let mut guard = Vec::new();
+    while let ExprKind::Binary(op, lhs, rhs) = cond.kind
+        && op.node == BinOpKind::And
+        && !matches!(rhs.kind, ExprKind::Let(_))
+    {
+        guard.push(rhs);
+        cond = lhs;
+    }
+    guard.reverse();
+
+    if let ExprKind::Let(let_expr) = cond.kind {
+        let branch = Branch {
+            pat: let_expr.pat,
+            guard,
+            body,
+        };
+        Some((let_expr.init, branch))
+    } else {
+        None
+    }
+}
+
+/// Checks whether evaluating the scrutinee once instead of in each branch can't make a
+/// difference, i.e. it is a place or a tuple of places without overloaded operators.
+fn is_side_effect_free(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Path(_) | ExprKind::Lit(_) => true,
+        ExprKind::Field(base, _) | ExprKind::AddrOf(_, _, base) => is_side_effect_free(cx, base),
+        ExprKind::Unary(UnOp::Deref, base) => !cx.typeck_results().is_method_call(e) && is_side_effect_free(cx, base),
+        ExprKind::Index(base, index, _) => {
+            !cx.typeck_results().is_method_call(e) && is_side_effect_free(cx, base) && is_side_effect_free(cx, index)
+        },
+        ExprKind::Tup(exprs) => exprs.iter().all(|e| is_side_effect_free(cx, e)),
+        _ => false,
+    }
+}
+
+/// Checks whether the unguarded patterns cover every variant of the matched enum.
+fn is_exhaustive(cx: &LateContext<'_>, scrutinee: &Expr<'_>, branches: &[Branch<'_>]) -> bool {
+    let ty = cx.typeck_results().expr_ty(scrutinee).peel_refs();
+    let ty::Adt(adt, _) = ty.kind() else {
+        return false;
+    };
+    if !adt.is_enum() || (adt.is_variant_list_non_exhaustive() && !adt.did().is_local()) {
+        return false;
+    }
+
+    let mut missing_variants: Vec<_> = adt.variants().iter().map(|variant| variant.def_id).collect();
+    for branch in branches.iter().filter(|branch| branch.guard.is_empty()) {
+        recurse_or_patterns(branch.pat, |pat| {
+            let pat = peel_hir_pat_refs(pat).0;
+            let (PatKind::Path(ref qpath) | PatKind::TupleStruct(ref qpath, ..) | PatKind::Struct(ref qpath, ..)) =
+                pat.kind
+            else {
+                return;
+            };
+            let covers_variant = match pat.kind {
+                PatKind::TupleStruct(_, pats, _) => pats.iter().all(|pat| !is_refutable(cx, pat)),
+                PatKind::Struct(_, fields, _) => fields.iter().all(|field| !is_refutable(cx, field.pat)),
+                _ => true,
+            };
+            if covers_variant
+                && let res @ Res::Def(DefKind::Variant | DefKind::Ctor(..), _) = cx.qpath_res(qpath, pat.hir_id)
+            {
+                let id = adt.variant_of_res(res).def_id;
+                missing_variants.retain(|&variant| variant != id);
+            }
+        });
+    }
+    missing_variants.is_empty()
+}
@@ -0,0 +1,131 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_res_lang_ctor, path_res, path_to_local_id, peel_blocks};
+use rustc_ast::{ByRef, Mutability};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome, ResultErr, ResultOk};
+use rustc_hir::{Arm, BorrowKind, Expr, ExprKind, LangItem, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::MANUAL_OK_ERR;
+
+pub(super) fn check_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    scrutinee: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+) {
+    if let [first, second] = arms
+        && first.guard.is_none()
+        && second.guard.is_none()
+    {
+        if let Some(variant) = some_arm_variant(cx, first.pat, first.body)
+            && is_none_arm(cx, second.pat, second.body, variant)
+        {
+            lint(cx, expr, scrutinee, first.pat, variant);
+        } else if let Some(variant) = some_arm_variant(cx, second.pat, second.body)
+            && !matches!(first.pat.kind, PatKind::Wild)
+            && is_none_arm(cx, first.pat, first.body, variant)
+        {
+            lint(cx, expr, scrutinee, second.pat, variant);
+        }
+    }
+}
+
+pub(super) fn check_if_let<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    let_pat: &'tcx Pat<'_>,
+    let_expr: &'tcx Expr<'_>,
+    then_expr: &'tcx Expr<'_>,
+    else_expr: &'tcx Expr<'_>,
+) {
+    if let Some(variant) = some_arm_variant(cx, let_pat, then_expr)
+        && is_none_expr(cx, else_expr)
+    {
+        lint(cx, expr, let_expr, let_pat, variant);
+    }
+}
+
+/// Checks for `Ok(x) => Some(x)` or `Err(x) => Some(x)`, returning the matched variant.
+fn some_arm_variant(cx: &LateContext<'_>, pat: &Pat<'_>, body: &Expr<'_>) -> Option<LangItem> {
+    if let PatKind::TupleStruct(ref qpath, [inner], _) = pat.kind
+        && let PatKind::Binding(_, binding_id, _, None) = inner.kind
+        && let ExprKind::Call(callee, [arg]) = peel_blocks(body).kind
+        && is_res_lang_ctor(cx, path_res(cx, callee), OptionSome)
+        && path_to_local_id(arg, binding_id)
+        && cx.typeck_results().expr_adjustments(arg).is_empty()
+    {
+        let res = cx.qpath_res(qpath, pat.hir_id);
+        [ResultOk, ResultErr]
+            .into_iter()
+            .find(|&variant| is_res_lang_ctor(cx, res, variant))
+    } else {
+        None
+    }
+}
+
+/// Checks for `Err(_) => None` (or `Ok(_) => None` if `some_variant` is `Err`) and `_ => None`.
+fn is_none_arm(cx: &LateContext<'_>, pat: &Pat<'_>, body: &Expr<'_>, some_variant: LangItem) -> bool {
+    let other_variant = if some_variant == ResultOk { ResultErr } else { ResultOk };
+    let pat_matches = match pat.kind {
+        PatKind::Wild => true,
+        PatKind::TupleStruct(ref qpath, [inner], _) => {
+            matches!(inner.kind, PatKind::Wild)
+                && is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), other_variant)
+        },
+        _ => false,
+    };
+    pat_matches && is_none_expr(cx, body)
+}
+
+fn is_none_expr(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    is_res_lang_ctor(cx, path_res(cx, peel_blocks(expr)), OptionNone)
+}
+
+fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    some_pat: &Pat<'_>,
+    variant: LangItem,
+) {
+    let scrutinee_ty = cx.typeck_results().expr_ty(scrutinee).peel_refs();
+    if !is_type_diagnostic_item(cx, scrutinee_ty, sym::Result) {
+        return;
+    }
+    let PatKind::TupleStruct(_, [inner], _) = some_pat.kind else {
+        return;
+    };
+    let Some(binding_mode) = cx
+        .typeck_results()
+        .extract_binding_mode(cx.sess(), inner.hir_id, inner.span)
+    else {
+        return;
+    };
+    let as_ref = match binding_mode.0 {
+        ByRef::Yes(Mutability::Mut) => ".as_mut()",
+        ByRef::Yes(Mutability::Not) => ".as_ref()",
+        ByRef::No => "",
+    };
+    let method = if variant == ResultOk { "ok" } else { "err" };
+    // `(&r).as_ref()` reads better as `r.as_ref()`
+    let scrutinee = match scrutinee.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, inner) if !as_ref.is_empty() => inner,
+        _ => scrutinee,
+    };
+
+    let mut app = Applicability::MachineApplicable;
+    let scrutinee = Sugg::hir_with_context(cx, scrutinee, expr.span.ctxt(), "..", &mut app).maybe_par();
+    span_lint_and_sugg(
+        cx,
+        MANUAL_OK_ERR,
+        expr.span,
+        format!("manual implementation of `{method}`"),
+        "replace with",
+        format!("{scrutinee}{as_ref}.{method}()"),
+        app,
+    );
+}
@@ -0,0 +1,248 @@
+use crate::question_mark_used::QUESTION_MARK_USED;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::visitors::local_used_once;
+use clippy_utils::{
+    can_move_expr_to_closure, is_lint_allowed, is_res_lang_ctor, is_trait_method, path_res, path_to_local_id,
+    peel_blocks, peel_blocks_with_stmt,
+};
+use rustc_ast::Mutability;
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome, ResultErr, ResultOk};
+use rustc_hir::{Arm, BindingMode, BorrowKind, ByRef, Expr, ExprKind, HirId, LangItem, Pat, PatKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::ty;
+use rustc_span::sym;
+use rustc_span::symbol::Ident;
+
+use super::{MANUAL_MAP, MANUAL_RESULT_MAP};
+
+pub(super) fn check_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    scrutinee: &'tcx Expr<'tcx>,
+    arms: &'tcx [Arm<'tcx>],
+) {
+    let [first, second] = arms else {
+        return;
+    };
+    if first.guard.is_some() || second.guard.is_some() {
+        return;
+    }
+
+    for (arm, other_arm) in [(first, second), (second, first)] {
+        let Some((variant, binding)) = variant_binding(cx, arm.pat) else {
+            continue;
+        };
+        if let Some(inner) = rewrapped_value(cx, arm.body, variant, binding.id)
+            && is_passthrough_arm(cx, other_arm, other_variant(variant))
+            && (variant != OptionSome || is_lint_allowed(cx, MANUAL_MAP, expr.hir_id))
+        {
+            lint_map(cx, expr, scrutinee, variant, &binding, inner);
+            return;
+        }
+        if matches!(variant, ResultOk | OptionSome)
+            && binding.by_ref == ByRef::No
+            && path_to_local_id(peel_blocks(arm.body), binding.id)
+            && cx.typeck_results().expr_adjustments(peel_blocks(arm.body)).is_empty()
+            && is_early_return_arm(cx, other_arm, scrutinee, other_variant(variant))
+            && is_lint_allowed(cx, QUESTION_MARK_USED, expr.hir_id)
+        {
+            lint_question_mark(cx, expr, scrutinee);
+            return;
+        }
+    }
+}
+
+struct Binding {
+    id: HirId,
+    ident: Ident,
+    /// Whether the binding is declared `mut`
+    mutbl: Mutability,
+    /// How the value is bound, after applying the default binding modes
+    by_ref: ByRef,
+}
+
+/// Checks for `Ok(x)`, `Err(x)` or `Some(x)`, returning the matched variant and the binding.
+fn variant_binding(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<(LangItem, Binding)> {
+    if let PatKind::TupleStruct(ref qpath, [inner], _) = pat.kind
+        && let PatKind::Binding(BindingMode(_, mutbl), id, ident, None) = inner.kind
+        && let Some(BindingMode(by_ref, _)) = cx
+            .typeck_results()
+            .extract_binding_mode(cx.sess(), inner.hir_id, inner.span)
+    {
+        let res = cx.qpath_res(qpath, pat.hir_id);
+        let binding = Binding {
+            id,
+            ident,
+            mutbl,
+            by_ref,
+        };
+        [ResultOk, ResultErr, OptionSome]
+            .into_iter()
+            .find(|&variant| is_res_lang_ctor(cx, res, variant))
+            .map(|variant| (variant, binding))
+    } else {
+        None
+    }
+}
+
+fn other_variant(variant: LangItem) -> LangItem {
+    match variant {
+        ResultOk => ResultErr,
+        ResultErr => ResultOk,
+        _ => OptionNone,
+    }
+}
+
+/// Checks for `Ok(f(x))` where `x` is used once and `f(x)` could be moved into a closure,
+/// returning `f(x)`.
+fn rewrapped_value<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Expr<'tcx>,
+    variant: LangItem,
+    binding: HirId,
+) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Call(callee, [inner]) = peel_blocks(body).kind
+        && is_res_lang_ctor(cx, path_res(cx, callee), variant)
+        // `Ok(x) => Ok(x)` is `needless_match`
+        && !path_to_local_id(inner, binding)
+        && local_used_once(cx, inner, binding).is_some()
+        && can_move_expr_to_closure(cx, inner).is_some()
+    {
+        Some(inner)
+    } else {
+        None
+    }
+}
+
+/// Checks for `Err(e) => Err(e)`, `None => None` and `_ => None`.
+fn is_passthrough_arm(cx: &LateContext<'_>, arm: &Arm<'_>, variant: LangItem) -> bool {
+    let body = peel_blocks(arm.body);
+    if variant == OptionNone {
+        (matches!(arm.pat.kind, PatKind::Wild) || is_res_lang_ctor(cx, path_res(cx, arm.pat), OptionNone))
+            && is_res_lang_ctor(cx, path_res(cx, body), OptionNone)
+    } else if let Some((pat_variant, binding)) = variant_binding(cx, arm.pat)
+        && pat_variant == variant
+        && let ExprKind::Call(callee, [arg]) = body.kind
+        && is_res_lang_ctor(cx, path_res(cx, callee), variant)
+        && path_to_local_id(arg, binding.id)
+    {
+        cx.typeck_results().expr_adjustments(arg).is_empty()
+    } else {
+        false
+    }
+}
+
+/// Checks for `Err(e) => return Err(e)`, `Err(e) => return Err(e.into())` and
+/// `None => return None`, where the `?` operator would do the same.
+fn is_early_return_arm(cx: &LateContext<'_>, arm: &Arm<'_>, scrutinee: &Expr<'_>, variant: LangItem) -> bool {
+    let ExprKind::Ret(Some(ret_val)) = peel_blocks_with_stmt(arm.body).kind else {
+        return false;
+    };
+    if variant == OptionNone {
+        return is_res_lang_ctor(cx, path_res(cx, arm.pat), OptionNone)
+            && is_res_lang_ctor(cx, path_res(cx, ret_val), OptionNone);
+    }
+
+    let Some((ResultErr, binding)) = variant_binding(cx, arm.pat) else {
+        return false;
+    };
+    let ExprKind::Call(callee, [arg]) = ret_val.kind else {
+        return false;
+    };
+    if binding.by_ref != ByRef::No || !is_res_lang_ctor(cx, path_res(cx, callee), ResultErr) {
+        return false;
+    }
+    let (arg, converted) = match arg.kind {
+        ExprKind::MethodCall(_, receiver, [], _) if is_trait_method(cx, arg, sym::Into) => (receiver, true),
+        _ => (arg, false),
+    };
+    if !path_to_local_id(arg, binding.id) || !cx.typeck_results().expr_adjustments(arg).is_empty() {
+        return false;
+    }
+
+    // The function returns `Result<_, E>`, `?` converts the error with `From<E>`
+    let scrutinee_ty = cx.typeck_results().expr_ty(scrutinee);
+    let ret_ty = cx.typeck_results().expr_ty(ret_val);
+    if let ty::Adt(_, scrutinee_args) = scrutinee_ty.kind()
+        && let ty::Adt(_, ret_args) = ret_ty.kind()
+        && is_type_diagnostic_item(cx, scrutinee_ty, sym::Result)
+        && is_type_diagnostic_item(cx, ret_ty, sym::Result)
+    {
+        let err_ty = scrutinee_args.type_at(1);
+        let ret_err_ty = ret_args.type_at(1);
+        if converted {
+            cx.tcx
+                .get_diagnostic_item(sym::From)
+                .is_some_and(|from| implements_trait(cx, ret_err_ty, from, &[err_ty.into()]))
+        } else {
+            err_ty == ret_err_ty
+        }
+    } else {
+        false
+    }
+}
+
+fn lint_map<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    variant: LangItem,
+    binding: &Binding,
+    inner: &'tcx Expr<'_>,
+) {
+    let as_ref = match binding.by_ref {
+        ByRef::Yes(Mutability::Mut) => ".as_mut()",
+        ByRef::Yes(Mutability::Not) => ".as_ref()",
+        ByRef::No => "",
+    };
+    let method = if variant == ResultErr { "map_err" } else { "map" };
+    // `(&r).as_ref()` reads better as `r.as_ref()`
+    let scrutinee = match scrutinee.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, inner) if !as_ref.is_empty() => inner,
+        _ => scrutinee,
+    };
+
+    let ctxt = expr.span.ctxt();
+    let mut app = Applicability::MachineApplicable;
+    let scrutinee = Sugg::hir_with_context(cx, scrutinee, ctxt, "..", &mut app).maybe_par();
+    // `Ok(f(x))` becomes `.map(f)`, anything else needs a closure
+    let func = if let ExprKind::Call(callee, [arg]) = inner.kind
+        && let ExprKind::Path(_) = callee.kind
+        && path_to_local_id(arg, binding.id)
+        && cx.typeck_results().expr_adjustments(arg).is_empty()
+    {
+        snippet_with_context(cx, callee.span, ctxt, "..", &mut app).0.into_owned()
+    } else {
+        let mutability = if binding.mutbl == Mutability::Mut { "mut " } else { "" };
+        let body = snippet_with_context(cx, inner.span, ctxt, "..", &mut app).0;
+        format!("|{mutability}{}| {body}", binding.ident)
+    };
+
+    span_lint_and_sugg(
+        cx,
+        MANUAL_RESULT_MAP,
+        expr.span,
+        format!("manual implementation of `{method}`"),
+        "replace with",
+        format!("{scrutinee}{as_ref}.{method}({func})"),
+        app,
+    );
+}
+
+fn lint_question_mark(cx: &LateContext<'_>, expr: &Expr<'_>, scrutinee: &Expr<'_>) {
+    let mut app = Applicability::MaybeIncorrect;
+    let scrutinee = Sugg::hir_with_context(cx, scrutinee, expr.span.ctxt(), "..", &mut app).maybe_par();
+    span_lint_and_sugg(
+        cx,
+        MANUAL_RESULT_MAP,
+        expr.span,
+        "manual implementation of the `?` operator",
+        "replace with",
+        format!("{scrutinee}?"),
+        app,
+    );
+}
@@ -1,16 +1,22 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
 use clippy_utils::is_unit_expr;
 use clippy_utils::source::{expr_block, snippet};
 use clippy_utils::sugg::Sugg;
+use core::fmt::Write;
 use rustc_ast::LitKind;
 use rustc_errors::Applicability;
-use rustc_hir::{Arm, Expr, ExprKind, PatKind};
+use rustc_hir::{Arm, Expr, ExprKind, Pat, PatKind};
 use rustc_lint::LateContext;
 use rustc_middle::ty;
 
-use super::MATCH_BOOL;
+use super::{MATCH_BOOL, MATCH_ON_BOOL_TUPLE};
 
-pub(crate) fn check(cx: &LateContext<'_>, scrutinee: &Expr<'_>, arms: &[Arm<'_>], expr: &Expr<'_>) {
+pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, scrutinee: &'tcx Expr<'_>, arms: &[Arm<'_>], expr: &Expr<'_>) {
+    if let ExprKind::Tup(elems) = scrutinee.kind {
+        check_tuple(cx, elems, arms, expr);
+        return;
+    }
     // Type of expression is `bool`.
     if *cx.typeck_results().expr_ty(scrutinee).kind() == ty::Bool {
         span_lint_and_then(
@@ -75,3 +81,86 @@ pub(crate) fn check(cx: &LateContext<'_>, scrutinee: &Expr<'_>, arms: &[Arm<'_>]
         );
     }
 }
+
+/// Checks matches on tuples of booleans where every arm only matches the elements against `true`,
+/// `false` or `_`, e.g. `match (a, b) { (true, _) => .., (false, true) => .., _ => .. }`.
+fn check_tuple<'tcx>(cx: &LateContext<'tcx>, elems: &'tcx [Expr<'_>], arms: &[Arm<'_>], expr: &Expr<'_>) {
+    if elems.len() < 2 || !elems.iter().all(|e| cx.typeck_results().expr_ty(e).is_bool()) {
+        return;
+    }
+    let Some(arm_values) = arms
+        .iter()
+        .map(|arm| arm.guard.is_none().then(|| bool_values(arm.pat, elems.len())).flatten())
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+
+    span_lint_and_then(
+        cx,
+        MATCH_ON_BOOL_TUPLE,
+        expr.span,
+        "you seem to be trying to match on a tuple of boolean expressions",
+        move |diag| {
+            // The elements are evaluated once per arm in the `if`/`else` chain
+            if !elems.iter().all(|e| switch_to_eager_eval(cx, e)) {
+                return;
+            }
+            let Some((last, arms)) = arms.split_last().filter(|(_, arms)| !arms.is_empty()) else {
+                return;
+            };
+            let mut app = Applicability::MachineApplicable;
+            let ctxt = expr.span.ctxt();
+            let mut sugg = String::new();
+            for (arm, values) in arms.iter().zip(&arm_values) {
+                // Wildcards don't need to be checked. An arm matching everything makes the
+                // following arms unreachable, so there's nothing to suggest.
+                let Some(cond) = elems
+                    .iter()
+                    .zip(values)
+                    .filter_map(|(elem, value)| {
+                        let value = (*value)?;
+                        let elem = Sugg::hir_with_context(cx, elem, ctxt, "..", &mut app);
+                        Some(if value { elem } else { !elem })
+                    })
+                    .reduce(|lhs, rhs| lhs.and(&rhs))
+                else {
+                    return;
+                };
+                let _ = write!(
+                    sugg,
+                    "if {cond} {} else ",
+                    expr_block(cx, arm.body, ctxt, "..", Some(expr.span), &mut app)
+                );
+            }
+            if is_unit_expr(last.body) {
+                sugg.truncate(sugg.len() - " else ".len());
+            } else {
+                sugg.push_str(&expr_block(cx, last.body, ctxt, "..", Some(expr.span), &mut app));
+            }
+            diag.span_suggestion(expr.span, "consider using an `if`/`else` chain", sugg, app);
+        },
+    );
+}
+
+/// Gets the value each element of a tuple of booleans must have to match the pattern, `None`
+/// standing for any value.
+fn bool_values(pat: &Pat<'_>, len: usize) -> Option<Vec<Option<bool>>> {
+    match pat.kind {
+        PatKind::Wild => Some(vec![None; len]),
+        PatKind::Tuple(pats, dotdot) if dotdot.as_opt_usize().is_none() => pats
+            .iter()
+            .map(|pat| match pat.kind {
+                PatKind::Wild => Some(None),
+                PatKind::Lit(lit)
+                    if let ExprKind::Lit(lit) = lit.kind
+                        && let LitKind::Bool(value) = lit.node =>
+                {
+                    Some(Some(value))
+                },
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
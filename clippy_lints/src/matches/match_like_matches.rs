@@ -1,3 +1,4 @@
+use super::redundant_pattern_match::find_variant_method;
 use super::REDUNDANT_PATTERN_MATCHING;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
@@ -90,6 +91,16 @@ where
             }
         }
 
+        // `redundant_pattern_matching` suggests the `is_*` method of the enum instead
+        if iter_without_last.len() == 1
+            && first_guard.is_none()
+            && let Some((_, Some(pat), ..)) = iter_without_last.clone().next()
+            && !is_lint_allowed(cx, REDUNDANT_PATTERN_MATCHING, pat.hir_id)
+            && find_variant_method(cx, expr, cx.typeck_results().expr_ty(ex).peel_refs(), pat).is_some()
+        {
+            return false;
+        }
+
         // The suggestion may be incorrect, because some arms can have `cfg` attributes
         // evaluated into `false` and so such arms will be stripped before.
         let mut applicability = Applicability::MaybeIncorrect;
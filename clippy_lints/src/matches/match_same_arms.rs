@@ -1,16 +1,19 @@
 use clippy_utils::diagnostics::span_lint_hir_and_then;
-use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::{is_lint_allowed, path_to_local, search_same, SpanlessEq, SpanlessHash};
+use clippy_utils::source::{indent_of, snippet_with_applicability};
+use clippy_utils::{is_lint_allowed, path_to_local, search_same, span_extract_comment, SpanlessEq, SpanlessHash};
 use core::cmp::Ordering;
+use core::fmt::Write;
 use core::{iter, slice};
 use rustc_arena::DroplessArena;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::def_id::DefId;
-use rustc_hir::{Arm, Expr, ExprKind, HirId, HirIdMap, HirIdMapEntry, HirIdSet, Pat, PatKind, RangeEnd};
+use rustc_hir::{
+    Arm, BindingMode, Expr, ExprKind, HirId, HirIdMap, HirIdMapEntry, HirIdSet, Pat, PatKind, RangeEnd,
+};
 use rustc_lint::builtin::NON_EXHAUSTIVE_OMITTED_PATTERNS;
 use rustc_lint::{LateContext, LintContext};
-use rustc_middle::ty;
+use rustc_middle::ty::{self, Ty};
 use rustc_span::{ErrorGuaranteed, Span, Symbol};
 
 use super::MATCH_SAME_ARMS;
@@ -110,7 +113,6 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
             && check_same_body()
     };
 
-    let mut appl = Applicability::MaybeIncorrect;
     let indexed_arms: Vec<(usize, &Arm<'_>)> = arms.iter().enumerate().collect();
     for (&(i, arm1), &(j, arm2)) in search_same(&indexed_arms, hash, eq) {
         if matches!(arm2.pat.kind, PatKind::Wild) {
@@ -125,7 +127,7 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                     arm_span,
                     "this match arm has an identical body to the `_` wildcard arm",
                     |diag| {
-                        diag.span_suggestion(arm_span, "try removing the arm", "", appl)
+                        diag.span_suggestion(arm_span, "try removing the arm", "", Applicability::MaybeIncorrect)
                             .help("or try changing either arm body")
                             .span_note(arm2.span, "`_` wildcard arm here");
                     },
@@ -146,22 +148,49 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                 keep_arm.span,
                 "this match arm has an identical body to another arm",
                 |diag| {
+                    let mut appl = Applicability::MaybeIncorrect;
                     let move_pat_snip = snippet_with_applicability(cx, move_arm.pat.span, "<pat2>", &mut appl);
-                    let keep_pat_snip = snippet_with_applicability(cx, keep_arm.pat.span, "<pat1>", &mut appl);
+                    let move_arm_span = adjusted_arm_span(cx, move_arm.span);
+                    diag.help("try changing either arm body");
 
-                    diag.span_suggestion(
-                        keep_arm.pat.span,
-                        "or try merging the arm patterns",
-                        format!("{keep_pat_snip} | {move_pat_snip}"),
-                        appl,
-                    )
-                    .span_suggestion(
-                        adjusted_arm_span(cx, move_arm.span),
-                        "and remove this obsolete arm",
-                        "",
-                        appl,
-                    )
-                    .help("try changing either arm body");
+                    // Top-level or-patterns are left alone, and the merged pattern must bind the same
+                    // names in the same way in both alternatives.
+                    if !matches!(keep_arm.pat.kind, PatKind::Or(_))
+                        && !matches!(move_arm.pat.kind, PatKind::Or(_))
+                        && bindings(cx, keep_arm.pat) == bindings(cx, move_arm.pat)
+                        && appl != Applicability::HasPlaceholders
+                    {
+                        let mut sugg = vec![
+                            (keep_arm.pat.span.shrink_to_hi(), format!(" | {move_pat_snip}")),
+                            (move_arm_span, String::new()),
+                        ];
+                        // Move the comments of the removed arm over to the kept one
+                        let comments = span_extract_comment(cx.sess().source_map(), move_arm.span);
+                        if comments.is_empty() {
+                            appl = Applicability::MachineApplicable;
+                        } else {
+                            let indent = " ".repeat(indent_of(cx, keep_arm.span).unwrap_or(0));
+                            let comments = comments.lines().fold(String::new(), |mut acc, line| {
+                                let _ = write!(acc, "{line}\n{indent}");
+                                acc
+                            });
+                            sugg.push((keep_arm.span.shrink_to_lo(), comments));
+                        }
+                        diag.multipart_suggestion(
+                            "or try merging the arm patterns and removing the obsolete arm",
+                            sugg,
+                            appl,
+                        );
+                    } else {
+                        let keep_pat_snip = snippet_with_applicability(cx, keep_arm.pat.span, "<pat1>", &mut appl);
+                        diag.span_suggestion(
+                            keep_arm.pat.span,
+                            "or try merging the arm patterns",
+                            format!("{keep_pat_snip} | {move_pat_snip}"),
+                            appl,
+                        )
+                        .span_suggestion(move_arm_span, "and remove this obsolete arm", "", appl);
+                    }
                 },
             );
         }
@@ -440,6 +469,16 @@ fn pat_contains_local(pat: &Pat<'_>, id: HirId) -> bool {
     result
 }
 
+/// Gets the name, binding mode and type of each binding in the pattern, ordered by name.
+fn bindings<'tcx>(cx: &LateContext<'tcx>, pat: &Pat<'_>) -> Vec<(Symbol, BindingMode, Ty<'tcx>)> {
+    let mut bindings = Vec::new();
+    pat.each_binding_or_first(&mut |mode, id, _, ident| {
+        bindings.push((ident.name, mode, cx.typeck_results().node_type(id)));
+    });
+    bindings.sort_by(|(a, ..), (b, ..)| a.as_str().cmp(b.as_str()));
+    bindings
+}
+
 /// Returns true if all the bindings in the `Pat` are in `ids` and vice versa
 fn bindings_eq(pat: &Pat<'_>, mut ids: HirIdSet) -> bool {
     let mut result = true;
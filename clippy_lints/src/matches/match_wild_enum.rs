@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{is_refutable, peel_hir_pat_refs, recurse_or_patterns};
+use clippy_utils::{is_lint_allowed, is_refutable, peel_hir_pat_refs, recurse_or_patterns};
 use rustc_errors::Applicability;
 use rustc_hir::def::{CtorKind, DefKind, Res};
 use rustc_hir::{Arm, Expr, PatKind, PathSegment, QPath, Ty, TyKind};
@@ -8,10 +8,15 @@ use rustc_lint::LateContext;
 use rustc_middle::ty::{self, VariantDef};
 use rustc_span::sym;
 
-use super::{MATCH_WILDCARD_FOR_SINGLE_VARIANTS, WILDCARD_ENUM_MATCH_ARM};
+use super::{MATCH_WILDCARD_FOR_LOCAL_NON_EXHAUSTIVE, MATCH_WILDCARD_FOR_SINGLE_VARIANTS, WILDCARD_ENUM_MATCH_ARM};
 
 #[expect(clippy::too_many_lines)]
-pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
+pub(crate) fn check(
+    cx: &LateContext<'_>,
+    ex: &Expr<'_>,
+    arms: &[Arm<'_>],
+    local_exhaustive_enum_variant_threshold: Option<u64>,
+) {
     let ty = cx.typeck_results().expr_ty(ex).peel_refs();
     let adt_def = match ty.kind() {
         ty::Adt(adt_def, _)
@@ -136,6 +141,36 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
         )
     };
 
+    // Inside the defining crate, the variants covered by the wildcard can always be listed.
+    let is_non_exhaustive = adt_def.is_variant_list_non_exhaustive();
+    if !is_external
+        && !missing_variants.is_empty()
+        && (is_non_exhaustive
+            || local_exhaustive_enum_variant_threshold
+                .is_some_and(|threshold| adt_def.variants().len() as u64 >= threshold))
+        && !is_lint_allowed(cx, MATCH_WILDCARD_FOR_LOCAL_NON_EXHAUSTIVE, ex.hir_id)
+    {
+        span_lint_and_sugg(
+            cx,
+            MATCH_WILDCARD_FOR_LOCAL_NON_EXHAUSTIVE,
+            wildcard_span,
+            if is_non_exhaustive {
+                "wildcard arm hides future variants of a local `#[non_exhaustive]` enum"
+            } else {
+                "wildcard arm hides future variants of a local enum"
+            },
+            "list the variants covered by the wildcard instead",
+            missing_variants
+                .iter()
+                .copied()
+                .map(format_suggestion)
+                .collect::<Vec<_>>()
+                .join(" | "),
+            Applicability::MaybeIncorrect,
+        );
+        return;
+    }
+
     match missing_variants.as_slice() {
         [] => (),
         [x] if !adt_def.is_variant_list_non_exhaustive() && !has_external_hidden => span_lint_and_sugg(
@@ -0,0 +1,92 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_copy;
+use clippy_utils::{can_move_expr_to_closure, is_res_lang_ctor};
+use rustc_ast::Mutability;
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionSome, ResultErr, ResultOk};
+use rustc_hir::{Arm, BindingMode, BorrowKind, ByRef, Expr, ExprKind, PatKind};
+use rustc_lint::{LateContext, LintContext};
+
+use super::MATCHES_IS_VARIANT_AND;
+
+/// Checks for `matches!(x, Some(y) if pred(y))`, `expr` being the expansion of `matches!`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    arm: &'tcx Arm<'_>,
+    msrv: &Msrv,
+) {
+    let Some(guard) = arm.guard else {
+        return;
+    };
+    let PatKind::TupleStruct(ref qpath, [inner], _) = arm.pat.kind else {
+        return;
+    };
+    let PatKind::Binding(BindingMode(_, mutbl), _, ident, None) = inner.kind else {
+        return;
+    };
+    let res = cx.qpath_res(qpath, arm.pat.hir_id);
+    let method = if is_res_lang_ctor(cx, res, OptionSome) {
+        "is_some_and"
+    } else if is_res_lang_ctor(cx, res, ResultOk) {
+        "is_ok_and"
+    } else if is_res_lang_ctor(cx, res, ResultErr) {
+        "is_err_and"
+    } else {
+        return;
+    };
+    if !msrv.meets(msrvs::OPTION_RESULT_IS_VARIANT_AND) || can_move_expr_to_closure(cx, guard).is_none() {
+        return;
+    }
+    let Some(BindingMode(by_ref, _)) = cx
+        .typeck_results()
+        .extract_binding_mode(cx.sess(), inner.hir_id, inner.span)
+    else {
+        return;
+    };
+
+    let call_span = expr.span.source_callsite();
+    let ctxt = call_span.ctxt();
+    let mut app = Applicability::MachineApplicable;
+    // The guard sees the binding as it is bound by the pattern, the receiver is adjusted so that
+    // the closure gets the same type
+    let (as_ref, param) = match by_ref {
+        ByRef::Yes(Mutability::Mut) => (".as_mut()", ident.to_string()),
+        ByRef::Yes(Mutability::Not) => (".as_ref()", ident.to_string()),
+        ByRef::No if mutbl == Mutability::Mut => ("", format!("mut {ident}")),
+        ByRef::No => {
+            let scrutinee_ty = cx.typeck_results().expr_ty(scrutinee);
+            if is_copy(cx, scrutinee_ty) || !scrutinee.is_syntactic_place_expr() {
+                ("", ident.to_string())
+            } else if is_copy(cx, cx.typeck_results().node_type(inner.hir_id)) {
+                // Copy the value out of a borrow instead of moving the whole scrutinee
+                (".as_ref()", format!("&{ident}"))
+            } else {
+                // The pattern moves the value out of the scrutinee, the method moves all of it
+                app = Applicability::MaybeIncorrect;
+                ("", ident.to_string())
+            }
+        },
+    };
+    // `(&x).as_ref()` reads better as `x.as_ref()`
+    let scrutinee = match scrutinee.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, inner) if !as_ref.is_empty() => inner,
+        _ => scrutinee,
+    };
+
+    let scrutinee = Sugg::hir_with_context(cx, scrutinee, ctxt, "..", &mut app).maybe_par();
+    let guard = snippet_with_context(cx, guard.span, ctxt, "..", &mut app).0;
+    span_lint_and_sugg(
+        cx,
+        MATCHES_IS_VARIANT_AND,
+        call_span,
+        format!("this `matches!` can be written with `{method}`"),
+        "try",
+        format!("{scrutinee}{as_ref}.{method}(|{param}| {guard})"),
+        app,
+    );
+}
@@ -1,7 +1,10 @@
 mod collapsible_match;
+mod if_let_chain_same_scrutinee;
 mod infallible_destructuring_match;
 mod manual_filter;
 mod manual_map;
+mod manual_ok_err;
+mod manual_result_map;
 mod manual_unwrap_or;
 mod manual_utils;
 mod match_as_ref;
@@ -14,6 +17,7 @@ mod match_single_binding;
 mod match_str_case_mismatch;
 mod match_wild_enum;
 mod match_wild_err_arm;
+mod matches_is_variant_and;
 mod needless_match;
 mod overlapping_arms;
 mod redundant_guards;
@@ -180,6 +184,42 @@ declare_clippy_lint! {
     "a `match` on a boolean expression instead of an `if..else` block"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for matches on a tuple of `bool`s where every arm only matches the elements
+    /// against `true`, `false` or `_`.
+    ///
+    /// ### Why restrict this?
+    /// Such a match spells out a truth table, which some prefer to write as an `if`/`else`
+    /// chain testing the conditions directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn foo() {}
+    /// # fn bar() {}
+    /// # let (a, b) = (true, false);
+    /// match (a, b) {
+    ///     (true, true) => foo(),
+    ///     _ => bar(),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn foo() {}
+    /// # fn bar() {}
+    /// # let (a, b) = (true, false);
+    /// if a && b {
+    ///     foo();
+    /// } else {
+    ///     bar();
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MATCH_ON_BOOL_TUPLE,
+    restriction,
+    "a `match` on a tuple of boolean expressions instead of an `if..else` chain"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for overlapping match arms.
@@ -470,6 +510,10 @@ declare_clippy_lint! {
     /// Lint for redundant pattern matching over `Result`, `Option`,
     /// `std::task::Poll`, `std::net::IpAddr` or `bool`s
     ///
+    /// Matches on other enums which only return whether a variant matched are linted too,
+    /// suggesting the `is_*` method of the enum for that variant if there is one, and
+    /// `matches!` otherwise (if `match_like_matches_macro` is allowed).
+    ///
     /// ### Why is this bad?
     /// It's more concise and clear to just use the proper
     /// utility function or using the condition directly
@@ -724,6 +768,102 @@ declare_clippy_lint! {
     "finds patterns that can be encoded more concisely with `Option::unwrap_or` or `Result::unwrap_or`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` and `if let` expressions that convert a `Result` into
+    /// an `Option` by hand.
+    ///
+    /// ### Why is this bad?
+    /// `Result::ok` and `Result::err` express the same conversion more concisely.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let r: Result<u32, &str> = Ok(42);
+    /// let a = match r {
+    ///     Ok(v) => Some(v),
+    ///     Err(_) => None,
+    /// };
+    /// let b = if let Err(e) = r { Some(e) } else { None };
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// let r: Result<u32, &str> = Ok(42);
+    /// let a = r.ok();
+    /// let b = r.err();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_OK_ERR,
+    complexity,
+    "find manual implementations of `.ok()` or `.err()` on `Result`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for two-arm `match` expressions that transform the value of one variant of a
+    /// `Result` or an `Option` and pass the other one through unchanged, or return it early.
+    ///
+    /// ### Why is this bad?
+    /// `map`, `map_err` and the `?` operator express the same thing more concisely.
+    ///
+    /// ### Known problems
+    /// `Some(x) => Some(f(x)), None => None` is only linted if `manual_map` is allowed.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn f(r: Result<u32, String>) -> Result<u32, String> {
+    ///     let a = match r {
+    ///         Ok(v) => Ok(v + 1),
+    ///         Err(e) => Err(e),
+    ///     };
+    ///     let b = match a {
+    ///         Ok(v) => v,
+    ///         Err(e) => return Err(e),
+    ///     };
+    ///     Ok(b)
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// fn f(r: Result<u32, String>) -> Result<u32, String> {
+    ///     let a = r.map(|v| v + 1);
+    ///     let b = a?;
+    ///     Ok(b)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_RESULT_MAP,
+    complexity,
+    "a `match` that can be replaced with `map`, `map_err` or the `?` operator"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `matches!` invocations whose pattern is `Some(x)`, `Ok(x)` or `Err(x)`
+    /// followed by a guard.
+    ///
+    /// ### Why is this bad?
+    /// `Option::is_some_and`, `Result::is_ok_and` and `Result::is_err_and` express the same
+    /// check without a macro.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let opt = Some(5);
+    /// let _ = matches!(opt, Some(x) if x > 3);
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// let opt = Some(5);
+    /// let _ = opt.is_some_and(|x| x > 3);
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MATCHES_IS_VARIANT_AND,
+    style,
+    "`matches!` with a guard on `Some`, `Ok` or `Err` that could use `is_some_and`, `is_ok_and` or `is_err_and`"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for `match vec[idx]` or `match vec[n..m]`.
@@ -957,6 +1097,8 @@ declare_clippy_lint! {
     /// match x {
     ///     Some(x) if matches!(x, Some(1)) => ..,
     ///     Some(x) if x == Some(2) => ..,
+    ///     Some(Some(x)) if x == MAX => ..,
+    ///     Some(Some(x)) if (10..=20).contains(&x) => ..,
     ///     _ => todo!(),
     /// }
     /// ```
@@ -965,6 +1107,8 @@ declare_clippy_lint! {
     /// match x {
     ///     Some(Some(1)) => ..,
     ///     Some(Some(2)) => ..,
+    ///     Some(Some(MAX)) => ..,
+    ///     Some(Some(10..=20)) => ..,
     ///     _ => todo!(),
     /// }
     /// ```
@@ -974,16 +1118,109 @@ declare_clippy_lint! {
     "checks for unnecessary guards in match expressions"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard arms in matches on `#[non_exhaustive]` enums defined in the
+    /// current crate.
+    ///
+    /// Using the `local-exhaustive-enum-variant-threshold` configuration, it can also be
+    /// applied to exhaustive enums with at least the configured number of variants.
+    ///
+    /// ### Why is this bad?
+    /// `#[non_exhaustive]` only forces other crates to have a wildcard arm. Inside the defining
+    /// crate, a variant added later silently falls into the wildcard arm instead of causing a
+    /// compile error at every `match` which needs to handle it.
+    ///
+    /// ### Known problems
+    /// Suggested replacements may not use correct path to enum
+    /// if it's not present in the current scope.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[non_exhaustive]
+    /// pub enum Foo { A, B, C }
+    ///
+    /// # let x = Foo::B;
+    /// match x {
+    ///     Foo::A => {},
+    ///     _ => {},
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// #[non_exhaustive]
+    /// pub enum Foo { A, B, C }
+    ///
+    /// # let x = Foo::B;
+    /// match x {
+    ///     Foo::A => {},
+    ///     Foo::B | Foo::C => {},
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MATCH_WILDCARD_FOR_LOCAL_NON_EXHAUSTIVE,
+    pedantic,
+    "a wildcard arm in a match on a local `#[non_exhaustive]` enum"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if let` .. `else if let` chains testing the same value against
+    /// different patterns.
+    ///
+    /// ### Why is this bad?
+    /// A single `match` states the intent more clearly, only evaluates the value once and
+    /// lets the compiler check whether all the cases are handled.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # enum Pet { Dog(u32), Cat(u32), Fish }
+    /// # fn f(pet: Option<Pet>) {
+    /// if let Some(Pet::Dog(age)) = pet {
+    ///     println!("dog of age {age}");
+    /// } else if let Some(Pet::Cat(_)) = pet {
+    ///     println!("cat");
+    /// } else {
+    ///     println!("something else");
+    /// }
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # enum Pet { Dog(u32), Cat(u32), Fish }
+    /// # fn f(pet: Option<Pet>) {
+    /// match pet {
+    ///     Some(Pet::Dog(age)) => {
+    ///         println!("dog of age {age}");
+    ///     },
+    ///     Some(Pet::Cat(_)) => {
+    ///         println!("cat");
+    ///     },
+    ///     _ => {
+    ///         println!("something else");
+    ///     },
+    /// }
+    /// # }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub IF_LET_CHAIN_SAME_SCRUTINEE,
+    style,
+    "`if let` chains testing the same value which could be a `match`"
+}
+
 pub struct Matches {
     msrv: Msrv,
+    local_exhaustive_enum_variant_threshold: Option<u64>,
     infallible_destructuring_match_linted: bool,
 }
 
 impl Matches {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
+    pub fn new(msrv: Msrv, local_exhaustive_enum_variant_threshold: Option<u64>) -> Self {
         Self {
             msrv,
+            local_exhaustive_enum_variant_threshold,
             infallible_destructuring_match_linted: false,
         }
     }
@@ -993,6 +1230,7 @@ impl_lint_pass!(Matches => [
     SINGLE_MATCH,
     MATCH_REF_PATS,
     MATCH_BOOL,
+    MATCH_ON_BOOL_TUPLE,
     SINGLE_MATCH_ELSE,
     MATCH_OVERLAPPING_ARM,
     MATCH_WILD_ERR_ARM,
@@ -1016,6 +1254,11 @@ impl_lint_pass!(Matches => [
     MANUAL_MAP,
     MANUAL_FILTER,
     REDUNDANT_GUARDS,
+    MANUAL_OK_ERR,
+    MATCH_WILDCARD_FOR_LOCAL_NON_EXHAUSTIVE,
+    IF_LET_CHAIN_SAME_SCRUTINEE,
+    MANUAL_RESULT_MAP,
+    MATCHES_IS_VARIANT_AND,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
@@ -1029,8 +1272,9 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
             if is_direct_expn_of(expr.span, "matches").is_some()
                 && let [arm, _] = arms
             {
-                redundant_pattern_match::check_match(cx, expr, ex, arms);
+                redundant_pattern_match::check_match(cx, expr, ex, arms, &self.msrv);
                 redundant_pattern_match::check_matches_true(cx, expr, arm, ex);
+                matches_is_variant_and::check(cx, expr, ex, arm, &self.msrv);
             }
 
             if source == MatchSource::Normal && !is_span_match(cx, expr.span) {
@@ -1057,11 +1301,11 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
                         match_same_arms::check(cx, arms);
                     }
 
-                    redundant_pattern_match::check_match(cx, expr, ex, arms);
+                    redundant_pattern_match::check_match(cx, expr, ex, arms, &self.msrv);
                     single_match::check(cx, ex, arms, expr);
                     match_bool::check(cx, ex, arms, expr);
                     overlapping_arms::check(cx, ex, arms);
-                    match_wild_enum::check(cx, ex, arms);
+                    match_wild_enum::check(cx, ex, arms, self.local_exhaustive_enum_variant_threshold);
                     match_as_ref::check(cx, ex, arms, expr);
                     needless_match::check_match(cx, ex, arms, expr);
                     match_on_vec_items::check(cx, ex);
@@ -1070,6 +1314,8 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
 
                     if !in_constant(cx, expr.hir_id) {
                         manual_unwrap_or::check_match(cx, expr, ex, arms);
+                        manual_ok_err::check_match(cx, expr, ex, arms);
+                        manual_result_map::check_match(cx, expr, ex, arms);
                         manual_map::check_match(cx, expr, ex, arms);
                         manual_filter::check_match(cx, ex, arms, expr);
                     }
@@ -1105,6 +1351,14 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
                             if_let.if_then,
                             else_expr,
                         );
+                        manual_ok_err::check_if_let(
+                            cx,
+                            expr,
+                            if_let.let_pat,
+                            if_let.let_expr,
+                            if_let.if_then,
+                            else_expr,
+                        );
                         manual_map::check_if_let(cx, expr, if_let.let_pat, if_let.let_expr, if_let.if_then, else_expr);
                         manual_filter::check_if_let(
                             cx,
@@ -1121,14 +1375,20 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
                     expr,
                     if_let.let_pat,
                     if_let.let_expr,
-                    if_let.if_else.is_some(),
+                    if_let.if_then,
+                    if_let.if_else,
                     if_let.let_span,
+                    &self.msrv,
                 );
                 needless_match::check_if_let(cx, expr, &if_let);
             }
         } else if !from_expansion {
             redundant_pattern_match::check(cx, expr);
         }
+
+        if !from_expansion && let ExprKind::If(..) = expr.kind {
+            if_let_chain_same_scrutinee::check(cx, expr);
+        }
     }
 
     fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'_>) {
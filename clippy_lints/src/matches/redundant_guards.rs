@@ -1,14 +1,18 @@
-use clippy_config::msrvs::Msrv;
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::macros::matching_root_macro_call;
 use clippy_utils::source::snippet;
+use clippy_utils::ty::implements_trait;
 use clippy_utils::visitors::{for_each_expr_without_closures, is_local_used};
-use clippy_utils::{in_constant, path_to_local};
-use rustc_ast::{BorrowKind, LitKind};
+use clippy_utils::{higher, in_constant, path_to_local};
+use rustc_ast::{BorrowKind, LitKind, RangeLimits};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{Arm, BinOpKind, Expr, ExprKind, MatchSource, Node, PatKind, UnOp};
 use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
 use rustc_span::symbol::Ident;
 use rustc_span::{sym, Span, Symbol};
 use std::borrow::Cow;
@@ -92,6 +96,24 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'tcx>], msrv:
                 &binding,
                 None,
             );
+        }
+        // `x if (1..=5).contains(&x)`
+        else if let ExprKind::MethodCall(path, recv, [arg], _) = guard.kind
+            && path.ident.name == sym!(contains)
+            && let Some(range) = higher::Range::hir(recv)
+            && let ExprKind::AddrOf(BorrowKind::Ref, _, local) = arg.kind
+            && let Some(binding) = get_pat_binding(cx, local, outer_arm)
+            && binding.byref_ident.is_none()
+            && let Some(mut sugg) = range_pat(cx, &range, local, msrv)
+        {
+            // `&1..=5` is ambiguous, so the range needs parentheses in a reference or box pattern
+            if let Some(id) = path_to_local(local)
+                && let Node::Pat(parent) = cx.tcx.parent_hir_node(id)
+                && matches!(parent.kind, PatKind::Ref(..) | PatKind::Box(_))
+            {
+                sugg = format!("({sugg})");
+            }
+            emit_redundant_guards(cx, outer_arm, guard.span, sugg.into(), &binding, None);
         } else if let ExprKind::MethodCall(path, recv, args, ..) = guard.kind
             && let Some(binding) = get_pat_binding(cx, recv, outer_arm)
         {
@@ -153,6 +175,41 @@ fn check_method_calls<'tcx>(
     emit_redundant_guards(cx, arm, if_expr.span, sugg, binding, None);
 }
 
+/// Gets the range pattern matching the same values as `range.contains(&local)`. Both bounds must
+/// be usable in a pattern and evaluate to constants forming a non-empty range, since empty range
+/// patterns are an error.
+fn range_pat(cx: &LateContext<'_>, range: &higher::Range<'_>, local: &Expr<'_>, msrv: &Msrv) -> Option<String> {
+    let (Some(start), Some(end)) = (range.start, range.end) else {
+        return None;
+    };
+    let typeck = cx.typeck_results();
+    let ty = typeck.expr_ty(local);
+    if !(ty.is_integral() || ty.is_char())
+        || typeck.expr_ty(start) != ty
+        || typeck.expr_ty(end) != ty
+        || !expr_can_be_pat(cx, start)
+        || !expr_can_be_pat(cx, end)
+    {
+        return None;
+    }
+    let ord = Constant::partial_cmp(
+        cx.tcx,
+        ty,
+        &constant(cx, typeck, start)?,
+        &constant(cx, typeck, end)?,
+    )?;
+    let op = match range.limits {
+        RangeLimits::Closed if ord.is_le() => "..=",
+        RangeLimits::HalfOpen if ord.is_lt() && msrv.meets(msrvs::EXCLUSIVE_RANGE_PATTERN) => "..",
+        _ => return None,
+    };
+    Some(format!(
+        "{}{op}{}",
+        snippet(cx, start.span, "<start>"),
+        snippet(cx, end.span, "<end>")
+    ))
+}
+
 struct PatBindingInfo {
     span: Span,
     byref_ident: Option<Ident>,
@@ -256,11 +313,15 @@ fn expr_can_be_pat(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
                 // Allow ctors
                 matches!(cx.qpath_res(&qpath, c.hir_id), Res::Def(DefKind::Ctor(..), ..))
             },
-            ExprKind::Path(qpath) => {
-                matches!(
-                    cx.qpath_res(&qpath, expr.hir_id),
-                    Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Ctor(..), ..),
-                )
+            ExprKind::Path(qpath) => match cx.qpath_res(&qpath, expr.hir_id) {
+                Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Ctor(..), ..) => true,
+                // Constants can only be used if they don't depend on generic parameters, and if
+                // their type compares structurally
+                Res::Def(DefKind::Const | DefKind::AssocConst, _) => {
+                    !cx.typeck_results().node_args(expr.hir_id).has_param()
+                        && is_structural(cx, cx.typeck_results().expr_ty(expr), &mut FxHashSet::default())
+                },
+                _ => false,
             },
             ExprKind::AddrOf(..)
             | ExprKind::Array(..)
@@ -277,3 +338,26 @@ fn expr_can_be_pat(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     })
     .is_none()
 }
+
+/// Checks if a constant of the given type can be used in a pattern, i.e. if its equality is
+/// structural all the way down. Floats are excluded for the same reason as float literals.
+fn is_structural<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, seen: &mut FxHashSet<Ty<'tcx>>) -> bool {
+    if !seen.insert(ty) {
+        return true;
+    }
+    match *ty.kind() {
+        ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Str => true,
+        ty::Ref(_, ty, _) | ty::Array(ty, _) | ty::Slice(ty) => is_structural(cx, ty, seen),
+        ty::Tuple(tys) => tys.iter().all(|ty| is_structural(cx, ty, seen)),
+        ty::Adt(adt, args) => {
+            cx.tcx
+                .lang_items()
+                .structural_peq_trait()
+                .is_some_and(|id| implements_trait(cx, ty, id, &[]))
+                && adt
+                    .all_fields()
+                    .all(|field| is_structural(cx, field.ty(cx.tcx, args), seen))
+        },
+        _ => false,
+    }
+}
@@ -1,17 +1,20 @@
-use super::REDUNDANT_PATTERN_MATCHING;
+use super::{MATCH_LIKE_MATCHES_MACRO, REDUNDANT_PATTERN_MATCHING};
+use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
-use clippy_utils::source::{snippet, walk_span_to_context};
+use clippy_utils::source::{snippet, snippet_with_applicability, walk_span_to_context};
+use clippy_utils::str_utils::to_snake_case;
 use clippy_utils::sugg::{make_unop, Sugg};
-use clippy_utils::ty::{is_type_diagnostic_item, needs_ordered_drop};
+use clippy_utils::ty::{get_adt_inherent_method, is_type_diagnostic_item, is_type_lang_item, needs_ordered_drop};
 use clippy_utils::visitors::{any_temporaries_need_ordered_drop, for_each_expr_without_closures};
-use clippy_utils::{higher, is_expn_of, is_trait_method};
+use clippy_utils::{higher, is_expn_of, is_lint_allowed, is_trait_method, is_wild, peel_blocks};
 use rustc_ast::ast::LitKind;
+use rustc_ast::Mutability;
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::LangItem::{self, OptionNone, OptionSome, PollPending, PollReady, ResultErr, ResultOk};
 use rustc_hir::{Arm, Expr, ExprKind, Node, Pat, PatKind, QPath, UnOp};
 use rustc_lint::LateContext;
-use rustc_middle::ty::{self, GenericArgKind, Ty};
+use rustc_middle::ty::{self, AdtDef, GenericArgKind, Ty};
 use rustc_span::{sym, Span, Symbol};
 use std::fmt::Write;
 use std::ops::ControlFlow;
@@ -29,16 +32,25 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
     }
 }
 
+#[expect(clippy::too_many_arguments)]
 pub(super) fn check_if_let<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'_>,
     pat: &'tcx Pat<'_>,
     scrutinee: &'tcx Expr<'_>,
-    has_else: bool,
+    then_expr: &'tcx Expr<'_>,
+    else_expr: Option<&'tcx Expr<'_>>,
     let_span: Span,
+    msrv: &Msrv,
 ) {
     find_if_let_true(cx, pat, scrutinee, let_span);
-    find_method_sugg_for_if_let(cx, expr, pat, scrutinee, "if", has_else);
+    find_method_sugg_for_if_let(cx, expr, pat, scrutinee, "if", else_expr.is_some());
+    if let Some(else_expr) = else_expr
+        && let Some(is_true) = find_bool_lit(then_expr)
+        && find_bool_lit(else_expr) == Some(!is_true)
+    {
+        find_sugg_for_enum(cx, expr, expr.span, scrutinee, pat, None, is_true, msrv);
+    }
 }
 
 /// Looks for:
@@ -264,7 +276,22 @@ fn find_method_sugg_for_if_let<'tcx>(
     );
 }
 
-pub(super) fn check_match<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, op: &Expr<'_>, arms: &[Arm<'_>]) {
+pub(super) fn check_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+    msrv: &Msrv,
+) {
+    if let [arm, other] = arms
+        && is_wild(other.pat)
+        && other.guard.is_none()
+        && let Some(is_true) = find_bool_lit(arm.body)
+        && find_bool_lit(other.body) == Some(!is_true)
+    {
+        let span = is_expn_of(expr.span, "matches").unwrap_or(expr.span);
+        find_sugg_for_enum(cx, expr, span, op, arm.pat, arm.guard, is_true, msrv);
+    }
     if arms.len() == 2 {
         let node_pair = (&arms[0].pat.kind, &arms[1].pat.kind);
 
@@ -313,6 +340,123 @@ pub(super) fn check_match<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, op
     }
 }
 
+/// Extract a `bool` or `{ bool }`
+fn find_bool_lit(e: &Expr<'_>) -> Option<bool> {
+    if let ExprKind::Lit(lit) = peel_blocks(e).kind
+        && let LitKind::Bool(b) = lit.node
+    {
+        Some(b)
+    } else {
+        None
+    }
+}
+
+/// Checks `match`es and `if let`s on enums without dedicated methods listed above, which only
+/// return whether the pattern matched, e.g. `match state { State::Ready => true, _ => false }`.
+///
+/// If the enum has an inherent `is_ready`-like method for the matched variant, that method is
+/// suggested. Otherwise `matches!` is suggested, unless `match_like_matches_macro` does so.
+#[expect(clippy::too_many_arguments)]
+fn find_sugg_for_enum<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    span: Span,
+    scrutinee: &'tcx Expr<'_>,
+    pat: &'tcx Pat<'_>,
+    guard: Option<&'tcx Expr<'_>>,
+    is_true: bool,
+    msrv: &Msrv,
+) {
+    let ty = cx.typeck_results().expr_ty(scrutinee).peel_refs();
+    if !ty.ty_adt_def().is_some_and(AdtDef::is_enum)
+        || is_type_diagnostic_item(cx, ty, sym::Option)
+        || is_type_diagnostic_item(cx, ty, sym::Result)
+        || is_type_diagnostic_item(cx, ty, sym::IpAddr)
+        || is_type_lang_item(cx, ty, LangItem::Poll)
+    {
+        return;
+    }
+
+    let neg = if is_true { "" } else { "!" };
+    if guard.is_none()
+        && let Some(method) = find_variant_method(cx, expr, ty, pat)
+    {
+        let result_expr = match scrutinee.kind {
+            ExprKind::AddrOf(_, _, borrowed) => borrowed,
+            _ => scrutinee,
+        };
+        let mut app = Applicability::MaybeIncorrect;
+        let recv = Sugg::hir_with_context(cx, result_expr, span.ctxt(), "_", &mut app).maybe_par();
+        span_lint_and_sugg(
+            cx,
+            REDUNDANT_PATTERN_MATCHING,
+            span,
+            format!("redundant pattern matching, consider using `{method}()`"),
+            "try",
+            format!("{neg}{recv}.{method}()"),
+            app,
+        );
+    } else if is_expn_of(expr.span, "matches").is_none()
+        && msrv.meets(msrvs::MATCHES_MACRO)
+        && is_lint_allowed(cx, MATCH_LIKE_MATCHES_MACRO, expr.hir_id)
+    {
+        let mut app = Applicability::MachineApplicable;
+        let mut pat = snippet_with_applicability(cx, pat.span, "..", &mut app).into_owned();
+        if let Some(guard) = guard {
+            let _ = write!(pat, " if {}", snippet_with_applicability(cx, guard.span, "..", &mut app));
+        }
+        span_lint_and_sugg(
+            cx,
+            REDUNDANT_PATTERN_MATCHING,
+            span,
+            "redundant pattern matching, consider using `matches!`",
+            "try",
+            format!(
+                "{neg}matches!({}, {pat})",
+                snippet_with_applicability(cx, scrutinee.span, "..", &mut app)
+            ),
+            app,
+        );
+    }
+}
+
+/// Finds the inherent method of the enum checking for the variant matched by the pattern, e.g.
+/// `is_ready` for `State::Ready`. The pattern must not check the fields of the variant.
+pub(super) fn find_variant_method(cx: &LateContext<'_>, expr: &Expr<'_>, ty: Ty<'_>, pat: &Pat<'_>) -> Option<String> {
+    let adt = ty.ty_adt_def()?;
+    let res = match pat.kind {
+        PatKind::Path(ref qpath) => cx.qpath_res(qpath, pat.hir_id),
+        PatKind::TupleStruct(ref qpath, pats, _) if pats.iter().all(is_wild) => cx.qpath_res(qpath, pat.hir_id),
+        PatKind::Struct(ref qpath, fields, _) if fields.iter().all(|field| is_wild(field.pat)) => {
+            cx.qpath_res(qpath, pat.hir_id)
+        },
+        _ => return None,
+    };
+    if !matches!(res, Res::Def(DefKind::Ctor(..) | DefKind::Variant, _)) {
+        return None;
+    }
+    let name = format!("is_{}", to_snake_case(adt.variant_of_res(res).name.as_str()));
+    let method = get_adt_inherent_method(cx, ty, Symbol::intern(&name))?;
+    let sig = cx.tcx.fn_sig(method.def_id).instantiate_identity().skip_binder();
+    // `fn is_ready(&self) -> bool`, usable here and not the method itself
+    if method.fn_has_self_parameter
+        && cx.tcx.hir().get_parent_item(expr.hir_id).to_def_id() != method.def_id
+        && let [self_ty] = sig.inputs()
+        && let ty::Ref(_, self_ty, Mutability::Not) = self_ty.kind()
+        && self_ty.ty_adt_def() == Some(adt)
+        && sig.output().is_bool()
+        && cx.tcx.generics_of(method.def_id).own_params.is_empty()
+        && cx
+            .tcx
+            .visibility(method.def_id)
+            .is_accessible_from(cx.tcx.parent_module(expr.hir_id), cx.tcx)
+    {
+        Some(name)
+    } else {
+        None
+    }
+}
+
 fn found_good_method<'tcx>(
     cx: &LateContext<'_>,
     arms: &'tcx [Arm<'tcx>],
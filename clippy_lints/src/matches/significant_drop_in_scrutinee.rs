@@ -128,8 +128,8 @@ impl<'a, 'tcx> SigDropChecker<'a, 'tcx> {
                 self.cx.tcx.get_attrs_unchecked(adt.did()),
                 "has_significant_drop",
             )
-            .count()
-                > 0
+            .next()
+            .is_some()
             {
                 return true;
             }
@@ -1,12 +1,18 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::{expr_block, get_source_text, snippet};
+use clippy_utils::source::{expr_block, get_source_text, indent_of, snippet};
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item, peel_mid_ty_refs};
-use clippy_utils::{is_lint_allowed, is_unit_expr, is_wild, peel_blocks, peel_hir_pat_refs, peel_n_hir_expr_refs};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{
+    is_lint_allowed, is_unit_expr, is_wild, path_to_local, peel_blocks, peel_hir_pat_refs, peel_n_hir_expr_refs,
+    span_contains_comment,
+};
 use core::cmp::max;
+use rustc_ast::Mutability;
 use rustc_errors::Applicability;
-use rustc_hir::{Arm, BindingMode, Block, Expr, ExprKind, Pat, PatKind};
-use rustc_lint::LateContext;
+use rustc_hir::{Arm, BindingMode, Block, ByRef, Expr, ExprKind, Pat, PatKind};
+use rustc_lint::{LateContext, LintContext};
 use rustc_middle::ty::{self, Ty};
+use rustc_span::symbol::Ident;
 use rustc_span::{sym, Span};
 
 use super::{MATCH_BOOL, SINGLE_MATCH, SINGLE_MATCH_ELSE};
@@ -54,16 +60,47 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr:
             return;
         };
 
+        // `other => ..` is only linted if `other` can be bound again in the `else` block
+        let rebinding = match arms[1].pat.kind {
+            PatKind::Binding(BindingMode(ByRef::No, mutbl), id, ident, None) if is_local_used(cx, arms[1].body, id) => {
+                if !is_cheap_place(ex) {
+                    return;
+                }
+                Some((mutbl, ident))
+            },
+            _ => None,
+        };
+
         let ty = cx.typeck_results().expr_ty(ex);
         if (*ty.kind() != ty::Bool || is_lint_allowed(cx, MATCH_BOOL, ex.hir_id)) &&
             (check_single_pattern(arms) || check_opt_like(cx, arms, ty)) {
-            report_single_pattern(cx, ex, arms, expr, els);
+            report_single_pattern(cx, ex, arms, expr, els, rebinding);
         }
     }
 }
 
+/// Checks if the second arm is `_` or a binding, which matches anything.
 fn check_single_pattern(arms: &[Arm<'_>]) -> bool {
-    is_wild(arms[1].pat)
+    is_wild(arms[1].pat) || matches!(arms[1].pat.kind, PatKind::Binding(BindingMode(ByRef::No, _), .., None))
+}
+
+/// Checks if the scrutinee is a local or a field of one, so that evaluating it once more to
+/// rebind it in the `else` block doesn't make a difference.
+fn is_cheap_place(e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Path(_) => path_to_local(e).is_some(),
+        ExprKind::Field(base, _) | ExprKind::AddrOf(_, _, base) => is_cheap_place(base),
+        _ => false,
+    }
+}
+
+/// Checks if there are comments in the `match` outside of the arm bodies, which would be lost
+/// by the suggestion.
+fn has_comment_between_arms(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr: &Expr<'_>) -> bool {
+    let sm = cx.sess().source_map();
+    span_contains_comment(sm, ex.span.between(arms[0].pat.span))
+        || span_contains_comment(sm, arms[0].body.span.between(arms[1].pat.span))
+        || span_contains_comment(sm, arms[1].body.span.between(expr.span.shrink_to_hi()))
 }
 
 fn report_single_pattern(
@@ -72,12 +109,25 @@ fn report_single_pattern(
     arms: &[Arm<'_>],
     expr: &Expr<'_>,
     els: Option<&Expr<'_>>,
+    rebinding: Option<(Mutability, Ident)>,
 ) {
     let lint = if els.is_some() { SINGLE_MATCH_ELSE } else { SINGLE_MATCH };
     let ctxt = expr.span.ctxt();
-    let mut app = Applicability::MachineApplicable;
+    let mut app = if has_comment_between_arms(cx, ex, arms, expr) {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
     let els_str = els.map_or(String::new(), |els| {
-        format!(" else {}", expr_block(cx, els, ctxt, "..", Some(expr.span), &mut app))
+        let els = expr_block(cx, els, ctxt, "..", Some(expr.span), &mut app);
+        if let Some((mutbl, ident)) = rebinding {
+            // `other => { .. }` becomes `else { let other = <scrutinee>; .. }`
+            let indent = " ".repeat(indent_of(cx, expr.span).unwrap_or(0) + 4);
+            let binding = format!("{{\n{indent}let {}{ident} = {};", mutbl.prefix_str(), snippet(cx, ex.span, ".."));
+            format!(" else {}", els.replacen('{', &binding, 1))
+        } else {
+            format!(" else {els}")
+        }
     });
 
     let (pat, pat_ref_count) = peel_hir_pat_refs(arms[0].pat);
@@ -0,0 +1,96 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::get_parent_expr;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem};
+use rustc_lint::LateContext;
+
+use super::CHARS_COUNT_COMPARISON;
+
+/// lint comparisons of `str::chars().count()` or `str::bytes().count()` against a constant
+///
+/// Returns true if the lint was emitted
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    count_recv: &'tcx Expr<'_>,
+    str_recv: &'tcx Expr<'_>,
+    iter_name: &str,
+) -> bool {
+    if let Some(parent) = get_parent_expr(cx, expr)
+        && let ExprKind::Binary(op, lhs, rhs) = parent.kind
+        && !parent.span.from_expansion()
+        && let Some(iter_id) = cx.typeck_results().type_dependent_def_id(count_recv.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(iter_id)
+        && cx.tcx.type_of(impl_id).instantiate_identity().is_str()
+        && let ty = cx.typeck_results().expr_ty(str_recv).peel_refs()
+        && (ty.is_str() || is_type_lang_item(cx, ty, LangItem::String))
+        // Normalize the comparison to `count <op> n`.
+        && let Some((op, other)) = if lhs.hir_id == expr.hir_id {
+            Some((op.node, rhs))
+        } else if rhs.hir_id == expr.hir_id {
+            flip(op.node).map(|op| (op, lhs))
+        } else {
+            None
+        }
+        && let Some(Constant::Int(n)) = constant(cx, cx.typeck_results(), other)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let recv = Sugg::hir_with_context(cx, str_recv, parent.span.ctxt(), "..", &mut applicability).maybe_par();
+        let is_empty = match (op, n) {
+            (BinOpKind::Eq | BinOpKind::Le, 0) | (BinOpKind::Lt, 1) => Some(true),
+            (BinOpKind::Ne | BinOpKind::Gt, 0) | (BinOpKind::Ge, 1) => Some(false),
+            _ => None,
+        };
+        if let Some(is_empty) = is_empty {
+            let sugg = Sugg::NonParen(format!("{recv}.is_empty()").into());
+            span_lint_and_sugg(
+                cx,
+                CHARS_COUNT_COMPARISON,
+                parent.span,
+                format!("counting all `{iter_name}` to check whether a string is empty"),
+                "use `is_empty` instead",
+                if is_empty { sugg } else { !sugg }.to_string(),
+                applicability,
+            );
+            return true;
+        }
+
+        // `bytes().count()` is `len()` and is taken care of by `bytes_count_to_len`.
+        if iter_name != "chars" {
+            return false;
+        }
+        let (nth, is_none) = match op {
+            BinOpKind::Lt if n > 1 => (n - 1, true),
+            BinOpKind::Le if n > 0 => (n, true),
+            BinOpKind::Gt if n > 0 => (n, false),
+            BinOpKind::Ge if n > 1 => (n - 1, false),
+            _ => return false,
+        };
+        span_lint_and_sugg(
+            cx,
+            CHARS_COUNT_COMPARISON,
+            parent.span,
+            "counting all `chars` to compare against a constant",
+            "stop at the first char past the limit instead",
+            format!("{recv}.chars().nth({nth}).{}()", if is_none { "is_none" } else { "is_some" }),
+            Applicability::MaybeIncorrect,
+        );
+        return true;
+    }
+    false
+}
+
+/// Returns the operator to use when swapping the operands of a comparison.
+pub(super) fn flip(op: BinOpKind) -> Option<BinOpKind> {
+    match op {
+        BinOpKind::Eq | BinOpKind::Ne => Some(op),
+        BinOpKind::Lt => Some(BinOpKind::Gt),
+        BinOpKind::Le => Some(BinOpKind::Ge),
+        BinOpKind::Gt => Some(BinOpKind::Lt),
+        BinOpKind::Ge => Some(BinOpKind::Le),
+        _ => None,
+    }
+}
@@ -0,0 +1,83 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{is_trait_method, path_to_local};
+use rustc_ast::Mutability;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Expr, Node, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{sym, Span};
+
+use super::{method_call, DOUBLE_ENDED_ITERATOR_LAST};
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, call_span: Span) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    if !is_trait_method(cx, expr, sym::Iterator)
+        || !cx
+            .tcx
+            .get_diagnostic_item(sym::DoubleEndedIterator)
+            .is_some_and(|double_ended_iterator| implements_trait(cx, recv_ty, double_ended_iterator, &[]))
+        || has_closure_adapter(cx, recv)
+    {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        DOUBLE_ENDED_ITERATOR_LAST,
+        expr.span,
+        "called `Iterator::last` on a `DoubleEndedIterator`; this will needlessly iterate the entire iterator",
+        |diag| {
+            diag.note("`next_back` skips the preceding elements, so side effects of producing them won't happen");
+            let mut app = Applicability::MachineApplicable;
+            let mut sugg = vec![(call_span, String::from("next_back()"))];
+            // `next_back` takes `&mut self`, temporaries and `&mut` references can be used as is
+            if recv.is_place_expr(|_| true) && !matches!(recv_ty.kind(), ty::Ref(_, _, Mutability::Mut)) {
+                if let Some(binding_span) = immutable_binding(cx, recv) {
+                    sugg.push((binding_span.shrink_to_lo(), String::from("mut ")));
+                } else if !is_mut_binding(cx, recv) {
+                    app = Applicability::MaybeIncorrect;
+                }
+            }
+            diag.multipart_suggestion("try", sugg, app);
+        },
+    );
+}
+
+/// Checks if any adapter in the receiver chain takes a closure or function, e.g. `map` or
+/// `inspect`. Those would no longer be called for the skipped elements.
+fn has_closure_adapter<'tcx>(cx: &LateContext<'tcx>, mut recv: &'tcx Expr<'tcx>) -> bool {
+    while let Some((_, inner, args, _, _)) = method_call(recv) {
+        if args.iter().any(|arg| is_callable(cx.typeck_results().expr_ty(arg))) {
+            return true;
+        }
+        recv = inner;
+    }
+    false
+}
+
+fn is_callable(ty: Ty<'_>) -> bool {
+    matches!(ty.peel_refs().kind(), ty::Closure(..) | ty::FnDef(..) | ty::FnPtr(..))
+}
+
+/// Returns the span of the binding's identifier if `recv` is a local bound without `mut`.
+fn immutable_binding(cx: &LateContext<'_>, recv: &Expr<'_>) -> Option<Span> {
+    if let Some(id) = path_to_local(recv)
+        && let Node::Pat(pat) = cx.tcx.hir_node(id)
+        && let PatKind::Binding(BindingMode::NONE, _, ident, None) = pat.kind
+        && !pat.span.from_expansion()
+    {
+        Some(ident.span)
+    } else {
+        None
+    }
+}
+
+fn is_mut_binding(cx: &LateContext<'_>, recv: &Expr<'_>) -> bool {
+    path_to_local(recv).is_some_and(|id| {
+        matches!(
+            cx.tcx.hir_node(id),
+            Node::Pat(pat) if matches!(pat.kind, PatKind::Binding(BindingMode::MUT, ..))
+        )
+    })
+}
@@ -27,17 +27,29 @@ fn types_match_diagnostic_item(cx: &LateContext<'_>, expr: Ty<'_>, recv: Ty<'_>,
 }
 
 /// Checks `std::{vec::Vec, collections::VecDeque}`.
-fn check_vec(cx: &LateContext<'_>, args: &[Expr<'_>], expr: Ty<'_>, recv: Ty<'_>, recv_path: &Path<'_>) -> bool {
+fn check_vec(
+    cx: &LateContext<'_>,
+    args: &[Expr<'_>],
+    expr: Ty<'_>,
+    recv: Ty<'_>,
+    recv_path: Option<&Path<'_>>,
+) -> bool {
     (types_match_diagnostic_item(cx, expr, recv, sym::Vec)
         || types_match_diagnostic_item(cx, expr, recv, sym::VecDeque))
-        && matches!(args, [arg] if is_range_full(cx, arg, Some(recv_path)))
+        && matches!(args, [arg] if is_range_full(cx, arg, recv_path))
 }
 
 /// Checks `std::string::String`
-fn check_string(cx: &LateContext<'_>, args: &[Expr<'_>], expr: Ty<'_>, recv: Ty<'_>, recv_path: &Path<'_>) -> bool {
+fn check_string(
+    cx: &LateContext<'_>,
+    args: &[Expr<'_>],
+    expr: Ty<'_>,
+    recv: Ty<'_>,
+    recv_path: Option<&Path<'_>>,
+) -> bool {
     is_type_lang_item(cx, expr, LangItem::String)
         && is_type_lang_item(cx, recv, LangItem::String)
-        && matches!(args, [arg] if is_range_full(cx, arg, Some(recv_path)))
+        && matches!(args, [arg] if is_range_full(cx, arg, recv_path))
 }
 
 /// Checks `std::collections::{HashSet, HashMap, BinaryHeap}`.
@@ -53,14 +65,24 @@ pub(super) fn check(cx: &LateContext<'_>, args: &[Expr<'_>], expr: &Expr<'_>, re
     let recv_ty = cx.typeck_results().expr_ty(recv);
     let recv_ty_no_refs = recv_ty.peel_refs();
 
-    if let ExprKind::Path(QPath::Resolved(_, recv_path)) = recv.kind
+    // `v.drain(..v.len())` is only recognized if `v` is a path
+    let recv_path = if let ExprKind::Path(QPath::Resolved(_, recv_path)) = recv.kind {
+        Some(recv_path)
+    } else {
+        None
+    };
+    // Temporaries can't be taken from, and the collection has to be the exact same type, including
+    // e.g. the hasher of a `HashMap`
+    let is_ref = matches!(recv_ty.kind(), ty::Ref(..));
+    if (is_ref || recv.is_syntactic_place_expr())
+        && expr_ty == recv_ty_no_refs
         && let Some(typename) = check_vec(cx, args, expr_ty, recv_ty_no_refs, recv_path)
             .then_some("Vec")
             .or_else(|| check_string(cx, args, expr_ty, recv_ty_no_refs, recv_path).then_some("String"))
             .or_else(|| check_collections(cx, expr_ty, recv_ty_no_refs))
     {
         let recv = snippet(cx, recv.span, "<expr>");
-        let sugg = if let ty::Ref(..) = recv_ty.kind() {
+        let sugg = if is_ref {
             format!("std::mem::take({recv})")
         } else {
             format!("std::mem::take(&mut {recv})")
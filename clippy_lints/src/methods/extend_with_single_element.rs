@@ -0,0 +1,132 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher::VecArgs;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{is_res_lang_ctor, match_def_path, match_trait_method, path_def_id, path_res, paths};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::OptionSome;
+use rustc_hir::{Expr, ExprKind, LangItem, Node, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{sym, Span, Symbol};
+
+use super::EXTEND_WITH_SINGLE_ELEMENT;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    method_name: Symbol,
+    recv: &'tcx Expr<'tcx>,
+    args: &'tcx [Expr<'tcx>],
+    method_span: Span,
+) {
+    if method_name.as_str() == "extend"
+        && let [arg] = args
+        && let Some(elem) = single_element(cx, arg)
+        && !elem.span.from_expansion()
+        && match_trait_method(cx, expr, &paths::CORE_ITER_EXTEND)
+    {
+        span_lint_and_then(
+            cx,
+            EXTEND_WITH_SINGLE_ELEMENT,
+            expr.span,
+            "calling `extend` with a single element",
+            |diag| {
+                let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+                if let Some(insert) = InsertMethod::for_collection(cx, recv_ty, elem) {
+                    let mut app = Applicability::MachineApplicable;
+                    let ctxt = expr.span.ctxt();
+                    // `insert` returns a value, so it can only replace `extend` if the result is unused
+                    if !matches!(insert, InsertMethod::Push | InsertMethod::PushBack) && !is_stmt(cx, expr) {
+                        app = Applicability::MaybeIncorrect;
+                    }
+                    let elem_sugg = match (insert, elem.kind) {
+                        (InsertMethod::MapInsert, ExprKind::Tup([key, value])) => format!(
+                            "{}, {}",
+                            Sugg::hir_with_context(cx, key, ctxt, "..", &mut app),
+                            Sugg::hir_with_context(cx, value, ctxt, "..", &mut app),
+                        ),
+                        _ => Sugg::hir_with_context(cx, elem, ctxt, "..", &mut app).to_string(),
+                    };
+                    let method = insert.name();
+                    diag.span_suggestion(
+                        method_span.with_hi(expr.span.hi()),
+                        format!("use `{method}` instead"),
+                        format!("{method}({elem_sugg})"),
+                        app,
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// Returns the element of `iter::once(x)`, `Some(x)`, `[x]` or `vec![x]`.
+fn single_element<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let Some(VecArgs::Vec([elem])) = VecArgs::hir(cx, arg) {
+        return Some(elem);
+    }
+    if arg.span.from_expansion() {
+        return None;
+    }
+    match arg.kind {
+        ExprKind::Array([elem]) => Some(elem),
+        ExprKind::Call(func, [elem])
+            if is_res_lang_ctor(cx, path_res(cx, func), OptionSome)
+                || path_def_id(cx, func).is_some_and(|id| match_def_path(cx, id, &paths::CORE_ITER_ONCE)) =>
+        {
+            Some(elem)
+        },
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum InsertMethod {
+    Push,
+    PushBack,
+    Insert,
+    /// `insert(key, value)` with the fields of a tuple literal
+    MapInsert,
+}
+
+impl InsertMethod {
+    /// Returns the method adding `elem` to a collection of type `ty`, if there is one.
+    fn for_collection<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, elem: &Expr<'_>) -> Option<Self> {
+        let elem_ty = cx.typeck_results().expr_ty(elem);
+        if is_type_lang_item(cx, ty, LangItem::String) {
+            return elem_ty.is_char().then_some(Self::Push);
+        }
+        let ty::Adt(adt, args) = ty.kind() else {
+            return None;
+        };
+        // Some collections also extend from references, e.g. `Vec<T: Copy>` implements `Extend<&T>`
+        match cx.tcx.get_diagnostic_name(adt.did())? {
+            sym::Vec if args.type_at(0) == elem_ty => Some(Self::Push),
+            sym::VecDeque if args.type_at(0) == elem_ty => Some(Self::PushBack),
+            sym::HashSet | sym::BTreeSet if args.type_at(0) == elem_ty => Some(Self::Insert),
+            sym::HashMap | sym::BTreeMap
+                if matches!(elem.kind, ExprKind::Tup([_, _]))
+                    && let ty::Tuple(elem_tys) = elem_ty.kind()
+                    && let [key_ty, value_ty] = elem_tys.as_slice()
+                    && *key_ty == args.type_at(0)
+                    && *value_ty == args.type_at(1) =>
+            {
+                Some(Self::MapInsert)
+            },
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Push => "push",
+            Self::PushBack => "push_back",
+            Self::Insert | Self::MapInsert => "insert",
+        }
+    }
+}
+
+fn is_stmt(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(cx.tcx.parent_hir_node(expr.hir_id), Node::Stmt(stmt) if matches!(stmt.kind, StmtKind::Semi(_)))
+}
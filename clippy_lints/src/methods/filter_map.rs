@@ -31,7 +31,11 @@ fn is_method(cx: &LateContext<'_>, expr: &Expr<'_>, method_name: Symbol) -> bool
                         && let Res::Local(ref local) = cx.qpath_res(path, receiver.hir_id)
                         && !body.params.is_empty()
                     {
-                        let arg_id = body.params[0].pat.hir_id;
+                        // `|&o| o.is_some()`
+                        let arg_id = match body.params[0].pat.kind {
+                            PatKind::Ref(inner, _) => inner.hir_id,
+                            _ => body.params[0].pat.hir_id,
+                        };
                         return arg_id == *local;
                     }
                     false
@@ -24,7 +24,7 @@ pub(super) fn check<'tcx>(
         let msg = "called `filter_map(..).next()` on an `Iterator`. This is more succinctly expressed by calling \
                    `.find_map(..)` instead";
         let filter_snippet = snippet(cx, arg.span, "..");
-        if filter_snippet.lines().count() <= 1 {
+        if filter_snippet.lines().nth(1).is_none() {
             let iter_snippet = snippet(cx, recv.span, "..");
             span_lint_and_sugg(
                 cx,
@@ -1,4 +1,5 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
+use clippy_utils::is_lint_allowed;
 use clippy_utils::source::snippet;
 use clippy_utils::ty::implements_trait;
 use rustc_ast::{BindingMode, Mutability};
@@ -7,7 +8,7 @@ use rustc_hir as hir;
 use rustc_lint::LateContext;
 use rustc_span::sym;
 
-use super::FILTER_NEXT;
+use super::{FILTER_NEXT, SEARCH_IS_SOME};
 
 fn path_to_local(expr: &hir::Expr<'_>) -> Option<hir::HirId> {
     match expr.kind {
@@ -35,11 +36,11 @@ pub(super) fn check<'tcx>(
     let recv_impls_iterator = cx.tcx.get_diagnostic_item(sym::Iterator).map_or(false, |id| {
         implements_trait(cx, cx.typeck_results().expr_ty(recv), id, &[])
     });
-    if recv_impls_iterator {
+    if recv_impls_iterator && !is_checked_by_search_is_some(cx, expr) {
         let msg = "called `filter(..).next()` on an `Iterator`. This is more succinctly expressed by calling \
                    `.find(..)` instead";
         let filter_snippet = snippet(cx, filter_arg.span, "..");
-        if filter_snippet.lines().count() <= 1 {
+        if filter_snippet.lines().nth(1).is_none() {
             let iter_snippet = snippet(cx, recv.span, "..");
             // add note if not multi-line
             span_lint_and_then(cx, FILTER_NEXT, expr.span, msg, |diag| {
@@ -71,3 +72,17 @@ pub(super) fn check<'tcx>(
         }
     }
 }
+
+/// `filter(..).next().is_some()` and `filter(..).next().is_none()` are linted by `search_is_some`,
+/// which suggests `any` instead
+fn is_checked_by_search_is_some(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    if let hir::Node::Expr(parent) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let hir::ExprKind::MethodCall(path, recv, [], _) = parent.kind
+        && recv.hir_id == expr.hir_id
+        && matches!(path.ident.as_str(), "is_some" | "is_none")
+    {
+        !is_lint_allowed(cx, SEARCH_IS_SOME, parent.hir_id)
+    } else {
+        false
+    }
+}
@@ -2,10 +2,13 @@ use super::utils::derefs_to_slice;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::get_parent_expr;
 use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::Ty;
 use rustc_span::sym;
 
 use super::GET_UNWRAP;
@@ -21,38 +24,49 @@ pub(super) fn check<'tcx>(
     // because they do not implement `IndexMut`
     let mut applicability = Applicability::MachineApplicable;
     let expr_ty = cx.typeck_results().expr_ty(recv);
-    let get_args_str = snippet_with_applicability(cx, get_arg.span, "..", &mut applicability);
-    let caller_type = if derefs_to_slice(cx, recv, expr_ty).is_some() {
-        "slice"
-    } else if is_type_diagnostic_item(cx, expr_ty, sym::Vec) {
-        "Vec"
-    } else if is_type_diagnostic_item(cx, expr_ty, sym::VecDeque) {
-        "VecDeque"
-    } else if !is_mut && is_type_diagnostic_item(cx, expr_ty, sym::HashMap) {
-        "HashMap"
-    } else if !is_mut && is_type_diagnostic_item(cx, expr_ty, sym::BTreeMap) {
-        "BTreeMap"
+    let caller_type = if let Some(caller_type) = caller_type(cx, recv, expr_ty, is_mut) {
+        caller_type
+    } else if cx
+        .typeck_results()
+        .expr_adjustments(recv)
+        .iter()
+        .any(|adjust| matches!(adjust.kind, Adjust::Deref(Some(_))))
+        && let Some(caller_type) = caller_type(
+            cx,
+            recv,
+            cx.typeck_results().expr_ty_adjusted(recv).peel_refs(),
+            is_mut,
+        )
+    {
+        // The receiver only derefs to the indexed type, e.g. a `Ref<'_, Vec<_>>`. Indexing
+        // auto-derefs the same way, but the guard's temporary may now need to live longer.
+        applicability = Applicability::MaybeIncorrect;
+        caller_type
     } else {
         return; // caller is not a type that we want to lint
     };
 
+    let get_args_str = snippet_with_applicability(cx, get_arg.span, "..", &mut applicability);
     let mut span = expr.span;
 
     // Handle the case where the result is immediately dereferenced,
     // either directly be the user, or as a result of a method call or the like
     // by not requiring an explicit reference
-    let needs_ref = if let Some(parent) = get_parent_expr(cx, expr)
-        && let hir::ExprKind::Unary(hir::UnOp::Deref, _)
-        | hir::ExprKind::MethodCall(..)
-        | hir::ExprKind::Field(..)
-        | hir::ExprKind::Index(..) = parent.kind
-    {
-        if let hir::ExprKind::Unary(hir::UnOp::Deref, _) = parent.kind {
-            // if the user explicitly dereferences the result, we can adjust
-            // the span to also include the deref part
-            span = parent.span;
+    let needs_ref = if let Some(parent) = get_parent_expr(cx, expr) {
+        match parent.kind {
+            hir::ExprKind::Unary(hir::UnOp::Deref, _) => {
+                // if the user explicitly dereferences the result, we can adjust
+                // the span to also include the deref part
+                span = parent.span;
+                false
+            },
+            // only the receiver or base is auto-referenced, e.g. `foo(v.get(0).unwrap())`
+            // still needs to pass a reference
+            hir::ExprKind::MethodCall(_, base, ..) | hir::ExprKind::Field(base, _) | hir::ExprKind::Index(base, ..) => {
+                base.hir_id != expr.hir_id
+            },
+            _ => true,
         }
-        false
     } else {
         true
     };
@@ -65,6 +79,7 @@ pub(super) fn check<'tcx>(
     } else {
         "&"
     };
+    let recv = Sugg::hir_with_applicability(cx, recv, "..", &mut applicability).maybe_par();
 
     span_lint_and_sugg(
         cx,
@@ -72,10 +87,28 @@ pub(super) fn check<'tcx>(
         span,
         format!("called `.get{mut_str}().unwrap()` on a {caller_type}. Using `[]` is more clear and more concise"),
         "try",
-        format!(
-            "{borrow_str}{}[{get_args_str}]",
-            snippet_with_applicability(cx, recv.span, "..", &mut applicability)
-        ),
+        format!("{borrow_str}{recv}[{get_args_str}]"),
         applicability,
     );
 }
+
+fn caller_type<'tcx>(
+    cx: &LateContext<'tcx>,
+    recv: &'tcx hir::Expr<'tcx>,
+    ty: Ty<'tcx>,
+    is_mut: bool,
+) -> Option<&'static str> {
+    if derefs_to_slice(cx, recv, ty).is_some() {
+        Some("slice")
+    } else if is_type_diagnostic_item(cx, ty, sym::Vec) {
+        Some("Vec")
+    } else if is_type_diagnostic_item(cx, ty, sym::VecDeque) {
+        Some("VecDeque")
+    } else if !is_mut && is_type_diagnostic_item(cx, ty, sym::HashMap) {
+        Some("HashMap")
+    } else if !is_mut && is_type_diagnostic_item(cx, ty, sym::BTreeMap) {
+        Some("BTreeMap")
+    } else {
+        None
+    }
+}
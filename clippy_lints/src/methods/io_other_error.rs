@@ -0,0 +1,41 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{match_def_path, path_def_id, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::LateContext;
+
+use super::IO_OTHER_ERROR;
+
+/// Checks for `std::io::Error::new(std::io::ErrorKind::Other, error)`
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, func: &Expr<'_>, args: &[Expr<'_>], msrv: &Msrv) {
+    if let [error_kind, error] = args
+        && let ExprKind::Path(QPath::TypeRelative(_, new_segment)) = func.kind
+        && let ctxt = expr.span.ctxt()
+        && func.span.ctxt() == ctxt
+        && error_kind.span.ctxt() == ctxt
+        && error.span.ctxt() == ctxt
+        && let Some(new_id) = path_def_id(cx, func)
+        && match_def_path(cx, new_id, &paths::IO_ERROR_NEW)
+        && let Some(kind_id) = path_def_id(cx, error_kind)
+        && match_def_path(cx, kind_id, &paths::IO_ERRORKIND_OTHER)
+        && msrv.meets(msrvs::IO_ERROR_OTHER)
+    {
+        span_lint_and_then(
+            cx,
+            IO_OTHER_ERROR,
+            expr.span,
+            "this can be `std::io::Error::other(_)`",
+            |diag| {
+                diag.multipart_suggestion_verbose(
+                    "use `std::io::Error::other`",
+                    vec![
+                        (new_segment.ident.span, String::from("other")),
+                        (error_kind.span.until(error.span), String::new()),
+                    ],
+                    Applicability::MachineApplicable,
+                );
+            },
+        );
+    }
+}
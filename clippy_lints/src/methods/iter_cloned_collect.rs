@@ -1,28 +1,54 @@
 use crate::methods::utils::derefs_to_slice;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_diag_trait_item, path_def_id};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::sym;
 
 use super::ITER_CLONED_COLLECT;
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, method_name: &str, expr: &hir::Expr<'_>, recv: &'tcx hir::Expr<'_>) {
-    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec)
+    check_adapter(cx, &format!("{method_name}()"), expr, recv);
+}
+
+/// Checks for `iter().map(Clone::clone).collect()`
+pub(super) fn check_map_clone<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    map_arg: &hir::Expr<'_>,
+) {
+    if path_def_id(cx, map_arg).is_some_and(|id| is_diag_trait_item(cx, id, sym::Clone)) {
+        check_adapter(cx, "map(Clone::clone)", expr, recv);
+    }
+}
+
+fn check_adapter<'tcx>(cx: &LateContext<'tcx>, adapter: &str, expr: &hir::Expr<'_>, recv: &'tcx hir::Expr<'_>) {
+    let vec_ty = cx.typeck_results().expr_ty(expr);
+    if is_type_diagnostic_item(cx, vec_ty, sym::Vec)
         && let Some(slice) = derefs_to_slice(cx, recv, cx.typeck_results().expr_ty(recv))
         && let Some(to_replace) = expr.span.trim_start(slice.span.source_callsite())
     {
+        let slice_ty = cx.typeck_results().expr_ty(slice);
+        // `clone` needs the same allocator, and on a `&&Vec` it would only clone the reference
+        let (kind, method) = match *slice_ty.kind() {
+            ty::Ref(_, inner_ty, _) if inner_ty == vec_ty => ("a `Vec`", "clone"),
+            _ if slice_ty == vec_ty => ("a `Vec`", "clone"),
+            _ => ("a slice", "to_vec"),
+        };
         span_lint_and_sugg(
             cx,
             ITER_CLONED_COLLECT,
             to_replace,
             format!(
-                "called `iter().{method_name}().collect()` on a slice to create a `Vec`. Calling `to_vec()` is both faster and \
-            more readable"
+                "called `iter().{adapter}.collect()` on {kind} to create a `Vec`. Calling `{method}()` is both faster \
+            and more readable"
             ),
             "try",
-            ".to_vec()".to_string(),
+            format!(".{method}()"),
             Applicability::MachineApplicable,
         );
     }
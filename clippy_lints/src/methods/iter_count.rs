@@ -10,26 +10,7 @@ use rustc_span::sym;
 use super::ITER_COUNT;
 
 pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, recv: &'tcx Expr<'tcx>, iter_method: &str) {
-    let ty = cx.typeck_results().expr_ty(recv);
-    let caller_type = if derefs_to_slice(cx, recv, ty).is_some() {
-        "slice"
-    } else if is_type_diagnostic_item(cx, ty, sym::Vec) {
-        "Vec"
-    } else if is_type_diagnostic_item(cx, ty, sym::VecDeque) {
-        "VecDeque"
-    } else if is_type_diagnostic_item(cx, ty, sym::HashSet) {
-        "HashSet"
-    } else if is_type_diagnostic_item(cx, ty, sym::HashMap) {
-        "HashMap"
-    } else if is_type_diagnostic_item(cx, ty, sym::BTreeMap) {
-        "BTreeMap"
-    } else if is_type_diagnostic_item(cx, ty, sym::BTreeSet) {
-        "BTreeSet"
-    } else if is_type_diagnostic_item(cx, ty, sym::LinkedList) {
-        "LinkedList"
-    } else if is_type_diagnostic_item(cx, ty, sym::BinaryHeap) {
-        "BinaryHeap"
-    } else {
+    let Some(caller_type) = collection_name(cx, recv) else {
         return;
     };
     let mut applicability = Applicability::MachineApplicable;
@@ -46,3 +27,29 @@ pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, recv: &'tcx E
         applicability,
     );
 }
+
+/// Returns the name of the collection type if it has a `len` method.
+pub(super) fn collection_name<'tcx>(cx: &LateContext<'tcx>, recv: &'tcx Expr<'tcx>) -> Option<&'static str> {
+    let ty = cx.typeck_results().expr_ty(recv);
+    if derefs_to_slice(cx, recv, ty).is_some() {
+        Some("slice")
+    } else if is_type_diagnostic_item(cx, ty, sym::Vec) {
+        Some("Vec")
+    } else if is_type_diagnostic_item(cx, ty, sym::VecDeque) {
+        Some("VecDeque")
+    } else if is_type_diagnostic_item(cx, ty, sym::HashSet) {
+        Some("HashSet")
+    } else if is_type_diagnostic_item(cx, ty, sym::HashMap) {
+        Some("HashMap")
+    } else if is_type_diagnostic_item(cx, ty, sym::BTreeMap) {
+        Some("BTreeMap")
+    } else if is_type_diagnostic_item(cx, ty, sym::BTreeSet) {
+        Some("BTreeSet")
+    } else if is_type_diagnostic_item(cx, ty, sym::LinkedList) {
+        Some("LinkedList")
+    } else if is_type_diagnostic_item(cx, ty, sym::BinaryHeap) {
+        Some("BinaryHeap")
+    } else {
+        None
+    }
+}
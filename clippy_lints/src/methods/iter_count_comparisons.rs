@@ -0,0 +1,75 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_parent_expr;
+use clippy_utils::sugg::Sugg;
+use rustc_ast::Mutability;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+use super::chars_count_comparison::flip;
+use super::{iter_count, method_call, ITER_COUNT_COMPARISONS};
+
+/// lint comparisons of `Iterator::count()` against zero or one
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>) {
+    // `iter_count` and `chars_count_comparison` already suggest `len` or `is_empty` for these
+    match method_call(recv) {
+        Some(("into_iter" | "iter" | "iter_mut", recv2, [], _, _))
+            if iter_count::collection_name(cx, recv2).is_some() =>
+        {
+            return;
+        },
+        Some(("chars" | "bytes", recv2, [], _, _))
+            if cx.typeck_results().expr_ty_adjusted(recv2).peel_refs().is_str() =>
+        {
+            return;
+        },
+        _ => {},
+    }
+
+    if let Some(parent) = get_parent_expr(cx, expr)
+        && let ExprKind::Binary(op, lhs, rhs) = parent.kind
+        && !parent.span.from_expansion()
+        // Normalize the comparison to `count <op> n`.
+        && let Some((op, other)) = if lhs.hir_id == expr.hir_id {
+            Some((op.node, rhs))
+        } else if rhs.hir_id == expr.hir_id {
+            flip(op.node).map(|op| (op, lhs))
+        } else {
+            None
+        }
+        && let Some(Constant::Int(n)) = constant(cx, cx.typeck_results(), other)
+        && let Some((method, is_none)) = match (op, n) {
+            (BinOpKind::Eq | BinOpKind::Le, 0) | (BinOpKind::Lt, 1) => Some(("next()", true)),
+            (BinOpKind::Ne | BinOpKind::Gt, 0) | (BinOpKind::Ge, 1) => Some(("next()", false)),
+            (BinOpKind::Le, 1) | (BinOpKind::Lt, 2) => Some(("nth(1)", true)),
+            (BinOpKind::Gt, 1) | (BinOpKind::Ge, 2) => Some(("nth(1)", false)),
+            _ => None,
+        }
+    {
+        span_lint_and_then(
+            cx,
+            ITER_COUNT_COMPARISONS,
+            parent.span,
+            format!("counting all elements of an iterator to compare the count with {n}"),
+            |diag| {
+                let mut app = Applicability::MachineApplicable;
+                // `next` and `nth` take `&mut self`, so a binding may need to be declared `mut`
+                if recv.is_place_expr(|_| true)
+                    && !matches!(cx.typeck_results().expr_ty(recv).kind(), ty::Ref(_, _, Mutability::Mut))
+                {
+                    app = Applicability::MaybeIncorrect;
+                }
+                let recv = Sugg::hir_with_context(cx, recv, parent.span.ctxt(), "..", &mut app).maybe_par();
+                diag.note("this stops early, so side effects of producing the remaining elements won't happen");
+                diag.span_suggestion(
+                    parent.span,
+                    format!("use `{method}` instead"),
+                    format!("{recv}.{method}.{}()", if is_none { "is_none" } else { "is_some" }),
+                    app,
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{is_trait_method, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::MANUAL_CONTAINS;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Receiver {
+    /// `slice.iter()`, yields `&T`
+    SliceIter,
+    /// `str.chars()`, yields `char`
+    Chars,
+    /// `str.bytes()`, yields `u8`
+    Bytes,
+}
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    iter: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    method: &str,
+    closure_arg: &'tcx Expr<'_>,
+) {
+    let recv_ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs();
+    let kind = match method {
+        "iter" if recv_ty.is_slice() => Receiver::SliceIter,
+        // `string_lit_chars_any` suggests `matches!` for string literals
+        "chars" if recv_ty.is_str() && !matches!(recv.kind, ExprKind::Lit(_)) => Receiver::Chars,
+        "bytes" if recv_ty.is_str() => Receiver::Bytes,
+        _ => return,
+    };
+
+    if !is_trait_method(cx, expr, sym::Iterator) || iter.span.from_expansion() {
+        return;
+    }
+
+    if let ExprKind::Closure(closure) = closure_arg.kind
+        && let body = cx.tcx.hir().body(closure.body)
+        && let [param] = body.params
+        && let ExprKind::Binary(op, lhs, rhs) = peel_blocks(body.value).kind
+        && op.node == BinOpKind::Eq
+        && !body.value.span.from_expansion()
+    {
+        let (binding, by_ref_pat) = match param.pat.kind {
+            PatKind::Binding(_, id, _, None) => (id, false),
+            PatKind::Ref(inner, _)
+                if kind == Receiver::SliceIter
+                    && let PatKind::Binding(_, id, _, None) = inner.kind =>
+            {
+                (id, true)
+            },
+            _ => return,
+        };
+
+        // Whether the needle can be passed to `contains` as is, or needs to be borrowed. A `&T`
+        // yielded by `iter()` and bound by `|x|` is compared with an `&T` by `x == &needle`.
+        let Some((needle, pass_as_is)) = [(lhs, rhs), (rhs, lhs)].into_iter().find_map(|(elem, needle)| {
+            let pass_as_is = match elem.kind {
+                _ if path_to_local_id(elem, binding) => match kind {
+                    Receiver::SliceIter => !by_ref_pat,
+                    Receiver::Chars => true,
+                    Receiver::Bytes => false,
+                },
+                ExprKind::Unary(UnOp::Deref, inner)
+                    if kind == Receiver::SliceIter && !by_ref_pat && path_to_local_id(inner, binding) =>
+                {
+                    false
+                },
+                _ => return None,
+            };
+            // The element has to be compared with its own type, otherwise the closure uses
+            // a different `PartialEq` impl than `contains` would.
+            (cx.typeck_results().expr_ty(elem) == cx.typeck_results().expr_ty(needle)
+                && !is_local_used(cx, needle, binding)
+                && switch_to_eager_eval(cx, needle))
+            .then_some((needle, pass_as_is))
+        }) else {
+            return;
+        };
+
+        let mut app = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let recv_sugg = Sugg::hir_with_context(cx, recv, ctxt, "..", &mut app).maybe_par();
+        let needle_sugg = match needle.kind {
+            _ if pass_as_is => Sugg::hir_with_context(cx, needle, ctxt, "..", &mut app),
+            // `*x == *needle`, pass the reference itself
+            ExprKind::Unary(UnOp::Deref, inner) if cx.typeck_results().expr_ty(inner).is_ref() => {
+                Sugg::hir_with_context(cx, inner, ctxt, "..", &mut app)
+            },
+            _ => Sugg::hir_with_context(cx, needle, ctxt, "..", &mut app).addr(),
+        };
+        let as_bytes = if kind == Receiver::Bytes { ".as_bytes()" } else { "" };
+
+        span_lint_and_sugg(
+            cx,
+            MANUAL_CONTAINS,
+            expr.span,
+            format!("using `{method}().any()` to check whether it contains an element"),
+            "try",
+            format!("{recv_sugg}{as_bytes}.contains({needle_sugg})"),
+            app,
+        );
+    }
+}
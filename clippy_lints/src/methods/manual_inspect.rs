@@ -165,14 +165,12 @@ pub(crate) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, arg: &Expr<'_>, name:
             } else {
                 edits.extend(addr_of_edits);
             }
-            edits.push((
-                name_span,
-                String::from(match name {
-                    "map" => "inspect",
-                    "map_err" => "inspect_err",
-                    _ => return,
-                }),
-            ));
+            let edit = match name {
+                "map" => "inspect",
+                "map_err" => "inspect_err",
+                _ => return,
+            };
+            edits.push((name_span, edit.into()));
             edits.push((
                 with_leading_whitespace(cx, final_expr.span).set_span_pos(final_expr.span),
                 String::new(),
@@ -182,7 +180,7 @@ pub(crate) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, arg: &Expr<'_>, name:
             } else {
                 Applicability::MachineApplicable
             };
-            span_lint_and_then(cx, MANUAL_INSPECT, name_span, "", |diag| {
+            span_lint_and_then(cx, MANUAL_INSPECT, name_span, format!("using `{name}` over `{edit}`"), |diag| {
                 diag.multipart_suggestion("try", edits, app);
             });
         }
@@ -0,0 +1,40 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{is_path_diagnostic_item, is_trait_method, std_or_core};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::MANUAL_REPEAT_N;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    repeat_expr: &Expr<'_>,
+    take_arg: &Expr<'_>,
+    msrv: &Msrv,
+) {
+    if let ExprKind::Call(repeat_fn, [repeat_arg]) = repeat_expr.kind
+        && is_path_diagnostic_item(cx, repeat_fn, sym::iter_repeat)
+        && is_trait_method(cx, expr, sym::Iterator)
+        && repeat_expr.span.ctxt() == expr.span.ctxt()
+        && msrv.meets(msrvs::REPEAT_N)
+        && let Some(std_or_core) = std_or_core(cx)
+    {
+        let ctxt = expr.span.ctxt();
+        let mut applicability = Applicability::MachineApplicable;
+        let value = snippet_with_context(cx, repeat_arg.span, ctxt, "..", &mut applicability).0;
+        let count = snippet_with_context(cx, take_arg.span, ctxt, "..", &mut applicability).0;
+        span_lint_and_sugg(
+            cx,
+            MANUAL_REPEAT_N,
+            expr.span,
+            "this `repeat().take()` can be written more concisely",
+            "consider using `repeat_n()` instead",
+            format!("{std_or_core}::iter::repeat_n({value}, {count})"),
+            applicability,
+        );
+    }
+}
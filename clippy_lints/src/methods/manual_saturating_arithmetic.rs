@@ -142,13 +142,9 @@ enum Sign {
 }
 
 fn lit_sign(expr: &hir::Expr<'_>) -> Option<Sign> {
-    if let hir::ExprKind::Unary(hir::UnOp::Neg, inner) = &expr.kind {
-        if let hir::ExprKind::Lit(..) = &inner.kind {
-            return Some(Sign::Neg);
-        }
-    } else if let hir::ExprKind::Lit(..) = &expr.kind {
-        return Some(Sign::Pos);
+    match &expr.kind {
+        hir::ExprKind::Unary(hir::UnOp::Neg, inner) if let hir::ExprKind::Lit(..) = &inner.kind => Some(Sign::Neg),
+        hir::ExprKind::Lit(..) => Some(Sign::Pos),
+        _ => None,
     }
-
-    None
 }
@@ -3,9 +3,11 @@ use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
 use clippy_utils::source::snippet;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::usage::mutated_variables;
+use clippy_utils::{can_move_expr_to_closure, CaptureKind};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::mir::Mutability;
 use rustc_span::symbol::sym;
 
 use super::MAP_UNWRAP_OR;
@@ -53,10 +55,26 @@ pub(super) fn check<'tcx>(
         let unwrap_snippet = snippet(cx, unwrap_arg.span, "..");
         // lint, with note if neither arg is > 1 line and both map() and
         // unwrap_or_else() have the same span
-        let multiline = map_snippet.lines().count() > 1 || unwrap_snippet.lines().count() > 1;
+        let multiline = map_snippet.lines().nth(1).is_some() || unwrap_snippet.lines().nth(1).is_some();
         let same_span = map_arg.span.eq_ctxt(unwrap_arg.span);
-        if same_span && !multiline {
+        // Both closures are alive at the same time when passed to `map_or_else`, so they can't
+        // share a capture unless both only borrow it immutably.
+        let captures = can_move_expr_to_closure(cx, map_arg).zip(can_move_expr_to_closure(cx, unwrap_arg));
+        let conflicting_captures = captures.as_ref().map_or(true, |(map_captures, unwrap_captures)| {
+            unwrap_captures.iter().any(|(id, unwrap_kind)| {
+                map_captures.get(id).is_some_and(|map_kind| {
+                    *map_kind != CaptureKind::Ref(Mutability::Not) || *unwrap_kind != CaptureKind::Ref(Mutability::Not)
+                })
+            })
+        });
+        if same_span && !multiline && !conflicting_captures {
             let var_snippet = snippet(cx, recv.span, "..");
+            // A default which doesn't capture anything can't depend on what happens in `map`.
+            let applicability = if captures.is_some_and(|(_, unwrap_captures)| unwrap_captures.is_empty()) {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
             span_lint_and_sugg(
                 cx,
                 MAP_UNWRAP_OR,
@@ -64,10 +82,10 @@ pub(super) fn check<'tcx>(
                 msg,
                 "try",
                 format!("{var_snippet}.map_or_else({unwrap_snippet}, {map_snippet})"),
-                Applicability::MachineApplicable,
+                applicability,
             );
             return true;
-        } else if same_span && multiline {
+        } else if same_span {
             span_lint(cx, MAP_UNWRAP_OR, expr.span, msg);
             return true;
         }
@@ -5,6 +5,7 @@ mod bytes_nth;
 mod case_sensitive_file_extension_comparisons;
 mod chars_cmp;
 mod chars_cmp_with_unwrap;
+mod chars_count_comparison;
 mod chars_last_cmp;
 mod chars_last_cmp_with_unwrap;
 mod chars_next_cmp;
@@ -14,10 +15,12 @@ mod clone_on_copy;
 mod clone_on_ref_ptr;
 mod cloned_instead_of_copied;
 mod collapsible_str_replace;
+mod double_ended_iterator_last;
 mod drain_collect;
 mod err_expect;
 mod expect_fun_call;
 mod extend_with_drain;
+mod extend_with_single_element;
 mod filetype_is_file;
 mod filter_map;
 mod filter_map_bool_then;
@@ -35,10 +38,12 @@ mod implicit_clone;
 mod inefficient_to_string;
 mod inspect_for_each;
 mod into_iter_on_ref;
+mod io_other_error;
 mod is_digit_ascii_radix;
 mod is_empty;
 mod iter_cloned_collect;
 mod iter_count;
+mod iter_count_comparisons;
 mod iter_filter;
 mod iter_kv_map;
 mod iter_next_slice;
@@ -53,11 +58,13 @@ mod iter_with_drain;
 mod iterator_step_by_zero;
 mod join_absolute_paths;
 mod manual_c_str_literals;
+mod manual_contains;
 mod manual_inspect;
 mod manual_is_variant_and;
 mod manual_next_back;
 mod manual_ok_or;
 mod manual_saturating_arithmetic;
+mod manual_repeat_n;
 mod manual_str_repeat;
 mod manual_try_fold;
 mod map_clone;
@@ -97,6 +104,8 @@ mod single_char_add_str;
 mod single_char_insert_string;
 mod single_char_push_string;
 mod skip_while_next;
+mod sliced_string_as_bytes;
+mod split_newline;
 mod stable_sort_primitive;
 mod str_split;
 mod str_splitn;
@@ -107,22 +116,27 @@ mod suspicious_map;
 mod suspicious_splitn;
 mod suspicious_to_owned;
 mod type_id_on_box;
+mod unbuffered_bytes;
 mod uninit_assumed_init;
 mod unit_hash;
+mod unnecessary_clone_into_iter;
 mod unnecessary_fallible_conversions;
 mod unnecessary_filter_map;
 mod unnecessary_fold;
 mod unnecessary_get_then_check;
+mod unnecessary_indexing;
 mod unnecessary_iter_cloned;
 mod unnecessary_join;
 mod unnecessary_lazy_eval;
 mod unnecessary_literal_unwrap;
+mod unnecessary_map_or;
 mod unnecessary_min_or_max;
 mod unnecessary_result_map_or_else;
 mod unnecessary_sort_by;
 mod unnecessary_to_owned;
 mod unused_enumerate_index;
 mod unwrap_expect_used;
+mod unwrap_or_else_panic;
 mod useless_asref;
 mod utils;
 mod vec_resize_to_zero;
@@ -501,7 +515,7 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usages of the following functions with an argument that constructs a default value
-    /// (e.g., `Default::default` or `String::new`):
+    /// (e.g., `Default::default`, `String::new` or a closure calling them):
     /// - `unwrap_or`
     /// - `unwrap_or_else`
     /// - `or_insert`
@@ -538,12 +552,12 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `option.map(_).unwrap_or(_)` or `option.map(_).unwrap_or_else(_)` or
-    /// `result.map(_).unwrap_or_else(_)`.
+    /// Checks for usage of `option.map(_).unwrap_or(_)`, `option.map(_).unwrap_or_else(_)`,
+    /// `result.map(_).unwrap_or(_)` or `result.map(_).unwrap_or_else(_)`.
     ///
     /// ### Why is this bad?
     /// Readability, these can be written more concisely (resp.) as
-    /// `option.map_or(_, _)`, `option.map_or_else(_, _)` and `result.map_or_else(_, _)`.
+    /// `map_or(_, _)` and `map_or_else(_, _)`.
     ///
     /// ### Known problems
     /// The order of the arguments is not in execution order
@@ -841,8 +855,9 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for an iterator or string search (such as `find()`,
-    /// `position()`, or `rposition()`) followed by a call to `is_some()` or `is_none()`.
+    /// Checks for an iterator or string search (such as `find()`, `find_map()`,
+    /// `filter().next()`, `position()`, or `rposition()`) followed by a call to `is_some()`
+    /// or `is_none()`.
     ///
     /// ### Why is this bad?
     /// Readability, this can be written more concisely as:
@@ -1421,11 +1436,11 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for the use of `.cloned().collect()` on slice to
-    /// create a `Vec`.
+    /// Checks for the use of `.cloned().collect()`, `.copied().collect()` or
+    /// `.map(Clone::clone).collect()` on slice to create a `Vec`.
     ///
     /// ### Why is this bad?
-    /// `.to_vec()` is clearer
+    /// `.to_vec()`, or `.clone()` if the slice is a `Vec`, is clearer
     ///
     /// ### Example
     /// ```no_run
@@ -1499,10 +1514,11 @@ declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of `fold` when a more succinct alternative exists.
     /// Specifically, this checks for `fold`s which could be replaced by `any`, `all`,
-    /// `sum` or `product`.
+    /// `sum` or `product`, and for `fold`s which add each element to an empty collection,
+    /// which could be replaced by `collect`.
     ///
     /// ### Why is this bad?
-    /// Readability.
+    /// Readability. `collect` can also reserve the space for the elements up front.
     ///
     /// ### Example
     /// ```no_run
@@ -1978,7 +1994,9 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for instances of `map(f)` where `f` is the identity function.
+    /// Checks for instances of `map(f)` where `f` is the identity function. This includes
+    /// closures which rebuild the tuple, array or tuple struct they destructure, like
+    /// `|(a, b)| (a, b)`.
     ///
     /// ### Why is this bad?
     /// It can be written more concisely without the call to `map`.
@@ -2273,10 +2291,15 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `.collect::<Vec<String>>().join("")` on iterators.
+    /// Checks for usage of `.collect::<Vec<String>>().join("")` and `.collect::<Vec<String>>().concat()`
+    /// on iterators, and the same with `Vec<&str>`.
     ///
     /// ### Why is this bad?
-    /// `.collect::<String>()` is more concise and might be more performant
+    /// `.collect::<String>()` is more concise and might be more performant, as it doesn't
+    /// allocate an intermediate `Vec`.
+    ///
+    /// Joining with a non-empty separator has no direct replacement in `std`. It is only linted
+    /// where `Itertools` is in scope, as `Itertools::join` works on the iterator itself.
     ///
     /// ### Example
     /// ```no_run
@@ -2299,8 +2322,8 @@ declare_clippy_lint! {
     /// with aarch64 tending to producing faster assembly in more cases when using `.collect::<String>()`
     #[clippy::version = "1.61.0"]
     pub UNNECESSARY_JOIN,
-    pedantic,
-    "using `.collect::<Vec<String>>().join(\"\")` on an iterator"
+    perf,
+    "using `.collect::<Vec<String>>().join(..)` on an iterator"
 }
 
 declare_clippy_lint! {
@@ -3060,6 +3083,8 @@ declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of `Vec::sort_by` passing in a closure
     /// which compares the two arguments, either directly or indirectly.
+    /// The same applies to `Iterator::max_by` and `Iterator::min_by`, as well as to
+    /// `slice::binary_search_by` comparing a projection of the element with a value.
     ///
     /// ### Why is this bad?
     /// It is more clear to use `Vec::sort_by_key` (or `Vec::sort` if
@@ -3410,7 +3435,7 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Looks for calls to [`Stdin::read_line`] to read a line from the standard input
+    /// Looks for calls to [`Stdin::read_line`] or [`BufRead::read_line`] to read a line
     /// into a string, then later attempting to use that string for an operation that will never
     /// work for strings with a trailing newline character in it (e.g. parsing into a `i32`).
     ///
@@ -4127,6 +4152,467 @@ declare_clippy_lint! {
     "use of `map` returning the original item"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if` expressions conditioned on `seq.is_empty()` or on `seq.len()` compared
+    /// against zero, where `seq` is a slice, an array, a `Vec` or a `VecDeque`, whose non-empty
+    /// branch then accesses the first element through `seq[0]` or `seq.get(0)`, or the last
+    /// element through `seq[seq.len() - 1]`. A `match` on such a condition is checked as well.
+    ///
+    /// ### Why is this bad?
+    /// The emptiness check and the indexing are redundant: an `if let` on `seq.first()` (or
+    /// `seq.last()`, or `front()` and `back()` for a `VecDeque`) checks for emptiness and binds
+    /// the element in one go, without a possible panic path.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let a: &[i32] = &[1];
+    /// if !a.is_empty() {
+    ///     let b = a[0];
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let a: &[i32] = &[1];
+    /// if let Some(first) = a.first() {
+    ///     let b = *first;
+    /// }
+    /// ```
+    ///
+    /// An early return on an empty sequence, followed by the indexing, is better written with
+    /// `let..else`:
+    /// ```no_run
+    /// fn head(a: &[i32]) -> Option<i32> {
+    ///     if a.is_empty() {
+    ///         return None;
+    ///     }
+    ///     Some(a[0])
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn head(a: &[i32]) -> Option<i32> {
+    ///     let Some(first) = a.first() else {
+    ///         return None;
+    ///     };
+    ///     Some(*first)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNNECESSARY_INDEXING,
+    complexity,
+    "checking `seq.is_empty()` before indexing its first element instead of using `if let` on `seq.first()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `while` loops conditioned on `!seq.is_empty()` or on `seq.len()` compared
+    /// against zero, where `seq` is a slice, an array, a `Vec` or a `VecDeque`, whose body
+    /// accesses the first element through `seq[0]` or `seq.get(0)`, or the last element through
+    /// `seq[seq.len() - 1]`.
+    ///
+    /// The body may not mutate `seq`, except for removing the first element with `seq.remove(0)`
+    /// or `seq.pop_front()` at its end, in which case taking the element with `pop_front()` is
+    /// suggested instead.
+    ///
+    /// ### Why is this bad?
+    /// A `while let` on `seq.first()` checks for emptiness and binds the element in one go,
+    /// without a possible panic path. Indexing the first element and then removing it is better
+    /// done with a single `pop_front()`, which is also cheaper than `Vec::remove(0)`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::VecDeque;
+    /// let mut queue: VecDeque<i32> = VecDeque::from([1, 2]);
+    /// while !queue.is_empty() {
+    ///     println!("{}", queue[0]);
+    ///     queue.pop_front();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::VecDeque;
+    /// let mut queue: VecDeque<i32> = VecDeque::from([1, 2]);
+    /// while let Some(front) = queue.pop_front() {
+    ///     println!("{front}");
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNNECESSARY_INDEXING_IN_LOOP,
+    complexity,
+    "checking `!seq.is_empty()` in a `while` loop before indexing the first element instead of using `while let`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `str::chars().count()` or `str::bytes().count()` being compared against a
+    /// constant.
+    ///
+    /// ### Why is this bad?
+    /// Counting the chars of a string walks all of it, while the comparison usually only needs
+    /// to look at a few of them. Whether a string is empty is better checked with `is_empty()`,
+    /// and comparing against other constants can stop early with `chars().nth(..)`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let s = "hello";
+    /// let _ = s.chars().count() == 0;
+    /// let _ = s.chars().count() > 4;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let s = "hello";
+    /// let _ = s.is_empty();
+    /// let _ = s.chars().nth(4).is_some();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CHARS_COUNT_COMPARISON,
+    perf,
+    "comparing `str::chars().count()` against a constant"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `as_bytes` called on a slice of a string, like `s[..n].as_bytes()`.
+    ///
+    /// ### Why is this bad?
+    /// Slicing a `str` checks that both ends of the range are on char boundaries and panics
+    /// otherwise. Slicing the bytes of the string instead gives the same bytes without that
+    /// check.
+    ///
+    /// ### Known problems
+    /// Where the original code would have panicked on a range that is not on a char boundary,
+    /// the suggested code doesn't.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let s = "Lorem ipsum";
+    /// let bytes = s[..5].as_bytes();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let s = "Lorem ipsum";
+    /// let bytes = &s.as_bytes()[..5];
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub SLICED_STRING_AS_BYTES,
+    perf,
+    "slicing a string before calling `as_bytes` instead of slicing the bytes"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Read::bytes()` called on a reader that isn't buffered, like a `File` or a
+    /// `TcpStream`.
+    ///
+    /// ### Why is this bad?
+    /// The iterator returned by `bytes()` reads one byte at a time from the underlying reader.
+    /// For unbuffered readers this means one system call per byte. Wrapping the reader in a
+    /// `BufReader` first reads it in larger chunks.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::io::Read;
+    /// let file = std::fs::File::open("data").unwrap();
+    /// for byte in file.bytes() {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// use std::io::{BufReader, Read};
+    /// let file = std::fs::File::open("data").unwrap();
+    /// for byte in BufReader::new(file).bytes() {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNBUFFERED_BYTES,
+    perf,
+    "calling `Read::bytes()` on an unbuffered reader"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `repeat().take()` that can be replaced with `repeat_n()`.
+    ///
+    /// ### Why is this bad?
+    /// `repeat_n()` is more concise, and the iterator it returns implements
+    /// `ExactSizeIterator`, which `collect` can use to allocate exactly once.
+    ///
+    /// ### Known problems
+    /// `repeat_n()` is only stable since Rust 1.82, and is suggested on older toolchains unless
+    /// the MSRV is configured.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let v: Vec<i32> = std::iter::repeat(10).take(3).collect();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # #![feature(iter_repeat_n)]
+    /// let v: Vec<i32> = std::iter::repeat_n(10, 3).collect();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_REPEAT_N,
+    nursery,
+    "using `repeat().take()` instead of `repeat_n()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `.map_or(true, <f>)` on an `Option`.
+    ///
+    /// ### Why is this bad?
+    /// `.is_none_or(<f>)` expresses the same check more directly.
+    ///
+    /// ### Known problems
+    /// `is_none_or` isn't stable yet, so it's only suggested when the MSRV is set.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let opt = Some(5);
+    /// let _ = opt.map_or(true, |x| x > 3);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # #![feature(is_none_or)]
+    /// let opt = Some(5);
+    /// let _ = opt.is_none_or(|x| x > 3);
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNNECESSARY_MAP_OR,
+    nursery,
+    "using `map_or(true, <f>)` on an `Option`, which can be written as `is_none_or(<f>)`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Iterator::last` being called on a `DoubleEndedIterator`, which can be replaced
+    /// with `DoubleEndedIterator::next_back`.
+    ///
+    /// ### Why is this bad?
+    /// `Iterator::last` is implemented by consuming the iterator, which is unnecessary if
+    /// the iterator is a `DoubleEndedIterator`. Since Rust traits do not allow specialization,
+    /// `Iterator::last` cannot be optimized for `DoubleEndedIterator`.
+    ///
+    /// ### Known problems
+    /// `next_back` doesn't produce the elements before the last one, so adapters with side effects
+    /// would behave differently. The lint doesn't fire if the chain contains an adapter taking a
+    /// closure, such as `map`, `inspect` or `filter`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let last_arg = "echo hello world".split(' ').last();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let last_arg = "echo hello world".split(' ').next_back();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub DOUBLE_ENDED_ITERATOR_LAST,
+    perf,
+    "using `Iterator::last` on a `DoubleEndedIterator`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `iter().any()` on slices, arrays and `Vec`s, or `chars().any()` and
+    /// `bytes().any()` on strings, when the closure only compares the element with a value.
+    ///
+    /// ### Why is this bad?
+    /// `contains()` is more concise and can be more efficient, e.g. for slices of bytes, which
+    /// use a specialized search.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn foo(values: &[u8]) -> bool {
+    ///     values.iter().any(|&v| v == 10)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn foo(values: &[u8]) -> bool {
+    ///     values.contains(&10)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_CONTAINS,
+    perf,
+    "using `iter().any()` on a slice or `chars().any()` on a string to check for an element instead of `contains()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `std::io::Error::new` with `std::io::ErrorKind::Other` as the error kind.
+    ///
+    /// ### Why is this bad?
+    /// `std::io::Error::other` does the same and is shorter. Spelling out `ErrorKind::Other`
+    /// also tends to encourage matching on it, which is discouraged since the standard library
+    /// may move errors from `Other` to more specific kinds in the future.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let _ = std::io::Error::new(std::io::ErrorKind::Other, "bad".to_string());
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let _ = std::io::Error::other("bad".to_string());
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub IO_OTHER_ERROR,
+    style,
+    "calling `std::io::Error::new(std::io::ErrorKind::Other, _)`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usages of `str.split('\n')`, `str.rsplit('\n')` and `str.split_terminator('\n')`
+    /// (or the same with `"\n"`).
+    ///
+    /// ### Why is this bad?
+    /// These are almost always meant to iterate over the lines of a string, but they don't handle
+    /// `\r\n` line endings, leaving a `\r` at the end of each line. `str.lines()` handles both.
+    ///
+    /// ### Known problems
+    /// `split` and `rsplit` yield an empty item after a trailing newline while `lines` doesn't.
+    /// `lines` also removes the `\r`s, which the code might expect, so the suggestion is never
+    /// machine-applicable.
+    ///
+    /// `splitn` and `rsplitn` are not linted, as the last item, which contains the rest of the
+    /// string, has no equivalent with `lines`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let text = "first\nsecond\n";
+    /// for line in text.split('\n') {}
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let text = "first\nsecond\n";
+    /// for line in text.lines() {}
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub SPLIT_NEWLINE,
+    pedantic,
+    "splitting a string on newlines instead of using `lines()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `extend` with a single element, given as `iter::once(x)`, `Some(x)`,
+    /// `[x]` or `vec![x]`.
+    ///
+    /// ### Why is this bad?
+    /// It's a roundabout way to add one element. The collection's own method, like `push` or
+    /// `insert`, is clearer.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut v = vec![1, 2];
+    /// v.extend(std::iter::once(3));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut v = vec![1, 2];
+    /// v.push(3);
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub EXTEND_WITH_SINGLE_ELEMENT,
+    complexity,
+    "calling `extend` with a single element instead of `push` or `insert`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons of `Iterator::count()` with zero or one, like `iter.count() == 0`
+    /// or `iter.count() > 1`.
+    ///
+    /// ### Why is this bad?
+    /// `count` consumes the whole iterator, while `next` or `nth` stop as soon as the answer
+    /// is known.
+    ///
+    /// ### Known problems
+    /// Fewer elements are produced, so side effects of the iterator (e.g. in `map` or `inspect`)
+    /// won't happen for the remaining elements.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let v = vec![1, 2, 3];
+    /// let no_even = v.iter().filter(|x| *x % 2 == 0).count() == 0;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let v = vec![1, 2, 3];
+    /// let no_even = v.iter().filter(|x| *x % 2 == 0).next().is_none();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub ITER_COUNT_COMPARISONS,
+    perf,
+    "comparing `Iterator::count()` with zero or one"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.clone().into_iter()` on a `Vec`, `VecDeque`, or a std map or set.
+    ///
+    /// ### Why is this bad?
+    /// The whole collection is cloned up front, only to be consumed element by element.
+    /// Iterating over references and cloning each element lazily avoids the extra allocation,
+    /// and if the original isn't used afterwards, the clone isn't needed at all.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let names = vec![String::from("a"), String::from("b")];
+    /// let upper: Vec<String> = names.clone().into_iter().map(|s| s.to_uppercase()).collect();
+    /// println!("{names:?}");
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let names = vec![String::from("a"), String::from("b")];
+    /// let upper: Vec<String> = names.iter().cloned().map(|s| s.to_uppercase()).collect();
+    /// println!("{names:?}");
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNNECESSARY_CLONE_INTO_ITER,
+    perf,
+    "cloning a collection only to call `into_iter` on the clone"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unwrap_or_else`, `map_or_else` and `or_else` calls on an `Option` or a `Result`,
+    /// where the closure handling the `None` or `Err` case does nothing but call `panic!`,
+    /// `unreachable!`, `todo!` or `unimplemented!`.
+    ///
+    /// ### Why restrict this?
+    /// It's a roundabout way to write `unwrap()` or `expect(..)`, which hides the panic from
+    /// readers of the code.
+    ///
+    /// ### Known problems
+    /// The panic message of `expect` on a `Result` also contains the error, and the message of
+    /// `unreachable!`, `todo!` and `unimplemented!` has a prefix, which the suggestion drops.
+    ///
+    /// Formatting the message inside the closure is the way to go if it has any arguments, as
+    /// explained by [`expect_fun_call`](#expect_fun_call). Such calls are still reported, but
+    /// without a suggestion.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let option = Some(1);
+    /// let value = option.unwrap_or_else(|| unreachable!("the option is always `Some`"));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let option = Some(1);
+    /// let value = option.expect("the option is always `Some`");
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNWRAP_OR_ELSE_PANIC,
+    restriction,
+    "`unwrap_or_else` and similar calls with a closure that always panics"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
@@ -4294,6 +4780,21 @@ impl_lint_pass!(Methods => [
     NEEDLESS_CHARACTER_ITERATION,
     MANUAL_INSPECT,
     UNNECESSARY_MIN_OR_MAX,
+    UNNECESSARY_INDEXING,
+    UNNECESSARY_INDEXING_IN_LOOP,
+    CHARS_COUNT_COMPARISON,
+    SLICED_STRING_AS_BYTES,
+    UNBUFFERED_BYTES,
+    MANUAL_REPEAT_N,
+    UNNECESSARY_MAP_OR,
+    DOUBLE_ENDED_ITERATOR_LAST,
+    MANUAL_CONTAINS,
+    IO_OTHER_ERROR,
+    SPLIT_NEWLINE,
+    EXTEND_WITH_SINGLE_ELEMENT,
+    ITER_COUNT_COMPARISONS,
+    UNNECESSARY_CLONE_INTO_ITER,
+    UNWRAP_OR_ELSE_PANIC,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4322,6 +4823,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 from_iter_instead_of_collect::check(cx, expr, args, func);
                 unnecessary_fallible_conversions::check_function(cx, expr, func);
                 manual_c_str_literals::check(cx, expr, func, args, &self.msrv);
+                io_other_error::check(cx, expr, func, args, &self.msrv);
             },
             ExprKind::MethodCall(method_call, receiver, args, _) => {
                 let method_span = method_call.ident.span;
@@ -4341,6 +4843,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 single_char_add_str::check(cx, expr, receiver, args);
                 into_iter_on_ref::check(cx, expr, method_span, method_call.ident.name, receiver);
                 unnecessary_to_owned::check(cx, expr, method_call.ident.name, receiver, args, &self.msrv);
+                extend_with_single_element::check(cx, expr, method_call.ident.name, receiver, args, method_span);
             },
             ExprKind::Binary(op, lhs, rhs) if op.node == hir::BinOpKind::Eq || op.node == hir::BinOpKind::Ne => {
                 let mut info = BinaryExprInfo {
@@ -4522,6 +5025,9 @@ impl Methods {
                 ("any", [arg]) => {
                     unused_enumerate_index::check(cx, expr, recv, arg);
                     needless_character_iteration::check(cx, expr, recv, arg, false);
+                    if let Some((name @ ("iter" | "chars" | "bytes"), iter_recv, [], _, _)) = method_call(recv) {
+                        manual_contains::check(cx, expr, recv, iter_recv, name, arg);
+                    }
                     match method_call(recv) {
                         Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
                             cx,
@@ -4551,11 +5057,16 @@ impl Methods {
                     if let Some(("as_str", recv, [], as_str_span, _)) = method_call(recv) {
                         redundant_as_str::check(cx, expr, recv, as_str_span, span);
                     }
+                    sliced_string_as_bytes::check(cx, expr, recv);
                 },
                 ("as_mut", []) => useless_asref::check(cx, expr, "as_mut", recv),
                 ("as_ptr", []) => manual_c_str_literals::check_as_ptr(cx, expr, recv, &self.msrv),
                 ("as_ref", []) => useless_asref::check(cx, expr, "as_ref", recv),
                 ("assume_init", []) => uninit_assumed_init::check(cx, expr, recv),
+                ("binary_search_by", [arg]) => {
+                    unnecessary_sort_by::check_binary_search(cx, expr, recv, arg);
+                },
+                ("bytes", []) => unbuffered_bytes::check(cx, expr, recv),
                 ("cloned", []) => {
                     cloned_instead_of_copied::check(cx, expr, recv, span, &self.msrv);
                     option_as_ref_cloned::check(cx, recv, span);
@@ -4569,6 +5080,7 @@ impl Methods {
                         Some(("map", m_recv, [m_arg], m_ident_span, _)) => {
                             map_collect_result_unit::check(cx, expr, m_recv, m_arg);
                             format_collect::check(cx, expr, m_arg, m_ident_span);
+                            iter_cloned_collect::check_map_clone(cx, expr, m_recv, m_arg);
                         },
                         Some(("take", take_self_arg, [take_arg], _, _)) => {
                             if self.msrv.meets(msrvs::STR_REPEAT) {
@@ -4581,17 +5093,39 @@ impl Methods {
                         _ => {},
                     }
                 },
-                ("count", []) if is_trait_method(cx, expr, sym::Iterator) => match method_call(recv) {
-                    Some(("cloned", recv2, [], _, _)) => {
-                        iter_overeager_cloned::check(cx, expr, recv, recv2, iter_overeager_cloned::Op::RmCloned, false);
-                    },
-                    Some((name2 @ ("into_iter" | "iter" | "iter_mut"), recv2, [], _, _)) => {
-                        iter_count::check(cx, expr, recv2, name2);
-                    },
-                    Some(("map", _, [arg], _, _)) => suspicious_map::check(cx, expr, recv, arg),
-                    Some(("filter", recv2, [arg], _, _)) => bytecount::check(cx, expr, recv2, arg),
-                    Some(("bytes", recv2, [], _, _)) => bytes_count_to_len::check(cx, expr, recv, recv2),
-                    _ => {},
+                ("concat", []) => {
+                    if let Some(("collect", _, _, span, _)) = method_call(recv) {
+                        unnecessary_join::check(cx, expr, recv, None, span);
+                    }
+                },
+                ("count", []) if is_trait_method(cx, expr, sym::Iterator) => {
+                    iter_count_comparisons::check(cx, expr, recv);
+                    match method_call(recv) {
+                        Some(("cloned", recv2, [], _, _)) => {
+                            iter_overeager_cloned::check(
+                                cx,
+                                expr,
+                                recv,
+                                recv2,
+                                iter_overeager_cloned::Op::RmCloned,
+                                false,
+                            );
+                        },
+                        Some((name2 @ ("into_iter" | "iter" | "iter_mut"), recv2, [], _, _)) => {
+                            iter_count::check(cx, expr, recv2, name2);
+                        },
+                        Some(("map", _, [arg], _, _)) => suspicious_map::check(cx, expr, recv, arg),
+                        Some(("filter", recv2, [arg], _, _)) => bytecount::check(cx, expr, recv2, arg),
+                        Some(("bytes", recv2, [], _, _)) => {
+                            if !chars_count_comparison::check(cx, expr, recv, recv2, "bytes") {
+                                bytes_count_to_len::check(cx, expr, recv, recv2);
+                            }
+                        },
+                        Some(("chars", recv2, [], _, _)) => {
+                            chars_count_comparison::check(cx, expr, recv, recv2, "chars");
+                        },
+                        _ => {},
+                    }
                 },
                 ("min" | "max", [arg]) => {
                     unnecessary_min_or_max::check(cx, expr, name, recv, arg);
@@ -4735,6 +5269,7 @@ impl Methods {
                         redundant_as_str::check(cx, expr, recv, as_str_span, span);
                     }
                     is_empty::check(cx, expr, recv);
+                    unnecessary_indexing::check(cx, expr, recv, &self.msrv);
                 },
                 ("is_file", []) => filetype_is_file::check(cx, expr, recv),
                 ("is_digit", [radix]) => is_digit_ascii_radix::check(cx, expr, recv, radix, &self.msrv),
@@ -4742,15 +5277,21 @@ impl Methods {
                 ("is_some", []) => check_is_some_is_none(cx, expr, recv, call_span, true),
                 ("iter" | "iter_mut" | "into_iter", []) => {
                     iter_on_single_or_empty_collections::check(cx, expr, name, recv);
+                    if name == "into_iter"
+                        && let Some(("clone", recv2, [], _, _)) = method_call(recv)
+                    {
+                        unnecessary_clone_into_iter::check(cx, expr, recv, recv2, &self.msrv);
+                    }
                 },
                 ("join", [join_arg]) => {
                     if let Some(("collect", _, _, span, _)) = method_call(recv) {
-                        unnecessary_join::check(cx, expr, recv, join_arg, span);
+                        unnecessary_join::check(cx, expr, recv, Some(join_arg), span);
                     } else {
                         join_absolute_paths::check(cx, recv, join_arg, expr.span);
                     }
                 },
                 ("last", []) => {
+                    double_ended_iterator_last::check(cx, expr, recv, call_span);
                     if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
                         iter_overeager_cloned::check(
                             cx,
@@ -4762,6 +5303,7 @@ impl Methods {
                         );
                     }
                 },
+                ("len", []) => unnecessary_indexing::check(cx, expr, recv, &self.msrv),
                 ("lock", []) => {
                     mut_mutex_lock::check(cx, expr, recv, span);
                 },
@@ -4806,10 +5348,15 @@ impl Methods {
                     option_map_or_none::check(cx, expr, recv, def, map);
                     manual_ok_or::check(cx, expr, recv, def, map);
                     option_map_or_err_ok::check(cx, expr, recv, def, map);
+                    unnecessary_map_or::check(cx, expr, recv, def, map, span, &self.msrv);
                 },
                 ("map_or_else", [def, map]) => {
                     result_map_or_else_none::check(cx, expr, recv, def, map);
                     unnecessary_result_map_or_else::check(cx, expr, recv, def, map);
+                    unwrap_or_else_panic::check(cx, &self.format_args, expr, recv, name, def, Some(map));
+                },
+                ("max_by" | "min_by", [arg]) => {
+                    unnecessary_sort_by::check_min_max(cx, expr, recv, arg, &name[..3]);
                 },
                 ("next", []) => {
                     if let Some((name2, recv2, args2, _, _)) = method_call(recv) {
@@ -4857,6 +5404,7 @@ impl Methods {
                     if !bind_instead_of_map::ResultOrElseErrInfo::check(cx, expr, recv, arg) {
                         unnecessary_lazy_eval::check(cx, expr, recv, arg, "or");
                     }
+                    unwrap_or_else_panic::check(cx, &self.format_args, expr, recv, name, arg, None);
                 },
                 ("push", [arg]) => {
                     path_buf_push_overwrite::check(cx, expr, arg);
@@ -4921,6 +5469,10 @@ impl Methods {
                 },
                 ("split", [arg]) => {
                     str_split::check(cx, expr, recv, arg);
+                    split_newline::check(cx, name, expr, recv, arg, call_span);
+                },
+                ("rsplit" | "split_terminator", [arg]) => {
+                    split_newline::check(cx, name, expr, recv, arg, call_span);
                 },
                 ("splitn" | "rsplitn", [count_arg, pat_arg]) => {
                     if let Some(Constant::Int(count)) = constant(cx, cx.typeck_results(), count_arg) {
@@ -4936,6 +5488,7 @@ impl Methods {
                 ("step_by", [arg]) => iterator_step_by_zero::check(cx, expr, arg),
                 ("take", [arg]) => {
                     iter_out_of_bounds::check_take(cx, expr, recv, arg);
+                    manual_repeat_n::check(cx, expr, recv, arg, &self.msrv);
                     if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
                         iter_overeager_cloned::check(
                             cx,
@@ -5035,6 +5588,7 @@ impl Methods {
                         },
                     }
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+                    unwrap_or_else_panic::check(cx, &self.format_args, expr, recv, name, u_arg, None);
                 },
                 ("wake", []) => {
                     waker_clone_wake::check(cx, expr, recv);
@@ -5057,9 +5611,14 @@ impl Methods {
 
 fn check_is_some_is_none(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, call_span: Span, is_some: bool) {
     match method_call(recv) {
-        Some((name @ ("find" | "position" | "rposition"), f_recv, [arg], span, _)) => {
+        Some((name @ ("find" | "find_map" | "position" | "rposition"), f_recv, [arg], span, _)) => {
             search_is_some::check(cx, expr, name, is_some, f_recv, arg, recv, span);
         },
+        Some(("next", next_recv, [], _, _))
+            if let Some(("filter", f_recv, [arg], span, _)) = method_call(next_recv) =>
+        {
+            search_is_some::check(cx, expr, "filter", is_some, f_recv, arg, recv, span);
+        },
         Some(("get", f_recv, [arg], _, _)) => {
             unnecessary_get_then_check::check(cx, call_span, recv, f_recv, arg, is_some);
         },
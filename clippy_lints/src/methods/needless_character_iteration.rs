@@ -34,7 +34,7 @@ fn handle_expr(
                 && method.ident.name.as_str() == "is_ascii"
                 && path_to_local_id(receiver, first_param)
                 && let char_arg_ty = cx.typeck_results().expr_ty_adjusted(receiver).peel_refs()
-                && *char_arg_ty.kind() == ty::Char
+                && matches!(char_arg_ty.kind(), ty::Char | ty::Uint(ty::UintTy::U8))
                 && let Some(snippet) = snippet_opt(cx, before_chars)
             {
                 span_lint_and_sugg(
@@ -77,7 +77,8 @@ fn handle_expr(
             if revert != is_all
                 && let ExprKind::Path(path) = fn_path.kind
                 && let Some(fn_def_id) = cx.qpath_res(&path, fn_path.hir_id).opt_def_id()
-                && match_def_path(cx, fn_def_id, &["core", "char", "methods", "<impl char>", "is_ascii"])
+                && (match_def_path(cx, fn_def_id, &["core", "char", "methods", "<impl char>", "is_ascii"])
+                    || match_def_path(cx, fn_def_id, &["core", "num", "<impl u8>", "is_ascii"]))
                 && path_to_local_id(peels_expr_ref(arg), first_param)
                 && let Some(snippet) = snippet_opt(cx, before_chars)
             {
@@ -101,7 +102,7 @@ pub(super) fn check(cx: &LateContext<'_>, call_expr: &Expr<'_>, recv: &Expr<'_>,
         && let body = cx.tcx.hir().body(body)
         && let Some(first_param) = body.params.first()
         && let ExprKind::MethodCall(method, mut recv, [], _) = recv.kind
-        && method.ident.name.as_str() == "chars"
+        && matches!(method.ident.name.as_str(), "chars" | "bytes")
         && let str_ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs()
         && *str_ty.kind() == ty::Str
     {
@@ -3,9 +3,10 @@ use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
 use clippy_utils::source::{snippet, snippet_with_applicability};
 use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::{is_type_diagnostic_item, make_normalized_projection, make_projection};
+use clippy_utils::visitors::{for_each_expr, is_local_used};
 use clippy_utils::{
-    can_move_expr_to_closure, fn_def_id, get_enclosing_block, higher, is_trait_method, path_to_local, path_to_local_id,
-    CaptureKind,
+    can_move_expr_to_closure, capture_local_usage, fn_def_id, get_enclosing_block, higher, is_trait_method,
+    path_to_local, path_to_local_id, CaptureKind,
 };
 use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::{Applicability, MultiSpan};
@@ -17,7 +18,8 @@ use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter;
 use rustc_middle::ty::{self, AssocKind, ClauseKind, EarlyBinder, GenericArg, GenericArgKind, Ty};
 use rustc_span::symbol::Ident;
-use rustc_span::{sym, Span};
+use rustc_span::{sym, DesugaringKind, Span};
+use std::ops::ControlFlow;
 
 const NEEDLESS_COLLECT_MSG: &str = "avoid using `collect()` when not needed";
 
@@ -29,6 +31,8 @@ pub(super) fn check<'tcx>(
     call_span: Span,
 ) {
     match cx.tcx.parent_hir_node(collect_expr.hir_id) {
+        // `for` loop heads are checked by the `loops` module.
+        Node::Expr(parent) if parent.span.is_desugaring(DesugaringKind::ForLoop) => {},
         Node::Expr(parent) => {
             check_collect_into_intoiterator(cx, parent, collect_expr, call_span, iter_expr);
 
@@ -88,13 +92,20 @@ pub(super) fn check<'tcx>(
         Node::LetStmt(l) => {
             if let PatKind::Binding(BindingMode::NONE | BindingMode::MUT, id, _, None) = l.pat.kind
                 && let ty = cx.typeck_results().expr_ty(collect_expr)
-                && [sym::Vec, sym::VecDeque, sym::BinaryHeap, sym::LinkedList]
+                && let is_set = [sym::HashSet, sym::BTreeSet]
                     .into_iter()
                     .any(|item| is_type_diagnostic_item(cx, ty, item))
+                && (is_set
+                    || [sym::Vec, sym::VecDeque, sym::BinaryHeap, sym::LinkedList]
+                        .into_iter()
+                        .any(|item| is_type_diagnostic_item(cx, ty, item)))
                 && let iter_ty = cx.typeck_results().expr_ty(iter_expr)
                 && let Some(block) = get_enclosing_block(cx, l.hir_id)
                 && let Some(iter_calls) = detect_iter_and_into_iters(block, id, cx, get_captured_ids(cx, iter_ty))
                 && let [iter_call] = &*iter_calls
+                // Sets remove duplicates, so only uses that don't care about them can take the iterator.
+                && (!is_set || matches!(iter_call.func, IterFunctionKind::IsEmpty | IterFunctionKind::Contains(_)))
+                && !is_iter_source_used_between(cx, block, iter_expr, l.span, iter_call.span)
             {
                 let mut used_count_visitor = UsedCountVisitor { cx, id, count: 0 };
                 walk_block(&mut used_count_visitor, block);
@@ -136,14 +147,29 @@ fn check_collect_into_intoiterator<'tcx>(
     call_span: Span,
     iter_expr: &'tcx Expr<'tcx>,
 ) {
+    if is_into_iterator_arg(cx, parent, collect_expr) {
+        span_lint_and_sugg(
+            cx,
+            NEEDLESS_COLLECT,
+            call_span.with_lo(iter_expr.span.hi()),
+            NEEDLESS_COLLECT_MSG,
+            "remove this call",
+            String::new(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Checks if `arg` is passed to a (generic) method or function argument taking an `IntoIterator`
+fn is_into_iterator_arg<'tcx>(cx: &LateContext<'tcx>, parent: &'tcx Expr<'tcx>, arg: &Expr<'_>) -> bool {
     if let Some(id) = fn_def_id(cx, parent) {
         let args = match parent.kind {
             ExprKind::Call(_, args) | ExprKind::MethodCall(_, _, args, _) => args,
             _ => &[],
         };
-        // find the argument index of the `collect_expr` in the
+        // find the argument index of `arg` in the
         // function / method call
-        if let Some(arg_idx) = args.iter().position(|e| e.hir_id == collect_expr.hir_id).map(|i| {
+        if let Some(arg_idx) = args.iter().position(|e| e.hir_id == arg.hir_id).map(|i| {
             if matches!(parent.kind, ExprKind::MethodCall(_, _, _, _)) {
                 i + 1
             } else {
@@ -151,7 +177,7 @@ fn check_collect_into_intoiterator<'tcx>(
             }
         }) {
             // extract the input types of the function/method call
-            // that contains `collect_expr`
+            // that contains `arg`
             let inputs = cx
                 .tcx
                 .liberate_late_bound_regions(id, cx.tcx.fn_sig(id).instantiate_identity())
@@ -160,7 +186,7 @@ fn check_collect_into_intoiterator<'tcx>(
             // map IntoIterator generic bounds to their signature
             // types and check whether the argument type is an
             // `IntoIterator`
-            if cx
+            return cx
                 .tcx
                 .param_env(id)
                 .caller_bounds()
@@ -174,20 +200,10 @@ fn check_collect_into_intoiterator<'tcx>(
                         None
                     }
                 })
-                .any(|ty| ty == inputs[arg_idx])
-            {
-                span_lint_and_sugg(
-                    cx,
-                    NEEDLESS_COLLECT,
-                    call_span.with_lo(iter_expr.span.hi()),
-                    NEEDLESS_COLLECT_MSG,
-                    "remove this call",
-                    String::new(),
-                    Applicability::MachineApplicable,
-                );
-            }
+                .any(|ty| ty == inputs[arg_idx]);
         }
     }
+    false
 }
 
 /// Checks if the given method call matches the expected signature of `([&[mut]] self) -> bool`
@@ -301,12 +317,15 @@ struct IterFunctionVisitor<'a, 'tcx> {
 impl<'tcx> Visitor<'tcx> for IterFunctionVisitor<'_, 'tcx> {
     fn visit_block(&mut self, block: &'tcx Block<'tcx>) {
         for (expr, hir_id) in block.stmts.iter().filter_map(get_expr_and_hir_id_from_stmt) {
-            if check_loop_kind(expr).is_some() {
+            if self.visit_for_loop_over_target(expr) || check_loop_kind(expr).is_some() {
                 continue;
             }
             self.visit_block_expr(expr, hir_id);
         }
         if let Some(expr) = block.expr {
+            if self.visit_for_loop_over_target(expr) {
+                return;
+            }
             if let Some(loop_kind) = check_loop_kind(expr) {
                 if let LoopKind::Conditional(block_expr) = loop_kind {
                     self.visit_block_expr(block_expr, None);
@@ -318,6 +337,23 @@ impl<'tcx> Visitor<'tcx> for IterFunctionVisitor<'_, 'tcx> {
     }
 
     fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        // Check if our collection is passed on as an `IntoIterator`, e.g. `other.extend(collection)`
+        if let ExprKind::Call(_, args) | ExprKind::MethodCall(_, _, args, _) = expr.kind
+            && let Some(arg) = args.iter().find(|arg| path_to_local_id(arg, self.target))
+            && is_into_iterator_arg(self.cx, expr, arg)
+        {
+            self.push_into_iter_use(arg, expr);
+            match expr.kind {
+                ExprKind::Call(callee, _) => self.visit_expr(callee),
+                ExprKind::MethodCall(_, recv, _, _) => self.visit_expr(recv),
+                _ => {},
+            }
+            for other in args.iter().filter(|other| other.hir_id != arg.hir_id) {
+                self.visit_expr(other);
+            }
+            return;
+        }
+
         // Check function calls on our collection
         if let ExprKind::MethodCall(method_name, recv, args, _) = &expr.kind {
             if method_name.ident.name == sym!(collect) && is_trait_method(self.cx, expr, sym::Iterator) {
@@ -418,6 +454,32 @@ impl<'tcx> IterFunctionVisitor<'_, 'tcx> {
         self.current_mutably_captured_ids = get_captured_ids(self.cx, self.cx.typeck_results().expr_ty(expr));
         self.visit_expr(expr);
     }
+
+    /// Records `for x in collection { .. }` as a use of our collection as an iterator, returns
+    /// true if `expr` is such a loop.
+    fn visit_for_loop_over_target(&mut self, expr: &'tcx Expr<'tcx>) -> bool {
+        if let Some(higher::ForLoop { arg, body, .. }) = higher::ForLoop::hir(expr)
+            && path_to_local_id(arg, self.target)
+        {
+            self.push_into_iter_use(arg, body);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Records `target` being consumed as an iterator, unless `user` touches something the
+    /// iterator mutably captures, as the iterator would still be alive there.
+    fn push_into_iter_use(&mut self, target: &Expr<'_>, user: &'tcx Expr<'tcx>) {
+        let conflicts = self
+            .illegal_mutable_capture_ids
+            .iter()
+            .any(|&id| is_local_used(self.cx, user, id));
+        self.uses.push((!conflicts).then_some(IterFunction {
+            func: IterFunctionKind::IntoIter,
+            span: target.span,
+        }));
+    }
 }
 
 fn get_expr_and_hir_id_from_stmt<'v>(stmt: &'v Stmt<'v>) -> Option<(&'v Expr<'v>, Option<HirId>)> {
@@ -434,6 +496,38 @@ fn get_expr_and_hir_id_from_stmt<'v>(stmt: &'v Stmt<'v>) -> Option<(&'v Expr<'v>
     }
 }
 
+/// Checks if a local the iterator refers to is mutated or moved after the `let` statement and
+/// before the iterator would be used instead of the collection. Moving the iterator there would
+/// keep the local borrowed for longer.
+fn is_iter_source_used_between<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    iter_expr: &'tcx Expr<'tcx>,
+    let_span: Span,
+    use_span: Span,
+) -> bool {
+    let mut locals = HirIdSet::default();
+    for_each_expr(cx, iter_expr, |e| {
+        if let Some(id) = path_to_local(e) {
+            locals.insert(id);
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    for_each_expr(cx, block, |e| {
+        if let Some(id) = path_to_local(e)
+            && locals.contains(&id)
+            && let_span.hi() <= e.span.lo()
+            && e.span.hi() <= use_span.lo()
+            && capture_local_usage(cx, e) != CaptureKind::Ref(Mutability::Not)
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
 struct UsedCountVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     id: HirId,
@@ -68,8 +68,10 @@ fn get_open_options(
         let obj_ty = cx.typeck_results().expr_ty(receiver).peel_refs();
 
         // Only proceed if this is a call on some object of type std::fs::OpenOptions
-        if !arguments.is_empty() && is_open_options(cx, obj_ty) {
-            let argument_option = match arguments[0].kind {
+        if let Some(first_argument) = arguments.first()
+            && is_open_options(cx, obj_ty)
+        {
+            let argument_option = match first_argument.kind {
                 ExprKind::Lit(span) => {
                     if let Spanned {
                         node: LitKind::Bool(lit),
@@ -13,7 +13,7 @@ use rustc_span::{sym, Span};
 
 use super::MAP_UNWRAP_OR;
 
-/// lint use of `map().unwrap_or()` for `Option`s
+/// lint use of `map().unwrap_or()` for `Option`s and `Result`s
 #[expect(clippy::too_many_arguments)]
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -25,8 +25,16 @@ pub(super) fn check<'tcx>(
     map_span: Span,
     msrv: &Msrv,
 ) {
-    // lint if the caller of `map()` is an `Option`
-    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Option) {
+    // lint if the caller of `map()` is an `Option` or a `Result`
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let is_option = is_type_diagnostic_item(cx, recv_ty, sym::Option);
+    let is_result = is_type_diagnostic_item(cx, recv_ty, sym::Result);
+
+    if is_result && !msrv.meets(msrvs::RESULT_MAP_OR_ELSE) {
+        return;
+    }
+
+    if is_option || is_result {
         if !is_copy(cx, cx.typeck_results().expr_ty(unwrap_arg)) {
             // Replacing `.map(<f>).unwrap_or(<a>)` with `.map_or(<a>, <f>)` can sometimes lead to
             // borrowck errors, see #10579 for one such instance.
@@ -75,29 +83,50 @@ pub(super) fn check<'tcx>(
         let suggest_is_some_and = msrv.meets(msrvs::OPTION_RESULT_IS_VARIANT_AND)
             && matches!(&unwrap_arg.kind, ExprKind::Lit(lit)
             if matches!(lit.node, rustc_ast::LitKind::Bool(false)));
+        let is_some_and = if is_option { "is_some_and" } else { "is_ok_and" };
+        // `unwrap_or` argument is true; suggest `is_none_or` instead, but only with an explicit MSRV
+        // as it isn't stable on all toolchains yet
+        let suggest_is_none_or = is_option
+            && msrv.meets_explicitly(msrvs::IS_NONE_OR)
+            && matches!(&unwrap_arg.kind, ExprKind::Lit(lit)
+            if matches!(lit.node, rustc_ast::LitKind::Bool(true)));
 
-        let mut applicability = Applicability::MachineApplicable;
+        // Moving the default in front of the closure is only certain to be fine for simple expressions,
+        // anything else might have side effects or borrow something used by the closure.
+        let mut applicability = if matches!(unwrap_arg.kind, ExprKind::Lit(_) | ExprKind::Path(_)) {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
         // get snippet for unwrap_or()
         let unwrap_snippet = snippet_with_applicability(cx, unwrap_arg.span, "..", &mut applicability);
         // lint message
         // comparing the snippet from source to raw text ("None") below is safe
         // because we already have checked the type.
-        let arg = if unwrap_snippet == "None" {
+        let unwrap_snippet_none = is_option && unwrap_snippet == "None";
+        let arg = if unwrap_snippet_none {
             "None"
         } else if suggest_is_some_and {
             "false"
+        } else if suggest_is_none_or {
+            "true"
         } else {
             "<a>"
         };
-        let unwrap_snippet_none = unwrap_snippet == "None";
         let suggest = if unwrap_snippet_none {
-            "and_then(<f>)"
+            "and_then(<f>)".to_string()
         } else if suggest_is_some_and {
-            "is_some_and(<f>)"
+            format!("{is_some_and}(<f>)")
+        } else if suggest_is_none_or {
+            "is_none_or(<f>)".to_string()
+        } else {
+            "map_or(<a>, <f>)".to_string()
+        };
+        let msg = if is_option {
+            format!("called `map(<f>).unwrap_or({arg})` on an `Option` value")
         } else {
-            "map_or(<a>, <f>)"
+            format!("called `map(<f>).unwrap_or({arg})` on a `Result` value")
         };
-        let msg = format!("called `map(<f>).unwrap_or({arg})` on an `Option` value");
 
         span_lint_and_then(cx, MAP_UNWRAP_OR, expr.span, msg, |diag| {
             let map_arg_span = map_arg.span;
@@ -108,7 +137,9 @@ pub(super) fn check<'tcx>(
                     String::from(if unwrap_snippet_none {
                         "and_then"
                     } else if suggest_is_some_and {
-                        "is_some_and"
+                        is_some_and
+                    } else if suggest_is_none_or {
+                        "is_none_or"
                     } else {
                         "map_or"
                     }),
@@ -116,7 +147,7 @@ pub(super) fn check<'tcx>(
                 (expr.span.with_lo(unwrap_recv.span.hi()), String::new()),
             ];
 
-            if !unwrap_snippet_none && !suggest_is_some_and {
+            if !unwrap_snippet_none && !suggest_is_some_and && !suggest_is_none_or {
                 suggestion.push((map_arg_span.with_hi(map_arg_span.lo()), format!("{unwrap_snippet}, ")));
             }
 
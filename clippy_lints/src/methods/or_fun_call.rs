@@ -1,9 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::eager_or_lazy::switch_to_lazy_eval;
 use clippy_utils::source::snippet_with_context;
-use clippy_utils::ty::{expr_type_is_certain, implements_trait, is_type_diagnostic_item};
-use clippy_utils::{contains_return, is_default_equivalent, is_default_equivalent_call, last_path_segment};
+use clippy_utils::ty::{expr_type_is_certain, implements_trait, is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::{
+    contains_return, is_default_equivalent, is_default_equivalent_call, is_integer_const, last_path_segment,
+    peel_blocks,
+};
 use rustc_errors::Applicability;
+use rustc_hir::LangItem;
 use rustc_lint::LateContext;
 use rustc_middle::ty;
 use rustc_span::symbol::{self, sym, Symbol};
@@ -33,7 +37,7 @@ pub(super) fn check<'tcx>(
         receiver: &hir::Expr<'_>,
         fun: &hir::Expr<'_>,
         call_expr: Option<&hir::Expr<'_>>,
-        span: Span,
+        expr: &hir::Expr<'_>,
         method_span: Span,
     ) -> bool {
         if !expr_type_is_certain(cx, receiver) {
@@ -85,18 +89,34 @@ pub(super) fn check<'tcx>(
             return false;
         }
 
+        // The value itself has to implement `Default`, the argument may only be coerced to it, like a
+        // `Box<Vec<u8>>` inserted into a map of `Box<dyn Debug>`
+        let output_ty = cx.typeck_results().expr_ty(expr);
+        let value_ty = match *output_ty.kind() {
+            // `or_insert` and `or_insert_with` return a reference to the value
+            ty::Ref(_, value_ty, _) if sugg == sym!(or_default) => value_ty,
+            _ => output_ty,
+        };
+        if !cx
+            .tcx
+            .get_diagnostic_item(sym::Default)
+            .is_some_and(|default_trait_id| implements_trait(cx, value_ty, default_trait_id, &[]))
+        {
+            return false;
+        }
+
         // needs to target Default::default in particular or be *::new and have a Default impl
         // available
         if (is_new(fun) && output_type_implements_default(fun))
             || match call_expr {
-                Some(call_expr) => is_default_equivalent(cx, call_expr),
-                None => is_default_equivalent_call(cx, fun) || closure_body_returns_empty_to_string(cx, fun),
+                Some(call_expr) => is_default_equivalent(cx, call_expr) || is_empty_constructor_call(cx, call_expr),
+                None => is_default_equivalent_call(cx, fun) || closure_body_returns_default(cx, fun),
             }
         {
             span_lint_and_sugg(
                 cx,
                 UNWRAP_OR_DEFAULT,
-                method_span.with_hi(span.hi()),
+                method_span.with_hi(expr.span.hi()),
                 format!("use of `{name}` to construct default value"),
                 "try",
                 format!("{sugg}()"),
@@ -128,7 +148,7 @@ pub(super) fn check<'tcx>(
             (sym::BTreeEntry, false, &["or_insert"], "with"),
             (sym::HashMapEntry, false, &["or_insert"], "with"),
             (sym::Option, false, &["map_or", "ok_or", "or", "unwrap_or"], "else"),
-            (sym::Result, true, &["or", "unwrap_or"], "else"),
+            (sym::Result, true, &["map_or", "or", "unwrap_or"], "else"),
         ];
 
         if KNOW_TYPES.iter().any(|k| k.2.contains(&name))
@@ -196,17 +216,22 @@ pub(super) fn check<'tcx>(
         match inner_arg.kind {
             hir::ExprKind::Call(fun, or_args) => {
                 let or_has_args = !or_args.is_empty();
-                if or_has_args
-                    || !check_unwrap_or_default(cx, name, receiver, fun, Some(inner_arg), expr.span, method_span)
+                if (or_has_args && !is_empty_constructor_call(cx, inner_arg))
+                    || !check_unwrap_or_default(cx, name, receiver, fun, Some(inner_arg), expr, method_span)
                 {
                     let fun_span = if or_has_args { None } else { Some(fun.span) };
                     check_general_case(cx, name, method_span, receiver, arg, None, expr.span, fun_span);
                 }
             },
             hir::ExprKind::Path(..) | hir::ExprKind::Closure(..) => {
-                check_unwrap_or_default(cx, name, receiver, inner_arg, None, expr.span, method_span);
+                check_unwrap_or_default(cx, name, receiver, inner_arg, None, expr, method_span);
+            },
+            hir::ExprKind::MethodCall(..) => {
+                if !check_unwrap_or_default(cx, name, receiver, inner_arg, Some(inner_arg), expr, method_span) {
+                    check_general_case(cx, name, method_span, receiver, arg, None, expr.span, None);
+                }
             },
-            hir::ExprKind::Index(..) | hir::ExprKind::MethodCall(..) => {
+            hir::ExprKind::Index(..) => {
                 check_general_case(cx, name, method_span, receiver, arg, None, expr.span, None);
             },
             _ => (),
@@ -223,21 +248,56 @@ pub(super) fn check<'tcx>(
     }
 }
 
-fn closure_body_returns_empty_to_string(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> bool {
-    if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = e.kind {
-        let body = cx.tcx.hir().body(body);
-
-        if body.params.is_empty()
-            && let hir::Expr { kind, .. } = &body.value
-            && let hir::ExprKind::MethodCall(hir::PathSegment { ident, .. }, self_arg, _, _) = kind
-            && ident.name == sym::to_string
-            && let hir::Expr { kind, .. } = self_arg
-            && let hir::ExprKind::Lit(lit) = kind
-            && let ast::LitKind::Str(symbol::kw::Empty, _) = lit.node
-        {
-            return true;
-        }
+/// Checks for calls creating an empty value without allocating, like `Vec::with_capacity(0)` or
+/// `"".to_string()`.
+fn is_empty_constructor_call(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> bool {
+    match e.kind {
+        hir::ExprKind::Call(fun, [arg]) => {
+            if let hir::ExprKind::Path(ref qpath) = fun.kind
+                && last_path_segment(qpath).ident.name == sym!(with_capacity)
+                && is_integer_const(cx, arg, 0)
+                && let Some(default_trait_id) = cx.tcx.get_diagnostic_item(sym::Default)
+            {
+                implements_trait(cx, cx.typeck_results().expr_ty(e), default_trait_id, &[])
+            } else {
+                false
+            }
+        },
+        hir::ExprKind::MethodCall(path, recv, [], _) => {
+            if matches!(path.ident.as_str(), "to_owned" | "to_string")
+                && let hir::ExprKind::Lit(lit) = recv.kind
+                && let ast::LitKind::Str(symbol::kw::Empty, _) = lit.node
+            {
+                is_type_lang_item(cx, cx.typeck_results().expr_ty(e), LangItem::String)
+            } else {
+                false
+            }
+        },
+        _ => false,
     }
+}
 
-    false
+/// Checks for closures without parameters which only construct a default value, like
+/// `|| Vec::new()`, `|| Default::default()` or `|| "".to_string()`.
+fn closure_body_returns_default(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> bool {
+    if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = e.kind
+        && let body = cx.tcx.hir().body(body)
+        && body.params.is_empty()
+    {
+        let value = peel_blocks(body.value);
+        match value.kind {
+            hir::ExprKind::Call(fun, []) => {
+                let is_new = matches!(fun.kind, hir::ExprKind::Path(ref qpath)
+                    if last_path_segment(qpath).ident.name == sym::new);
+                is_default_equivalent_call(cx, fun)
+                    || is_new
+                        && cx.tcx.get_diagnostic_item(sym::Default).is_some_and(|default_trait_id| {
+                            implements_trait(cx, cx.typeck_results().expr_ty(value), default_trait_id, &[])
+                        })
+            },
+            _ => is_empty_constructor_call(cx, value),
+        }
+    } else {
+        false
+    }
 }
@@ -4,7 +4,7 @@ use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::for_each_local_use_after_expr;
-use clippy_utils::{get_parent_expr, match_def_path};
+use clippy_utils::{get_parent_expr, match_def_path, match_trait_method, paths};
 use rustc_ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
@@ -32,12 +32,16 @@ fn parse_fails_on_trailing_newline(ty: Ty<'_>) -> bool {
 }
 
 pub fn check(cx: &LateContext<'_>, call: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) {
-    if let Some(recv_adt) = cx.typeck_results().expr_ty(recv).ty_adt_def()
-        && match_def_path(cx, recv_adt.did(), &["std", "io", "stdio", "Stdin"])
+    if (cx
+        .typeck_results()
+        .expr_ty(recv)
+        .ty_adt_def()
+        .is_some_and(|recv_adt| match_def_path(cx, recv_adt.did(), &paths::STD_IO_STDIN))
+        || match_trait_method(cx, call, &paths::STD_IO_BUF_READ))
         && let ExprKind::Path(QPath::Resolved(_, path)) = arg.peel_borrows().kind
         && let Res::Local(local_id) = path.res
     {
-        // We've checked that `call` is a call to `Stdin::read_line()` with the right receiver,
+        // We've checked that `call` is a call to `Stdin::read_line()` or `BufRead::read_line()`,
         // now let's check if the first use of the string passed to `::read_line()`
         // is used for operations that will always fail (e.g. parsing "6\n" into a number)
         for_each_local_use_after_expr(cx, local_id, call.hir_id, |expr| {
@@ -55,7 +59,7 @@ pub fn check(cx: &LateContext<'_>, call: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<
                         Some((
                             span,
                             "calling `.parse()` on a string without trimming the trailing newline character",
-                            "checking",
+                            "parsing",
                         ))
                     } else if segment.ident.name == sym!(ends_with)
                         && recv.span == expr.span
@@ -67,7 +71,7 @@ pub fn check(cx: &LateContext<'_>, call: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<
                         Some((
                             parent.span,
                             "checking the end of a string without trimming the trailing newline character",
-                            "parsing",
+                            "check",
                         ))
                     } else {
                         None
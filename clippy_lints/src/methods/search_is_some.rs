@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
 use clippy_utils::source::{snippet, snippet_with_applicability};
-use clippy_utils::sugg::deref_closure_args;
+use clippy_utils::sugg::{deref_closure_args, Sugg};
 use clippy_utils::ty::is_type_lang_item;
 use clippy_utils::{is_receiver_of_method_call, is_trait_method, strip_pat_refs};
 use hir::ExprKind;
@@ -15,6 +15,9 @@ use super::SEARCH_IS_SOME;
 
 /// lint searching an Iterator followed by `is_some()`
 /// or calling `find()` on a string followed by `is_some()` or `is_none()`
+///
+/// `search_method` is `filter` for `filter(..).next()`, in which case `is_some_recv` is the call to
+/// `next`
 #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 pub(super) fn check<'tcx>(
     cx: &LateContext<'_>,
@@ -29,13 +32,20 @@ pub(super) fn check<'tcx>(
     let option_check_method = if is_some { "is_some" } else { "is_none" };
     // lint if caller of search is an Iterator
     if is_trait_method(cx, is_some_recv, sym::Iterator) {
-        let msg = format!("called `{option_check_method}()` after searching an `Iterator` with `{search_method}`");
+        let search = if search_method == "filter" {
+            "filter(..).next()"
+        } else {
+            search_method
+        };
+        let msg = format!("called `{option_check_method}()` after searching an `Iterator` with `{search}`");
         let search_snippet = snippet(cx, search_arg.span, "..");
-        if search_snippet.lines().count() <= 1 {
+        if search_snippet.lines().nth(1).is_none() {
             // suggest `any(|x| ..)` instead of `any(|&x| ..)` for `find(|&x| ..).is_some()`
             // suggest `any(|..| *..)` instead of `any(|..| **..)` for `find(|..| **..).is_some()`
             let mut applicability = Applicability::MachineApplicable;
-            let any_search_snippet = if search_method == "find"
+            let any_search_snippet = if search_method == "find_map" {
+                Some(find_map_predicate(cx, search_arg, &mut applicability))
+            } else if matches!(search_method, "find" | "filter")
                 && let ExprKind::Closure(&hir::Closure { body, .. }) = search_arg.kind
                 && let closure_body = cx.tcx.hir().body(body)
                 && let Some(closure_arg) = closure_body.params.first()
@@ -156,3 +166,20 @@ pub(super) fn check<'tcx>(
         }
     }
 }
+
+/// Turns the function passed to `find_map` into a predicate for `any`, which checks whether the
+/// function returns `Some`
+fn find_map_predicate(cx: &LateContext<'_>, f: &hir::Expr<'_>, applicability: &mut Applicability) -> String {
+    if let ExprKind::Closure(&hir::Closure { body, fn_decl, .. }) = f.kind
+        && let hir::FnRetTy::DefaultReturn(_) = fn_decl.output
+        && let closure_body = cx.tcx.hir().body(body)
+    {
+        // `|x| f(x)` becomes `|x| f(x).is_some()`
+        let params = snippet_with_applicability(cx, f.span.until(closure_body.value.span), "..", applicability);
+        let value = Sugg::hir_with_applicability(cx, closure_body.value, "..", applicability).maybe_par();
+        format!("{params}{value}.is_some()")
+    } else {
+        let f = Sugg::hir_with_applicability(cx, f, "..", applicability).maybe_par();
+        format!("|x| {f}(x).is_some()")
+    }
+}
@@ -0,0 +1,47 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{get_parent_expr, higher, is_path_diagnostic_item};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::SLICED_STRING_AS_BYTES;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>) {
+    if let ExprKind::Index(indexed, index, _) = recv.kind
+        && higher::Range::hir(index).is_some()
+        && let ty = cx.typeck_results().expr_ty(indexed).peel_refs()
+        && (ty.is_str() || is_type_lang_item(cx, ty, LangItem::String))
+    {
+        let parent = get_parent_expr(cx, expr);
+        // The bytes are turned back into a `str`, which needs the char boundary check anyway.
+        if let Some(parent) = parent
+            && let ExprKind::Call(func, _) = parent.kind
+            && (is_path_diagnostic_item(cx, func, sym::str_from_utf8)
+                || is_path_diagnostic_item(cx, func, sym::str_from_utf8_unchecked))
+        {
+            return;
+        }
+        // Method calls and indexing auto-borrow the byte slice, anything else needs a reference.
+        let needs_ref = !parent.is_some_and(|parent| match parent.kind {
+            ExprKind::MethodCall(_, receiver, ..) | ExprKind::Index(receiver, ..) => receiver.hir_id == expr.hir_id,
+            _ => false,
+        });
+
+        let mut applicability = Applicability::MaybeIncorrect;
+        let string = Sugg::hir_with_applicability(cx, indexed, "..", &mut applicability).maybe_par();
+        let range = snippet_with_applicability(cx, index.span, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            SLICED_STRING_AS_BYTES,
+            expr.span,
+            "calling `as_bytes` after slicing a string",
+            "try",
+            format!("{}{string}.as_bytes()[{range}]", if needs_ref { "&" } else { "" }),
+            applicability,
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+use super::SPLIT_NEWLINE;
+
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    name: &str,
+    expr: &Expr<'_>,
+    recv: &Expr<'_>,
+    pat: &Expr<'_>,
+    call_span: Span,
+) {
+    if cx.typeck_results().expr_ty_adjusted(recv).peel_refs().is_str()
+        // `str_split_at_newline` handles `.trim().split('\n')`
+        && !(name == "split"
+            && matches!(recv.kind, ExprKind::MethodCall(trim, _, [], _) if trim.ident.as_str() == "trim"))
+        && let ExprKind::Lit(lit) = pat.kind
+        && (matches!(lit.node, LitKind::Char('\n'))
+            || matches!(lit.node, LitKind::Str(sym, _) if sym.as_str() == "\n"))
+        && !call_span.from_expansion()
+    {
+        let sugg = match name {
+            "split" | "split_terminator" => "lines()",
+            "rsplit" => "lines().rev()",
+            _ => return,
+        };
+
+        span_lint_and_then(
+            cx,
+            SPLIT_NEWLINE,
+            expr.span,
+            format!("using `{name}` on a newline to iterate over the lines of a string"),
+            |diag| {
+                diag.note("`lines` also removes a trailing `\\r` from each line");
+                if name != "split_terminator" {
+                    diag.note("unlike `lines`, this yields an empty item if the string ends with a newline");
+                }
+                diag.span_suggestion(call_span, "use `lines` instead", sugg, Applicability::MaybeIncorrect);
+            },
+        );
+    }
+}
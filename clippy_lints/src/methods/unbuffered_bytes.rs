@@ -0,0 +1,40 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::{is_trait_method, paths};
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::UNBUFFERED_BYTES;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>) {
+    if is_trait_method(cx, expr, sym::IoRead) {
+        let recv_ty = cx.typeck_results().expr_ty(recv);
+        let ty = recv_ty.peel_refs();
+        if is_type_diagnostic_item(cx, ty, sym::File)
+            || match_type(cx, ty, &paths::STD_NET_TCP_STREAM)
+            || match_type(cx, ty, &paths::STD_OS_UNIX_NET_UNIX_STREAM)
+            || match_type(cx, ty, &paths::STD_IO_STDIN)
+        {
+            // The `BufReader` may read ahead, so the bytes it buffers would be missing from a
+            // reader that is still used afterwards
+            let mut applicability = if recv_ty.is_ref() {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            let reader = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                UNBUFFERED_BYTES,
+                expr.span,
+                "calling `bytes()` on an unbuffered reader reads a single byte at a time",
+                "wrap the reader in a `BufReader` first",
+                format!("std::io::BufReader::new({reader}).bytes()"),
+                applicability,
+            );
+        }
+    }
+}
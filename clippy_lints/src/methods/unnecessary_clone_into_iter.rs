@@ -0,0 +1,104 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_copy;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{is_trait_method, path_to_local};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId, Node, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+use super::UNNECESSARY_CLONE_INTO_ITER;
+
+/// Checks for `recv.into_iter()` where `recv` is `cloned.clone()`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    recv: &'tcx Expr<'tcx>,
+    cloned: &'tcx Expr<'tcx>,
+    msrv: &Msrv,
+) {
+    if !is_trait_method(cx, expr, sym::IntoIterator) || !is_trait_method(cx, recv, sym::Clone) {
+        return;
+    }
+    let ty = cx.typeck_results().expr_ty(recv);
+    let ty::Adt(adt, args) = ty.kind() else {
+        return;
+    };
+    let is_map = match cx.tcx.get_diagnostic_name(adt.did()) {
+        Some(sym::Vec | sym::VecDeque | sym::HashSet | sym::BTreeSet) => false,
+        Some(sym::HashMap | sym::BTreeMap) => true,
+        _ => return,
+    };
+
+    let mut applicability = Applicability::MaybeIncorrect;
+    let cloned_sugg = Sugg::hir_with_context(cx, cloned, expr.span.ctxt(), "..", &mut applicability).maybe_par();
+
+    // The clone can be moved from directly if it's an owned temporary, or a local which isn't
+    // used afterwards.
+    if cx.typeck_results().expr_ty(cloned) == ty
+        && match path_to_local(cloned) {
+            Some(local_id) => !is_used_after(cx, local_id, expr),
+            None => !matches!(
+                cloned.kind,
+                ExprKind::Path(_) | ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(UnOp::Deref, _)
+            ),
+        }
+    {
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_CLONE_INTO_ITER,
+            expr.span,
+            "unnecessary clone before calling `into_iter`",
+            "remove the `clone` call",
+            format!("{cloned_sugg}.into_iter()"),
+            applicability,
+        );
+        return;
+    }
+
+    let sugg = if is_map {
+        let (key, value) = (clone_expr(cx, args.type_at(0), "k"), clone_expr(cx, args.type_at(1), "v"));
+        format!("{cloned_sugg}.iter().map(|(k, v)| ({key}, {value}))")
+    } else if is_copy(cx, args.type_at(0)) && msrv.meets(msrvs::ITERATOR_COPIED) {
+        format!("{cloned_sugg}.iter().copied()")
+    } else {
+        format!("{cloned_sugg}.iter().cloned()")
+    };
+    span_lint_and_sugg(
+        cx,
+        UNNECESSARY_CLONE_INTO_ITER,
+        expr.span,
+        "unnecessary clone before calling `into_iter`",
+        "clone the elements lazily instead",
+        sugg,
+        applicability,
+    );
+}
+
+fn clone_expr(cx: &LateContext<'_>, ty: Ty<'_>, name: &str) -> String {
+    if is_copy(cx, ty) {
+        format!("*{name}")
+    } else {
+        format!("{name}.clone()")
+    }
+}
+
+/// Checks whether the local may be used again after `expr`, either later in its block or by
+/// another iteration of a loop or another call of a closure around `expr`.
+fn is_used_after(cx: &LateContext<'_>, local_id: HirId, expr: &Expr<'_>) -> bool {
+    let local_span = cx.tcx.hir().span(local_id);
+    for_each_local_use_after_expr(cx, local_id, expr.hir_id, |_| ControlFlow::Break(())).is_break()
+        || cx.tcx.hir().parent_iter(expr.hir_id).any(|(_, node)| {
+            if let Node::Expr(e) = node
+                && let ExprKind::Loop(..) | ExprKind::Closure(_) = e.kind
+            {
+                !e.span.contains(local_span)
+            } else {
+                false
+            }
+        })
+}
@@ -1,3 +1,4 @@
+use crate::manual_collect::{single_push, CollectionKind, Element};
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::{is_trait_method, path_to_local_id, peel_blocks, strip_pat_refs};
@@ -8,6 +9,7 @@ use rustc_hir as hir;
 use rustc_hir::PatKind;
 use rustc_lint::LateContext;
 use rustc_middle::ty;
+use rustc_span::symbol::kw;
 use rustc_span::{sym, Span};
 
 use super::UNNECESSARY_FOLD;
@@ -107,6 +109,95 @@ fn check_fold_with_op(
     }
 }
 
+/// Checks for `fold(C::new(), |mut acc, x| { acc.push(f(x)); acc })`, which builds the collection
+/// `C` the same way as `map(f).collect::<C>()`, but without the capacity hint of the iterator.
+/// Conditionally adding the element becomes a `filter_map`.
+fn check_fold_into_collection(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    init: &hir::Expr<'_>,
+    acc: &hir::Expr<'_>,
+    fold_span: Span,
+) {
+    if let hir::ExprKind::Call(ctor, []) = init.kind
+        && let hir::ExprKind::Path(hir::QPath::TypeRelative(init_ty, ctor_name)) = ctor.kind
+        && matches!(ctor_name.ident.name, sym::new | kw::Default)
+        && let Some(collection) = CollectionKind::new(cx, cx.typeck_results().expr_ty(init))
+        && let hir::ExprKind::Closure(&hir::Closure { body, .. }) = acc.kind
+        && let closure_body = cx.tcx.hir().body(body)
+        && let [param_acc, param_elem] = closure_body.params
+        && let PatKind::Binding(_, acc_id, ..) = param_acc.pat.kind
+        && let hir::ExprKind::Block(block, _) = closure_body.value.kind
+        && let [stmt] = block.stmts
+        && let Some(ret) = block.expr
+        && path_to_local_id(ret, acc_id)
+        && let hir::StmtKind::Semi(stmt_expr) | hir::StmtKind::Expr(stmt_expr) = stmt.kind
+        && !stmt_expr.span.from_expansion()
+        && let Some((cond, elem)) = single_push(cx, stmt_expr, acc_id, collection)
+    {
+        let (mut applicability, method) = if cond.is_some() {
+            (Applicability::MaybeIncorrect, "filter_map")
+        } else {
+            (Applicability::MachineApplicable, "map")
+        };
+        // A borrowed element may borrow from the closure's parameter, which doesn't outlive the `map`
+        let is_ref = |e: &hir::Expr<'_>| cx.typeck_results().expr_ty(e).is_ref();
+        let (elem_sugg, borrows) = match elem {
+            Element::Single(elem) => (
+                snippet_with_applicability(cx, elem.span, "..", &mut applicability).into_owned(),
+                is_ref(elem),
+            ),
+            Element::Pair(key, value) => (
+                format!(
+                    "({}, {})",
+                    snippet_with_applicability(cx, key.span, "..", &mut applicability),
+                    snippet_with_applicability(cx, value.span, "..", &mut applicability),
+                ),
+                is_ref(key) || is_ref(value),
+            ),
+        };
+        if borrows {
+            applicability = Applicability::MaybeIncorrect;
+        }
+        let pat = snippet_with_applicability(cx, param_elem.pat.span, "..", &mut applicability);
+        let adapter = match cond {
+            Some(cond) => format!(
+                "{method}(|{pat}| if {} {{ Some({elem_sugg}) }} else {{ None }}).",
+                snippet_with_applicability(cx, cond.span, "..", &mut applicability),
+            ),
+            // Adding the elements as they are doesn't need an adapter
+            None if let Element::Single(arg) = elem
+                && let PatKind::Binding(_, elem_id, ..) = param_elem.pat.kind
+                && path_to_local_id(arg, elem_id) =>
+            {
+                String::new()
+            },
+            None => format!("{method}(|{pat}| {elem_sugg})."),
+        };
+        let turbofish = if needs_turbofish(cx, expr) {
+            let mut ty = snippet_with_applicability(cx, init_ty.span, "..", &mut applicability).into_owned();
+            if let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = init_ty.kind
+                && path.segments.last().is_some_and(|segment| segment.args.is_none())
+            {
+                ty.push_str(collection.placeholders());
+            }
+            format!("::<{ty}>")
+        } else {
+            String::new()
+        };
+
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_FOLD,
+            fold_span.with_hi(expr.span.hi()),
+            "this `.fold` can be written more succinctly using another method",
+            "try",
+            format!("{adapter}collect{turbofish}()"),
+            applicability,
+        );
+    }
+}
+
 pub(super) fn check(
     cx: &LateContext<'_>,
     expr: &hir::Expr<'_>,
@@ -178,5 +269,7 @@ pub(super) fn check(
             },
             _ => (),
         }
+    } else {
+        check_fold_into_collection(cx, expr, init, acc, fold_span);
     }
 }
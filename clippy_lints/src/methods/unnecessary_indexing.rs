@@ -0,0 +1,1004 @@
+use core::ops::ControlFlow;
+
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_copy, is_type_diagnostic_item};
+use clippy_utils::visitors::{for_each_expr, for_each_expr_without_closures, Descend, Visitable};
+use clippy_utils::{eq_expr_value, get_parent_expr, higher, is_never_expr, path_to_local, path_to_local_id};
+use rustc_ast::LitKind;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_pat, walk_path, Visitor};
+use rustc_hir::{
+    Arm, BinOpKind, BorrowKind, Expr, ExprKind, HirId, MatchSource, Mutability, Node, Pat, PatKind, Path, QPath, Stmt,
+    StmtKind, UnOp,
+};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_span::{sym, Span, Symbol};
+
+use super::{UNNECESSARY_INDEXING, UNNECESSARY_INDEXING_IN_LOOP};
+
+/// Checks `seq.is_empty()`, or `seq.len()` compared against zero, used as the condition of an
+/// `if`, or as the scrutinee of a `match`, whose non-empty branch then accesses the first or last
+/// element of `seq` through indexing or `get`. An `if` without `else` whose body diverges guards
+/// the statements following it.
+///
+/// All the sequences checked in one condition chain are bound together, and the lint is only
+/// emitted for the first of them.
+///
+/// The condition of a `while` loop is checked by `UNNECESSARY_INDEXING_IN_LOOP` instead.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, receiver: &'tcx Expr<'tcx>, msrv: &Msrv) {
+    if in_external_macro(cx.sess(), expr.span)
+        || receiver_root_local(receiver).is_none()
+        || Sequence::of(cx, receiver).is_none()
+    {
+        return;
+    }
+    let Some(guard) = get_guard(cx, expr) else {
+        return;
+    };
+    if let Some(while_loop) = enclosing_while_loop(cx, &guard) {
+        check_while_loop(cx, receiver, &guard, &while_loop);
+        return;
+    }
+    // The `if` and its condition are rewritten, so they must be written by the user.
+    if !guard.expr.span.from_expansion()
+        && !guard.cond().span.from_expansion()
+        && let Some(guarded) = guarded_code(cx, &guard, msrv)
+        && let (bound, other_conditions) = bind_sequences(cx, &guard, guarded)
+        && let Some((first_bound, _)) = bound.first()
+        && first_bound.hir_id == receiver.hir_id
+    {
+        let mut app = Applicability::MachineApplicable;
+        let Some(sugg) = make_suggestion(cx, &guard, guarded, &bound, &other_conditions, &mut app) else {
+            return;
+        };
+        let (msg, help) = match (&guard.kind, guarded) {
+            (GuardKind::Match { .. }, _) => (
+                "this `match` can be simplified by matching on the element",
+                "consider matching on the element",
+            ),
+            (GuardKind::If(_), Guarded::Branch(_)) => (
+                "condition can be simplified with `if..let` syntax",
+                "consider using `if..let` syntax",
+            ),
+            (GuardKind::If(_), Guarded::Following(..)) => (
+                "condition can be simplified with `if..let` syntax",
+                "consider using `let..else` syntax",
+            ),
+        };
+        span_lint_and_then(cx, UNNECESSARY_INDEXING, guard.cond().span, msg, |diag| {
+            diag.multipart_suggestion(help, sugg, app);
+        });
+    }
+}
+
+/// Returns the `while` loop whose condition is the one of the guard.
+fn enclosing_while_loop<'tcx>(cx: &LateContext<'tcx>, guard: &Guard<'tcx>) -> Option<higher::While<'tcx>> {
+    let mut parents = cx.tcx.hir().parent_iter(guard.expr.hir_id).map(|(_, node)| node);
+    if let Some(Node::Block(_)) = parents.next()
+        && let Some(Node::Expr(loop_expr)) = parents.next()
+        && let Some(while_loop) = higher::While::hir(loop_expr)
+        && while_loop.condition.hir_id == guard.cond().hir_id
+    {
+        Some(while_loop)
+    } else {
+        None
+    }
+}
+
+/// Checks `while !seq.is_empty()` loops whose body accesses the first or last element of `seq`.
+/// The body may not mutate `seq`, except for removing the first element with `seq.remove(0)` or
+/// `seq.pop_front()` as its last statement.
+fn check_while_loop<'tcx>(
+    cx: &LateContext<'tcx>,
+    receiver: &'tcx Expr<'tcx>,
+    guard: &Guard<'tcx>,
+    while_loop: &higher::While<'tcx>,
+) {
+    if guard.if_is_empty || guard.operands.len() != 1 || while_loop.condition.span.from_expansion() {
+        return;
+    }
+    let ExprKind::Block(body, _) = while_loop.body.kind else {
+        return;
+    };
+    let (stmts, removal) = match body.stmts {
+        [stmts @ .., last] if body.expr.is_none() && is_front_removal(cx, receiver, last) => (stmts, Some(last)),
+        stmts => (stmts, None),
+    };
+    let Some(local_id) = receiver_root_local(receiver) else {
+        return;
+    };
+    let Some(uses) = collect_element_uses(cx, receiver, local_id, (stmts, body.expr)) else {
+        return;
+    };
+    let mut used_names = used_names(cx, (stmts, body.expr), &[]);
+    let name = binding_name(receiver, uses.element_name(), &mut used_names, false);
+    let mut app = Applicability::MachineApplicable;
+    let receiver_snip = snippet_with_applicability(cx, receiver.span, "..", &mut app);
+
+    if let Some(removal) = removal {
+        if uses.element != Element::First {
+            return;
+        }
+        span_lint_and_then(
+            cx,
+            UNNECESSARY_INDEXING_IN_LOOP,
+            while_loop.condition.span,
+            "the first element is indexed, then removed at the end of each iteration",
+            |diag| {
+                diag.span_note(removal.span, "the element is removed here");
+                match uses.sequence {
+                    Sequence::VecDeque => diag.help(format!(
+                        "consider taking the element with `while let Some({name}) = {receiver_snip}.pop_front()`"
+                    )),
+                    Sequence::Slice => diag.help(format!(
+                        "consider using a `VecDeque`, and taking the element with `while let Some({name}) = {receiver_snip}.pop_front()`"
+                    )),
+                };
+            },
+        );
+    } else {
+        if uses.has_macro_uses {
+            app = Applicability::MaybeIncorrect;
+        }
+        let mut sugg = vec![(
+            while_loop.condition.span,
+            format!("let Some({name}) = {receiver_snip}.{}()", uses.method_name()),
+        )];
+        sugg.extend(uses.spans_to_replace(cx, &name));
+        span_lint_and_then(
+            cx,
+            UNNECESSARY_INDEXING_IN_LOOP,
+            while_loop.condition.span,
+            "condition can be simplified with `while..let` syntax",
+            |diag| {
+                diag.multipart_suggestion("consider using `while..let` syntax", sugg, app);
+            },
+        );
+    }
+}
+
+/// Returns `true` if `stmt` is `receiver.remove(0);` or `receiver.pop_front();`.
+fn is_front_removal(cx: &LateContext<'_>, receiver: &Expr<'_>, stmt: &Stmt<'_>) -> bool {
+    if let StmtKind::Semi(e) = stmt.kind
+        && let ExprKind::MethodCall(path, recv, args, _) = e.kind
+        && eq_expr_value(cx, recv, receiver)
+    {
+        match (path.ident.name.as_str(), args) {
+            ("remove", [idx]) => constant_full_int(cx, cx.typeck_results(), idx) == Some(FullInt::U(0)),
+            ("pop_front", []) => true,
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
+/// Returns the local the receiver is a place of, either the local itself or a chain of field
+/// projections on it such as `self.inner.items`. Receivers with side effects are rejected.
+fn receiver_root_local(mut receiver: &Expr<'_>) -> Option<HirId> {
+    while let ExprKind::Field(base, _) = receiver.kind {
+        receiver = base;
+    }
+    path_to_local(receiver)
+}
+
+/// The code in which the sequence is known not to be empty.
+#[derive(Clone, Copy)]
+enum Guarded<'tcx> {
+    /// The `then` or `else` branch of the `if`.
+    Branch(&'tcx Expr<'tcx>),
+    /// The `if` statement, e.g. `if seq.is_empty() { return; }`, followed by the rest of its block.
+    Following(&'tcx Stmt<'tcx>, &'tcx [Stmt<'tcx>], Option<&'tcx Expr<'tcx>>),
+}
+
+impl<'tcx> Visitable<'tcx> for Guarded<'tcx> {
+    fn visit<V: Visitor<'tcx>>(self, visitor: &mut V) {
+        match self {
+            Self::Branch(e) => e.visit(visitor),
+            Self::Following(_, stmts, e) => (stmts, e).visit(visitor),
+        }
+    }
+}
+
+/// Returns the code in which the sequence is known not to be empty.
+fn guarded_code<'tcx>(cx: &LateContext<'tcx>, guard: &Guard<'tcx>, msrv: &Msrv) -> Option<Guarded<'tcx>> {
+    match &guard.kind {
+        GuardKind::Match { non_empty_arm, .. } => Some(Guarded::Branch(non_empty_arm.body)),
+        GuardKind::If(if_expr) if !guard.if_is_empty => Some(Guarded::Branch(if_expr.then)),
+        GuardKind::If(if_expr) => {
+            if let Some(else_expr) = if_expr.r#else {
+                Some(Guarded::Branch(else_expr))
+            } else if msrv.meets(msrvs::LET_ELSE) {
+                following_stmts(cx, guard.expr, if_expr)
+            } else {
+                None
+            }
+        },
+    }
+}
+
+/// Returns the statements following the `if` of an early-return guard, i.e. an `if` with no
+/// `else` and a diverging body which is a statement of a block.
+fn following_stmts<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    if_expr: &higher::If<'tcx>,
+) -> Option<Guarded<'tcx>> {
+    is_never_expr(cx, if_expr.then)?;
+    let mut parents = cx.tcx.hir().parent_iter(expr.hir_id).map(|(_, node)| node);
+    if let Some(Node::Stmt(stmt)) = parents.next()
+        && matches!(stmt.kind, StmtKind::Expr(_) | StmtKind::Semi(_))
+        && let Some(Node::Block(block)) = parents.next()
+        && let Some(pos) = block.stmts.iter().position(|s| s.hir_id == stmt.hir_id)
+    {
+        Some(Guarded::Following(stmt, &block.stmts[pos + 1..], block.expr))
+    } else {
+        None
+    }
+}
+
+/// The kind of sequence, which determines the methods to access its elements with. Other types
+/// implementing `Index<usize>` are not linted, as their accessors are unknown.
+#[derive(Clone, Copy)]
+enum Sequence {
+    /// A slice, an array or a `Vec`: `first()` and `last()`.
+    Slice,
+    /// A `VecDeque`: `front()` and `back()`.
+    VecDeque,
+}
+
+impl Sequence {
+    /// Returns the kind of sequence of the receiver, possibly behind references.
+    fn of(cx: &LateContext<'_>, receiver: &Expr<'_>) -> Option<Self> {
+        let ty = cx.typeck_results().expr_ty(receiver).peel_refs();
+        if matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::Vec) {
+            Some(Self::Slice)
+        } else if is_type_diagnostic_item(cx, ty, sym::VecDeque) {
+            Some(Self::VecDeque)
+        } else {
+            None
+        }
+    }
+}
+
+/// The `if` or `match` expression guarded by an emptiness check.
+struct Guard<'tcx> {
+    expr: &'tcx Expr<'tcx>,
+    kind: GuardKind<'tcx>,
+    /// Whether the condition evaluates to `true` for an empty sequence, i.e. whether the element
+    /// can only be accessed in the `else` branch of an `if`.
+    if_is_empty: bool,
+    /// The operands of the condition, in order, when the emptiness check, e.g. `!seq.is_empty()`,
+    /// is part of an `&&` chain (or of an `||` chain if `if_is_empty`). Otherwise only the
+    /// emptiness check.
+    operands: Vec<&'tcx Expr<'tcx>>,
+}
+
+enum GuardKind<'tcx> {
+    If(higher::If<'tcx>),
+    /// A `match` on the emptiness check with a `true` and a `false` arm, without guards.
+    Match {
+        scrutinee: &'tcx Expr<'tcx>,
+        non_empty_arm: &'tcx Arm<'tcx>,
+        empty_arm: &'tcx Arm<'tcx>,
+    },
+}
+
+impl<'tcx> Guard<'tcx> {
+    /// The condition of the `if`, or the scrutinee of the `match`.
+    fn cond(&self) -> &'tcx Expr<'tcx> {
+        match self.kind {
+            GuardKind::If(ref if_expr) => if_expr.cond,
+            GuardKind::Match { scrutinee, .. } => scrutinee,
+        }
+    }
+}
+
+/// Walks up from the `is_empty` or `len` call to the `if` expression it is the condition of, or
+/// one of the operands of the condition, or to the `match` it is the scrutinee of.
+fn get_guard<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Guard<'tcx>> {
+    let (mut emptiness_check, mut if_is_empty) = if let ExprKind::MethodCall(path, ..) = expr.kind
+        && path.ident.name == sym::len
+    {
+        let parent = get_parent_expr(cx, expr)?;
+        (parent, len_comparison_is_empty(cx, parent, expr)?)
+    } else {
+        (expr, true)
+    };
+    let mut chain_op = None;
+    let mut child_id = emptiness_check.hir_id;
+    for (_, node) in cx.tcx.hir().parent_iter(emptiness_check.hir_id) {
+        let Node::Expr(parent) = node else {
+            return None;
+        };
+        match parent.kind {
+            ExprKind::Unary(UnOp::Not, _) if chain_op.is_none() => {
+                if_is_empty = !if_is_empty;
+                emptiness_check = parent;
+            },
+            ExprKind::Binary(op, ..)
+                if matches!(op.node, BinOpKind::And | BinOpKind::Or)
+                    && chain_op.map_or(true, |chain_op| chain_op == op.node) =>
+            {
+                chain_op = Some(op.node);
+            },
+            // `higher::If` looks through the `DropTemps` wrapping the condition.
+            ExprKind::DropTemps(_) => continue,
+            ExprKind::If(..) => {
+                let if_expr = higher::If::hir(parent).filter(|if_expr| if_expr.cond.hir_id == child_id)?;
+                // The element can only be bound if the branch accessing it implies the sequence is
+                // not empty: `!seq.is_empty() && ..` for the `then` branch, `seq.is_empty() || ..`
+                // for the `else` branch.
+                let operands = match chain_op {
+                    None => vec![emptiness_check],
+                    Some(op) if (op == BinOpKind::Or) == if_is_empty => {
+                        let mut operands = Vec::new();
+                        chain_operands(if_expr.cond, op, &mut operands);
+                        let pos = operands.iter().position(|e| e.hir_id == emptiness_check.hir_id)?;
+                        // The emptiness check is moved to the front of the chain, so the operands
+                        // before it must not have side effects.
+                        if operands[..pos].iter().any(|e| has_side_effects(cx, e)) {
+                            return None;
+                        }
+                        operands
+                    },
+                    Some(_) => return None,
+                };
+                return Some(Guard {
+                    expr: parent,
+                    kind: GuardKind::If(if_expr),
+                    if_is_empty,
+                    operands,
+                });
+            },
+            ExprKind::Match(scrutinee, [first_arm, second_arm], MatchSource::Normal)
+                if scrutinee.hir_id == child_id && chain_op.is_none() =>
+            {
+                let first_value = bool_arm(first_arm)?;
+                if bool_arm(second_arm)? == first_value {
+                    return None;
+                }
+                let (non_empty_arm, empty_arm) = if first_value == if_is_empty {
+                    (second_arm, first_arm)
+                } else {
+                    (first_arm, second_arm)
+                };
+                return Some(Guard {
+                    expr: parent,
+                    kind: GuardKind::Match {
+                        scrutinee,
+                        non_empty_arm,
+                        empty_arm,
+                    },
+                    if_is_empty,
+                    operands: vec![emptiness_check],
+                });
+            },
+            _ => return None,
+        }
+        child_id = parent.hir_id;
+    }
+    None
+}
+
+/// Returns the value of the `true` or `false` literal pattern of the arm, if it has no guard.
+fn bool_arm(arm: &Arm<'_>) -> Option<bool> {
+    if arm.guard.is_none()
+        && let PatKind::Lit(lit) = arm.pat.kind
+        && let ExprKind::Lit(lit) = lit.kind
+        && let LitKind::Bool(value) = lit.node
+    {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Flattens a chain of `op`-joined expressions into its operands.
+fn chain_operands<'tcx>(expr: &'tcx Expr<'tcx>, op: BinOpKind, operands: &mut Vec<&'tcx Expr<'tcx>>) {
+    if let ExprKind::Binary(bin_op, lhs, rhs) = expr.kind
+        && bin_op.node == op
+    {
+        chain_operands(lhs, op, operands);
+        chain_operands(rhs, op, operands);
+    } else {
+        operands.push(expr);
+    }
+}
+
+/// Returns `true` if the operand contains a call, an indexing, an assignment or an overloaded
+/// operator, other than the call of an emptiness check. Moving the emptiness check before such an
+/// operand could change which expression panics first.
+fn has_side_effects(cx: &LateContext<'_>, operand: &Expr<'_>) -> bool {
+    emptiness_check(cx, operand).is_none()
+        && for_each_expr_without_closures(operand, |e| match e.kind {
+            ExprKind::Call(..)
+            | ExprKind::MethodCall(..)
+            | ExprKind::Index(..)
+            | ExprKind::Assign(..)
+            | ExprKind::AssignOp(..) => ControlFlow::Break(()),
+            ExprKind::Binary(..) | ExprKind::Unary(..) if cx.typeck_results().is_method_call(e) => {
+                ControlFlow::Break(())
+            },
+            _ => ControlFlow::Continue(()),
+        })
+        .is_some()
+}
+
+/// Checks whether `operand` is an emptiness check of a sequence, e.g. `!seq.is_empty()` or
+/// `seq.len() == 0`, and returns the sequence along with whether the check holds for an empty
+/// sequence.
+fn emptiness_check<'tcx>(cx: &LateContext<'_>, mut operand: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    if operand.span.from_expansion() {
+        return None;
+    }
+    let mut is_negated = false;
+    while let ExprKind::Unary(UnOp::Not, inner) = operand.kind {
+        is_negated = !is_negated;
+        operand = inner;
+    }
+    let (receiver, is_empty) = match operand.kind {
+        ExprKind::MethodCall(path, receiver, [], _) if path.ident.name.as_str() == "is_empty" => (receiver, true),
+        ExprKind::Binary(_, lhs, rhs) => [lhs, rhs].into_iter().find_map(|side| {
+            if let ExprKind::MethodCall(path, receiver, [], _) = side.kind
+                && path.ident.name == sym::len
+            {
+                Some((receiver, len_comparison_is_empty(cx, operand, side)?))
+            } else {
+                None
+            }
+        })?,
+        _ => return None,
+    };
+    (receiver_root_local(receiver).is_some() && Sequence::of(cx, receiver).is_some())
+        .then_some((receiver, is_empty != is_negated))
+}
+
+/// Splits the operands of the condition into the sequences whose element can be bound, along
+/// with the uses of the element, and the conditions to keep.
+///
+/// A sequence is bound if its emptiness check implies the guarded code is only reached for a
+/// non-empty sequence and its element is accessed there. As the bindings are moved to the front of
+/// the chain, no operand with side effects may come before the emptiness check.
+fn bind_sequences<'tcx>(
+    cx: &LateContext<'tcx>,
+    guard: &Guard<'tcx>,
+    guarded: Guarded<'tcx>,
+) -> (Vec<(&'tcx Expr<'tcx>, ElementUses<'tcx>)>, Vec<&'tcx Expr<'tcx>>) {
+    let mut bound: Vec<(&Expr<'_>, ElementUses<'_>)> = Vec::new();
+    let mut other_conditions = Vec::new();
+    let mut has_side_effects_before = false;
+    for &operand in &guard.operands {
+        if !has_side_effects_before
+            && let Some((receiver, is_empty)) = emptiness_check(cx, operand)
+            && is_empty == guard.if_is_empty
+            && !bound.iter().any(|(other, _)| eq_expr_value(cx, other, receiver))
+            && let Some(local_id) = receiver_root_local(receiver)
+            && let Some(uses) = collect_element_uses(cx, receiver, local_id, guarded)
+        {
+            bound.push((receiver, uses));
+        } else {
+            has_side_effects_before |= has_side_effects(cx, operand);
+            other_conditions.push(operand);
+        }
+    }
+    (bound, other_conditions)
+}
+
+/// Checks whether `cmp` compares `len_call` against zero, e.g. `seq.len() > 0` or `0 == seq.len()`,
+/// and returns whether the comparison holds for an empty sequence.
+fn len_comparison_is_empty(cx: &LateContext<'_>, cmp: &Expr<'_>, len_call: &Expr<'_>) -> Option<bool> {
+    let ExprKind::Binary(op, lhs, rhs) = cmp.kind else {
+        return None;
+    };
+    // Normalize to `seq.len() <op> other`.
+    let (op, other) = if lhs.hir_id == len_call.hir_id {
+        (op.node, rhs)
+    } else if rhs.hir_id == len_call.hir_id {
+        let op = match op.node {
+            BinOpKind::Lt => BinOpKind::Gt,
+            BinOpKind::Le => BinOpKind::Ge,
+            BinOpKind::Gt => BinOpKind::Lt,
+            BinOpKind::Ge => BinOpKind::Le,
+            op => op,
+        };
+        (op, lhs)
+    } else {
+        return None;
+    };
+    match (op, constant_full_int(cx, cx.typeck_results(), other)?) {
+        (BinOpKind::Eq | BinOpKind::Le, FullInt::U(0)) | (BinOpKind::Lt, FullInt::U(1)) => Some(true),
+        (BinOpKind::Ne | BinOpKind::Gt, FullInt::U(0)) | (BinOpKind::Ge, FullInt::U(1)) => Some(false),
+        _ => None,
+    }
+}
+
+/// Which element of the sequence is accessed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Element {
+    /// `seq[0]`
+    First,
+    /// `seq[seq.len() - 1]`
+    Last,
+}
+
+/// The accesses to the first or last element found in the guarded block.
+struct ElementUses<'tcx> {
+    /// The `seq[0]` expressions.
+    indexing_exprs: Vec<&'tcx Expr<'tcx>>,
+    /// The `seq.get(0)` expressions.
+    get_exprs: Vec<&'tcx Expr<'tcx>>,
+    sequence: Sequence,
+    element: Element,
+    /// Whether any of the accesses needs a mutable place, requiring `first_mut()` or `last_mut()`.
+    is_mut: bool,
+    /// Whether some accesses come from macro expansions, and are left as they are.
+    has_macro_uses: bool,
+}
+
+impl ElementUses<'_> {
+    /// The name of the element, which is also the name of its immutable accessor.
+    fn element_name(&self) -> &'static str {
+        match (self.sequence, self.element) {
+            (Sequence::Slice, Element::First) => "first",
+            (Sequence::Slice, Element::Last) => "last",
+            (Sequence::VecDeque, Element::First) => "front",
+            (Sequence::VecDeque, Element::Last) => "back",
+        }
+    }
+
+    /// The name of the method to bind the element with.
+    fn method_name(&self) -> String {
+        if self.is_mut {
+            format!("{}_mut", self.element_name())
+        } else {
+            self.element_name().to_string()
+        }
+    }
+
+    /// The span of each access, along with the text to replace it with once the element is bound
+    /// to `binding`.
+    fn spans_to_replace(&self, cx: &LateContext<'_>, binding: &str) -> Vec<(Span, String)> {
+        self.indexing_exprs
+            .iter()
+            .map(|e| indexing_replacement(cx, e, binding, self.is_mut))
+            .chain(
+                self.get_exprs
+                    .iter()
+                    .map(|e| get_replacement(cx, e, binding, self.is_mut)),
+            )
+            .collect()
+    }
+}
+
+/// Collects every expression in `guarded` which accesses the first or the last element of
+/// `receiver`, whose root local is `local_id`. Returns `None` if no such expression was found, if
+/// both the first and the last element are accessed, if the local is mutated or moved, or if the
+/// element is mutated while the local is also used in some other way.
+fn collect_element_uses<'tcx>(
+    cx: &LateContext<'tcx>,
+    receiver: &Expr<'_>,
+    local_id: HirId,
+    guarded: impl Visitable<'tcx>,
+) -> Option<ElementUses<'tcx>> {
+    let mut indexing_exprs = Vec::new();
+    let mut get_exprs = Vec::new();
+    let mut elements = Vec::new();
+    let mut has_other_uses = false;
+    let mut has_macro_uses = false;
+    let mut is_receiver_mutated = false;
+    let _: Option<!> = for_each_expr(cx, guarded, |e| {
+        if let Some(element) = element_access(cx, receiver, e) {
+            // Accesses from macro expansions can't be rewritten, they keep using the sequence.
+            if e.span.from_expansion() {
+                has_macro_uses = true;
+                return ControlFlow::Continue(Descend::Yes);
+            }
+            if let ExprKind::Index(..) = e.kind {
+                indexing_exprs.push(e);
+            } else {
+                get_exprs.push(e);
+            }
+            elements.push(element);
+            ControlFlow::Continue(Descend::No)
+        } else {
+            if path_to_local_id(e, local_id) {
+                has_other_uses = true;
+                is_receiver_mutated |= is_mutated_or_moved(cx, e);
+            }
+            ControlFlow::Continue(Descend::Yes)
+        }
+    });
+    // The binding borrows the sequence for the whole block.
+    if is_receiver_mutated {
+        return None;
+    }
+    let (&element, rest) = elements.split_first()?;
+    // A single binding can't stand for both ends of the sequence.
+    if rest.iter().any(|&other| other != element) {
+        return None;
+    }
+
+    let is_mut = indexing_exprs.iter().any(|e| is_mutably_used(cx, e));
+    // The mutable borrow taken by `first_mut()` would conflict with the other uses.
+    if is_mut && has_other_uses {
+        return None;
+    }
+    Some(ElementUses {
+        indexing_exprs,
+        get_exprs,
+        sequence: Sequence::of(cx, receiver)?,
+        element,
+        is_mut,
+        has_macro_uses,
+    })
+}
+
+/// Returns which element of `receiver` is accessed if `expr` is either `receiver[idx]` or
+/// `receiver.get(idx)` with a supported index.
+fn element_access(cx: &LateContext<'_>, receiver: &Expr<'_>, expr: &Expr<'_>) -> Option<Element> {
+    match expr.kind {
+        ExprKind::Index(base, idx, _) if eq_expr_value(cx, base, receiver) => accessed_element(cx, receiver, idx),
+        ExprKind::MethodCall(path, recv, [arg], _)
+            if path.ident.name.as_str() == "get" && eq_expr_value(cx, recv, receiver) =>
+        {
+            accessed_element(cx, receiver, arg)
+        },
+        _ => None,
+    }
+}
+
+/// Returns which element of `receiver` the index `idx` refers to, if it is either `0` or
+/// `receiver.len() - 1`.
+fn accessed_element(cx: &LateContext<'_>, receiver: &Expr<'_>, idx: &Expr<'_>) -> Option<Element> {
+    match idx.kind {
+        ExprKind::Binary(op, lhs, rhs)
+            if op.node == BinOpKind::Sub
+                && let ExprKind::MethodCall(path, len_recv, [], _) = lhs.kind
+                && path.ident.name == sym::len
+                && eq_expr_value(cx, len_recv, receiver)
+                && constant_full_int(cx, cx.typeck_results(), rhs) == Some(FullInt::U(1)) =>
+        {
+            Some(Element::Last)
+        },
+        _ if constant_full_int(cx, cx.typeck_results(), idx) == Some(FullInt::U(0)) => Some(Element::First),
+        _ => None,
+    }
+}
+
+/// Checks whether the place `expr`, or a projection of it, is assigned to, mutably borrowed, or
+/// used as the receiver of a method taking `&mut self`.
+fn is_mutably_used<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> bool {
+    loop {
+        if cx.typeck_results().expr_adjustments(expr).iter().any(|adjust| {
+            matches!(
+                adjust.kind,
+                Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+            )
+        }) {
+            return true;
+        }
+        let Some(parent) = get_parent_expr(cx, expr) else {
+            return false;
+        };
+        match parent.kind {
+            ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) if lhs.hir_id == expr.hir_id => return true,
+            ExprKind::AddrOf(_, Mutability::Mut, _) => return true,
+            ExprKind::Field(..) => expr = parent,
+            ExprKind::Index(base, ..) if base.hir_id == expr.hir_id => expr = parent,
+            _ => return false,
+        }
+    }
+}
+
+/// Checks whether the local `expr`, or a projection of it, is mutated or moved out of.
+fn is_mutated_or_moved<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    if is_mutably_used(cx, expr) {
+        return true;
+    }
+    let mut place = expr;
+    while let Some(parent) = get_parent_expr(cx, place)
+        && let ExprKind::Field(..) = parent.kind
+    {
+        place = parent;
+    }
+    if !cx.typeck_results().expr_adjustments(place).is_empty() || is_copy(cx, cx.typeck_results().expr_ty(place)) {
+        return false;
+    }
+    match cx.tcx.parent_hir_node(place.hir_id) {
+        Node::LetStmt(_) => true,
+        Node::Expr(parent) => matches!(
+            parent.kind,
+            ExprKind::Call(..)
+                | ExprKind::MethodCall(..)
+                | ExprKind::Assign(..)
+                | ExprKind::Ret(..)
+                | ExprKind::Break(..)
+                | ExprKind::Struct(..)
+                | ExprKind::Tup(..)
+                | ExprKind::Array(..)
+        ),
+        _ => false,
+    }
+}
+
+/// Builds the replacement of `seq[0]` by the binding. Places which are auto-dereferenced, or
+/// which are immediately borrowed again, can use the reference directly.
+fn indexing_replacement(cx: &LateContext<'_>, expr: &Expr<'_>, binding: &str, is_mut: bool) -> (Span, String) {
+    match get_parent_expr(cx, expr) {
+        Some(parent) if matches!(parent.kind, ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _)) => {
+            // Reborrow a mutable binding instead of moving it.
+            let replacement = if is_mut {
+                format!("&*{binding}")
+            } else {
+                binding.to_string()
+            };
+            (parent.span, replacement)
+        },
+        Some(Expr {
+            kind: ExprKind::MethodCall(_, recv, ..) | ExprKind::Field(recv, _) | ExprKind::Index(recv, ..),
+            ..
+        }) if recv.hir_id == expr.hir_id => (expr.span, binding.to_string()),
+        _ => (expr.span, format!("*{binding}")),
+    }
+}
+
+/// Builds the replacement of `seq.get(0)`, consuming a directly following `unwrap` or `expect`.
+fn get_replacement(cx: &LateContext<'_>, expr: &Expr<'_>, binding: &str, is_mut: bool) -> (Span, String) {
+    let binding = if is_mut {
+        format!("&*{binding}")
+    } else {
+        binding.to_string()
+    };
+    match get_parent_expr(cx, expr) {
+        Some(parent)
+            if let ExprKind::MethodCall(path, recv, args, _) = parent.kind
+                && recv.hir_id == expr.hir_id
+                && matches!((path.ident.name, args), (sym::unwrap, []) | (sym::expect, [_])) =>
+        {
+            (parent.span, binding)
+        },
+        _ => (expr.span, format!("Some({binding})")),
+    }
+}
+
+/// Builds the parts of the suggestion: the condition becomes `let Some(first) = seq.first()`, or
+/// the matching `last`/`_mut` variant, and every access to the element is replaced by the
+/// binding. The binding is renamed if its name is already used in the guarded block.
+/// When the access happens in the `else` branch, the branches are swapped. An early-return guard
+/// becomes `let Some(first) = seq.first() else { return; };`.
+///
+/// Several sequences are bound in a let-chain if the feature is enabled, or with a tuple pattern
+/// such as `let (Some(a_first), Some(b_first)) = (a.first(), b.first())` otherwise. The other
+/// operands of a condition chain are kept in the let-chain, or in a nested `if`.
+///
+/// A `match` on the emptiness check becomes a `match` on `seq.first()`, with `Some(first)` and
+/// `None` arms.
+fn make_suggestion<'tcx>(
+    cx: &LateContext<'tcx>,
+    guard: &Guard<'tcx>,
+    guarded: Guarded<'tcx>,
+    bound: &[(&Expr<'_>, ElementUses<'_>)],
+    other_conditions: &[&'tcx Expr<'tcx>],
+    app: &mut Applicability,
+) -> Option<Vec<(Span, String)>> {
+    let mut used_names = used_names(cx, guarded, other_conditions);
+    if bound.iter().any(|(_, uses)| uses.has_macro_uses) {
+        // The accesses from macro expansions still index the sequence.
+        *app = Applicability::MaybeIncorrect;
+    }
+    let mut spans_to_replace = Vec::new();
+    let mut patterns = Vec::new();
+    let mut scrutinees = Vec::new();
+    for (receiver, uses) in bound {
+        let name = binding_name(receiver, uses.element_name(), &mut used_names, bound.len() > 1);
+        let receiver_snip = snippet_with_applicability(cx, receiver.span, "..", app);
+        spans_to_replace.extend(uses.spans_to_replace(cx, &name));
+        patterns.push(format!("Some({name})"));
+        scrutinees.push(format!("{receiver_snip}.{}()", uses.method_name()));
+    }
+    let let_chains = cx.tcx.features().let_chains && matches!(guarded, Guarded::Branch(_));
+    let binding = if let [pattern] = &patterns[..] {
+        format!("let {pattern} = {}", scrutinees[0])
+    } else if let_chains {
+        patterns
+            .iter()
+            .zip(&scrutinees)
+            .map(|(pattern, scrutinee)| format!("let {pattern} = {scrutinee}"))
+            .collect::<Vec<_>>()
+            .join(" && ")
+    } else {
+        format!("let ({}) = ({})", patterns.join(", "), scrutinees.join(", "))
+    };
+
+    let if_expr = match &guard.kind {
+        GuardKind::If(if_expr) => if_expr,
+        GuardKind::Match {
+            scrutinee,
+            non_empty_arm,
+            empty_arm,
+        } => {
+            let ([pattern], [new_scrutinee]) = (&patterns[..], &scrutinees[..]) else {
+                return None;
+            };
+            let mut sugg = vec![
+                (scrutinee.span, new_scrutinee.clone()),
+                (non_empty_arm.pat.span, pattern.clone()),
+                (empty_arm.pat.span, "None".to_string()),
+            ];
+            sugg.extend(spans_to_replace);
+            return Some(sugg);
+        },
+    };
+
+    if let Guarded::Following(stmt, ..) = guarded {
+        // The other operands can't be kept in the condition of a `let..else`.
+        if !other_conditions.is_empty() {
+            return None;
+        }
+        let else_snip = snippet_with_applicability(cx, if_expr.then.span, "..", app);
+        let mut sugg = vec![(stmt.span, format!("{binding} else {else_snip};"))];
+        sugg.extend(spans_to_replace);
+        return Some(sugg);
+    }
+
+    let mut sugg = Vec::new();
+
+    // The conditions which must hold along with the sequence not being empty. When swapping the
+    // branches, these are the negated operands of the `||` chain.
+    let other_conditions = other_conditions
+        .iter()
+        .map(|e| {
+            let cond = Sugg::hir_with_applicability(cx, e, "..", app);
+            if guard.if_is_empty { !cond } else { cond }
+        })
+        .reduce(|lhs, rhs| lhs.and(&rhs));
+    match other_conditions {
+        None => sugg.push((if_expr.cond.span, binding)),
+        Some(other_conditions) if let_chains => {
+            let cond = Sugg::NonParen(binding.into()).and(&other_conditions);
+            sugg.push((if_expr.cond.span, cond.to_string()));
+        },
+        Some(other_conditions) if if_expr.r#else.is_none() => {
+            sugg.push((if_expr.cond.span, binding));
+            sugg.push((if_expr.then.span.shrink_to_lo(), format!("{{ if {other_conditions} ")));
+            sugg.push((if_expr.then.span.shrink_to_hi(), " }".to_string()));
+        },
+        Some(_) => return None,
+    }
+
+    if guard.if_is_empty {
+        // Swapping the branches moves code around, possibly along with comments.
+        *app = Applicability::MaybeIncorrect;
+        let else_expr = if_expr.r#else?;
+        if !matches!(else_expr.kind, ExprKind::Block(..)) {
+            return None;
+        }
+        let then_snip = snippet_with_applicability(cx, if_expr.then.span, "..", app);
+        let else_snip = rewrite_snippet(cx, else_expr.span, &spans_to_replace, app)?;
+        sugg.push((if_expr.then.span, else_snip));
+        sugg.push((else_expr.span, then_snip.into_owned()));
+    } else {
+        sugg.extend(spans_to_replace);
+    }
+
+    Some(sugg)
+}
+
+/// Collects the names used in `guarded` and in the `other_conditions`, which the bindings are in
+/// scope of.
+fn used_names<'tcx>(
+    cx: &LateContext<'tcx>,
+    guarded: impl Visitable<'tcx>,
+    other_conditions: &[&'tcx Expr<'tcx>],
+) -> FxHashSet<Symbol> {
+    let mut names = NameCollector {
+        cx,
+        names: FxHashSet::default(),
+    };
+    guarded.visit(&mut names);
+    for cond in other_conditions {
+        names.visit_expr(cond);
+    }
+    names.names
+}
+
+/// Picks a name for the binding of the element which isn't in `used_names`, and adds it there:
+/// `base`, e.g. `first`, then prefixed by the name of the receiver, e.g. `items_first`, then with
+/// a numeric suffix. The prefixed name comes first if `prefer_receiver_name` is set, to tell apart
+/// the elements of several sequences.
+fn binding_name(
+    receiver: &Expr<'_>,
+    base: &str,
+    used_names: &mut FxHashSet<Symbol>,
+    prefer_receiver_name: bool,
+) -> String {
+    let mut reserve = |name: &str| used_names.insert(Symbol::intern(name));
+
+    let receiver_name = match receiver.kind {
+        ExprKind::Field(_, ident) => Some(ident.name),
+        ExprKind::Path(QPath::Resolved(
+            None,
+            Path {
+                segments: [segment], ..
+            },
+        )) => Some(segment.ident.name),
+        _ => None,
+    };
+    let mut candidates = [
+        Some(base.to_string()),
+        receiver_name.map(|recv| format!("{recv}_{base}")),
+    ];
+    if prefer_receiver_name {
+        candidates.reverse();
+    }
+    if let Some(name) = candidates.into_iter().flatten().find(|name| reserve(name)) {
+        return name;
+    }
+    let mut suffix = 2;
+    loop {
+        let name = format!("{base}_{suffix}");
+        if reserve(&name) {
+            return name;
+        }
+        suffix += 1;
+    }
+}
+
+/// Collects the names of the bindings and of the single-segment paths in the visited expressions.
+struct NameCollector<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    names: FxHashSet<Symbol>,
+}
+
+impl<'tcx> Visitor<'tcx> for NameCollector<'_, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn visit_path(&mut self, path: &Path<'tcx>, _: HirId) {
+        if let [segment] = path.segments {
+            self.names.insert(segment.ident.name);
+        }
+        walk_path(self, path);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx Pat<'tcx>) {
+        if let PatKind::Binding(_, _, ident, _) = pat.kind {
+            self.names.insert(ident.name);
+        }
+        walk_pat(self, pat);
+    }
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+}
+
+/// Returns the snippet of `span` with each of the contained `replacements` applied.
+fn rewrite_snippet(
+    cx: &LateContext<'_>,
+    span: Span,
+    replacements: &[(Span, String)],
+    app: &mut Applicability,
+) -> Option<String> {
+    let mut snip = snippet_with_applicability(cx, span, "..", app).into_owned();
+    let mut replacements = replacements.to_vec();
+    replacements.sort_by_key(|(sp, _)| sp.lo());
+    for (sp, replacement) in replacements.into_iter().rev() {
+        if !span.contains(sp) {
+            return None;
+        }
+        let start = (sp.lo() - span.lo()).0 as usize;
+        let end = (sp.hi() - span.lo()).0 as usize;
+        snip.replace_range(start..end, &replacement);
+    }
+    Some(snip)
+}
@@ -1,40 +1,72 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
 use clippy_utils::ty::is_type_lang_item;
-use rustc_ast::ast::LitKind;
+use clippy_utils::{match_def_path, paths};
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_hir::{Expr, LangItem};
 use rustc_lint::LateContext;
 use rustc_middle::ty;
 use rustc_span::Span;
 
 use super::UNNECESSARY_JOIN;
 
+/// Checks for `.collect::<Vec<_>>().join(join_arg)`, or `.collect::<Vec<_>>().concat()` if
+/// `join_arg` is `None`.
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'tcx>,
     join_self_arg: &'tcx Expr<'tcx>,
-    join_arg: &'tcx Expr<'tcx>,
+    join_arg: Option<&'tcx Expr<'tcx>>,
     span: Span,
 ) {
     let applicability = Applicability::MachineApplicable;
+    // The element type is read from the type of the collected `Vec`, so it doesn't matter whether
+    // it's given by a turbofish or inferred from elsewhere.
     let collect_output_adjusted_type = cx.typeck_results().expr_ty_adjusted(join_self_arg);
     if let ty::Ref(_, ref_type, _) = collect_output_adjusted_type.kind()
-        // the turbofish for collect is ::<Vec<String>>
         && let ty::Slice(slice) = ref_type.kind()
-        && is_type_lang_item(cx, *slice, LangItem::String)
-        // the argument for join is ""
-        && let ExprKind::Lit(spanned) = &join_arg.kind
-        && let LitKind::Str(symbol, _) = spanned.node
-        && symbol.is_empty()
+        && let Some(elem) = match slice.kind() {
+            ty::Ref(_, inner, _) if inner.is_str() => Some("&str"),
+            _ if is_type_lang_item(cx, *slice, LangItem::String) => Some("String"),
+            _ => None,
+        }
     {
+        let call = match join_arg.map(|join_arg| constant(cx, cx.typeck_results(), join_arg)) {
+            None => "concat()",
+            // the argument for join is ""
+            Some(Some(Constant::Str(sep))) if sep.is_empty() => r#"join("")"#,
+            // There is no replacement in `std`, only code already using `itertools` gets a hint
+            Some(_) if is_itertools_in_scope(cx, expr) => {
+                span_lint_and_help(
+                    cx,
+                    UNNECESSARY_JOIN,
+                    span.with_hi(expr.span.hi()),
+                    format!("called `.collect::<Vec<{elem}>>().join(..)` on an iterator"),
+                    None,
+                    "`Itertools::join` from the `itertools` crate joins the items without collecting them first",
+                );
+                return;
+            },
+            Some(_) => return,
+        };
         span_lint_and_sugg(
             cx,
             UNNECESSARY_JOIN,
             span.with_hi(expr.span.hi()),
-            r#"called `.collect::<Vec<String>>().join("")` on an iterator"#,
+            format!("called `.collect::<Vec<{elem}>>().{call}` on an iterator"),
             "consider using",
             "collect::<String>()".to_owned(),
             applicability,
         );
     }
 }
+
+/// Checks if `Itertools` is in scope for the `join` call, so that its `join` can be called on the
+/// iterator directly.
+fn is_itertools_in_scope(cx: &LateContext<'_>, join_call: &Expr<'_>) -> bool {
+    cx.tcx.in_scope_traits(join_call.hir_id).is_some_and(|traits| {
+        traits
+            .iter()
+            .any(|candidate| match_def_path(cx, candidate.def_id, &paths::ITERTOOLS))
+    })
+}
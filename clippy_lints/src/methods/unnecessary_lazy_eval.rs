@@ -1,12 +1,14 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{eager_or_lazy, is_from_proc_macro, usage};
+use clippy_utils::visitors::{for_each_expr_without_closures, is_const_evaluatable};
+use clippy_utils::{eager_or_lazy, is_default_equivalent, is_from_proc_macro, path_def_id, usage};
 use hir::FnRetTy;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
 use rustc_span::sym;
+use std::ops::ControlFlow;
 
 use super::UNNECESSARY_LAZY_EVALUATIONS;
 
@@ -32,7 +34,9 @@ pub(super) fn check<'tcx>(
                 return;
             }
 
-            if eager_or_lazy::switch_to_eager_eval(cx, body_expr) {
+            let is_eager = eager_or_lazy::switch_to_eager_eval(cx, body_expr);
+            let is_const_call = !is_eager && is_const_fn_call_with_const_args(cx, body_expr);
+            if is_eager || is_const_call {
                 let msg = if is_option {
                     "unnecessary closure used to substitute value for `Option::None`"
                 } else if is_result {
@@ -40,11 +44,12 @@ pub(super) fn check<'tcx>(
                 } else {
                     "unnecessary closure used with `bool::then`"
                 };
-                let applicability = if body
-                    .params
-                    .iter()
-                    // bindings are checked to be unused above
-                    .all(|param| matches!(param.pat.kind, hir::PatKind::Binding(..) | hir::PatKind::Wild))
+                let applicability = if !is_const_call
+                    && body
+                        .params
+                        .iter()
+                        // bindings are checked to be unused above
+                        .all(|param| matches!(param.pat.kind, hir::PatKind::Binding(..) | hir::PatKind::Wild))
                     && matches!(
                         fn_decl.output,
                         FnRetTy::DefaultReturn(_)
@@ -55,7 +60,8 @@ pub(super) fn check<'tcx>(
                     ) {
                     Applicability::MachineApplicable
                 } else {
-                    // replacing the lambda may break type inference
+                    // replacing the lambda may break type inference, and a `const fn` may panic where
+                    // the closure wouldn't have been called
                     Applicability::MaybeIncorrect
                 };
 
@@ -76,3 +82,31 @@ pub(super) fn check<'tcx>(
         }
     }
 }
+
+/// Whether the expression is a call to a `const fn` with constant arguments, like
+/// `Duration::from_secs(5)`. Such calls have no side effects, but the eager form must not panic
+/// where the closure wouldn't have been called, so indexing and division aren't allowed. The
+/// called function itself may still panic.
+fn is_const_fn_call_with_const_args<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) -> bool {
+    let callee_id = match expr.kind {
+        hir::ExprKind::Call(callee, _) => path_def_id(cx, callee),
+        hir::ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+        _ => None,
+    };
+    callee_id.is_some_and(|id| cx.tcx.is_const_fn_raw(id))
+        && is_const_evaluatable(cx, expr)
+        // `unwrap_or_else(|| Vec::new())` is better served by `unwrap_or_default`
+        && !is_default_equivalent(cx, expr)
+        && !cx
+            .typeck_results()
+            .expr_ty(expr)
+            .has_significant_drop(cx.tcx, cx.param_env)
+        && for_each_expr_without_closures(expr, |e| match e.kind {
+            hir::ExprKind::Index(..) => ControlFlow::Break(()),
+            hir::ExprKind::Binary(op, ..) if matches!(op.node, hir::BinOpKind::Div | hir::BinOpKind::Rem) => {
+                ControlFlow::Break(())
+            },
+            _ => ControlFlow::Continue(()),
+        })
+        .is_none()
+}
@@ -0,0 +1,43 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::UNNECESSARY_MAP_OR;
+
+/// lint use of `map_or(true, <f>)` for `Option`s
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    def: &'tcx Expr<'_>,
+    map: &'tcx Expr<'_>,
+    method_span: Span,
+    msrv: &Msrv,
+) {
+    if let ExprKind::Lit(lit) = def.kind
+        && let LitKind::Bool(true) = lit.node
+        && !expr.span.from_expansion()
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Option)
+        && cx.typeck_results().expr_ty(expr).is_bool()
+        && msrv.meets_explicitly(msrvs::IS_NONE_OR)
+    {
+        // `is_none_or` takes `self` by value just like `map_or`, so the receiver can be kept as is.
+        let mut applicability = Applicability::MachineApplicable;
+        let map = snippet_with_applicability(cx, map.span, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_MAP_OR,
+            expr.span.with_lo(method_span.lo()),
+            "called `map_or(true, <f>)` on an `Option` value",
+            "use `is_none_or` instead",
+            format!("is_none_or({map})"),
+            applicability,
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::is_trait_method;
 use clippy_utils::sugg::Sugg;
-use clippy_utils::ty::implements_trait;
+use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{is_trait_method, path_to_local_id};
 use rustc_errors::Applicability;
-use rustc_hir::{Closure, Expr, ExprKind, Mutability, Param, Pat, PatKind, Path, PathSegment, QPath};
+use rustc_hir::{Closure, Expr, ExprKind, Mutability, Param, Pat, PatKind, Path, PathSegment, QPath, UnOp};
 use rustc_lint::LateContext;
 use rustc_middle::ty;
 use rustc_middle::ty::GenericArgKind;
@@ -75,7 +76,7 @@ fn mirrored_exprs(a_expr: &Expr<'_>, a_ident: &Ident, b_expr: &Expr<'_>, b_ident
             mirrored_exprs(left_block, a_ident, right_block, b_ident)
         },
         (ExprKind::Field(left_expr, left_ident), ExprKind::Field(right_expr, right_ident)) => {
-            left_ident.name == right_ident.name && mirrored_exprs(left_expr, a_ident, right_expr, right_ident)
+            left_ident.name == right_ident.name && mirrored_exprs(left_expr, a_ident, right_expr, b_ident)
         },
         // Two paths: either one is a and the other is b, or they're identical to each other
         (
@@ -114,10 +115,14 @@ fn mirrored_exprs(a_expr: &Expr<'_>, a_ident: &Ident, b_expr: &Expr<'_>, b_ident
     }
 }
 
-fn detect_lint(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) -> Option<LintTrigger> {
-    if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
-        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
-        && cx.tcx.type_of(impl_id).instantiate_identity().is_slice()
+fn detect_lint(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    recv: &Expr<'_>,
+    arg: &Expr<'_>,
+    is_iter: bool,
+) -> Option<LintTrigger> {
+    if is_receiver_supported(cx, expr, is_iter)
         && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
         && let closure_body = cx.tcx.hir().body(body)
         && let &[
@@ -174,7 +179,7 @@ fn detect_lint(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Exp
             return Some(LintTrigger::Sort(SortDetection { vec_name }));
         }
 
-        if !expr_borrows(cx, left_expr) {
+        if !expr_borrows(cx, left_expr) && !moves_out_of_place(cx, left_expr) {
             return Some(LintTrigger::SortByKey(SortByKeyDetection {
                 vec_name,
                 closure_arg,
@@ -187,11 +192,32 @@ fn detect_lint(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Exp
     None
 }
 
+/// Checks that the method is either defined on slices or, if `is_iter` is set, on `Iterator`.
+fn is_receiver_supported(cx: &LateContext<'_>, expr: &Expr<'_>, is_iter: bool) -> bool {
+    if is_iter {
+        is_trait_method(cx, expr, sym::Iterator)
+    } else {
+        cx.typeck_results()
+            .type_dependent_def_id(expr.hir_id)
+            .and_then(|method_id| cx.tcx.impl_of_method(method_id))
+            .is_some_and(|impl_id| cx.tcx.type_of(impl_id).instantiate_identity().is_slice())
+    }
+}
+
 fn expr_borrows(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     let ty = cx.typeck_results().expr_ty(expr);
     matches!(ty.kind(), ty::Ref(..)) || ty.walk().any(|arg| matches!(arg.unpack(), GenericArgKind::Lifetime(_)))
 }
 
+/// Returns true if returning `expr` from the key closure would move a non-`Copy` value out of
+/// the borrowed element, e.g. a `String` field.
+fn moves_out_of_place(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(UnOp::Deref, _)
+    ) && !is_copy(cx, cx.typeck_results().expr_ty(expr))
+}
+
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'_>,
@@ -199,17 +225,33 @@ pub(super) fn check<'tcx>(
     arg: &'tcx Expr<'_>,
     is_unstable: bool,
 ) {
-    match detect_lint(cx, expr, recv, arg) {
+    let suffix = if is_unstable { "_unstable" } else { "" };
+    emit_lint(cx, expr, detect_lint(cx, expr, recv, arg, false), "sort", suffix);
+}
+
+/// lint use of `max_by()` and `min_by()` for `Iterator`s, `name` is either `max` or `min`
+pub(super) fn check_min_max<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+    name: &str,
+) {
+    emit_lint(cx, expr, detect_lint(cx, expr, recv, arg, true), name, "");
+}
+
+/// Suggests `{name}{suffix}()` or `{name}{suffix}_by_key()` in place of `{name}{suffix}_by()`
+fn emit_lint(cx: &LateContext<'_>, expr: &Expr<'_>, trigger: Option<LintTrigger>, name: &str, suffix: &str) {
+    match trigger {
         Some(LintTrigger::SortByKey(trigger)) => span_lint_and_sugg(
             cx,
             UNNECESSARY_SORT_BY,
             expr.span,
-            "consider using `sort_by_key`",
+            format!("consider using `{name}_by_key`"),
             "try",
             format!(
-                "{}.sort{}_by_key(|{}| {})",
+                "{}.{name}{suffix}_by_key(|{}| {})",
                 trigger.vec_name,
-                if is_unstable { "_unstable" } else { "" },
                 trigger.closure_arg,
                 if trigger.reverse {
                     format!("std::cmp::Reverse({})", trigger.closure_body)
@@ -227,15 +269,58 @@ pub(super) fn check<'tcx>(
             cx,
             UNNECESSARY_SORT_BY,
             expr.span,
-            "consider using `sort`",
+            format!("consider using `{name}`"),
             "try",
-            format!(
-                "{}.sort{}()",
-                trigger.vec_name,
-                if is_unstable { "_unstable" } else { "" },
-            ),
+            format!("{}.{name}{suffix}()", trigger.vec_name),
             Applicability::MachineApplicable,
         ),
         None => {},
     }
 }
+
+/// lint use of `binary_search_by()` for slices comparing a projection of the element with a
+/// value
+pub(super) fn check_binary_search<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+) {
+    if is_receiver_supported(cx, expr, false)
+        && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        && let closure_body = cx.tcx.hir().body(body)
+        && let [
+            Param {
+                pat:
+                    Pat {
+                        kind: PatKind::Binding(_, param_id, param_ident, _),
+                        ..
+                    },
+                ..
+            },
+        ] = closure_body.params
+        && let ExprKind::MethodCall(method_path, key_expr, [value_expr], _) = closure_body.value.kind
+        && method_path.ident.name == sym::cmp
+        && is_trait_method(cx, closure_body.value, sym::Ord)
+        // `|a| a.cmp(&b)` is `binary_search`, which is not the point of this lint.
+        && !path_to_local_id(key_expr, *param_id)
+        && is_local_used(cx, key_expr, *param_id)
+        && !is_local_used(cx, value_expr, *param_id)
+        && !expr_borrows(cx, key_expr)
+        && !moves_out_of_place(cx, key_expr)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let recv = Sugg::hir_with_applicability(cx, recv, "..", &mut applicability);
+        let key = Sugg::hir_with_applicability(cx, key_expr, "..", &mut applicability);
+        let value = Sugg::hir_with_applicability(cx, value_expr, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_SORT_BY,
+            expr.span,
+            "consider using `binary_search_by_key`",
+            "try",
+            format!("{recv}.binary_search_by_key({value}, |{param_ident}| {key})"),
+            applicability,
+        );
+    }
+}
@@ -52,6 +52,9 @@ pub fn check<'tcx>(
             if check_into_iter_call_arg(cx, expr, method_name, receiver, msrv) {
                 return;
             }
+            if check_into_iterator_param_arg(cx, expr, method_name, receiver, msrv) {
+                return;
+            }
             check_other_call_arg(cx, expr, method_name, receiver);
         }
     } else {
@@ -240,6 +243,104 @@ fn check_into_iter_call_arg(
     false
 }
 
+/// Checks whether `expr` is an argument in a function call whose parameter is a generic type only
+/// bounded by `IntoIterator` and, if so, determines whether its call of `to_vec` or `to_owned` on
+/// a slice, an array or a `Vec` is unnecessary.
+fn check_into_iterator_param_arg<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    method_name: Symbol,
+    receiver: &Expr<'_>,
+    msrv: &Msrv,
+) -> bool {
+    if matches!(method_name.as_str(), "to_vec" | "to_owned")
+        && let Some(parent) = get_parent_expr(cx, expr)
+        && let Some((callee_def_id, _, recv, call_args)) = get_callee_generic_args_and_args(cx, parent)
+        && let fn_sig = cx.tcx.fn_sig(callee_def_id).instantiate_identity().skip_binder()
+        && let Some(i) = recv.into_iter().chain(call_args).position(|arg| arg.hir_id == expr.hir_id)
+        && let Some(input) = fn_sig.inputs().get(i)
+        && let ty::Param(_) = input.kind()
+        && let (trait_predicates, projection_predicates) = get_input_traits_and_projections(cx, callee_def_id, *input)
+        && let Some(sized_def_id) = cx.tcx.lang_items().sized_trait()
+        && let [trait_predicate] = trait_predicates
+            .iter()
+            .filter(|trait_predicate| trait_predicate.def_id() != sized_def_id)
+            .collect::<Vec<_>>()[..]
+        && cx.tcx.is_diagnostic_item(sym::IntoIterator, trait_predicate.def_id())
+        && only_bounded_by_self_predicates(cx, callee_def_id, *input)
+        && let expr_ty = cx.typeck_results().expr_ty(expr)
+        && is_type_diagnostic_item(cx, expr_ty, sym::Vec)
+        && let ty::Adt(_, expr_args) = expr_ty.kind()
+        && let elem_ty = expr_args.type_at(0)
+        && let (receiver_ty, n_receiver_refs) = peel_mid_ty_refs(cx.typeck_results().expr_ty(receiver))
+        && match receiver_ty.kind() {
+            ty::Slice(ty) | ty::Array(ty, _) => *ty == elem_ty,
+            ty::Adt(_, args) => is_type_diagnostic_item(cx, receiver_ty, sym::Vec) && args.type_at(0) == elem_ty,
+            _ => false,
+        }
+        && let Some(receiver_snippet) = snippet_opt(cx, receiver.span)
+    {
+        let borrowed_ty = Ty::new_imm_ref(cx.tcx, cx.tcx.lifetimes.re_erased, receiver_ty);
+        let (sugg, new_ty) = match projection_predicates[..] {
+            // Without a constraint on `Item`, iterating over references is just as good.
+            [] => {
+                let sugg = if n_receiver_refs == 0 {
+                    format!("&{receiver_snippet}")
+                } else {
+                    receiver_snippet
+                };
+                (sugg, borrowed_ty)
+            },
+            [projection_predicate] if projection_predicate.term.as_type() == Some(elem_ty) => {
+                if n_receiver_refs == 0 && matches!(receiver_ty.kind(), ty::Array(..)) && is_copy(cx, receiver_ty) {
+                    (receiver_snippet, receiver_ty)
+                } else if is_copy(cx, elem_ty) && msrv.meets(msrvs::ITERATOR_COPIED) {
+                    (format!("{receiver_snippet}.iter().copied()"), borrowed_ty)
+                } else {
+                    return false;
+                }
+            },
+            _ => return false,
+        };
+        if !can_change_type(cx, expr, new_ty) {
+            return false;
+        }
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_TO_OWNED,
+            expr.span,
+            format!("unnecessary use of `{method_name}`"),
+            "use",
+            sugg,
+            Applicability::MachineApplicable,
+        );
+        return true;
+    }
+    false
+}
+
+/// Returns true if every bound of the callee mentioning `ty` is a trait or projection bound on
+/// `ty` itself. This excludes bounds like `I: 'static`, which a borrowed argument can't satisfy,
+/// as well as bounds on the item type, which might not hold for the suggested argument.
+fn only_bounded_by_self_predicates<'tcx>(cx: &LateContext<'tcx>, callee_def_id: DefId, ty: Ty<'tcx>) -> bool {
+    cx.tcx
+        .param_env(callee_def_id)
+        .caller_bounds()
+        .iter()
+        .all(|predicate| match predicate.kind().skip_binder() {
+            ClauseKind::Trait(trait_predicate) => {
+                trait_predicate.self_ty() == ty || !trait_predicate.trait_ref.args.types().any(|t| t.contains(ty))
+            },
+            ClauseKind::Projection(projection_predicate) => {
+                projection_predicate.projection_term.self_ty() == ty
+                    || !(projection_predicate.projection_term.args.types().any(|t| t.contains(ty))
+                        || projection_predicate.term.as_type().is_some_and(|t| t.contains(ty)))
+            },
+            ClauseKind::TypeOutlives(ty::OutlivesPredicate(outlived, _)) => !outlived.contains(ty),
+            _ => true,
+        })
+}
+
 /// Checks whether `expr` is an argument in an `into_iter` call and, if so, determines whether its
 /// call of a `to_owned`-like function is unnecessary.
 fn check_split_call_arg(cx: &LateContext<'_>, expr: &Expr<'_>, method_name: Symbol, receiver: &Expr<'_>) -> bool {
@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::{multispan_sugg_with_applicability, span_lint_hir_and_then};
 use clippy_utils::source::{snippet, snippet_opt};
-use clippy_utils::{expr_or_init, is_trait_method, pat_is_wild};
+use clippy_utils::{expr_or_init, is_trait_method, pat_is_unused};
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, FnDecl, PatKind, TyKind};
 use rustc_lint::LateContext;
@@ -52,7 +52,7 @@ pub(super) fn check(cx: &LateContext<'_>, call_expr: &Expr<'_>, recv: &Expr<'_>,
         // .. which is a tuple of 2 elements
         && let PatKind::Tuple([index, elem], ..) = closure_param.pat.kind
         // And that the first element (the index) is either `_` or unused in the body
-        && pat_is_wild(cx, &index.kind, closure_body)
+        && pat_is_unused(cx, &index.kind, closure_body)
         // Try to find the initializer for `recv`. This is needed in case `recv` is a local_binding. In the
         // first example below, `expr_or_init` would return `recv`.
         // ```
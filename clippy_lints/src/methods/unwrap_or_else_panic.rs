@@ -0,0 +1,170 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::macros::{find_format_arg_expr, is_panic, root_macro_call_first_node, FormatArgsStorage, MacroCall};
+use clippy_utils::source::{snippet_opt, snippet_with_applicability};
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use rustc_ast::FormatArgs;
+use rustc_errors::Applicability;
+use rustc_hir::{Block, BlockCheckMode, Closure, Expr, ExprKind, Stmt, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::UNWRAP_OR_ELSE_PANIC;
+
+/// Checks for `recv.unwrap_or_else(arg)`, `recv.or_else(arg)` and `recv.map_or_else(arg, map_arg)`
+/// where the closure `arg` does nothing but panic.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    format_args: &FormatArgsStorage,
+    expr: &'tcx Expr<'tcx>,
+    recv: &'tcx Expr<'tcx>,
+    name: &str,
+    arg: &'tcx Expr<'tcx>,
+    map_arg: Option<&'tcx Expr<'tcx>>,
+) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let is_option = is_type_diagnostic_item(cx, recv_ty, sym::Option);
+    if !is_option && !is_type_diagnostic_item(cx, recv_ty, sym::Result) {
+        return;
+    }
+    let (ExprKind::Closure(&Closure { body, .. }), ExprKind::MethodCall(method, ..)) = (arg.kind, expr.kind) else {
+        return;
+    };
+    let span = method.ident.span.with_hi(expr.span.hi());
+    let Some((is_panic, message)) = panicking_body(cx, format_args, cx.tcx.hir().body(body).value) else {
+        return;
+    };
+
+    let msg = format!("called `{name}` with a closure that always panics");
+    // `expect` and `unwrap` on a `Result` need the error to implement `Debug`
+    let can_unwrap = is_option
+        || if let ty::Adt(_, args) = recv_ty.kind()
+            && let Some(debug) = cx.tcx.get_diagnostic_item(sym::Debug)
+        {
+            implements_trait(cx, args.type_at(1), debug, &[])
+        } else {
+            false
+        };
+    // Only a missing or plain string literal message can be passed on to `expect`
+    let call = if let Some(message) = message {
+        if message.arguments.all_args().is_empty()
+            && let Some(message) = snippet_opt(cx, message.span)
+            && !message.contains(['{', '}'])
+        {
+            Some(format!("expect({message})"))
+        } else {
+            None
+        }
+    } else {
+        Some("unwrap()".to_owned())
+    };
+    let Some(call) = call.filter(|_| name != "or_else" && can_unwrap) else {
+        span_lint_and_help(
+            cx,
+            UNWRAP_OR_ELSE_PANIC,
+            span,
+            msg,
+            None,
+            "consider using `expect` or `unwrap` to make the panic visible",
+        );
+        return;
+    };
+
+    // The panic message only stays the same for `panic!` on an `Option`
+    let mut applicability = if is_option && is_panic {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+    let sugg = if let Some(map_arg) = map_arg {
+        let map_arg = snippet_with_applicability(cx, map_arg.span, "..", &mut applicability);
+        format!("map({map_arg}).{call}")
+    } else {
+        call
+    };
+    span_lint_and_sugg(
+        cx,
+        UNWRAP_OR_ELSE_PANIC,
+        span,
+        msg,
+        if message.is_some() {
+            "use `expect` instead"
+        } else {
+            "use `unwrap` instead"
+        },
+        sugg,
+        applicability,
+    );
+}
+
+/// If `body` does nothing but call `panic!`, `unreachable!`, `todo!` or `unimplemented!`, returns
+/// whether it's `panic!` and the message given to the macro, if any.
+fn panicking_body<'a>(
+    cx: &LateContext<'_>,
+    format_args: &'a FormatArgsStorage,
+    mut body: &Expr<'_>,
+) -> Option<(bool, Option<&'a FormatArgs>)> {
+    loop {
+        if let Some(macro_call) = root_macro_call_first_node(cx, body) {
+            let is_panic = is_panic(cx, macro_call.def_id);
+            if !is_panic
+                && !matches!(
+                    cx.tcx.get_diagnostic_name(macro_call.def_id),
+                    Some(sym::todo_macro | sym::unimplemented_macro | sym::unreachable_macro)
+                )
+            {
+                return None;
+            }
+            // Before the 2021 edition, a message isn't necessarily passed on to `format_args!`
+            if !macro_call.span.edition().at_least_rust_2021() {
+                return None;
+            }
+            return Some((is_panic, panic_message(cx, format_args, body, &macro_call)));
+        }
+        // Only peel blocks written by the user, the ones from the macro expansion are checked above
+        body = match body.kind {
+            ExprKind::Block(
+                Block {
+                    stmts: [],
+                    expr: Some(inner),
+                    rules: BlockCheckMode::DefaultBlock,
+                    ..
+                }
+                | Block {
+                    stmts:
+                        [
+                            Stmt {
+                                kind: StmtKind::Expr(inner) | StmtKind::Semi(inner),
+                                ..
+                            },
+                        ],
+                    expr: None,
+                    rules: BlockCheckMode::DefaultBlock,
+                    ..
+                },
+                _,
+            ) if !body.span.from_expansion() => inner,
+            _ => return None,
+        };
+    }
+}
+
+/// Returns the format arguments given to a panicking macro, looking through the ones added by the
+/// macro, e.g. `todo!("..")` calls `panic!("not yet implemented: {}", format_args!(".."))`.
+fn panic_message<'a>(
+    cx: &LateContext<'_>,
+    format_args: &'a FormatArgsStorage,
+    mut expr: &Expr<'_>,
+    macro_call: &MacroCall,
+) -> Option<&'a FormatArgs> {
+    loop {
+        let args = format_args.get(cx, expr, macro_call.expn)?;
+        if args.span.eq_ctxt(macro_call.span) {
+            return Some(args);
+        }
+        let [arg] = args.arguments.all_args() else {
+            return None;
+        };
+        expr = find_format_arg_expr(expr, arg).ok()?;
+    }
+}
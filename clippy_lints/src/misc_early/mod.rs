@@ -432,23 +432,27 @@ impl MiscEarlyLints {
         };
 
         let lit_kind = LitKind::from_token_lit(lit);
-        if let Ok(LitKind::Int(value, lit_int_type)) = lit_kind {
-            let suffix = match lit_int_type {
-                LitIntType::Signed(ty) => ty.name_str(),
-                LitIntType::Unsigned(ty) => ty.name_str(),
-                LitIntType::Unsuffixed => "",
-            };
-            literal_suffix::check(cx, span, &lit_snip, suffix, "integer");
-            if lit_snip.starts_with("0x") {
-                mixed_case_hex_literals::check(cx, span, suffix, &lit_snip);
-            } else if lit_snip.starts_with("0b") || lit_snip.starts_with("0o") {
-                // nothing to do
-            } else if value != 0 && lit_snip.starts_with('0') {
-                zero_prefixed_literal::check(cx, span, &lit_snip);
-            }
-        } else if let Ok(LitKind::Float(_, LitFloatType::Suffixed(float_ty))) = lit_kind {
-            let suffix = float_ty.name_str();
-            literal_suffix::check(cx, span, &lit_snip, suffix, "float");
+        match lit_kind {
+            Ok(LitKind::Int(value, lit_int_type)) => {
+                let suffix = match lit_int_type {
+                    LitIntType::Signed(ty) => ty.name_str(),
+                    LitIntType::Unsigned(ty) => ty.name_str(),
+                    LitIntType::Unsuffixed => "",
+                };
+                literal_suffix::check(cx, span, &lit_snip, suffix, "integer");
+                if lit_snip.starts_with("0x") {
+                    mixed_case_hex_literals::check(cx, span, suffix, &lit_snip);
+                } else if lit_snip.starts_with("0b") || lit_snip.starts_with("0o") {
+                    // nothing to do
+                } else if value != 0 && lit_snip.starts_with('0') {
+                    zero_prefixed_literal::check(cx, span, &lit_snip);
+                }
+            },
+            Ok(LitKind::Float(_, LitFloatType::Suffixed(float_ty))) => {
+                let suffix = float_ty.name_str();
+                literal_suffix::check(cx, span, &lit_snip, suffix, "float");
+            },
+            _ => {},
         }
     }
 }
@@ -120,7 +120,7 @@ impl MissingDoc {
         let has_doc = attrs
             .iter()
             .any(|a| a.doc_str().is_some() || Self::has_include(a.meta()))
-            || matches!(self.search_span(sp), Some(span) if span_to_snippet_contains_docs(cx, span));
+            || self.search_span(sp).is_some_and(|span| span_to_snippet_contains_docs(cx, span));
 
         if !has_doc {
             span_lint(
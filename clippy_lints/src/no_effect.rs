@@ -115,84 +115,88 @@ impl<'tcx> LateLintPass<'tcx> for NoEffect {
 
 impl NoEffect {
     fn check_no_effect(&mut self, cx: &LateContext<'_>, stmt: &Stmt<'_>) -> bool {
-        if let StmtKind::Semi(expr) = stmt.kind {
-            // Covered by rustc `path_statements` lint
-            if matches!(expr.kind, ExprKind::Path(_)) {
-                return true;
-            }
-
-            if expr.span.from_expansion() {
-                return false;
-            }
-            let expr = peel_blocks(expr);
+        match stmt.kind {
+            StmtKind::Semi(expr) => {
+                // Covered by rustc `path_statements` lint
+                if matches!(expr.kind, ExprKind::Path(_)) {
+                    return true;
+                }
 
-            if is_operator_overridden(cx, expr) {
-                // Return `true`, to prevent `check_unnecessary_operation` from
-                // linting on this statement as well.
-                return true;
-            }
-            if has_no_effect(cx, expr) {
-                span_lint_hir_and_then(
-                    cx,
-                    NO_EFFECT,
-                    expr.hir_id,
-                    stmt.span,
-                    "statement with no effect",
-                    |diag| {
-                        for parent in cx.tcx.hir().parent_iter(stmt.hir_id) {
-                            if let Node::Item(item) = parent.1
-                                && let ItemKind::Fn(..) = item.kind
-                                && let Node::Block(block) = cx.tcx.parent_hir_node(stmt.hir_id)
-                                && let [.., final_stmt] = block.stmts
-                                && final_stmt.hir_id == stmt.hir_id
-                            {
-                                let expr_ty = cx.typeck_results().expr_ty(expr);
-                                let mut ret_ty = cx
-                                    .tcx
-                                    .fn_sig(item.owner_id)
-                                    .instantiate_identity()
-                                    .output()
-                                    .skip_binder();
+                if expr.span.from_expansion() {
+                    return false;
+                }
+                let expr = peel_blocks(expr);
 
-                                // Remove `impl Future<Output = T>` to get `T`
-                                if cx.tcx.ty_is_opaque_future(ret_ty)
-                                    && let Some(true_ret_ty) =
-                                        cx.tcx.infer_ctxt().build().get_impl_future_output_ty(ret_ty)
+                if is_operator_overridden(cx, expr) {
+                    // Return `true`, to prevent `check_unnecessary_operation` from
+                    // linting on this statement as well.
+                    return true;
+                }
+                if has_no_effect(cx, expr) {
+                    span_lint_hir_and_then(
+                        cx,
+                        NO_EFFECT,
+                        expr.hir_id,
+                        stmt.span,
+                        "statement with no effect",
+                        |diag| {
+                            for parent in cx.tcx.hir().parent_iter(stmt.hir_id) {
+                                if let Node::Item(item) = parent.1
+                                    && let ItemKind::Fn(..) = item.kind
+                                    && let Node::Block(block) = cx.tcx.parent_hir_node(stmt.hir_id)
+                                    && let [.., final_stmt] = block.stmts
+                                    && final_stmt.hir_id == stmt.hir_id
                                 {
-                                    ret_ty = true_ret_ty;
-                                }
+                                    let expr_ty = cx.typeck_results().expr_ty(expr);
+                                    let mut ret_ty = cx
+                                        .tcx
+                                        .fn_sig(item.owner_id)
+                                        .instantiate_identity()
+                                        .output()
+                                        .skip_binder();
+
+                                    // Remove `impl Future<Output = T>` to get `T`
+                                    if cx.tcx.ty_is_opaque_future(ret_ty)
+                                        && let Some(true_ret_ty) =
+                                            cx.tcx.infer_ctxt().build().get_impl_future_output_ty(ret_ty)
+                                    {
+                                        ret_ty = true_ret_ty;
+                                    }
 
-                                if !ret_ty.is_unit() && ret_ty == expr_ty {
-                                    diag.span_suggestion(
-                                        stmt.span.shrink_to_lo(),
-                                        "did you mean to return it?",
-                                        "return ",
-                                        Applicability::MaybeIncorrect,
-                                    );
+                                    if !ret_ty.is_unit() && ret_ty == expr_ty {
+                                        diag.span_suggestion(
+                                            stmt.span.shrink_to_lo(),
+                                            "did you mean to return it?",
+                                            "return ",
+                                            Applicability::MaybeIncorrect,
+                                        );
+                                    }
                                 }
                             }
-                        }
-                    },
-                );
-                return true;
-            }
-        } else if let StmtKind::Let(local) = stmt.kind {
-            if !is_lint_allowed(cx, NO_EFFECT_UNDERSCORE_BINDING, local.hir_id)
-                && !matches!(local.source, LocalSource::AsyncFn)
-                && let Some(init) = local.init
-                && local.els.is_none()
-                && !local.pat.span.from_expansion()
-                && has_no_effect(cx, init)
-                && let PatKind::Binding(_, hir_id, ident, _) = local.pat.kind
-                && ident.name.to_ident_string().starts_with('_')
-                && !any_parent_is_automatically_derived(cx.tcx, local.hir_id)
-            {
-                if let Some(l) = self.local_bindings.last_mut() {
-                    l.push(hir_id);
-                    self.underscore_bindings.insert(hir_id, ident.span);
+                        },
+                    );
+                    return true;
                 }
-                return true;
-            }
+            },
+            StmtKind::Let(local) => {
+                if !is_lint_allowed(cx, NO_EFFECT_UNDERSCORE_BINDING, local.hir_id)
+                    && !matches!(local.source, LocalSource::AsyncFn)
+                    && let Some(init) = local.init
+                    && local.els.is_none()
+                    && !local.pat.span.from_expansion()
+                    && has_no_effect(cx, init)
+                    && let PatKind::Binding(_, hir_id, ident, _) = local.pat.kind
+                    && ident.name.to_ident_string().starts_with('_')
+                    && !any_parent_is_automatically_derived(cx.tcx, local.hir_id)
+                {
+                    if let Some(l) = self.local_bindings.last_mut() {
+                        l.push(hir_id);
+                        self.underscore_bindings.insert(hir_id, ident.span);
+                    }
+                    return true;
+                }
+            },
+            _ => {},
         }
         false
     }
@@ -1,14 +1,21 @@
 use super::ARITHMETIC_SIDE_EFFECTS;
-use clippy_utils::consts::{constant, constant_simple, Constant};
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{expr_or_init, is_from_proc_macro, is_lint_allowed, peel_hir_expr_refs, peel_hir_expr_unary};
+use clippy_config::types::{ArithmeticOperator, ArithmeticSideEffectsAllowedBinary};
+use clippy_utils::consts::{constant, constant_simple, Constant, FullInt};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{deref_chain, is_type_diagnostic_item};
+use clippy_utils::{
+    clip, expr_or_init, int_bits, is_diag_item_method, is_from_proc_macro, is_guarded_by_condition, is_in_test,
+    is_lint_allowed, path_to_local, path_to_local_id, peel_hir_expr_refs, peel_hir_expr_unary,
+};
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::{Applicability, Diag};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, Ty};
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol::sym;
 use rustc_span::{Span, Symbol};
+use rustc_target::abi::Integer;
 use {rustc_ast as ast, rustc_hir as hir};
 
 const HARD_CODED_ALLOWED_BINARY: &[[&str; 2]] = &[["f32", "f32"], ["f64", "f64"], ["std::string::String", "str"]];
@@ -20,11 +27,16 @@ const DISALLOWED_INT_METHODS: &[Symbol] = &[
     sym::wrapping_rem_euclid,
 ];
 
+/// Operators a pair of types is allowed in, `None` meaning all of them.
+type AllowedOperators = Option<FxHashSet<hir::BinOpKind>>;
+
 #[derive(Debug)]
 pub struct ArithmeticSideEffects {
-    allowed_binary: FxHashMap<String, FxHashSet<String>>,
+    allow_in_tests: bool,
+    allowed_binary: FxHashMap<String, FxHashMap<String, AllowedOperators>>,
     allowed_unary: FxHashSet<String>,
-    // Used to check whether expressions are constants, such as in enum discriminants and consts
+    // Used to check whether expressions are in a const context, such as in enum discriminants, consts
+    // or `const fn`s
     const_span: Option<Span>,
     disallowed_int_methods: FxHashSet<Symbol>,
     expr_span: Option<Span>,
@@ -34,21 +46,39 @@ impl_lint_pass!(ArithmeticSideEffects => [ARITHMETIC_SIDE_EFFECTS]);
 
 impl ArithmeticSideEffects {
     #[must_use]
-    pub fn new(user_allowed_binary: Vec<[String; 2]>, user_allowed_unary: Vec<String>) -> Self {
-        let mut allowed_binary: FxHashMap<String, FxHashSet<String>> = <_>::default();
-        for [lhs, rhs] in user_allowed_binary.into_iter().chain(
+    pub fn new(
+        user_allowed_binary: Vec<ArithmeticSideEffectsAllowedBinary>,
+        user_allowed_unary: Vec<String>,
+        allow_in_tests: bool,
+    ) -> Self {
+        let mut allowed_binary: FxHashMap<String, FxHashMap<String, AllowedOperators>> = <_>::default();
+        for entry in user_allowed_binary.into_iter().chain(
             HARD_CODED_ALLOWED_BINARY
                 .iter()
-                .copied()
-                .map(|[lhs, rhs]| [lhs.to_string(), rhs.to_string()]),
+                .map(|[lhs, rhs]| ArithmeticSideEffectsAllowedBinary::Pair([lhs.to_string(), rhs.to_string()])),
         ) {
-            allowed_binary.entry(lhs).or_default().insert(rhs);
+            let [lhs, rhs] = entry.types();
+            let new_ops = entry
+                .operators()
+                .map(|ops| ops.iter().copied().map(bin_op_kind).collect::<FxHashSet<_>>());
+            let ops = allowed_binary
+                .entry(lhs.to_string())
+                .or_default()
+                .entry(rhs.to_string())
+                .or_insert_with(|| Some(FxHashSet::default()));
+            // An entry without operators allows the pair in all of them
+            match (ops, new_ops) {
+                (Some(ops), Some(new_ops)) => ops.extend(new_ops),
+                (ops, None) => *ops = None,
+                (None, Some(_)) => {},
+            }
         }
         let allowed_unary = user_allowed_unary
             .into_iter()
             .chain(HARD_CODED_ALLOWED_UNARY.iter().copied().map(String::from))
             .collect();
         Self {
+            allow_in_tests,
             allowed_binary,
             allowed_unary,
             const_span: None,
@@ -58,21 +88,22 @@ impl ArithmeticSideEffects {
     }
 
     /// Checks if the lhs and the rhs types of a binary operation like "addition" or
-    /// "multiplication" are present in the inner set of allowed types.
-    fn has_allowed_binary(&self, lhs_ty: Ty<'_>, rhs_ty: Ty<'_>) -> bool {
+    /// "multiplication" are present in the inner set of allowed types for the given operator.
+    fn has_allowed_binary(&self, lhs_ty: Ty<'_>, op: hir::BinOpKind, rhs_ty: Ty<'_>) -> bool {
         let lhs_ty_string = lhs_ty.to_string();
         let lhs_ty_string_elem = lhs_ty_string.split('<').next().unwrap_or_default();
         let rhs_ty_string = rhs_ty.to_string();
         let rhs_ty_string_elem = rhs_ty_string.split('<').next().unwrap_or_default();
+        let allows_op = |ops: &AllowedOperators| ops.as_ref().map_or(true, |ops| ops.contains(&op));
         if let Some(rhs_from_specific) = self.allowed_binary.get(lhs_ty_string_elem)
             && {
-                let rhs_has_allowed_ty = rhs_from_specific.contains(rhs_ty_string_elem);
-                rhs_has_allowed_ty || rhs_from_specific.contains("*")
+                let rhs_has_allowed_ty = rhs_from_specific.get(rhs_ty_string_elem).map_or(false, allows_op);
+                rhs_has_allowed_ty || rhs_from_specific.get("*").map_or(false, allows_op)
             }
         {
             true
         } else if let Some(rhs_from_glob) = self.allowed_binary.get("*") {
-            rhs_from_glob.contains(rhs_ty_string_elem)
+            rhs_from_glob.get(rhs_ty_string_elem).map_or(false, allows_op)
         } else {
             false
         }
@@ -115,8 +146,12 @@ impl ArithmeticSideEffects {
 
             unsigned_int_types.contains(&int_type)
         };
-        let is_sat_or_wrap = |ty: Ty<'_>| {
-            is_type_diagnostic_item(cx, ty, sym::Saturating) || is_type_diagnostic_item(cx, ty, sym::Wrapping)
+        // `Saturating` and `Wrapping` can also be reached through references, `Box`es or other
+        // `Deref` implementors, like in `*boxed_wrapping += Wrapping(1)`.
+        let is_sat_or_wrap = |ty: Ty<'tcx>| {
+            deref_chain(cx, ty).any(|ty| {
+                is_type_diagnostic_item(cx, ty, sym::Saturating) || is_type_diagnostic_item(cx, ty, sym::Wrapping)
+            })
         };
 
         // If the RHS is `NonZero<u*>`, then division or module by zero will never occur.
@@ -132,22 +167,253 @@ impl ArithmeticSideEffects {
         false
     }
 
+    /// Checks if a division or a remainder can't panic because its RHS is obtained from a
+    /// `NonZero*::get` call, like in `x / nz.get()`.
+    ///
+    /// Signed divisions can still overflow with `MIN / -1`, so they are only allowed when the LHS
+    /// is a constant other than `MIN`.
+    fn has_non_zero_get_divisor<'tcx>(
+        cx: &LateContext<'tcx>,
+        lhs: &hir::Expr<'tcx>,
+        lhs_ty: Ty<'tcx>,
+        op: hir::BinOpKind,
+        rhs: &hir::Expr<'tcx>,
+    ) -> bool {
+        if !matches!(op, hir::BinOpKind::Div | hir::BinOpKind::Rem) {
+            return false;
+        }
+        let is_non_zero_get = match rhs.kind {
+            hir::ExprKind::MethodCall(ps, _, [], _) => {
+                ps.ident.name.as_str() == "get"
+                    && cx
+                        .typeck_results()
+                        .type_dependent_def_id(rhs.hir_id)
+                        .map_or(false, |def_id| is_diag_item_method(cx, def_id, sym::NonZero))
+            },
+            hir::ExprKind::Call(func, [_]) => {
+                if let hir::ExprKind::Path(ref qpath) = func.kind
+                    && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+                {
+                    cx.tcx.item_name(def_id).as_str() == "get" && is_diag_item_method(cx, def_id, sym::NonZero)
+                } else {
+                    false
+                }
+            },
+            _ => false,
+        };
+        if !is_non_zero_get {
+            return false;
+        }
+        match *lhs_ty.kind() {
+            ty::Uint(_) => true,
+            ty::Int(ity) => constant(cx, cx.typeck_results(), lhs)
+                .and_then(|c| c.int_value(cx, lhs_ty))
+                .map_or(false, |n| {
+                    let min = i128::MIN >> (128 - int_bits(cx.tcx, ity));
+                    n != FullInt::S(min)
+                }),
+            _ => false,
+        }
+    }
+
+    /// Checks if `expr` is inside a branch of an `if` whose condition rules out any overflow,
+    /// underflow or division by zero, like `a - b` in `if b <= a { a - b } else { 0 }`.
+    ///
+    /// Only unsigned integers and operands that are locals not mutated inside the branch are
+    /// considered.
+    fn is_guarded_by_condition<'tcx>(
+        cx: &LateContext<'tcx>,
+        expr: &hir::Expr<'tcx>,
+        op: hir::BinOpKind,
+        lhs: &hir::Expr<'tcx>,
+        rhs: &hir::Expr<'tcx>,
+    ) -> bool {
+        let ty::Uint(uty) = *cx.typeck_results().expr_ty(lhs).peel_refs().kind() else {
+            return false;
+        };
+        let lhs_id = path_to_local(peel_hir_expr_refs(lhs).0);
+        let Some(rhs_id) = path_to_local(peel_hir_expr_refs(rhs).0) else {
+            return false;
+        };
+        let locals: Vec<_> = [Some(rhs_id), lhs_id].into_iter().flatten().collect();
+        is_guarded_by_condition(cx, expr, &locals, |cond, is_then| {
+            Self::condition_rules_out_overflow(cx, cond, is_then, op, lhs_id, rhs_id, uty)
+        })
+    }
+
+    /// Checks if `cond`, evaluated to `is_then`, guarantees that `lhs op rhs` can't panic.
+    /// Conditions are normalized to `x < y`, `x <= y`, `x == y` or `x != y` beforehand.
+    fn condition_rules_out_overflow(
+        cx: &LateContext<'_>,
+        cond: &hir::Expr<'_>,
+        is_then: bool,
+        op: hir::BinOpKind,
+        lhs_id: Option<hir::HirId>,
+        rhs_id: hir::HirId,
+        uty: ty::UintTy,
+    ) -> bool {
+        let cond = if let hir::ExprKind::DropTemps(inner) = cond.kind {
+            inner
+        } else {
+            cond
+        };
+        let hir::ExprKind::Binary(cmp, x, y) = cond.kind else {
+            return false;
+        };
+        let mut cmp = cmp.node;
+        if !is_then {
+            cmp = match cmp {
+                hir::BinOpKind::Lt => hir::BinOpKind::Ge,
+                hir::BinOpKind::Le => hir::BinOpKind::Gt,
+                hir::BinOpKind::Gt => hir::BinOpKind::Le,
+                hir::BinOpKind::Ge => hir::BinOpKind::Lt,
+                hir::BinOpKind::Eq => hir::BinOpKind::Ne,
+                hir::BinOpKind::Ne => hir::BinOpKind::Eq,
+                _ => return false,
+            };
+        }
+        let (cmp, x, y) = match cmp {
+            hir::BinOpKind::Gt => (hir::BinOpKind::Lt, y, x),
+            hir::BinOpKind::Ge => (hir::BinOpKind::Le, y, x),
+            _ => (cmp, x, y),
+        };
+        let is_zero = |e: &hir::Expr<'_>| matches!(constant(cx, cx.typeck_results(), e), Some(Constant::Int(0)));
+        // `MAX - other`, where `other` is the local with the given id
+        let is_max_minus = |e: &hir::Expr<'_>, other: hir::HirId| {
+            if let hir::ExprKind::Binary(sub, max, local) = e.kind
+                && sub.node == hir::BinOpKind::Sub
+                && path_to_local_id(local, other)
+                && let Some(Constant::Int(n)) = constant(cx, cx.typeck_results(), max)
+            {
+                n == clip(cx.tcx, u128::MAX, uty)
+            } else {
+                false
+            }
+        };
+        match (op, cmp) {
+            // `b != 0` guarding `a / b` or `a % b`
+            (hir::BinOpKind::Div | hir::BinOpKind::Rem, hir::BinOpKind::Ne) => {
+                (path_to_local_id(x, rhs_id) && is_zero(y)) || (is_zero(x) && path_to_local_id(y, rhs_id))
+            },
+            // `b <= a` guarding `a - b`
+            (hir::BinOpKind::Sub, hir::BinOpKind::Le | hir::BinOpKind::Lt) => {
+                lhs_id.map_or(false, |lhs_id| path_to_local_id(x, rhs_id) && path_to_local_id(y, lhs_id))
+            },
+            // `a <= MAX - b` guarding `a + b`
+            (hir::BinOpKind::Add, hir::BinOpKind::Le | hir::BinOpKind::Lt) => lhs_id.map_or(false, |lhs_id| {
+                (path_to_local_id(x, lhs_id) && is_max_minus(y, rhs_id))
+                    || (path_to_local_id(x, rhs_id) && is_max_minus(y, lhs_id))
+            }),
+            _ => false,
+        }
+    }
+
+    /// Checks if the RHS of a shift is a constant smaller than the bit width of the LHS, like `3`
+    /// in `x << 3` for a `u32` `x`.
+    fn is_shift_amount_in_range<'tcx>(cx: &LateContext<'tcx>, lhs_ty: Ty<'tcx>, rhs: &hir::Expr<'tcx>) -> bool {
+        let bits = match *lhs_ty.kind() {
+            ty::Int(ity) => Integer::from_int_ty(&cx.tcx, ity).size().bits(),
+            ty::Uint(uty) => Integer::from_uint_ty(&cx.tcx, uty).size().bits(),
+            _ => return false,
+        };
+        let rhs_ty = cx.typeck_results().expr_ty(rhs).peel_refs();
+        match constant(cx, cx.typeck_results(), rhs).and_then(|c| c.peel_refs().int_value(cx, rhs_ty)) {
+            Some(FullInt::S(n)) => (0..i128::from(bits)).contains(&n),
+            Some(FullInt::U(n)) => n < u128::from(bits),
+            None => false,
+        }
+    }
+
     // For example, 8i32 or &i64::MAX.
     fn is_integral(ty: Ty<'_>) -> bool {
         ty.peel_refs().is_integral()
     }
 
     // Common entry-point to avoid code duplication.
-    fn issue_lint<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
+    fn issue_lint<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        expr: &'tcx hir::Expr<'_>,
+        decorate: impl FnOnce(&mut Diag<'_, ()>),
+    ) {
         if is_from_proc_macro(cx, expr) {
             return;
         }
 
         let msg = "arithmetic operation that can potentially result in unexpected side-effects";
-        span_lint(cx, ARITHMETIC_SIDE_EFFECTS, expr.span, msg);
+        span_lint_and_then(cx, ARITHMETIC_SIDE_EFFECTS, expr.span, msg, decorate);
         self.expr_span = Some(expr.span);
     }
 
+    /// Suggests replacing a binary operation or a compound assignment over primitive integers with
+    /// its `checked_*`, `saturating_*` or `wrapping_*` counterparts.
+    fn suggest_explicit_methods(cx: &LateContext<'_>, diag: &mut Diag<'_, ()>, expr: &hir::Expr<'_>) {
+        let (op, lhs, rhs, is_assign) = match expr.kind {
+            hir::ExprKind::Binary(op, lhs, rhs) => (op.node, lhs, rhs, false),
+            hir::ExprKind::AssignOp(op, lhs, rhs) => (op.node, lhs, rhs, true),
+            _ => return,
+        };
+        let lhs_ty = cx.typeck_results().expr_ty(lhs);
+        let rhs_ty = cx.typeck_results().expr_ty(rhs);
+        if !lhs_ty.is_integral() || !rhs_ty.is_integral() {
+            return;
+        }
+        // `saturating_*` and `wrapping_*` still panic when dividing by zero, and shifts only accept
+        // an `u32` as their RHS.
+        let (name, methods): (_, &[&str]) = match op {
+            hir::BinOpKind::Add => ("add", &["checked", "saturating", "wrapping"]),
+            hir::BinOpKind::Sub => ("sub", &["checked", "saturating", "wrapping"]),
+            hir::BinOpKind::Mul => ("mul", &["checked", "saturating", "wrapping"]),
+            hir::BinOpKind::Div => ("div", &["checked"]),
+            hir::BinOpKind::Rem => ("rem", &["checked"]),
+            hir::BinOpKind::Shl if *rhs_ty.kind() == ty::Uint(ty::UintTy::U32) => ("shl", &["checked", "wrapping"]),
+            hir::BinOpKind::Shr if *rhs_ty.kind() == ty::Uint(ty::UintTy::U32) => ("shr", &["checked", "wrapping"]),
+            _ => return,
+        };
+        let msg = if let hir::BinOpKind::Div | hir::BinOpKind::Rem = op {
+            "consider handling the division by zero explicitly"
+        } else {
+            "consider using a method with explicit overflow behavior"
+        };
+        let mut app = Applicability::MaybeIncorrect;
+        let ctxt = expr.span.ctxt();
+        let rhs_sugg = Sugg::hir_with_context(cx, rhs, ctxt, "..", &mut app);
+        if is_assign {
+            // `checked_*` returns an `Option`, which can't be assigned back to the LHS
+            diag.note(format!("`checked_{name}` can be used to get a `None` instead of a panic"));
+            let place = Sugg::hir_with_context(cx, lhs, ctxt, "..", &mut app);
+            let receiver = place.clone().maybe_par();
+            let suggestions = methods
+                .iter()
+                .filter(|&&method| method != "checked")
+                .map(|method| format!("{place} = {receiver}.{method}_{name}({rhs_sugg})"))
+                .collect::<Vec<_>>();
+            if !suggestions.is_empty() {
+                diag.span_suggestions(expr.span, msg, suggestions, app);
+            }
+        } else {
+            let receiver = if let hir::ExprKind::Lit(lit) = peel_hir_expr_unary(lhs).0.kind
+                && let ast::LitKind::Int(_, ast::LitIntType::Unsuffixed) = lit.node
+            {
+                // Methods can't be called on literals of an ambiguous numeric type
+                let snip = format!("{}_{lhs_ty}", Sugg::hir_with_context(cx, lhs, ctxt, "..", &mut app));
+                if lhs.span == lit.span {
+                    Sugg::NonParen(snip.into())
+                } else {
+                    Sugg::MaybeParen(snip.into()).maybe_par()
+                }
+            } else {
+                Sugg::hir_with_context(cx, lhs, ctxt, "..", &mut app).maybe_par()
+            };
+            diag.span_suggestions(
+                expr.span,
+                msg,
+                methods.iter().map(|method| format!("{receiver}.{method}_{name}({rhs_sugg})")),
+                app,
+            );
+        }
+    }
+
     /// Returns the numeric value of a literal integer originated from `expr`, if any.
     ///
     /// Literal integers can be originated from adhoc declarations like `1`, associated constants
@@ -218,17 +484,19 @@ impl ArithmeticSideEffects {
         actual_rhs = expr_or_init(cx, actual_rhs);
         let lhs_ty = cx.typeck_results().expr_ty(actual_lhs).peel_refs();
         let rhs_ty = cx.typeck_results().expr_ty(actual_rhs).peel_refs();
-        if self.has_allowed_binary(lhs_ty, rhs_ty) {
+        if self.has_allowed_binary(lhs_ty, op, rhs_ty) {
             return;
         }
         if Self::has_specific_allowed_type_and_operation(cx, lhs_ty, op, rhs_ty) {
             return;
         }
-        let has_valid_op = if Self::is_integral(lhs_ty) && Self::is_integral(rhs_ty) {
-            if let hir::BinOpKind::Shl | hir::BinOpKind::Shr = op {
-                // At least for integers, shifts are already handled by the CTFE
-                return;
-            }
+        if Self::has_non_zero_get_divisor(cx, actual_lhs, lhs_ty, op, actual_rhs) {
+            return;
+        }
+        let is_integral_op = Self::is_integral(lhs_ty) && Self::is_integral(rhs_ty);
+        let has_valid_op = if is_integral_op && matches!(op, hir::BinOpKind::Shl | hir::BinOpKind::Shr) {
+            Self::is_shift_amount_in_range(cx, lhs_ty, actual_rhs)
+        } else if is_integral_op {
             match (
                 Self::literal_integer(cx, actual_lhs),
                 Self::literal_integer(cx, actual_rhs),
@@ -259,8 +527,8 @@ impl ArithmeticSideEffects {
         } else {
             false
         };
-        if !has_valid_op {
-            self.issue_lint(cx, expr);
+        if !has_valid_op && !Self::is_guarded_by_condition(cx, expr, op, lhs, rhs) {
+            self.issue_lint(cx, expr, |diag| Self::suggest_explicit_methods(cx, diag, expr));
         }
     }
 
@@ -290,7 +558,7 @@ impl ArithmeticSideEffects {
         }
         let (actual_arg, _) = peel_hir_expr_refs(arg);
         match Self::literal_integer(cx, actual_arg) {
-            None | Some(0) => self.issue_lint(cx, arg),
+            None | Some(0) => self.issue_lint(cx, arg, |_| {}),
             Some(_) => {},
         }
     }
@@ -316,13 +584,14 @@ impl ArithmeticSideEffects {
         if Self::literal_integer(cx, actual_un_expr).is_some() {
             return;
         }
-        self.issue_lint(cx, expr);
+        self.issue_lint(cx, expr, |_| {});
     }
 
     fn should_skip_expr<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &hir::Expr<'tcx>) -> bool {
         is_lint_allowed(cx, ARITHMETIC_SIDE_EFFECTS, expr.hir_id)
             || self.expr_span.is_some()
             || self.const_span.map_or(false, |sp| sp.contains(expr.span))
+            || (self.allow_in_tests && is_in_test(cx.tcx, expr.hir_id))
     }
 }
 
@@ -349,8 +618,9 @@ impl<'tcx> LateLintPass<'tcx> for ArithmeticSideEffects {
         let body_owner = cx.tcx.hir().body_owner(body.id());
         let body_owner_def_id = cx.tcx.hir().body_owner_def_id(body.id());
 
-        let body_owner_kind = cx.tcx.hir().body_owner_kind(body_owner_def_id);
-        if let hir::BodyOwnerKind::Const { .. } | hir::BodyOwnerKind::Static(_) = body_owner_kind {
+        // Overflows in constants, statics, `const fn`s and inline `const` blocks are evaluated or
+        // checked at compile time.
+        if cx.tcx.hir().body_const_context(body_owner_def_id).is_some() {
             let body_span = cx.tcx.hir().span_with_body(body_owner);
             if let Some(span) = self.const_span
                 && span.contains(body_span)
@@ -378,3 +648,15 @@ impl<'tcx> LateLintPass<'tcx> for ArithmeticSideEffects {
         }
     }
 }
+
+fn bin_op_kind(op: ArithmeticOperator) -> hir::BinOpKind {
+    match op {
+        ArithmeticOperator::Add => hir::BinOpKind::Add,
+        ArithmeticOperator::Sub => hir::BinOpKind::Sub,
+        ArithmeticOperator::Mul => hir::BinOpKind::Mul,
+        ArithmeticOperator::Div => hir::BinOpKind::Div,
+        ArithmeticOperator::Rem => hir::BinOpKind::Rem,
+        ArithmeticOperator::Shl => hir::BinOpKind::Shl,
+        ArithmeticOperator::Shr => hir::BinOpKind::Shr,
+    }
+}
@@ -0,0 +1,178 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{higher, is_else_clause, is_trait_method, path_def_id, peel_blocks, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{sym, Symbol};
+
+use super::MANUAL_ABS_DIFF;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: &Msrv) {
+    let Some((a, b, widened_ty)) = if_else_difference(cx, expr)
+        .or_else(|| max_min_difference(cx, expr))
+        .map(|(a, b)| (a, b, None))
+        .or_else(|| widened_abs_difference(cx, expr))
+    else {
+        return;
+    };
+    if !msrv.meets(msrvs::ABS_DIFF) {
+        return;
+    }
+
+    let mut applicability = Applicability::MachineApplicable;
+    let a = Sugg::hir_with_applicability(cx, a, "..", &mut applicability).maybe_par();
+    let b = snippet_with_applicability(cx, b.span, "..", &mut applicability);
+    let sugg = match widened_ty {
+        Some(ty) => format!("{ty}::from({a}.abs_diff({b}))"),
+        None => format!("{a}.abs_diff({b})"),
+    };
+    span_lint_and_sugg(
+        cx,
+        MANUAL_ABS_DIFF,
+        expr.span,
+        "manual implementation of `abs_diff`",
+        "use `abs_diff` instead",
+        sugg,
+        applicability,
+    );
+}
+
+/// Matches `if a > b { a - b } else { b - a }`, also with `>=`, `<` or `<=`
+fn if_else_difference<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<(&'a Expr<'a>, &'a Expr<'a>)> {
+    let higher::If {
+        cond,
+        then,
+        r#else: Some(els),
+    } = higher::If::hir(expr)?
+    else {
+        return None;
+    };
+    let ExprKind::Binary(op, left, right) = cond.kind else {
+        return None;
+    };
+    let (big, small) = match op.node {
+        BinOpKind::Gt | BinOpKind::Ge => (left, right),
+        BinOpKind::Lt | BinOpKind::Le => (right, left),
+        _ => return None,
+    };
+    // The method call can't replace the `if` of an `else if`
+    if !cond.span.from_expansion()
+        && !is_else_clause(cx.tcx, expr)
+        && is_unsigned_difference(cx, peel_blocks(then), big, small)
+        && is_unsigned_difference(cx, peel_blocks(els), small, big)
+    {
+        Some((big, small))
+    } else {
+        None
+    }
+}
+
+/// Matches `max(a, b) - min(a, b)` and `a.max(b) - a.min(b)`, with the operands of `min` in
+/// either order
+fn max_min_difference<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<(&'a Expr<'a>, &'a Expr<'a>)> {
+    if let ExprKind::Binary(op, max, min) = expr.kind
+        && op.node == BinOpKind::Sub
+        && !expr.span.from_expansion()
+        && matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Uint(_))
+        && let Some((sym::max, a, b)) = min_or_max(cx, max)
+        && let Some((sym::min, c, d)) = min_or_max(cx, min)
+        && (eq_side_effect_free(cx, a, c) && eq_side_effect_free(cx, b, d)
+            || eq_side_effect_free(cx, a, d) && eq_side_effect_free(cx, b, c))
+    {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+/// Matches `(a as i64 - b as i64).abs() as u64`, where the subtraction is done in a signed type
+/// wider than the one of `a` and `b`. Also returns the type the result needs to be converted
+/// to, if it's wider than the one returned by `abs_diff`.
+fn widened_abs_difference<'a, 'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'a Expr<'a>,
+) -> Option<(&'a Expr<'a>, &'a Expr<'a>, Option<Ty<'tcx>>)> {
+    let ExprKind::Cast(abs, _) = expr.kind else {
+        return None;
+    };
+    let ExprKind::MethodCall(path, difference, [], _) = abs.kind else {
+        return None;
+    };
+    let ExprKind::Binary(op, left, right) = difference.kind else {
+        return None;
+    };
+    let (ExprKind::Cast(a, _), ExprKind::Cast(b, _)) = (left.kind, right.kind) else {
+        return None;
+    };
+    if path.ident.name != sym::abs || op.node != BinOpKind::Sub || expr.span.from_expansion() {
+        return None;
+    }
+
+    let typeck = cx.typeck_results();
+    let operand_ty = typeck.expr_ty(a);
+    let diff_ty = match *operand_ty.kind() {
+        ty::Uint(_) => operand_ty,
+        ty::Int(int_ty) => Ty::new_uint(cx.tcx, int_ty.to_unsigned()),
+        _ => return None,
+    };
+    let widened_ty = typeck.expr_ty(difference);
+    let result_ty = typeck.expr_ty(expr);
+    if typeck.expr_ty(b) != operand_ty
+        || !matches!(widened_ty.kind(), ty::Int(_))
+        || bits(cx, widened_ty)? <= bits(cx, operand_ty)?
+        || !matches!(result_ty.kind(), ty::Uint(_))
+    {
+        return None;
+    }
+    if result_ty == diff_ty {
+        Some((a, b, None))
+    // `From` isn't implemented between `usize` or `isize` and the fixed size integers
+    } else if !diff_ty.is_ptr_sized_integral()
+        && !result_ty.is_ptr_sized_integral()
+        && bits(cx, result_ty)? > bits(cx, diff_ty)?
+    {
+        Some((a, b, Some(result_ty)))
+    } else {
+        None
+    }
+}
+
+/// Checks if `expr` is `left - right` on unsigned integers
+fn is_unsigned_difference(cx: &LateContext<'_>, expr: &Expr<'_>, left: &Expr<'_>, right: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(op, l, r) = expr.kind
+        && op.node == BinOpKind::Sub
+        && !expr.span.from_expansion()
+        && matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Uint(_))
+    {
+        eq_side_effect_free(cx, l, left) && eq_side_effect_free(cx, r, right)
+    } else {
+        false
+    }
+}
+
+/// Returns the operands of `max(a, b)`, `a.max(b)` or the `min` counterparts, along with
+/// whether it's `max` or `min`
+fn min_or_max<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<(Symbol, &'a Expr<'a>, &'a Expr<'a>)> {
+    match expr.kind {
+        ExprKind::Call(func, [a, b]) => match cx.tcx.get_diagnostic_name(path_def_id(cx, func)?)? {
+            sym::cmp_max => Some((sym::max, a, b)),
+            sym::cmp_min => Some((sym::min, a, b)),
+            _ => None,
+        },
+        ExprKind::MethodCall(path, a, [b], _) if is_trait_method(cx, expr, sym::Ord) => Some((path.ident.name, a, b)),
+        _ => None,
+    }
+}
+
+fn eq_side_effect_free(cx: &LateContext<'_>, left: &Expr<'_>, right: &Expr<'_>) -> bool {
+    SpanlessEq::new(cx).deny_side_effects().eq_expr(left, right)
+}
+
+fn bits<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<u64> {
+    cx.layout_of(ty).ok().map(|layout| layout.size.bits())
+}
@@ -0,0 +1,125 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{higher, is_else_clause, is_res_lang_ctor, path_res, peel_blocks, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+use super::{MANUAL_CHECKED_SUB, MANUAL_SATURATING_SUB};
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    let Some(higher::If {
+        cond,
+        then,
+        r#else: Some(els),
+    }) = higher::If::hir(expr)
+    else {
+        return;
+    };
+    let ExprKind::Binary(cmp_op, cmp_left, cmp_right) = cond.kind else {
+        return;
+    };
+    // The method call can't replace the `if` of an `else if`
+    if cond.span.from_expansion() || is_else_clause(cx.tcx, expr) {
+        return;
+    }
+
+    // Try both `if a >= b { <sub> } else { <fallback> }` and `if a < b { <fallback> } else { <sub> }`.
+    for (sub_branch, fallback, negated) in [(then, els, false), (els, then, true)] {
+        let Some((strict, big, small)) = guarded_relation(cmp_op.node, cmp_left, cmp_right, negated) else {
+            continue;
+        };
+        let sub_branch = peel_blocks(sub_branch);
+        let fallback = peel_blocks(fallback);
+
+        let (lint, method, sub) = if let ExprKind::Call(callee, [sub]) = sub_branch.kind
+            && is_res_lang_ctor(cx, path_res(cx, callee), OptionSome)
+            && is_res_lang_ctor(cx, path_res(cx, fallback), OptionNone)
+            // `if a > b { Some(a - b) } else { None }` returns `None` when `a == b`.
+            && !strict
+        {
+            (MANUAL_CHECKED_SUB, "checked_sub", sub)
+        } else if matches!(constant(cx, cx.typeck_results(), fallback), Some(Constant::Int(0))) {
+            (MANUAL_SATURATING_SUB, "saturating_sub", sub_branch)
+        } else {
+            continue;
+        };
+
+        if let ExprKind::Binary(sub_op, a, b) = sub.kind
+            && sub_op.node == BinOpKind::Sub
+            && !sub.span.from_expansion()
+            // For signed integers neither method matches the guarded subtraction: `checked_sub` and
+            // `saturating_sub` return negative differences instead of `None` or zero.
+            && matches!(cx.typeck_results().expr_ty(sub).kind(), ty::Uint(_))
+            && eq_side_effect_free(cx, a, big)
+            && eq_side_effect_free(cx, b, small)
+        {
+            span_lint_and_then(
+                cx,
+                lint,
+                expr.span,
+                format!("manual implementation of `{method}`"),
+                |diag| {
+                    let mut applicability = if is_simple_place(a) && is_simple_place(b) {
+                        Applicability::MachineApplicable
+                    } else {
+                        Applicability::MaybeIncorrect
+                    };
+                    let a = Sugg::hir_with_applicability(cx, a, "..", &mut applicability).maybe_par();
+                    let b = snippet_with_applicability(cx, b.span, "..", &mut applicability);
+                    diag.span_suggestion(
+                        expr.span,
+                        format!("use `{method}` instead"),
+                        format!("{a}.{method}({b})"),
+                        applicability,
+                    );
+                },
+            );
+            return;
+        }
+    }
+}
+
+/// Returns the relation `big >= small` (or `big > small` if `strict`) that holds when the
+/// comparison `left <op> right` evaluates to `!negated`.
+fn guarded_relation<'tcx>(
+    op: BinOpKind,
+    left: &'tcx Expr<'tcx>,
+    right: &'tcx Expr<'tcx>,
+    negated: bool,
+) -> Option<(bool, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let op = if negated {
+        match op {
+            BinOpKind::Lt => BinOpKind::Ge,
+            BinOpKind::Le => BinOpKind::Gt,
+            BinOpKind::Gt => BinOpKind::Le,
+            BinOpKind::Ge => BinOpKind::Lt,
+            _ => return None,
+        }
+    } else {
+        op
+    };
+    match op {
+        BinOpKind::Ge => Some((false, left, right)),
+        BinOpKind::Gt => Some((true, left, right)),
+        BinOpKind::Le => Some((false, right, left)),
+        BinOpKind::Lt => Some((true, right, left)),
+        _ => None,
+    }
+}
+
+fn eq_side_effect_free(cx: &LateContext<'_>, left: &Expr<'_>, right: &Expr<'_>) -> bool {
+    SpanlessEq::new(cx).deny_side_effects().eq_expr(left, right)
+}
+
+fn is_simple_place(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Path(_) | ExprKind::Lit(_) => true,
+        ExprKind::Field(inner, _) | ExprKind::Unary(UnOp::Deref, inner) => is_simple_place(inner),
+        _ => false,
+    }
+}
@@ -0,0 +1,140 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{higher, is_integer_const, peel_blocks, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+use super::MANUAL_DIV_CEIL;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'_>,
+    right: &'tcx Expr<'_>,
+    msrv: &Msrv,
+) {
+    // `div_ceil` is only stable for unsigned integers, and rounds differently
+    // than these patterns for negative operands.
+    let ty = cx.typeck_results().expr_ty(expr);
+    if !matches!(ty.kind(), ty::Uint(_)) {
+        return;
+    }
+
+    let (x, y, can_overflow) = if let Some((x, y)) = offset_quotient(cx, op, left, right) {
+        (x, y, true)
+    } else if let Some((x, y)) = quotient_plus_remainder_check(cx, op, left, right) {
+        (x, y, false)
+    } else {
+        return;
+    };
+
+    if cx.typeck_results().expr_ty(x) != ty || cx.typeck_results().expr_ty(y) != ty || !msrv.meets(msrvs::DIV_CEIL) {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        MANUAL_DIV_CEIL,
+        expr.span,
+        "manual implementation of `.div_ceil()`",
+        |diag| {
+            let mut applicability = Applicability::MachineApplicable;
+            let x = Sugg::hir_with_applicability(cx, x, "..", &mut applicability).maybe_par();
+            let y = snippet_with_applicability(cx, y.span, "..", &mut applicability);
+            diag.span_suggestion(expr.span, "consider using", format!("{x}.div_ceil({y})"), applicability);
+            if can_overflow {
+                diag.note("`div_ceil` doesn't overflow for large operands, unlike the original expression");
+            }
+        },
+    );
+}
+
+/// Matches `(x + y - 1) / y` and `(x + (y - 1)) / y`, including the commuted additions
+fn offset_quotient<'tcx>(
+    cx: &LateContext<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'tcx>,
+    right: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if op != BinOpKind::Div || left.span.from_expansion() {
+        return None;
+    }
+    let y = right;
+    let x = match left.kind {
+        // `x + y - 1`
+        ExprKind::Binary(sub_op, sum, one)
+            if sub_op.node == BinOpKind::Sub
+                && is_integer_const(cx, one, 1)
+                && let ExprKind::Binary(add_op, a, b) = sum.kind
+                && add_op.node == BinOpKind::Add
+                && !sum.span.from_expansion() =>
+        {
+            [(a, b), (b, a)]
+                .into_iter()
+                .find_map(|(x, y2)| eq_side_effect_free(cx, y, y2).then_some(x))?
+        },
+        // `x + (y - 1)`
+        ExprKind::Binary(add_op, a, b) if add_op.node == BinOpKind::Add => [(a, b), (b, a)]
+            .into_iter()
+            .find_map(|(x, y_minus_one)| {
+                if let ExprKind::Binary(sub_op, y2, one) = y_minus_one.kind
+                    && sub_op.node == BinOpKind::Sub
+                    && is_integer_const(cx, one, 1)
+                    && !y_minus_one.span.from_expansion()
+                    && eq_side_effect_free(cx, y, y2)
+                {
+                    Some(x)
+                } else {
+                    None
+                }
+            })?,
+        _ => return None,
+    };
+    Some((x, y))
+}
+
+/// Matches `x / y + if x % y != 0 { 1 } else { 0 }` and the commuted addition
+fn quotient_plus_remainder_check<'tcx>(
+    cx: &LateContext<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'tcx>,
+    right: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if op != BinOpKind::Add {
+        return None;
+    }
+    [(left, right), (right, left)].into_iter().find_map(|(quotient, check)| {
+        if let ExprKind::Binary(div_op, x, y) = quotient.kind
+            && div_op.node == BinOpKind::Div
+            && !quotient.span.from_expansion()
+            && let Some(higher::If {
+                cond,
+                then,
+                r#else: Some(els),
+            }) = higher::If::hir(check)
+            && !check.span.from_expansion()
+            && let ExprKind::Binary(ne_op, rem, zero) = cond.kind
+            && ne_op.node == BinOpKind::Ne
+            && is_integer_const(cx, zero, 0)
+            && let ExprKind::Binary(rem_op, x2, y2) = rem.kind
+            && rem_op.node == BinOpKind::Rem
+            && is_integer_const(cx, peel_blocks(then), 1)
+            && is_integer_const(cx, peel_blocks(els), 0)
+            && eq_side_effect_free(cx, x, x2)
+            && eq_side_effect_free(cx, y, y2)
+        {
+            Some((x, y))
+        } else {
+            None
+        }
+    })
+}
+
+fn eq_side_effect_free(cx: &LateContext<'_>, left: &Expr<'_>, right: &Expr<'_>) -> bool {
+    SpanlessEq::new(cx).deny_side_effects().eq_expr(left, right)
+}
@@ -0,0 +1,131 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::SpanlessEq;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, FloatTy};
+
+use super::MANUAL_MIDPOINT;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'_>,
+    right: &'tcx Expr<'_>,
+    msrv: &Msrv,
+) {
+    let ty = cx.typeck_results().expr_ty(expr);
+    let required_msrv = match ty.kind() {
+        ty::Uint(_) | ty::Float(FloatTy::F32 | FloatTy::F64) => msrvs::UINT_FLOAT_MIDPOINT,
+        ty::Int(_) => msrvs::INT_MIDPOINT,
+        _ => return,
+    };
+
+    let (a, b, is_sum) = if let Some((a, b)) = halved_sum(cx, op, left, right) {
+        (a, b, true)
+    } else if let Some((a, b)) = offset_half_difference(cx, op, left, right)
+        // For signed integers `a + (b - a) / 2` rounds towards `a`, unlike `midpoint`.
+        && !ty.is_signed()
+    {
+        (a, b, false)
+    } else {
+        return;
+    };
+
+    if cx.typeck_results().expr_ty(a) != ty || cx.typeck_results().expr_ty(b) != ty || !msrv.meets_explicitly(required_msrv) {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        MANUAL_MIDPOINT,
+        expr.span,
+        format!("manual implementation of `{ty}::midpoint`"),
+        |diag| {
+            // `a + (b - a) / 2` overflows when `b` is less than `a`, which `midpoint` doesn't
+            let mut applicability = if is_sum && is_side_effect_free(a) && is_side_effect_free(b) {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let a = snippet_with_applicability(cx, a.span, "..", &mut applicability);
+            let b = snippet_with_applicability(cx, b.span, "..", &mut applicability);
+            diag.span_suggestion(
+                expr.span,
+                "use `midpoint` instead",
+                format!("{ty}::midpoint({a}, {b})"),
+                applicability,
+            );
+            if is_sum {
+                diag.note("`midpoint` doesn't overflow for large operands, unlike the original expression");
+            }
+        },
+    );
+}
+
+/// Matches `(a + b) / 2`
+fn halved_sum<'tcx>(
+    cx: &LateContext<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'tcx>,
+    right: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if op == BinOpKind::Div
+        && is_two(cx, right)
+        && let ExprKind::Binary(sum_op, a, b) = left.kind
+        && sum_op.node == BinOpKind::Add
+        && !left.span.from_expansion()
+    {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+/// Matches `a + (b - a) / 2` and `(b - a) / 2 + a`
+fn offset_half_difference<'tcx>(
+    cx: &LateContext<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'tcx>,
+    right: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if op != BinOpKind::Add {
+        return None;
+    }
+    [(left, right), (right, left)].into_iter().find_map(|(a, half)| {
+        if let ExprKind::Binary(div_op, difference, two) = half.kind
+            && div_op.node == BinOpKind::Div
+            && is_two(cx, two)
+            && let ExprKind::Binary(sub_op, b, a2) = difference.kind
+            && sub_op.node == BinOpKind::Sub
+            && !half.span.from_expansion()
+            && !difference.span.from_expansion()
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(a, a2)
+        {
+            Some((a, b))
+        } else {
+            None
+        }
+    })
+}
+
+fn is_two(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(
+        constant(cx, cx.typeck_results(), expr),
+        Some(Constant::Int(2) | Constant::F32(2.0) | Constant::F64(2.0))
+    )
+}
+
+fn is_side_effect_free(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Path(_) | ExprKind::Lit(_) => true,
+        ExprKind::Field(inner, _) | ExprKind::Unary(UnOp::Deref | UnOp::Neg, inner) | ExprKind::AddrOf(_, _, inner) => {
+            is_side_effect_free(inner)
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,113 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::SpanlessEq;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+
+use super::MANUAL_ROTATE;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'_>,
+    right: &'tcx Expr<'_>,
+) {
+    if op != BinOpKind::BitOr {
+        return;
+    }
+    let (Some((shl_x, shl_amount)), Some((shr_x, shr_amount))) = (
+        shift(BinOpKind::Shl, left).or_else(|| shift(BinOpKind::Shl, right)),
+        shift(BinOpKind::Shr, right).or_else(|| shift(BinOpKind::Shr, left)),
+    ) else {
+        return;
+    };
+    let ty = cx.typeck_results().expr_ty(shl_x);
+    // `>>` on signed integers keeps the sign bit, so it's not a rotation
+    if !matches!(ty.kind(), ty::Uint(_))
+        || cx.typeck_results().expr_ty(expr) != ty
+        || !SpanlessEq::new(cx).deny_side_effects().eq_expr(shl_x, shr_x)
+    {
+        return;
+    }
+    let Some(bits) = bits(cx, ty) else {
+        return;
+    };
+
+    let mut applicability = Applicability::MachineApplicable;
+    let (method, amount) = if let (Some(shl_bits), Some(shr_bits)) =
+        (constant_int(cx, shl_amount), constant_int(cx, shr_amount))
+    {
+        if shl_bits == 0 || shr_bits == 0 || shl_bits.checked_add(shr_bits) != Some(bits) {
+            return;
+        }
+        ("rotate_left", shl_bits.to_string())
+    } else if let Some(amount) = complemented_amount(cx, shr_amount, shl_amount, bits) {
+        ("rotate_left", snippet_with_applicability(cx, amount.span, "..", &mut applicability).into_owned())
+    } else if let Some(amount) = complemented_amount(cx, shl_amount, shr_amount, bits) {
+        ("rotate_right", snippet_with_applicability(cx, amount.span, "..", &mut applicability).into_owned())
+    } else {
+        return;
+    };
+    let x = Sugg::hir_with_applicability(cx, shl_x, "..", &mut applicability).maybe_par();
+
+    span_lint_and_sugg(
+        cx,
+        MANUAL_ROTATE,
+        expr.span,
+        format!("manual implementation of `{method}`"),
+        format!("use `{method}` instead"),
+        format!("{x}.{method}({amount})"),
+        applicability,
+    );
+}
+
+/// Returns the operands of `x << amount` or `x >> amount`, depending on `op`
+fn shift<'a>(op: BinOpKind, expr: &'a Expr<'a>) -> Option<(&'a Expr<'a>, &'a Expr<'a>)> {
+    if let ExprKind::Binary(shift_op, x, amount) = expr.kind
+        && shift_op.node == op
+        && !expr.span.from_expansion()
+    {
+        Some((x, amount))
+    } else {
+        None
+    }
+}
+
+/// If `complement` is `BITS - amount`, where `BITS` is the bit width of the rotated type, returns
+/// `amount`, which is passed on to the rotate method
+fn complemented_amount<'a>(
+    cx: &LateContext<'_>,
+    complement: &Expr<'_>,
+    amount: &'a Expr<'a>,
+    bits: u128,
+) -> Option<&'a Expr<'a>> {
+    if let ExprKind::Binary(op, width, n) = complement.kind
+        && op.node == BinOpKind::Sub
+        && !complement.span.from_expansion()
+        && constant_int(cx, width) == Some(bits)
+        // `rotate_left` and `rotate_right` take the amount as a `u32`
+        && matches!(cx.typeck_results().expr_ty(amount).kind(), ty::Uint(ty::UintTy::U32))
+        && SpanlessEq::new(cx).deny_side_effects().eq_expr(n, amount)
+    {
+        Some(amount)
+    } else {
+        None
+    }
+}
+
+fn constant_int(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u128> {
+    match constant(cx, cx.typeck_results(), expr)? {
+        Constant::Int(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn bits<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<u128> {
+    cx.layout_of(ty).ok().map(|layout| layout.size.bits().into())
+}
@@ -0,0 +1,216 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{higher, is_default_equivalent, is_else_clause, is_trait_method, peel_blocks, sext, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, MatchSource, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::MANUAL_SIGNUM;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    let Some((x, is_exact)) = three_way_if(cx, expr)
+        .or_else(|| cmp_match(cx, expr))
+        .map(|x| (x, true))
+        .or_else(|| two_way_if(cx, expr).map(|x| (x, false)))
+    else {
+        return;
+    };
+    let ty = cx.typeck_results().expr_ty(x);
+    if !matches!(ty.kind(), ty::Int(_) | ty::Float(_)) || cx.typeck_results().expr_ty(expr) != ty {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        MANUAL_SIGNUM,
+        expr.span,
+        "manual implementation of `signum`",
+        |diag| {
+            let mut applicability = if is_exact && !ty.is_floating_point() {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let x = Sugg::hir_with_applicability(cx, x, "..", &mut applicability).maybe_par();
+            diag.span_suggestion(expr.span, "use `signum` instead", format!("{x}.signum()"), applicability);
+            if ty.is_floating_point() {
+                diag.note("for floats, `signum` returns `1.0` for `0.0`, `-1.0` for `-0.0` and NaN for NaN");
+            } else if !is_exact {
+                diag.note("`signum` returns `0` for zero, unlike this expression");
+            }
+        },
+    );
+}
+
+/// Matches `if x > 0 { 1 } else if x < 0 { -1 } else { 0 }`, with the comparisons in any order
+fn three_way_if<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    let higher::If {
+        cond,
+        then,
+        r#else: Some(els),
+    } = higher::If::hir(expr)?
+    else {
+        return None;
+    };
+    // The method call can't replace the `if` of an `else if`
+    if is_else_clause(cx.tcx, expr) {
+        return None;
+    }
+    let higher::If {
+        cond: else_if_cond,
+        then: else_if_then,
+        r#else: Some(else_if_else),
+    } = higher::If::hir(peel_blocks(els))?
+    else {
+        return None;
+    };
+    let (x, first) = compared_to_zero(cx, cond)?;
+    let (else_if_x, second) = compared_to_zero(cx, else_if_cond)?;
+    let (first, second) = (comparison_sign(first)?, comparison_sign(second)?);
+    // The signs are distinct values of `1`, `-1` and `0`, so the last one is what's missing
+    let last = -(first + second);
+    (first != second
+        && sign_value(cx, then)? == first
+        && sign_value(cx, else_if_then)? == second
+        && sign_value(cx, else_if_else)? == last
+        && SpanlessEq::new(cx).deny_side_effects().eq_expr(x, else_if_x))
+    .then_some(x)
+}
+
+/// Matches `if x >= 0 { 1 } else { -1 }` and similar, which only give the same result as
+/// `signum` if `x` isn't zero
+fn two_way_if<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    let higher::If {
+        cond,
+        then,
+        r#else: Some(els),
+    } = higher::If::hir(expr)?
+    else {
+        return None;
+    };
+    // The last part of a three-way comparison is handled by `three_way_if`
+    if is_else_clause(cx.tcx, expr) {
+        return None;
+    }
+    let (x, op) = compared_to_zero(cx, cond)?;
+    let (then_sign, else_sign) = match op {
+        BinOpKind::Gt | BinOpKind::Ge => (1, -1),
+        BinOpKind::Lt | BinOpKind::Le => (-1, 1),
+        _ => return None,
+    };
+    (sign_value(cx, then)? == then_sign && sign_value(cx, els)? == else_sign).then_some(x)
+}
+
+/// Matches `match x.cmp(&0) { Ordering::Greater => 1, Ordering::Less => -1, Ordering::Equal => 0 }`,
+/// with the arms in any order and optionally a wildcard as the last one
+fn cmp_match<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    let ExprKind::Match(scrutinee, arms @ [_, _, _], MatchSource::Normal) = expr.kind else {
+        return None;
+    };
+    let ExprKind::MethodCall(path, x, [zero], _) = scrutinee.kind else {
+        return None;
+    };
+    let ExprKind::AddrOf(BorrowKind::Ref, _, zero) = zero.kind else {
+        return None;
+    };
+    if path.ident.name != sym::cmp || !is_trait_method(cx, scrutinee, sym::Ord) || !is_zero(cx, zero) {
+        return None;
+    }
+
+    let mut covered = Vec::with_capacity(3);
+    for (i, arm) in arms.iter().enumerate() {
+        if arm.guard.is_some() {
+            return None;
+        }
+        let sign = match arm.pat.kind {
+            PatKind::Wild if i == 2 => -covered.iter().sum::<i8>(),
+            _ => ordering_sign(cx, arm.pat)?,
+        };
+        if covered.contains(&sign) || sign_value(cx, arm.body)? != sign {
+            return None;
+        }
+        covered.push(sign);
+    }
+    Some(x)
+}
+
+/// If `cond` compares an expression with zero, returns the expression and the comparison, as if
+/// the expression were on the left
+fn compared_to_zero<'a>(cx: &LateContext<'_>, cond: &'a Expr<'a>) -> Option<(&'a Expr<'a>, BinOpKind)> {
+    let ExprKind::Binary(op, left, right) = cond.kind else {
+        return None;
+    };
+    if cond.span.from_expansion() {
+        None
+    } else if is_zero(cx, right) {
+        Some((left, op.node))
+    } else if is_zero(cx, left) {
+        let op = match op.node {
+            BinOpKind::Lt => BinOpKind::Gt,
+            BinOpKind::Le => BinOpKind::Ge,
+            BinOpKind::Gt => BinOpKind::Lt,
+            BinOpKind::Ge => BinOpKind::Le,
+            op => op,
+        };
+        Some((right, op))
+    } else {
+        None
+    }
+}
+
+/// Returns the sign of `x` for which `x <op> 0` holds, if there is exactly one
+fn comparison_sign(op: BinOpKind) -> Option<i8> {
+    match op {
+        BinOpKind::Gt => Some(1),
+        BinOpKind::Lt => Some(-1),
+        BinOpKind::Eq => Some(0),
+        _ => None,
+    }
+}
+
+fn ordering_sign(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<i8> {
+    if let PatKind::Path(ref qpath) = pat.kind
+        && let Res::Def(DefKind::Ctor(..), ctor_id) = cx.qpath_res(qpath, pat.hir_id)
+        && let variant_id = cx.tcx.parent(ctor_id)
+        && cx.tcx.is_diagnostic_item(sym::Ordering, cx.tcx.parent(variant_id))
+    {
+        match cx.tcx.item_name(variant_id).as_str() {
+            "Greater" => Some(1),
+            "Less" => Some(-1),
+            "Equal" => Some(0),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Returns the value of `expr` if it's a constant of `1`, `-1` or `0`
+fn sign_value(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<i8> {
+    let expr = peel_blocks(expr);
+    let float_sign = |value: f64| match value {
+        1.0 => Some(1),
+        -1.0 => Some(-1),
+        0.0 => Some(0),
+        _ => None,
+    };
+    match (constant(cx, cx.typeck_results(), expr)?, cx.typeck_results().expr_ty(expr).kind()) {
+        (Constant::Int(value), &ty::Int(int_ty)) => match sext(cx.tcx, value, int_ty) {
+            1 => Some(1),
+            -1 => Some(-1),
+            0 => Some(0),
+            _ => None,
+        },
+        (Constant::F32(value), _) => float_sign(value.into()),
+        (Constant::F64(value), _) => float_sign(value),
+        _ => None,
+    }
+}
+
+fn is_zero(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    is_default_equivalent(cx, expr) || sign_value(cx, expr) == Some(0)
+}
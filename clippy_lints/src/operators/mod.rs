@@ -11,6 +11,12 @@ mod float_cmp;
 mod float_equality_without_abs;
 mod identity_op;
 mod integer_division;
+mod manual_abs_diff;
+mod manual_checked_sub;
+mod manual_div_ceil;
+mod manual_midpoint;
+mod manual_rotate;
+mod manual_signum;
 mod misrefactored_assign_op;
 mod modulo_arithmetic;
 mod modulo_one;
@@ -23,6 +29,7 @@ mod verbose_bit_mask;
 
 pub(crate) mod arithmetic_side_effects;
 
+use clippy_config::msrvs::Msrv;
 use rustc_hir::{Body, Expr, ExprKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
@@ -768,10 +775,207 @@ declare_clippy_lint! {
     "explicit self-assignment"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual computations of the midpoint of two numbers, like
+    /// `(a + b) / 2` or `a + (b - a) / 2`.
+    ///
+    /// ### Why is this bad?
+    /// `(a + b) / 2` overflows when the sum of the two operands does not fit in
+    /// the type, and `a + (b - a) / 2` is harder to read. The `midpoint` method
+    /// of the numeric types computes the same value without overflowing.
+    ///
+    /// ### Known problems
+    /// `midpoint` isn't stable yet, so it's only suggested when the MSRV is set.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn average(a: u32, b: u32) -> u32 {
+    ///     (a + b) / 2
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # #![feature(num_midpoint)]
+    /// fn average(a: u32, b: u32) -> u32 {
+    ///     u32::midpoint(a, b)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_MIDPOINT,
+    pedantic,
+    "manual implementation of `midpoint` which can overflow"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual implementations of rounding-up integer division, like
+    /// `(x + y - 1) / y`, `(x + (y - 1)) / y` or `x / y + if x % y != 0 { 1 } else { 0 }`.
+    ///
+    /// ### Why is this bad?
+    /// `div_ceil` states the intent directly, and unlike `(x + y - 1) / y` it
+    /// can't overflow when `x` is large.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn pages(bytes: u64, page_size: u64) -> u64 {
+    ///     (bytes + page_size - 1) / page_size
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn pages(bytes: u64, page_size: u64) -> u64 {
+    ///     bytes.div_ceil(page_size)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_DIV_CEIL,
+    complexity,
+    "manual implementation of `div_ceil`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for subtractions of unsigned integers guarded by a comparison of
+    /// the same operands, like `if a >= b { Some(a - b) } else { None }`.
+    ///
+    /// ### Why is this bad?
+    /// `checked_sub` does the same without repeating the operands.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn remaining(len: usize, used: usize) -> Option<usize> {
+    ///     if len >= used { Some(len - used) } else { None }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn remaining(len: usize, used: usize) -> Option<usize> {
+    ///     len.checked_sub(used)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_CHECKED_SUB,
+    complexity,
+    "manual implementation of `checked_sub`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for subtractions of unsigned integers that fall back to zero when
+    /// they would underflow, like `if a >= b { a - b } else { 0 }`.
+    ///
+    /// ### Why is this bad?
+    /// `saturating_sub` does the same without repeating the operands.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn remaining(len: usize, used: usize) -> usize {
+    ///     if len > used { len - used } else { 0 }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn remaining(len: usize, used: usize) -> usize {
+    ///     len.saturating_sub(used)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_SATURATING_SUB,
+    complexity,
+    "manual implementation of `saturating_sub`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual computations of the absolute difference of two integers, like
+    /// `if a > b { a - b } else { b - a }`, `a.max(b) - a.min(b)` or
+    /// `(a as i64 - b as i64).abs() as u64`.
+    ///
+    /// ### Why is this bad?
+    /// `abs_diff` is shorter, can't underflow, and doesn't need a cast to a wider type.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn distance(a: u32, b: u32) -> u32 {
+    ///     if a > b { a - b } else { b - a }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn distance(a: u32, b: u32) -> u32 {
+    ///     a.abs_diff(b)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_ABS_DIFF,
+    complexity,
+    "manual implementation of `abs_diff`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual implementations of `signum`, like
+    /// `if x > 0 { 1 } else if x < 0 { -1 } else { 0 }` or a `match` on `x.cmp(&0)` returning
+    /// the same values.
+    ///
+    /// ### Why is this bad?
+    /// `signum` is shorter and makes the intent clear.
+    ///
+    /// ### Known problems
+    /// For floats, `signum` returns `1.0` for `0.0` and `-1.0` for `-0.0`, so the suggestion may
+    /// change the result. `if x >= 0 { 1 } else { -1 }` is also linted, but it returns `1` for
+    /// zero where `signum` returns `0`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn sign(x: i32) -> i32 {
+    ///     if x > 0 { 1 } else if x < 0 { -1 } else { 0 }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn sign(x: i32) -> i32 {
+    ///     x.signum()
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_SIGNUM,
+    complexity,
+    "manual implementation of `signum`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for bit rotations written with shifts, like `(x << n) | (x >> (u32::BITS - n))`.
+    ///
+    /// ### Why is this bad?
+    /// `rotate_left` and `rotate_right` are clearer, and unlike the shifts they don't panic when
+    /// `n` is zero and the complement is the bit width of the type.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn rotate(x: u32, n: u32) -> u32 {
+    ///     (x << n) | (x >> (32 - n))
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn rotate(x: u32, n: u32) -> u32 {
+    ///     x.rotate_left(n)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_ROTATE,
+    style,
+    "manual implementation of `rotate_left` or `rotate_right`"
+}
+
 pub struct Operators {
     arithmetic_context: numeric_arithmetic::Context,
     verbose_bit_mask_threshold: u64,
     modulo_arithmetic_allow_comparison_to_zero: bool,
+    msrv: Msrv,
 }
 impl_lint_pass!(Operators => [
     ABSURD_EXTREME_COMPARISONS,
@@ -800,13 +1004,21 @@ impl_lint_pass!(Operators => [
     NEEDLESS_BITWISE_BOOL,
     PTR_EQ,
     SELF_ASSIGNMENT,
+    MANUAL_MIDPOINT,
+    MANUAL_DIV_CEIL,
+    MANUAL_CHECKED_SUB,
+    MANUAL_SATURATING_SUB,
+    MANUAL_ABS_DIFF,
+    MANUAL_SIGNUM,
+    MANUAL_ROTATE,
 ]);
 impl Operators {
-    pub fn new(verbose_bit_mask_threshold: u64, modulo_arithmetic_allow_comparison_to_zero: bool) -> Self {
+    pub fn new(verbose_bit_mask_threshold: u64, modulo_arithmetic_allow_comparison_to_zero: bool, msrv: Msrv) -> Self {
         Self {
             arithmetic_context: numeric_arithmetic::Context::default(),
             verbose_bit_mask_threshold,
             modulo_arithmetic_allow_comparison_to_zero,
+            msrv,
         }
     }
 }
@@ -825,6 +1037,10 @@ impl<'tcx> LateLintPass<'tcx> for Operators {
                     identity_op::check(cx, e, op.node, lhs, rhs);
                     needless_bitwise_bool::check(cx, e, op.node, lhs, rhs);
                     ptr_eq::check(cx, e, op.node, lhs, rhs);
+                    manual_midpoint::check(cx, e, op.node, lhs, rhs, &self.msrv);
+                    manual_div_ceil::check(cx, e, op.node, lhs, rhs, &self.msrv);
+                    manual_abs_diff::check(cx, e, &self.msrv);
+                    manual_rotate::check(cx, e, op.node, lhs, rhs);
                 }
                 self.arithmetic_context.check_binary(cx, e, op.node, lhs, rhs);
                 bit_mask::check(cx, e, op.node, lhs, rhs);
@@ -860,6 +1076,23 @@ impl<'tcx> LateLintPass<'tcx> for Operators {
                     self.arithmetic_context.check_negate(cx, e, arg);
                 }
             },
+            ExprKind::If(..) => {
+                if !e.span.from_expansion() {
+                    manual_checked_sub::check(cx, e);
+                    manual_abs_diff::check(cx, e, &self.msrv);
+                    manual_signum::check(cx, e);
+                }
+            },
+            ExprKind::Match(..) => {
+                if !e.span.from_expansion() {
+                    manual_signum::check(cx, e);
+                }
+            },
+            ExprKind::Cast(..) => {
+                if !e.span.from_expansion() {
+                    manual_abs_diff::check(cx, e, &self.msrv);
+                }
+            },
             _ => (),
         }
     }
@@ -875,6 +1108,8 @@ impl<'tcx> LateLintPass<'tcx> for Operators {
     fn check_body_post(&mut self, cx: &LateContext<'tcx>, b: &Body<'_>) {
         self.arithmetic_context.body_post(cx, b);
     }
+
+    extract_msrv_attr!(LateContext);
 }
 
 fn macro_with_not_op(e: &Expr<'_>) -> bool {
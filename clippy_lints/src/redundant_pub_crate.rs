@@ -82,17 +82,12 @@ impl<'tcx> LateLintPass<'tcx> for RedundantPubCrate {
 }
 
 fn is_not_macro_export<'tcx>(item: &'tcx Item<'tcx>) -> bool {
-    if let ItemKind::Use(path, _) = item.kind {
-        if path
+    match item.kind {
+        ItemKind::Use(path, _) => !path
             .res
             .iter()
-            .all(|res| matches!(res, Res::Def(DefKind::Macro(MacroKind::Bang), _)))
-        {
-            return false;
-        }
-    } else if let ItemKind::Macro(..) = item.kind {
-        return false;
+            .all(|res| matches!(res, Res::Def(DefKind::Macro(MacroKind::Bang), _))),
+        ItemKind::Macro(..) => false,
+        _ => true,
     }
-
-    true
 }
@@ -0,0 +1,206 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{is_copy, is_type_diagnostic_item};
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{higher, path_to_local, peel_hir_expr_refs, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, HirId, Node, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if !set.contains(&value) { set.insert(value); .. }` on a `HashSet` or a
+    /// `BTreeSet`.
+    ///
+    /// ### Why is this bad?
+    /// `insert` returns whether the value was newly inserted, so checking `contains` first
+    /// looks the value up twice.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::HashSet;
+    /// # let mut seen = HashSet::new();
+    /// # let value = 1;
+    /// if !seen.contains(&value) {
+    ///     seen.insert(value);
+    ///     println!("{value} is new");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::HashSet;
+    /// # let mut seen = HashSet::new();
+    /// # let value = 1;
+    /// if seen.insert(value) {
+    ///     println!("{value} is new");
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub SET_CONTAINS_OR_INSERT,
+    perf,
+    "calling `contains` on a set before inserting the same value"
+}
+
+declare_lint_pass!(SetContainsOrInsert => [SET_CONTAINS_OR_INSERT]);
+
+impl<'tcx> LateLintPass<'tcx> for SetContainsOrInsert {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let Some(higher::If { cond, then, r#else }) = higher::If::hir(expr) else {
+            return;
+        };
+        let (contains, negated) = match cond.kind {
+            ExprKind::Unary(UnOp::Not, inner) => (inner, true),
+            _ => (cond, false),
+        };
+        // `insert` has to happen when the set didn't contain the value
+        let branch = if negated { Some(then) } else { r#else };
+        if let ExprKind::MethodCall(path, set, [value], _) = contains.kind
+            && path.ident.name.as_str() == "contains"
+            && !contains.span.from_expansion()
+            && let Some(set_name) = set_type(cx, set)
+            && let Some(ExprKind::Block(block, _)) = branch.map(|branch| &branch.kind)
+            && let Some((index, insert, is_cloned)) = find_insert(cx, block, set, value)
+        {
+            span_lint_and_then(
+                cx,
+                SET_CONTAINS_OR_INSERT,
+                contains.span,
+                format!("usage of `{set_name}::insert` after `{set_name}::contains`"),
+                |diag| {
+                    diag.span_note(insert.span, "the value is inserted here");
+                    let help = format!("use the return value of `{set_name}::insert` instead");
+                    // The value is now moved even if the set already contains it
+                    let other_branch = if negated { r#else } else { Some(then) };
+                    let mut applicability = if can_move_value(cx, insert, other_branch) {
+                        Applicability::MachineApplicable
+                    } else {
+                        Applicability::MaybeIncorrect
+                    };
+                    // The `insert` can only replace the condition if nothing comes before it
+                    if index == 0 && !is_cloned {
+                        // The rest of the branch is left over when the `insert` is removed
+                        if let Some(removal) = removal_span(block, index) {
+                            let insert = snippet_with_applicability(cx, insert.span, "..", &mut applicability);
+                            let not = if negated { "" } else { "!" };
+                            diag.multipart_suggestion(
+                                help,
+                                vec![(cond.span, format!("{not}{insert}")), (removal, String::new())],
+                                applicability,
+                            );
+                            return;
+                        }
+                        // `if !set.contains(&value) { set.insert(value); }` is just the `insert`
+                        if negated
+                            && r#else.is_none()
+                            && block.stmts.len() == 1
+                            && block.expr.is_none()
+                            && let Node::Stmt(stmt) = cx.tcx.parent_hir_node(expr.hir_id)
+                        {
+                            let insert = snippet_with_applicability(cx, insert.span, "..", &mut applicability);
+                            let semi = if matches!(stmt.kind, StmtKind::Semi(_)) { "" } else { ";" };
+                            diag.span_suggestion_verbose(expr.span, help, format!("{insert}{semi}"), applicability);
+                            return;
+                        }
+                    }
+                    diag.help(help);
+                },
+            );
+        }
+    }
+}
+
+/// Returns the name of the type of `set` if it's a `HashSet` or a `BTreeSet`
+fn set_type(cx: &LateContext<'_>, set: &Expr<'_>) -> Option<&'static str> {
+    let ty = cx.typeck_results().expr_ty(set).peel_refs();
+    if is_type_diagnostic_item(cx, ty, sym::HashSet) {
+        Some("HashSet")
+    } else if is_type_diagnostic_item(cx, ty, sym::BTreeSet) {
+        Some("BTreeSet")
+    } else {
+        None
+    }
+}
+
+/// Finds a statement of `block` which inserts `value` into `set`, with `value` written the same
+/// way as the argument of `contains` without the `&`, or cloned. Returns the index of the
+/// statement, the `insert` call and whether the value is cloned.
+///
+/// The statements before the `insert` must not use the variable holding the set, so it can't be
+/// changed between the two calls.
+fn find_insert<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    set: &Expr<'_>,
+    value: &Expr<'_>,
+) -> Option<(usize, &'tcx Expr<'tcx>, bool)> {
+    let set_local = root_local(set)?;
+    let value = match value.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, inner) => inner,
+        _ => value,
+    };
+    for (index, stmt) in block.stmts.iter().enumerate() {
+        if let StmtKind::Semi(insert) = stmt.kind
+            && let ExprKind::MethodCall(path, insert_set, [insert_value], _) = insert.kind
+            && path.ident.name.as_str() == "insert"
+            && !stmt.span.from_expansion()
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(set, insert_set)
+        {
+            let mut eq = SpanlessEq::new(cx).deny_side_effects();
+            if eq.eq_expr(value, peel_hir_expr_refs(insert_value).0) {
+                return Some((index, insert, false));
+            }
+            if let ExprKind::MethodCall(path, cloned, [], _) = insert_value.kind
+                && path.ident.name == sym::clone
+                && eq.eq_expr(value, cloned)
+            {
+                return Some((index, insert, true));
+            }
+        }
+        if is_local_used(cx, stmt, set_local) {
+            return None;
+        }
+    }
+    None
+}
+
+/// Checks if the value inserted by `insert` can be moved into the condition, i.e. it's `Copy` or
+/// a local which isn't used in `other_branch` or after the `insert`.
+fn can_move_value(cx: &LateContext<'_>, insert: &Expr<'_>, other_branch: Option<&Expr<'_>>) -> bool {
+    let ExprKind::MethodCall(_, _, [value], _) = insert.kind else {
+        return false;
+    };
+    if is_copy(cx, cx.typeck_results().expr_ty(value)) {
+        return true;
+    }
+    path_to_local(value).is_some_and(|local| {
+        !local_used_after_expr(cx, local, insert)
+            && other_branch.map_or(true, |branch| !is_local_used(cx, branch, local))
+    })
+}
+
+/// Returns the span to remove the statement at `index` of `block`, up to the start of what
+/// follows it. There has to be something after it, so the branch isn't left empty.
+fn removal_span(block: &Block<'_>, index: usize) -> Option<Span> {
+    let next = block
+        .stmts
+        .get(index + 1)
+        .map(|stmt| stmt.span)
+        .or(block.expr.map(|expr| expr.span))?;
+    (!next.from_expansion()).then(|| block.stmts[index].span.until(next))
+}
+
+/// Returns the local variable `expr` is a field or dereference of
+fn root_local(mut expr: &Expr<'_>) -> Option<HirId> {
+    while let ExprKind::Field(inner, _) | ExprKind::Unary(UnOp::Deref, inner) | ExprKind::AddrOf(_, _, inner) =
+        expr.kind
+    {
+        expr = inner;
+    }
+    path_to_local(expr)
+}
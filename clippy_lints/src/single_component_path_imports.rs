@@ -3,6 +3,7 @@ use rustc_ast::node_id::{NodeId, NodeMap};
 use rustc_ast::ptr::P;
 use rustc_ast::visit::{walk_expr, Visitor};
 use rustc_ast::{Crate, Expr, ExprKind, Item, ItemKind, MacroDef, ModKind, Ty, TyKind, UseTreeKind};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_session::impl_lint_pass;
@@ -99,7 +100,7 @@ struct ImportUsageVisitor {
     //     let _ = self::std::io::stdout();
     // }
     // ```
-    imports_referenced_with_self: Vec<Symbol>,
+    imports_referenced_with_self: FxHashSet<Symbol>,
 }
 
 impl<'tcx> Visitor<'tcx> for ImportUsageVisitor {
@@ -108,7 +109,7 @@ impl<'tcx> Visitor<'tcx> for ImportUsageVisitor {
             && path.segments.len() > 1
             && path.segments[0].ident.name == kw::SelfLower
         {
-            self.imports_referenced_with_self.push(path.segments[1].ident.name);
+            self.imports_referenced_with_self.insert(path.segments[1].ident.name);
         }
         walk_expr(self, expr);
     }
@@ -118,7 +119,7 @@ impl<'tcx> Visitor<'tcx> for ImportUsageVisitor {
             && path.segments.len() > 1
             && path.segments[0].ident.name == kw::SelfLower
         {
-            self.imports_referenced_with_self.push(path.segments[1].ident.name);
+            self.imports_referenced_with_self.insert(path.segments[1].ident.name);
         }
     }
 }
@@ -132,7 +133,7 @@ impl SingleComponentPathImports {
         //
         // use self::crypto_hash::{Algorithm, Hasher};
         // ```
-        let mut imports_reused_with_self = Vec::new();
+        let mut imports_reused_with_self = FxHashSet::default();
 
         // keep track of single use statements such as `crypto_hash` in the example below
         // ```
@@ -165,7 +166,7 @@ impl SingleComponentPathImports {
     fn track_uses(
         &mut self,
         item: &Item,
-        imports_reused_with_self: &mut Vec<Symbol>,
+        imports_reused_with_self: &mut FxHashSet<Symbol>,
         single_use_usages: &mut Vec<SingleUse>,
         macros: &mut Vec<Symbol>,
     ) {
@@ -224,7 +225,7 @@ impl SingleComponentPathImports {
                     if segments[0].ident.name == kw::SelfLower {
                         // simple case such as `use self::module::SomeStruct`
                         if segments.len() > 1 {
-                            imports_reused_with_self.push(segments[1].ident.name);
+                            imports_reused_with_self.insert(segments[1].ident.name);
                             return;
                         }
 
@@ -233,7 +234,7 @@ impl SingleComponentPathImports {
                             for tree in items {
                                 let segments = &tree.0.prefix.segments;
                                 if !segments.is_empty() {
-                                    imports_reused_with_self.push(segments[0].ident.name);
+                                    imports_reused_with_self.insert(segments[0].ident.name);
                                 }
                             }
                         }
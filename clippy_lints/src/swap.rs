@@ -266,18 +266,16 @@ enum ExprOrIdent<'a> {
 }
 
 fn parse<'a, 'hir>(stmt: &'a Stmt<'hir>) -> Option<(ExprOrIdent<'hir>, &'a Expr<'hir>)> {
-    if let StmtKind::Semi(expr) = stmt.kind {
-        if let ExprKind::Assign(lhs, rhs, _) = expr.kind {
-            return Some((ExprOrIdent::Expr(lhs), rhs));
-        }
-    } else if let StmtKind::Let(expr) = stmt.kind {
-        if let Some(rhs) = expr.init {
-            if let PatKind::Binding(_, _, ident_l, _) = expr.pat.kind {
-                return Some((ExprOrIdent::Ident(ident_l), rhs));
-            }
-        }
+    match stmt.kind {
+        StmtKind::Semi(expr) if let ExprKind::Assign(lhs, rhs, _) = expr.kind => Some((ExprOrIdent::Expr(lhs), rhs)),
+        StmtKind::Let(expr)
+            if let Some(rhs) = expr.init
+                && let PatKind::Binding(_, _, ident_l, _) = expr.pat.kind =>
+        {
+            Some((ExprOrIdent::Ident(ident_l), rhs))
+        },
+        _ => None,
     }
-    None
 }
 
 /// Implementation of the xor case for `MANUAL_SWAP` lint.
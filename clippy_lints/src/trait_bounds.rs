@@ -205,7 +205,6 @@ impl<'tcx> LateLintPass<'tcx> for TraitBounds {
                 let fixed_trait_snippet = unique_traits
                     .iter()
                     .filter_map(|b| snippet_opt(cx, b.span))
-                    .collect::<Vec<_>>()
                     .join(" + ");
 
                 span_lint_and_sugg(
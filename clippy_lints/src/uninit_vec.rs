@@ -1,13 +1,19 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
 use clippy_utils::higher::{get_vec_init_kind, VecInitKind};
 use clippy_utils::ty::{is_type_diagnostic_item, is_uninit_value_valid_for_ty};
-use clippy_utils::{is_integer_literal, is_lint_allowed, path_to_local_id, peel_hir_expr_while, SpanlessEq};
-use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, PathSegment, Stmt, StmtKind};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{
+    expr_or_init, get_parent_expr, is_integer_literal, is_lint_allowed, path_to_local, path_to_local_id, peel_blocks,
+    peel_hir_expr_while, SpanlessEq,
+};
+use rustc_ast::Mutability;
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, HirId, PatKind, PathSegment, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_session::declare_lint_pass;
-use rustc_span::{sym, Span};
+use rustc_span::sym;
+use std::ops::ControlFlow;
 
 // TODO: add `ReadBuf` (RFC 2930) in "How to fix" once it is available in std
 declare_clippy_lint! {
@@ -25,7 +31,8 @@ declare_clippy_lint! {
     /// creates out-of-bound values that lead to heap memory corruption when used.
     ///
     /// ### Known Problems
-    /// This lint only checks directly adjacent statements.
+    /// A `Vec` stored in a local is followed through the rest of the enclosing block, but a
+    /// `Vec` stored elsewhere (e.g. in a struct field) is only checked in the directly following statement.
     ///
     /// ### Example
     /// ```rust,ignore
@@ -60,20 +67,16 @@ declare_clippy_lint! {
 
 declare_lint_pass!(UninitVec => [UNINIT_VEC]);
 
-// FIXME: update to a visitor-based implementation.
-// Threads: https://github.com/rust-lang/rust-clippy/pull/7682#discussion_r710998368
 impl<'tcx> LateLintPass<'tcx> for UninitVec {
     fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
         if !in_external_macro(cx.tcx.sess, block.span) {
-            for w in block.stmts.windows(2) {
-                if let StmtKind::Expr(expr) | StmtKind::Semi(expr) = w[1].kind {
-                    handle_uninit_vec_pair(cx, &w[0], expr);
+            for (i, stmt) in block.stmts.iter().enumerate() {
+                if let Some(vec) = extract_init_or_reserve_target(cx, stmt)
+                    && let Some(set_len) = find_set_len(cx, vec, &block.stmts[i + 1..], block.expr)
+                {
+                    handle_uninit_vec_pair(cx, stmt, vec, set_len);
                 }
             }
-
-            if let (Some(stmt), Some(expr)) = (block.stmts.last(), block.expr) {
-                handle_uninit_vec_pair(cx, stmt, expr);
-            }
         }
     }
 }
@@ -81,15 +84,14 @@ impl<'tcx> LateLintPass<'tcx> for UninitVec {
 fn handle_uninit_vec_pair<'tcx>(
     cx: &LateContext<'tcx>,
     maybe_init_or_reserve: &'tcx Stmt<'tcx>,
-    maybe_set_len: &'tcx Expr<'tcx>,
+    vec: TargetVec<'tcx>,
+    set_len: &'tcx Expr<'tcx>,
 ) {
-    if let Some(vec) = extract_init_or_reserve_target(cx, maybe_init_or_reserve)
-        && let Some((set_len_self, call_span)) = extract_set_len_self(cx, maybe_set_len)
-        && vec.location.eq_expr(cx, set_len_self)
+    if let ExprKind::MethodCall(_, set_len_self, ..) = set_len.kind
         && let ty::Ref(_, vec_ty, _) = cx.typeck_results().expr_ty_adjusted(set_len_self).kind()
         && let ty::Adt(_, args) = vec_ty.kind()
         // `#[allow(...)]` attribute can be set on enclosing unsafe block of `set_len()`
-        && !is_lint_allowed(cx, UNINIT_VEC, maybe_set_len.hir_id)
+        && !is_lint_allowed(cx, UNINIT_VEC, set_len.hir_id)
     {
         if vec.has_capacity() {
             // with_capacity / reserve -> set_len
@@ -101,8 +103,8 @@ fn handle_uninit_vec_pair<'tcx>(
                 span_lint_and_then(
                     cx,
                     UNINIT_VEC,
-                    vec![call_span, maybe_init_or_reserve.span],
-                    "calling `set_len()` immediately after reserving a buffer creates uninitialized values",
+                    vec![set_len.span, maybe_init_or_reserve.span],
+                    "calling `set_len()` after reserving a buffer without initializing it creates uninitialized values",
                     |diag| {
                         diag.help("initialize the buffer or wrap the content in `MaybeUninit`");
                     },
@@ -113,7 +115,7 @@ fn handle_uninit_vec_pair<'tcx>(
             span_lint(
                 cx,
                 UNINIT_VEC,
-                vec![call_span, maybe_init_or_reserve.span],
+                vec![set_len.span, maybe_init_or_reserve.span],
                 "calling `set_len()` on empty `Vec` creates out-of-bound values",
             );
         }
@@ -156,7 +158,7 @@ fn extract_init_or_reserve_target<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt
         StmtKind::Let(local) => {
             if let Some(init_expr) = local.init
                 && let PatKind::Binding(_, hir_id, _, None) = local.pat.kind
-                && let Some(init_kind) = get_vec_init_kind(cx, init_expr)
+                && let Some(init_kind) = vec_init_kind(cx, init_expr)
             {
                 return Some(TargetVec {
                     location: VecLocation::Local(hir_id),
@@ -166,7 +168,7 @@ fn extract_init_or_reserve_target<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt
         },
         StmtKind::Expr(expr) | StmtKind::Semi(expr) => match expr.kind {
             ExprKind::Assign(lhs, rhs, _span) => {
-                if let Some(init_kind) = get_vec_init_kind(cx, rhs) {
+                if let Some(init_kind) = vec_init_kind(cx, rhs) {
                     return Some(TargetVec {
                         location: VecLocation::Expr(lhs),
                         init_kind: Some(init_kind),
@@ -175,7 +177,7 @@ fn extract_init_or_reserve_target<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt
             },
             ExprKind::MethodCall(path, self_expr, [_], _) if is_reserve(cx, path, self_expr) => {
                 return Some(TargetVec {
-                    location: VecLocation::Expr(self_expr),
+                    location: path_to_local(self_expr).map_or(VecLocation::Expr(self_expr), VecLocation::Local),
                     init_kind: None,
                 });
             },
@@ -191,8 +193,82 @@ fn is_reserve(cx: &LateContext<'_>, path: &PathSegment<'_>, self_expr: &Expr<'_>
         && path.ident.name.as_str() == "reserve"
 }
 
+/// Like `get_vec_init_kind`, but also looks through a call to a closure defined in the same body,
+/// e.g. `let alloc = |n| Vec::with_capacity(n);`
+fn vec_init_kind<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<VecInitKind> {
+    get_vec_init_kind(cx, expr).or_else(|| {
+        if let ExprKind::Call(callee, _) = expr.kind
+            && let ExprKind::Closure(closure) = expr_or_init(cx, callee).kind
+        {
+            get_vec_init_kind(cx, peel_blocks(cx.tcx.hir().body(closure.body).value))
+        } else {
+            None
+        }
+    })
+}
+
+/// Finds the `set_len()` call on `vec` in the statements following its initialization or
+/// reservation. Locals are followed through the rest of the block, including nested blocks and
+/// loops, until they're used in a way that might initialize the buffer.
+fn find_set_len<'tcx>(
+    cx: &LateContext<'tcx>,
+    vec: TargetVec<'tcx>,
+    stmts: &'tcx [Stmt<'tcx>],
+    block_expr: Option<&'tcx Expr<'tcx>>,
+) -> Option<&'tcx Expr<'tcx>> {
+    let mut exprs = stmts
+        .iter()
+        .map(|stmt| match stmt.kind {
+            StmtKind::Let(local) => local.init,
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+            StmtKind::Item(_) => None,
+        })
+        .chain([block_expr]);
+
+    let VecLocation::Local(local_id) = vec.location else {
+        // only check the directly following statement for other places
+        let expr = exprs.next()??;
+        return extract_set_len_self(cx, expr)
+            .filter(|(set_len_self, _)| vec.location.eq_expr(cx, set_len_self))
+            .map(|(_, set_len)| set_len);
+    };
+
+    for expr in exprs.flatten() {
+        let found = for_each_expr(cx, expr, |e| {
+            if let Some((set_len_self, set_len)) = extract_set_len_self(cx, e)
+                && path_to_local_id(set_len_self, local_id)
+            {
+                return ControlFlow::Break(Some(set_len));
+            }
+            if path_to_local_id(e, local_id) && !is_read_only_use(cx, e) {
+                // e.g. `push()`, `spare_capacity_mut()` or passing `&mut vec` to a function
+                return ControlFlow::Break(None);
+            }
+            ControlFlow::Continue(())
+        });
+        if let Some(found) = found {
+            return found;
+        }
+    }
+    None
+}
+
+/// Checks if the use of the `Vec` can't initialize its spare capacity.
+fn is_read_only_use(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match get_parent_expr(cx, expr).map(|parent| parent.kind) {
+        Some(ExprKind::MethodCall(path, recv, [], _)) => {
+            recv.hir_id == expr.hir_id && matches!(path.ident.name.as_str(), "len" | "capacity" | "is_empty")
+        },
+        Some(ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _)) => true,
+        _ => false,
+    }
+}
+
 /// Returns self if the expression is `Vec::set_len()`
-fn extract_set_len_self<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'_>) -> Option<(&'tcx Expr<'tcx>, Span)> {
+fn extract_set_len_self<'tcx>(
+    cx: &LateContext<'_>,
+    expr: &'tcx Expr<'_>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
     // peel unsafe blocks in `unsafe { vec.set_len() }`
     let expr = peel_hir_expr_while(expr, |e| {
         if let ExprKind::Block(block, _) = e.kind {
@@ -213,7 +289,7 @@ fn extract_set_len_self<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'_>) -> Opt
                 && path.ident.name.as_str() == "set_len"
                 && !is_integer_literal(arg, 0)
             {
-                Some((self_expr, expr.span))
+                Some((self_expr, expr))
             } else {
                 None
             }
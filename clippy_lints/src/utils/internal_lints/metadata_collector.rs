@@ -751,7 +751,7 @@ fn get_lint_group(cx: &LateContext<'_>, lint_id: LintId) -> Option<String> {
             continue;
         }
 
-        if lints.iter().any(|group_lint| *group_lint == lint_id) {
+        if lints.contains(&lint_id) {
             let group = group_name.strip_prefix(CLIPPY_LINT_GROUP_PREFIX).unwrap_or(group_name);
             return Some((*group).to_string());
         }
@@ -0,0 +1,137 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{get_enclosing_loop_or_multi_call_closure, get_parent_expr, paths};
+use rustc_hir::{Expr, ExprKind, HirId, LetStmt, MatchSource, Node, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::{sym, DesugaringKind};
+use std::ops::ControlFlow;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Looks for code that spawns a process but never calls `wait()` on the child.
+    ///
+    /// ### Why is this bad?
+    /// As explained in the [standard library documentation](https://doc.rust-lang.org/stable/std/process/struct.Child.html#warning),
+    /// calling `wait()` is necessary on Unix platforms to properly release all OS resources associated with the process.
+    /// Not doing so will effectively leak process IDs and/or other limited global resources,
+    /// which can eventually lead to resource exhaustion, so it's recommended to call `wait()` in long-running applications.
+    /// Such processes are called "zombie processes".
+    ///
+    /// ### Known problems
+    /// Passing the child to a function, returning it or otherwise moving it somewhere
+    /// that can't be seen from the current body suppresses the lint.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use std::process::Command;
+    ///
+    /// let _child = Command::new("ls").spawn().expect("failed to execute child");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("ls").spawn().expect("failed to execute child");
+    /// child.wait().expect("failed to wait on child");
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub ZOMBIE_PROCESSES,
+    suspicious,
+    "not waiting on a spawned child process"
+}
+
+declare_lint_pass!(ZombieProcesses => [ZOMBIE_PROCESSES]);
+
+impl<'tcx> LateLintPass<'tcx> for ZombieProcesses {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(path, recv, [], _) = expr.kind
+            && path.ident.as_str() == "spawn"
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty_adjusted(recv).peel_refs(), sym::Command)
+            && !expr.span.from_expansion()
+        {
+            let child_expr = peel_unwraps(cx, expr);
+            if !match_type(cx, cx.typeck_results().expr_ty(child_expr), &paths::STD_PROCESS_CHILD) {
+                return;
+            }
+
+            let is_dropped = match cx.tcx.parent_hir_node(child_expr.hir_id) {
+                Node::LetStmt(LetStmt { pat, .. }) => match pat.kind {
+                    PatKind::Binding(_, local_id, _, None) => !is_waited_on_or_escapes(cx, local_id, child_expr.hir_id),
+                    PatKind::Wild => true,
+                    _ => false,
+                },
+                Node::Stmt(stmt) => matches!(stmt.kind, StmtKind::Semi(_)),
+                // e.g. `Command::new("ls").spawn().unwrap().stdout`, the child is a temporary
+                Node::Expr(parent) => matches!(parent.kind, ExprKind::Field(..)),
+                _ => false,
+            };
+
+            if is_dropped {
+                span_lint_and_then(
+                    cx,
+                    ZOMBIE_PROCESSES,
+                    expr.span,
+                    "spawned process is never `wait()`ed on",
+                    |diag| {
+                        diag.help("consider calling `.wait()`")
+                            .note("not doing so might leave behind zombie processes")
+                            .note("see https://doc.rust-lang.org/stable/std/process/struct.Child.html#warning");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Walks up from the `spawn()` call through `.unwrap()`, `.expect(..)` and `?` to the expression
+/// that yields the `Child`.
+fn peel_unwraps<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    while let Some(parent) = get_parent_expr(cx, expr) {
+        match parent.kind {
+            ExprKind::MethodCall(path, recv, ..)
+                if recv.hir_id == expr.hir_id && matches!(path.ident.name, sym::unwrap | sym::expect) => {},
+            ExprKind::Call(_, [arg])
+                if arg.hir_id == expr.hir_id && parent.span.is_desugaring(DesugaringKind::QuestionMark) => {},
+            ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_)) if scrutinee.hir_id == expr.hir_id => {},
+            _ => break,
+        }
+        expr = parent;
+    }
+    expr
+}
+
+/// Checks whether the child bound to `local_id` is waited on after `after`, or moved somewhere
+/// we can't follow it.
+fn is_waited_on_or_escapes(cx: &LateContext<'_>, local_id: HirId, after: HirId) -> bool {
+    for_each_local_use_after_expr(cx, local_id, after, |e| {
+        let found = match cx.tcx.parent_hir_node(e.hir_id) {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::MethodCall(path, recv, ..) if recv.hir_id == e.hir_id => match path.ident.as_str() {
+                    "wait" | "wait_with_output" => true,
+                    // `try_wait` only reaps the child if it's polled until it has exited
+                    "try_wait" => get_enclosing_loop_or_multi_call_closure(cx, parent).is_some(),
+                    "kill" | "id" => false,
+                    _ => true,
+                },
+                ExprKind::Field(..) => false,
+                _ => true,
+            },
+            // `let other = child;`
+            Node::LetStmt(LetStmt {
+                pat, init: Some(init), ..
+            }) if init.hir_id == e.hir_id => match pat.kind {
+                PatKind::Binding(_, other_id, _, None) => is_waited_on_or_escapes(cx, other_id, e.hir_id),
+                _ => true,
+            },
+            _ => true,
+        };
+        if found {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_break()
+}
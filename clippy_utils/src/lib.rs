@@ -1347,6 +1347,46 @@ pub fn get_parent_expr_for_hir<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> O
     }
 }
 
+/// Checks if `expr` is inside a branch of an `if` whose condition holds a guarantee about the
+/// branch, like `b <= a` for `a - b` in `if b <= a { a - b } else { 0 }`.
+///
+/// `is_guard` is called with the condition of each enclosing `if` and whether `expr` is in its
+/// `then` branch. The condition is only considered if none of the `locals` it refers to are
+/// mutated in the branch. Enclosing closures and items are not looked at.
+pub fn is_guarded_by_condition<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    locals: &[HirId],
+    mut is_guard: impl FnMut(&'tcx Expr<'tcx>, bool) -> bool,
+) -> bool {
+    let mut child_id = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::If(cond, then, els) => {
+                    let branch = if then.hir_id == child_id {
+                        Some((then, true))
+                    } else {
+                        els.filter(|els| els.hir_id == child_id).map(|els| (els, false))
+                    };
+                    if let Some((branch, is_then)) = branch
+                        && locals.iter().all(|&local| !usage::is_potentially_mutated(local, branch, cx))
+                        && is_guard(cond, is_then)
+                    {
+                        return true;
+                    }
+                },
+                ExprKind::Closure(_) => return false,
+                _ => {},
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => return false,
+            _ => {},
+        }
+        child_id = parent_id;
+    }
+    false
+}
+
 /// Gets the enclosing block, if any.
 pub fn get_enclosing_block<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> Option<&'tcx Block<'tcx>> {
     let map = &cx.tcx.hir();
@@ -2059,6 +2099,8 @@ pub fn is_must_use_func_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
 /// * `|x| { return x }`
 /// * `|x| { return x; }`
 /// * `|(x, y)| (x, y)`
+/// * `|[x, y]| [x, y]`
+/// * `|Wrapper(x)| Wrapper(x)`
 ///
 /// Consider calling [`is_expr_untyped_identity_function`] or [`is_expr_identity_function`] instead.
 fn is_body_identity_function(cx: &LateContext<'_>, func: &Body<'_>) -> bool {
@@ -2076,7 +2118,7 @@ fn is_body_identity_function(cx: &LateContext<'_>, func: &Body<'_>) -> bool {
         }
 
         match (pat.kind, expr.kind) {
-            (PatKind::Binding(_, id, _, _), _) => {
+            (PatKind::Binding(BindingMode::NONE, id, _, None), _) => {
                 path_to_local_id(expr, id) && cx.typeck_results().expr_adjustments(expr).is_empty()
             },
             (PatKind::Tuple(pats, dotdot), ExprKind::Tup(tup))
@@ -2084,6 +2126,19 @@ fn is_body_identity_function(cx: &LateContext<'_>, func: &Body<'_>) -> bool {
             {
                 pats.iter().zip(tup).all(|(pat, expr)| check_pat(cx, pat, expr))
             },
+            (PatKind::Slice(pats, None, []), ExprKind::Array(elems)) if pats.len() == elems.len() => {
+                pats.iter().zip(elems).all(|(pat, expr)| check_pat(cx, pat, expr))
+            },
+            (PatKind::TupleStruct(ref qpath, pats, dotdot), ExprKind::Call(func, args))
+                if dotdot.as_opt_usize().is_none() && pats.len() == args.len() =>
+            {
+                // The constructor has to be the same one, e.g. `|Wrapper(x)| Wrapper(x)`
+                matches!(
+                    cx.qpath_res(qpath, pat.hir_id),
+                    res @ Res::Def(DefKind::Ctor(def::CtorOf::Struct, _), _) if path_res(cx, func) == res
+                ) && cx.typeck_results().pat_ty(pat) == cx.typeck_results().expr_ty(expr)
+                    && pats.iter().zip(args).all(|(pat, expr)| check_pat(cx, pat, expr))
+            },
             _ => false,
         }
     }
@@ -3020,6 +3075,16 @@ pub fn pat_is_wild<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx PatKind<'_>, body: i
     }
 }
 
+/// Returns `true` if the pattern is a `PatWild`, or is an ident that is not locally used,
+/// regardless of its name.
+pub fn pat_is_unused<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx PatKind<'_>, body: impl Visitable<'tcx>) -> bool {
+    match *pat {
+        PatKind::Wild => true,
+        PatKind::Binding(_, id, _, None) => !visitors::is_local_used(cx, body, id),
+        _ => false,
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum RequiresSemi {
     Yes,
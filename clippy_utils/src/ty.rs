@@ -959,7 +959,7 @@ impl AdtVariantInfo {
                 }
             })
             .collect::<Vec<_>>();
-        variants_size.sort_by(|a, b| (b.size.cmp(&a.size)));
+        variants_size.sort_by_key(|v| std::cmp::Reverse(v.size));
         variants_size
     }
 }
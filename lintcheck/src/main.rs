@@ -741,10 +741,9 @@ fn lintcheck(config: LintcheckConfig) {
     let mut warnings: Vec<ClippyWarning> = vec![];
     let mut raw_ices: Vec<RustcIce> = vec![];
     for entry in clippy_entries {
-        if let ClippyCheckOutput::ClippyWarning(x) = entry {
-            warnings.push(x);
-        } else if let ClippyCheckOutput::RustcIce(x) = entry {
-            raw_ices.push(x);
+        match entry {
+            ClippyCheckOutput::ClippyWarning(x) => warnings.push(x),
+            ClippyCheckOutput::RustcIce(x) => raw_ices.push(x),
         }
     }
 
@@ -0,0 +1,30 @@
+//@revisions: default allow_in_tests
+//@[default] rustc-env:CLIPPY_CONF_DIR=tests/ui-toml/arithmetic_side_effects_allow_in_tests/default
+//@[allow_in_tests] rustc-env:CLIPPY_CONF_DIR=tests/ui-toml/arithmetic_side_effects_allow_in_tests/allow_in_tests
+//@compile-flags: --test
+#![warn(clippy::arithmetic_side_effects)]
+
+fn main() {}
+
+pub fn outside_of_tests(n: i32) -> i32 {
+    n + 1
+}
+
+#[test]
+fn lonely_test() {
+    let n = outside_of_tests(1);
+    let _ = n * 2;
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper(n: i32) -> i32 {
+        n - 1
+    }
+
+    #[test]
+    fn test_fn() {
+        let n = helper(2);
+        let _ = n / n;
+    }
+}
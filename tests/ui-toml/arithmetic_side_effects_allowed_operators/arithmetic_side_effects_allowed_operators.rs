@@ -0,0 +1,54 @@
+#![warn(clippy::arithmetic_side_effects)]
+
+use core::ops::{Add, Div, Mul, Sub};
+
+#[derive(Clone, Copy)]
+struct MyWrapper(u32);
+
+impl Add<u32> for MyWrapper {
+    type Output = MyWrapper;
+    fn add(self, other: u32) -> Self::Output {
+        MyWrapper(self.0.wrapping_add(other))
+    }
+}
+
+impl Sub<u32> for MyWrapper {
+    type Output = MyWrapper;
+    fn sub(self, other: u32) -> Self::Output {
+        MyWrapper(self.0.wrapping_sub(other))
+    }
+}
+
+impl Div<u32> for MyWrapper {
+    type Output = MyWrapper;
+    fn div(self, other: u32) -> Self::Output {
+        MyWrapper(self.0.checked_div(other).unwrap())
+    }
+}
+
+impl Mul<MyWrapper> for u32 {
+    type Output = MyWrapper;
+    fn mul(self, other: MyWrapper) -> Self::Output {
+        MyWrapper(self.wrapping_mul(other.0))
+    }
+}
+
+impl Div<MyWrapper> for u32 {
+    type Output = MyWrapper;
+    fn div(self, other: MyWrapper) -> Self::Output {
+        MyWrapper(self.checked_div(other.0).unwrap())
+    }
+}
+
+fn main() {
+    let n = MyWrapper(1);
+
+    // allowed operators
+    let _ = n + 1;
+    let _ = n - 1;
+    let _ = 2 * n;
+
+    // not allowed operators
+    let _ = n / 2;
+    let _ = 2 / n;
+}
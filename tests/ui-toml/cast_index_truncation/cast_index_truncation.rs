@@ -0,0 +1,13 @@
+#![warn(clippy::cast_index_truncation)]
+
+fn main() {
+    let values = [0_u8; 4];
+    let (n, i, wide) = (1_u64, 1_i64, 1_u128);
+
+    // `usize` is 64 bits wide on all the targets
+    let _ = values[n as usize];
+    let _ = values.get(i as usize);
+
+    let _ = values[wide as usize];
+    let _ = values.len() as u32;
+}
@@ -0,0 +1,11 @@
+#![warn(clippy::cast_lossless)]
+
+fn main() {
+    // `From<bool>` for integers is stable since 1.28
+    let _ = true as u8;
+    let _ = true as i32;
+
+    // The `From` impls between integers are older than any supported MSRV
+    let _ = 1u8 as u16;
+    let _ = 1i16 as f32;
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::linear_search_in_loop)]
+
+fn main() {
+    let small = [1, 2];
+    let large = [1, 2, 3, 4];
+    for x in 0..100 {
+        let _ = small.contains(&x);
+        let _ = large.contains(&x);
+    }
+}
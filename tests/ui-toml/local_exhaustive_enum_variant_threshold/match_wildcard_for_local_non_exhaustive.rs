@@ -0,0 +1,25 @@
+#![warn(clippy::match_wildcard_for_local_non_exhaustive)]
+#![allow(dead_code)]
+
+enum Small {
+    A,
+    B,
+}
+
+enum Large {
+    A,
+    B,
+    C,
+}
+
+fn main() {
+    match Small::A {
+        Small::A => {},
+        _ => {},
+    }
+
+    match Large::A {
+        Large::A => {},
+        _ => {},
+    }
+}
@@ -4,6 +4,7 @@
     clippy::assign_op_pattern,
     clippy::erasing_op,
     clippy::identity_op,
+    clippy::manual_saturating_sub,
     clippy::no_effect,
     clippy::op_ref,
     clippy::unnecessary_owned_empty_strings,
@@ -200,6 +201,35 @@ pub fn const_ops_should_not_trigger_the_lint() {
     let _ = const { let mut n = 1; n = -1; n = -(-1); n = -n; n };
 }
 
+pub const fn const_fn_should_not_trigger_the_lint(n: i32) -> i32 {
+    let mut m = n * 2;
+    m += n;
+    -m / n
+}
+
+pub struct ConstContexts;
+
+impl ConstContexts {
+    const ASSOC: i32 = ONE * 2 + ONE;
+    const LEN: usize = 4;
+
+    pub const fn assoc_const_fn(n: u32) -> u32 {
+        n - 1
+    }
+
+    pub fn const_contexts_should_not_trigger_the_lint<const N: usize>(n: usize) -> usize {
+        let _: [u8; Self::LEN * 2] = [0; Self::LEN * 2];
+        let _ = const { N * 2 + 1 };
+        let _ = const { [0u8; Self::LEN - 1] };
+        n
+    }
+
+    pub fn non_const_fn_should_trigger_the_lint(n: i32) -> i32 {
+        let m = n * 2;
+        const_fn_should_not_trigger_the_lint(m)
+    }
+}
+
 pub fn non_overflowing_ops_or_ops_already_handled_by_the_compiler_should_not_trigger_the_lint() {
     let mut _n = i32::MAX;
 
@@ -531,4 +561,101 @@ pub fn issue_12318() {
     one.sub_assign(1);
 }
 
+pub fn non_zero_get_divisors(x: u32, nz: NonZero<u32>, y: i32, snz: NonZero<i32>) {
+    let _ = x / nz.get();
+    let _ = x % nz.get();
+    let _ = x / NonZero::get(nz);
+    let _ = 64usize % NonZero::new(8usize).unwrap().get();
+    let divisor = nz.get();
+    let _ = x / divisor;
+    let _ = x % divisor;
+    let _ = 10i32 / snz.get();
+    let _ = -10i32 % snz.get();
+
+    // Signed divisions can still overflow with `MIN / -1`
+    let _ = i32::MIN / snz.get();
+    let _ = i32::MIN % snz.get();
+    let _ = y / snz.get();
+    let _ = y % snz.get();
+    // Not obtained from `NonZero::get`
+    let mut divisor = nz.get();
+    divisor -= 1;
+    let _ = x / divisor;
+}
+
+pub fn manually_guarded_arithmetic(a: u32, b: u32, c: i32, d: i32) {
+    let _ = if b <= a { a - b } else { 0 };
+    let _ = if a >= b { a - b } else { 0 };
+    let _ = if a < b { 0 } else { a - b };
+    let _ = if a <= u32::MAX - b { a + b } else { u32::MAX };
+    let _ = if b <= u32::MAX - a { a + b } else { u32::MAX };
+    let _ = if b != 0 { a / b } else { 0 };
+    let _ = if 0 != b { a % b } else { 0 };
+    let _ = if b == 0 { 0 } else { a / b };
+
+    // Wrong operand order
+    let _ = if a <= b { a - b } else { 0 };
+    let _ = if a != 0 { a / b } else { 0 };
+    let _ = if b < a { 0 } else { a - b };
+    // Signed integers can still overflow
+    let _ = if d <= c { c - d } else { 0 };
+    // Mutated inside the branch
+    let mut e = a;
+    if b <= e {
+        e = 0;
+        let _ = e - b;
+    }
+}
+
+pub fn shifts_by_constants(x: u8, y: u32, amount: u32) {
+    const SHIFT: u32 = 3;
+    let _ = x << 7;
+    let _ = x >> 7;
+    let _ = y << SHIFT;
+    let _ = y >> SHIFT;
+    let _ = y << 31u8;
+    let mut z = y;
+    z <<= SHIFT;
+
+    let _ = x << 8;
+    let _ = x >> 8;
+    let _ = y << amount;
+    let _ = y >> amount;
+    z <<= amount;
+}
+
+pub struct BoxedWrapping {
+    value: Box<Wrapping<u16>>,
+}
+
+pub struct DerefWrapping(Wrapping<u64>);
+
+impl core::ops::Deref for DerefWrapping {
+    type Target = Wrapping<u64>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for DerefWrapping {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+pub fn wrapping_through_pointers(by_ref: &mut Wrapping<u8>, boxed: &mut BoxedWrapping, deref: &mut DerefWrapping) {
+    *by_ref += Wrapping(1);
+    *by_ref = *by_ref * Wrapping(2);
+    let _ = &*by_ref - Wrapping(1);
+    *boxed.value += Wrapping(1);
+    let _ = *boxed.value - Wrapping(1);
+    **deref += Wrapping(1);
+    let _ = **deref * Wrapping(2);
+    let cell = std::cell::RefCell::new(Wrapping(0u32));
+    *cell.borrow_mut() += Wrapping(1);
+
+    // Division by zero still panics
+    **deref /= Wrapping(2);
+}
+
 fn main() {}
@@ -1,5 +1,5 @@
 #![warn(clippy::box_default)]
-#![allow(clippy::boxed_local, clippy::default_constructed_unit_structs)]
+#![allow(clippy::boxed_local, clippy::default_constructed_unit_structs, clippy::empty_literal_constructor)]
 
 #[derive(Default)]
 struct ImplementsDefault;
@@ -6,4 +6,7 @@ fn main() {
     let _ = s.bytes().nth(3);
     let _ = &s.bytes().nth(3).unwrap();
     let _ = s[..].bytes().nth(3);
+    let s: &str = "str";
+    let _ = s.bytes().nth(3);
+    let _ = s.bytes().nth(3).unwrap();
 }
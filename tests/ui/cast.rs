@@ -127,7 +127,7 @@ fn main() {
     (-1i8).saturating_abs() as u8;
     // abs() can return a negative value in release builds
     (i8::MIN).abs() as u8;
-    //~^ ERROR: casting `i8` to `u8` may lose the sign of the value
+    //~^ ERROR: casting the result of `i8::abs()` to `u8` may lose the sign of the value
     (-1i16).saturating_abs() as u16;
     (-1i32).saturating_abs() as u32;
     (-1i64).abs() as u64;
@@ -499,3 +499,72 @@ fn issue12721() {
     (255 % 999999u64) as u8;
     //~^ ERROR: casting `u64` to `u8` may truncate the value
 }
+
+fn bounded_casts(x: u32, y: i32) {
+    // Don't lint, the value is bounded to the range of the target type
+    x.min(255) as u8;
+    (x & 0xFF) as u8;
+    x.clamp(0, 65535) as u16;
+    (x % 256) as u8;
+    y.clamp(-128, 127) as i8;
+    (y & 0x7F) as i8;
+    (y % 128) as i8;
+    y.max(-128).min(127) as i8;
+    y.min(127).max(-128) as i8;
+
+    x.min(256) as u8;
+    //~^ ERROR: casting `u32` to `u8` may truncate the value
+    (x & 0x1FF) as u8;
+    //~^ ERROR: casting `u32` to `u8` may truncate the value
+    (x % 257) as u8;
+    //~^ ERROR: casting `u32` to `u8` may truncate the value
+    y.min(127) as i8;
+    //~^ ERROR: casting `i32` to `i8` may truncate the value
+    y.max(-128) as i8;
+    //~^ ERROR: casting `i32` to `i8` may truncate the value
+    y.clamp(-129, 127) as i8;
+    //~^ ERROR: casting `i32` to `i8` may truncate the value
+    (y % 256) as i8;
+    //~^ ERROR: casting `i32` to `i8` may truncate the value
+}
+
+fn non_negative_casts(x: i32, y: i64, mut z: i32) {
+    // Don't lint, the value can't be negative
+    x.max(0) as u32;
+    x.rem_euclid(3) as u32;
+    x.pow(2) as u32;
+    x.unsigned_abs() as u64;
+    (-1i64).abs() as u64;
+    if x >= 0 {
+        let _ = x as u32;
+    }
+    if -1 < x && y > 0 {
+        let _ = (x as u32, y as u64);
+    }
+    if x < 0 {
+    } else {
+        let _ = x as u32;
+    }
+
+    x.max(-1) as u32;
+    //~^ ERROR: casting `i32` to `u32` may lose the sign of the value
+    if x >= -1 {
+        let _ = x as u32;
+        //~^ ERROR: casting `i32` to `u32` may lose the sign of the value
+    }
+    if x >= 0 || y >= 0 {
+        let _ = x as u32;
+        //~^ ERROR: casting `i32` to `u32` may lose the sign of the value
+    }
+    if z >= 0 {
+        z -= 1;
+        let _ = z as u32;
+        //~^ ERROR: casting `i32` to `u32` may lose the sign of the value
+    }
+    x.abs() as u32;
+    //~^ ERROR: casting the result of `i32::abs()` to `u32` may lose the sign of the value
+    (x - 1).abs() as u32;
+    //~^ ERROR: casting the result of `i32::abs()` to `u32` may lose the sign of the value
+    (y - i64::from(x)).abs() as u64;
+    //~^ ERROR: casting the result of `i64::abs()` to `u64` may lose the sign of the value
+}
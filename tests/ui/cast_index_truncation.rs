@@ -0,0 +1,62 @@
+#![warn(clippy::cast_index_truncation)]
+
+fn indices(values: &mut [u8], n: u64, i: i64, wide: u128, small: u32) {
+    let _ = values[n as usize];
+    let _ = values.get(i as usize);
+    let _ = values.get_mut(wide as usize);
+    let _ = values.split_at(n as usize);
+    let _ = &values[n as usize..];
+    let _ = &values[..=n as usize];
+    for _ in 0..n as usize {}
+
+    // Not wider than the pointers of the targets
+    let _ = values[small as usize];
+    // Not used as an index
+    let _ = n as usize;
+    let _ = values.get(n as usize + 1);
+}
+
+fn lengths(values: &[u8]) {
+    let _ = values.len() as u32;
+    let _ = values.iter().count() as i32;
+
+    // Can't truncate
+    let _ = values.len() as u64;
+}
+
+fn guarded(values: &[u8], n: u64, mut m: u64) {
+    if n < values.len() as u64 {
+        let _ = values[n as usize];
+    }
+    if n <= u32::MAX as u64 {
+        let _ = values[n as usize];
+    }
+    let _ = if n > 100 { 0 } else { values[n as usize] };
+    if n < 100 && !values.is_empty() {
+        let _ = values[n as usize];
+    }
+    if values.len() <= u32::MAX as usize {
+        let _ = values.len() as u32;
+    }
+    let _ = values[(n & 0xFF) as usize];
+    let _ = values[n.min(255) as usize];
+
+    // Not checked
+    if n > 100 {
+        let _ = values[n as usize];
+    }
+    if n < 1 << 40 {
+        let _ = values[n as usize];
+    }
+    if m < values.len() as u64 {
+        m += 1;
+        let _ = values[m as usize];
+    }
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn reported_as_possible_truncation(values: &[u8], n: u64) -> u8 {
+    values[n as usize]
+}
+
+fn main() {}
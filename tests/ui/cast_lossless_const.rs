@@ -0,0 +1,36 @@
+#![warn(clippy::cast_lossless)]
+#![allow(dead_code)]
+
+// `T::from` can't be called in const contexts, so nothing is linted here
+
+const A: u32 = 1u8 as u32;
+static B: i64 = 1i32 as i64;
+const C: u8 = true as u8;
+
+const fn widen(x: u16) -> u64 {
+    x as u64
+}
+
+const fn from_bool(b: bool) -> i32 {
+    b as i32
+}
+
+struct S;
+
+impl S {
+    const D: f64 = 1.0f32 as f64;
+
+    const fn widen(x: u8) -> u16 {
+        x as u16
+    }
+}
+
+trait T {
+    const E: i16 = 1i8 as i16;
+}
+
+fn inline_const() -> u32 {
+    const { 1u8 as u32 }
+}
+
+fn main() {}
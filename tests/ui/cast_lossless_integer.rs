@@ -70,3 +70,16 @@ fn issue11458() {
     let _ = sign_cast!(x, u8, i8) as i32;
     let _ = (sign_cast!(x, u8, i8) + 1) as i32;
 }
+
+fn infer_target(x: u8) {
+    fn take_u32(_: u32) {}
+
+    // The type is declared where the value is used, `Into::into` infers it
+    let _: u32 = x as _;
+    take_u32(x as _);
+    let _: u64 = (x + 1) as _;
+
+    // `Into::into` would need an annotation
+    let _: u64 = if x > 1 { x as _ } else { 0 };
+    let _ = [x as _, 0u32];
+}
@@ -0,0 +1,62 @@
+#![warn(clippy::char_indices_as_byte_indices)]
+
+fn key(line: &str) -> &str {
+    for (i, c) in line.chars().enumerate() {
+        if c == ':' {
+            return &line[..i];
+        }
+    }
+    line
+}
+
+fn split_words(s: String) -> (String, String) {
+    for (i, c) in s.chars().enumerate() {
+        if c.is_whitespace() {
+            let (a, b) = s.split_at(i);
+            return (a.to_string(), b.to_string());
+        }
+    }
+    (s, String::new())
+}
+
+fn other_string(a: &str, b: &str) {
+    for (i, c) in a.chars().enumerate() {
+        if c == 'x' {
+            println!("{}", &b[i..]);
+        }
+    }
+}
+
+fn value(line: &str) -> Option<&str> {
+    let i = line.chars().position(|c| c == '=')?;
+    Some(&line[i + 1..])
+}
+
+fn name(s: &str) -> &str {
+    let i = s.chars().position(char::is_alphabetic).unwrap();
+    s.split_at(i).1
+}
+
+fn rest(s: &str) -> &str {
+    if let Some(i) = s.chars().position(|c| !c.is_whitespace()) {
+        return s.get(i..).unwrap();
+    }
+    ""
+}
+
+fn no_lint(s: &str, v: &[u8], out: &mut String) {
+    for (i, c) in s.chars().enumerate() {
+        println!("{i}: {c} {}", v[i]);
+    }
+    // Only used in the inserted value, not as the position
+    for (i, _) in s.chars().enumerate() {
+        out.insert(0, char::from_digit(i as u32, 10).unwrap());
+    }
+    for (i, c) in s.char_indices() {
+        println!("{}", &s[i..]);
+    }
+    let i = s.chars().position(|c| c == ' ').unwrap();
+    println!("{:?}", s.chars().nth(i));
+}
+
+fn main() {}
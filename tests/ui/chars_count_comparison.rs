@@ -0,0 +1,46 @@
+#![warn(clippy::chars_count_comparison)]
+#![allow(clippy::bytes_count_to_len, clippy::iter_count_comparisons)]
+
+const LIMIT: usize = 4;
+
+fn main() {
+    let s = "hello";
+    let string = String::from("hello");
+
+    let _ = s.chars().count() == 0;
+    //~^ ERROR: counting all `chars` to check whether a string is empty
+    let _ = 0 == s.chars().count();
+    //~^ ERROR: counting all `chars` to check whether a string is empty
+    let _ = s.chars().count() != 0;
+    //~^ ERROR: counting all `chars` to check whether a string is empty
+    let _ = string.chars().count() > 0;
+    //~^ ERROR: counting all `chars` to check whether a string is empty
+    let _ = 1 <= string.chars().count();
+    //~^ ERROR: counting all `chars` to check whether a string is empty
+    let _ = s.chars().count() < 1;
+    //~^ ERROR: counting all `chars` to check whether a string is empty
+    let _ = s.bytes().count() == 0;
+    //~^ ERROR: counting all `bytes` to check whether a string is empty
+    let _ = string.bytes().count() > 0;
+    //~^ ERROR: counting all `bytes` to check whether a string is empty
+    let flag = string.is_empty();
+    let _ = (if flag { s } else { "x" }).chars().count() != 0;
+    //~^ ERROR: counting all `chars` to check whether a string is empty
+
+    let _ = s.chars().count() <= 4;
+    //~^ ERROR: counting all `chars` to compare against a constant
+    let _ = s.chars().count() < 4;
+    //~^ ERROR: counting all `chars` to compare against a constant
+    let _ = LIMIT < s.chars().count();
+    //~^ ERROR: counting all `chars` to compare against a constant
+    let _ = string.chars().count() >= 4;
+    //~^ ERROR: counting all `chars` to compare against a constant
+
+    // Should not lint.
+    let _ = s.chars().count() == 4;
+    let _ = s.bytes().count() > 4;
+    let _ = s.char_indices().count() == 0;
+    let _ = (0..2).count() == 0;
+    let n = 4;
+    let _ = s.chars().count() > n;
+}
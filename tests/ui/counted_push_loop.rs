@@ -0,0 +1,77 @@
+#![warn(clippy::counted_push_loop)]
+#![allow(clippy::same_item_push)]
+
+fn side_effect() -> u8 {
+    0
+}
+
+fn main() {
+    let n: usize = 16;
+    let items = [1u32, 2, 3];
+    let name = String::from("name");
+
+    let mut zeros = Vec::new();
+    for _ in 0..n {
+        zeros.push(0u8);
+    }
+
+    let mut names: Vec<String> = Vec::new();
+    for _ in 0..items.len() {
+        names.push(name.clone());
+    }
+
+    let mut indices = Vec::new();
+    for i in 0..n {
+        indices.push(i);
+    }
+
+    let mut squares: Vec<usize> = Vec::new();
+    for i in 0..10 {
+        squares.push(i * i);
+    }
+
+    let mut bytes = Vec::new();
+    for _ in 0..n {
+        bytes.push(side_effect());
+    }
+
+    // Don't lint
+
+    // Not directly after `Vec::new()`
+    let mut v = Vec::new();
+    println!("filling");
+    for _ in 0..n {
+        v.push(0u8);
+    }
+
+    // Doesn't start at 0
+    let mut v = Vec::new();
+    for i in 1..n {
+        v.push(i);
+    }
+
+    // Several statements in the body
+    let mut v = Vec::new();
+    for i in 0..n {
+        println!("{i}");
+        v.push(i);
+    }
+
+    // The `Vec` is used in the pushed value
+    let mut v = Vec::new();
+    for _ in 0..n {
+        v.push(v.len());
+    }
+
+    // Not a `usize` range
+    let mut v = Vec::new();
+    for i in 0..10u32 {
+        v.push(i);
+    }
+
+    // Control flow in the pushed value
+    let mut v = Vec::new();
+    for i in 0..n {
+        v.push(if i == 3 { break } else { i });
+    }
+}
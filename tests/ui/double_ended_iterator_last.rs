@@ -0,0 +1,70 @@
+#![warn(clippy::double_ended_iterator_last)]
+
+// Typical case
+pub fn last_arg(s: &str) -> Option<&str> {
+    s.split(' ').last()
+}
+
+fn main() {
+    // General case
+    struct DeIterator;
+    impl Iterator for DeIterator {
+        type Item = ();
+        fn next(&mut self) -> Option<Self::Item> {
+            Some(())
+        }
+    }
+    impl DoubleEndedIterator for DeIterator {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            Some(())
+        }
+    }
+    let _ = DeIterator.last();
+    // Should not apply to other methods of Iterator
+    let _ = DeIterator.count();
+
+    // Should not apply to simple iterators
+    struct SimpleIterator;
+    impl Iterator for SimpleIterator {
+        type Item = ();
+        fn next(&mut self) -> Option<Self::Item> {
+            Some(())
+        }
+    }
+    let _ = SimpleIterator.last();
+
+    // Should not apply to custom last methods
+    struct CustomLast;
+    impl CustomLast {
+        fn last(self) -> Option<()> {
+            Some(())
+        }
+    }
+    let _ = CustomLast.last();
+}
+
+fn temporaries(v: &[i32]) {
+    let _ = v.iter().last();
+    let _ = v.iter().rev().last();
+    let _ = (0..10).last();
+}
+
+fn locals(v: Vec<i32>) {
+    let it = v.iter();
+    let _ = it.last();
+
+    let mut it = v.iter();
+    it.next();
+    let _ = it.last();
+
+    let mut it = v.iter();
+    let r = &mut it;
+    let _ = r.last();
+}
+
+fn with_closures(v: &[i32]) {
+    // `next_back` would skip the side effects for all but the last element
+    let _ = v.iter().inspect(|x| println!("{x}")).last();
+    let _ = v.iter().map(|x| x * 2).last();
+    let _ = v.iter().filter(|&&x| x > 0).last();
+}
@@ -73,3 +73,35 @@ fn not_whole_length(v: &mut Vec<i32>) -> Vec<i32> {
 }
 
 fn main() {}
+
+struct Wrapper {
+    items: Vec<i32>,
+}
+
+impl Wrapper {
+    fn items_mut(&mut self) -> &mut Vec<i32> {
+        &mut self.items
+    }
+}
+
+fn field(w: &mut Wrapper) -> Vec<i32> {
+    w.items.drain(..).collect()
+}
+
+fn through_method(w: &mut Wrapper) -> Vec<i32> {
+    w.items_mut().drain(..).collect()
+}
+
+fn let_binding(v: &mut Vec<i32>) {
+    let moved: Vec<_> = v.drain(..).collect();
+}
+
+fn consumed_dont_lint(v: &mut Vec<i32>) -> Vec<i32> {
+    v.drain(..).filter(|x| *x > 0).collect()
+}
+
+type OtherHasher = std::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>;
+
+fn other_hasher_dont_lint(m: &mut HashMap<i32, i32>) -> HashMap<i32, i32, OtherHasher> {
+    m.drain().collect()
+}
@@ -0,0 +1,37 @@
+#![warn(clippy::empty_literal_constructor)]
+#![allow(clippy::useless_conversion)]
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+const EMPTY: &str = "";
+const NOT_EMPTY: &str = "a";
+const CONCAT: &str = concat!("");
+
+macro_rules! from_empty {
+    () => {
+        PathBuf::from("")
+    };
+}
+
+fn main() {
+    let _ = PathBuf::from("");
+    let _ = OsString::from("");
+    let _: Vec<u8> = Vec::from([]);
+    let _: Vec<u8> = Vec::from(vec![]);
+    let _ = Vec::<u8>::from([]);
+    let _ = std::path::PathBuf::from("");
+    let _: Vec<u8> = From::from([]);
+    let _ = PathBuf::from(EMPTY);
+
+    // Should not lint
+    // `String`s are handled by `manual_string_new`
+    let _ = String::from("");
+    let _ = PathBuf::from("a");
+    let _ = Vec::from([1]);
+    let _ = PathBuf::from(NOT_EMPTY);
+    let _ = PathBuf::from(CONCAT);
+    let _ = PathBuf::from(concat!(""));
+    let _ = from_empty!();
+    let _: PathBuf = From::from("");
+}
@@ -278,3 +278,64 @@ mod issue_10058 {
         }
     }
 }
+
+mod counter_used_after_loop {
+    pub fn test() {
+        let items = [1, 2, 3];
+
+        // should not trigger the lint because the count is used after the loop
+        let mut count = 0;
+        for item in &items {
+            if *item == 2 {
+                continue;
+            }
+            println!("{}", count);
+            count += 1;
+        }
+        println!("{}", count);
+
+        // should not trigger the lint because the count is used after the loop, even if only
+        // in a nested block
+        let mut count = 0;
+        for _item in &items {
+            count += 1;
+        }
+        if count > 1 {
+            println!("{}", count);
+        }
+
+        // should not trigger the lint because the labeled `continue` skips the increment
+        let mut count = 0;
+        'outer: for item in &items {
+            for i in 0..*item {
+                if i == 1 {
+                    continue 'outer;
+                }
+            }
+            println!("{}", count);
+            count += 1;
+        }
+
+        // should trigger the lint because the counter dies inside the loop
+        let mut count = 0;
+        for item in &items {
+            //~^ ERROR: the variable `count` is used as a loop counter
+            println!("{} {}", count, item);
+            count += 1;
+        }
+
+        // should trigger the lint because the `continue` belongs to the nested loop
+        let mut count = 0;
+        for item in &items {
+            //~^ ERROR: the variable `count` is used as a loop counter
+            for i in 0..*item {
+                if i == 1 {
+                    continue;
+                }
+                println!("{}", i);
+            }
+            println!("{}", count);
+            count += 1;
+        }
+    }
+}
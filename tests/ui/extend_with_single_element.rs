@@ -0,0 +1,51 @@
+#![warn(clippy::extend_with_single_element)]
+#![allow(clippy::useless_vec)]
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::iter;
+
+fn main() {
+    let x = 1;
+    let mut v = vec![0];
+    v.extend(iter::once(x));
+    v.extend(std::iter::once(x + 1));
+    v.extend(Some(x * 2));
+    v.extend([x]);
+    v.extend(vec![x]);
+    v.extend(Some(if x > 0 { 1 } else { 2 }));
+
+    let mut deque = VecDeque::new();
+    deque.extend([x]);
+
+    let mut s = String::new();
+    s.extend(Some('a'));
+
+    let mut set = HashSet::new();
+    set.extend([x]);
+    let mut set = BTreeSet::new();
+    set.extend(Some(x));
+
+    let mut map = HashMap::new();
+    map.extend([(x, "one")]);
+    let mut map = BTreeMap::new();
+    map.extend(Some((x, "one")));
+    let pair = (x, "one");
+    map.extend([pair]);
+
+    // `insert` returns a value
+    let mut set = HashSet::new();
+    let _ = || set.extend([x]);
+
+    // no structured suggestion
+    let mut s = String::new();
+    s.extend(["a"]);
+    let refs: &mut Vec<i32> = &mut v;
+    refs.extend([&x]);
+
+    // not a single element
+    v.extend([x, x]);
+    v.extend(vec![x; 2]);
+    v.extend(None);
+    v.extend([]);
+    v.extend(iter::empty());
+}
@@ -4,7 +4,8 @@
     clippy::map_identity,
     clippy::unnecessary_lazy_evaluations,
     clippy::unnecessary_filter_map,
-    unused
+    unused,
+    clippy::unnecessary_clone_into_iter
 )]
 #![warn(clippy::filter_map_bool_then)]
 
@@ -166,3 +166,47 @@ mod issue_9256 {
         print_substring("Hello, world!");
     }
 }
+
+mod forwarded_format_args {
+    use std::fmt;
+
+    macro_rules! log {
+        ($($arg:tt)*) => {
+            println!($($arg)*)
+        };
+    }
+
+    #[clippy::format_args]
+    macro_rules! my_format {
+        ($($arg:tt)*) => {
+            format!($($arg)*)
+        };
+    }
+
+    macro_rules! evaluated_twice {
+        ($fmt:literal, $arg:expr) => {{
+            let _ = $arg;
+            println!($fmt, $arg)
+        }};
+    }
+
+    struct Name(String);
+
+    impl fmt::Display for Name {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0.to_string())
+        }
+    }
+
+    fn main() {
+        let name = "name";
+        log!("{}", name.to_string());
+        log!("{}", name[1..].to_string());
+        let _ = my_format!("{}", name.to_string());
+        let _ = my_format!("{x}", x = name.to_string());
+
+        // negative tests
+        evaluated_twice!("{}", name.to_string());
+        log!("{:?}", name.to_string());
+    }
+}
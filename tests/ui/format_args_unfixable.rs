@@ -1,6 +1,6 @@
 #![warn(clippy::format_in_format_args, clippy::to_string_in_format_args)]
 #![allow(unused)]
-#![allow(clippy::assertions_on_constants, clippy::eq_op, clippy::uninlined_format_args)]
+#![allow(clippy::assertions_on_constants, clippy::eq_op, clippy::uninlined_format_args, clippy::io_other_error)]
 
 use std::io::{stdout, Error, ErrorKind, Write};
 use std::ops::Deref;
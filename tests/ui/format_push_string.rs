@@ -6,6 +6,13 @@ fn main() {
     //~^ ERROR: `format!(..)` appended to existing `String`
     string.push_str(&format!("{:?}", 5678));
     //~^ ERROR: `format!(..)` appended to existing `String`
+    let (a, b) = (1, 2);
+    string.push_str(format!("{a}: {b}, ").as_str());
+    //~^ ERROR: `format!(..)` appended to existing `String`
+
+    // `format!("{}", x)` is left to `useless_format`
+    string.push_str(&format!("{}", a));
+    string += &format!("{a}");
 }
 
 mod issue9493 {
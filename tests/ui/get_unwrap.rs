@@ -106,3 +106,37 @@ mod issue9909 {
         assert_eq!([9.0, 5.0, 1.0, 10.0, 6.0, 2.0, 11.0, 7.0, 3.0, 12.0, 8.0, 4.0], mat);
     }
 }
+mod use_context {
+    #![allow(clippy::unwrap_used, dead_code)]
+
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+
+    fn takes_mut(_: &mut [u8]) {}
+
+    fn mut_use(v: &mut [Vec<u8>], d: &mut VecDeque<u32>) {
+        let first = v.get_mut(0).unwrap();
+        first.push(1);
+        takes_mut(v.get_mut(1).unwrap());
+        let x: &mut u32 = d.get_mut(0).unwrap();
+        *x += 1;
+    }
+
+    fn slices(s: &[u32], other: &mut Vec<u32>) {
+        other.push(*s.get(0).unwrap());
+        // the argument of a method call is not auto-referenced
+        other.extend_from_slice(s.get(1..).unwrap());
+        let a: &[u32; 3] = &[1, 2, 3];
+        let _: &u32 = (a as &[u32]).get(0).unwrap();
+    }
+
+    fn boxed() -> u32 {
+        let mut b = Box::new(vec![1, 2, 3]);
+        *b.get_mut(0).unwrap() += 1;
+        *b.get(0).unwrap()
+    }
+
+    fn ref_cell(c: &RefCell<Vec<u32>>) -> u32 {
+        *c.borrow().get(0).unwrap()
+    }
+}
@@ -0,0 +1,111 @@
+#![feature(let_chains)]
+#![warn(clippy::if_let_chain_same_scrutinee)]
+#![allow(dead_code)]
+
+enum Pet {
+    Dog(u32),
+    Cat(u32),
+    Fish,
+}
+
+struct Owner {
+    pet: Option<Pet>,
+}
+
+fn pet() -> Option<Pet> {
+    None
+}
+
+fn with_else(pet: Option<Pet>) {
+    if let Some(Pet::Dog(age)) = pet {
+        println!("dog of age {age}");
+    } else if let Some(Pet::Cat(_)) = pet {
+        println!("cat");
+    } else {
+        println!("something else");
+    }
+}
+
+fn without_else(owner: &Owner) {
+    if let Some(Pet::Dog(_)) = &owner.pet {
+        println!("dog");
+    } else if let Some(Pet::Cat(age)) | Some(Pet::Dog(age)) = &owner.pet {
+        println!("{age}");
+    }
+}
+
+fn guards(pet: &Pet, old: u32) {
+    let _ = if let Pet::Dog(age) = pet
+        && *age > old
+    {
+        "old dog"
+    } else if let Pet::Dog(_) = pet {
+        "dog"
+    } else if let Pet::Cat(age) = pet
+        && *age > old
+        && old > 0
+    {
+        "old cat"
+    } else {
+        "something else"
+    };
+}
+
+fn exhaustive(pet: Pet) -> u32 {
+    if let Pet::Dog(age) = pet {
+        age
+    } else if let Pet::Cat(age) = pet {
+        age
+    } else if let Pet::Fish = pet {
+        0
+    } else {
+        unreachable!()
+    }
+}
+
+fn rest_of_chain(pets: [Pet; 2]) {
+    if let Pet::Dog(_) = pets[0] {
+        println!("dog");
+    } else if let Pet::Fish = pets[0] {
+        println!("fish");
+    } else if let Pet::Fish = pets[1] {
+        println!("other fish");
+    }
+}
+
+fn no_lint(pet: Option<Pet>, mut pets: Vec<Pet>) {
+    // Only one `if let`
+    if let Some(Pet::Dog(_)) = pet {
+    } else if pet.is_none() {
+    }
+
+    // Different scrutinees
+    if let Some(Pet::Dog(_)) = pet {
+    } else if let Some(Pet::Dog(_)) = super_pet() {
+    }
+
+    // Calls may have side effects
+    if let Some(Pet::Dog(_)) = self::pet() {
+    } else if let Some(Pet::Cat(_)) = self::pet() {
+    }
+    if let Some(Pet::Dog(_)) = pets.pop() {
+    } else if let Some(Pet::Cat(_)) = pets.pop() {
+    }
+
+    // Overloaded indexing
+    if let Pet::Dog(_) = pets[0] {
+    } else if let Pet::Cat(_) = pets[0] {
+    }
+
+    // Part of a larger `if` chain
+    if pet.is_none() {
+    } else if let Some(Pet::Dog(_)) = pet {
+    } else if let Some(Pet::Cat(_)) = pet {
+    }
+}
+
+fn super_pet() -> Option<Pet> {
+    None
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 //@no-rustfix
 //@aux-build:proc_macros.rs
 
-#![allow(clippy::never_loop)]
+#![allow(clippy::never_loop, clippy::manual_while_loop, clippy::matches_is_variant_and)]
 #![warn(clippy::infinite_loop)]
 
 extern crate proc_macros;
@@ -368,6 +368,13 @@ fn inf_loop_in_res() -> Result<(), i32> {
     })
 }
 
+async fn inf_loop_in_async_fn() {
+    loop {
+        //~^ ERROR: infinite loop detected
+        do_something();
+    }
+}
+
 with_span! { span
     fn no_loop() {}
 }
@@ -0,0 +1,50 @@
+#![warn(clippy::io_other_error)]
+#![allow(unused)]
+use std::fmt;
+
+#[derive(Debug)]
+struct E;
+
+impl std::error::Error for E {}
+impl fmt::Display for E {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("E")
+    }
+}
+
+macro_rules! other {
+    () => {
+        std::io::ErrorKind::Other
+    };
+}
+
+fn main() {
+    let _err = std::io::Error::new(std::io::ErrorKind::Other, E);
+    let other_err_kind = std::io::ErrorKind::Other;
+    let _err = std::io::Error::new(other_err_kind, E);
+    let _err = std::io::Error::new(std::io::ErrorKind::InvalidInput, E);
+    let _err = std::io::Error::new(other!(), E);
+}
+
+mod aliased {
+    use std::io;
+    use std::io::ErrorKind::Other;
+    use std::io::{Error as IoError, ErrorKind};
+
+    fn f() {
+        let _err = io::Error::new(io::ErrorKind::Other, "other");
+        let _err = IoError::new(ErrorKind::Other, "other");
+        let _err = IoError::new(Other, String::from("other"));
+        let _err = <io::Error>::new(Other, "other");
+    }
+}
+
+#[clippy::msrv = "1.73"]
+fn under_msrv() {
+    let _err = std::io::Error::new(std::io::ErrorKind::Other, E);
+}
+
+#[clippy::msrv = "1.74"]
+fn over_msrv() {
+    let _err = std::io::Error::new(std::io::ErrorKind::Other, E);
+}
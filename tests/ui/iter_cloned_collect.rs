@@ -1,5 +1,5 @@
 #![allow(unused)]
-#![allow(clippy::useless_vec)]
+#![allow(clippy::map_clone, clippy::useless_vec)]
 
 use std::collections::{HashSet, VecDeque};
 
@@ -27,4 +27,14 @@ fn main() {
 
     // Issue #6703
     let _: Vec<isize> = v.iter().copied().collect();
+
+    // `map(Clone::clone)` and `Vec`s, which can be cloned
+    let strings = vec![String::new()];
+    let _: Vec<String> = strings.iter().map(Clone::clone).collect();
+    let _: Vec<String> = strings[..].iter().map(Clone::clone).collect();
+    let r = &strings;
+    let _: Vec<String> = r.iter().cloned().collect();
+    // `clone` would only clone the reference
+    let rr = &r;
+    let _: Vec<String> = rr.iter().cloned().collect();
 }
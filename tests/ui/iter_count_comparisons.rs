@@ -0,0 +1,36 @@
+#![warn(clippy::iter_count_comparisons)]
+#![allow(unused_mut, clippy::filter_next, clippy::iter_count, clippy::chars_count_comparison)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = v.iter().filter(|x| **x > 1).count() == 0;
+    let _ = 0 == v.iter().filter(|x| **x > 1).count();
+    let _ = v.iter().filter(|x| **x > 1).count() != 0;
+    let _ = v.iter().filter(|x| **x > 1).count() > 0;
+    let _ = 0 < v.iter().filter(|x| **x > 1).count();
+    let _ = v.iter().filter(|x| **x > 1).count() >= 1;
+    let _ = v.iter().filter(|x| **x > 1).count() < 1;
+    let _ = (0..10).filter(|x| x % 3 == 0).count() <= 1;
+    let _ = (0..10).filter(|x| x % 3 == 0).count() < 2;
+    let _ = 1 < (0..10).filter(|x| x % 3 == 0).count();
+    let _ = (0..10).filter(|x| x % 3 == 0).count() >= 2;
+
+    let mut iter = v.iter().skip(1);
+    let _ = iter.count() == 0;
+    let iter = &mut v.iter();
+    let _ = iter.count() > 0;
+
+    // Should not lint.
+    let _ = v.iter().filter(|x| **x > 1).count() == 1;
+    let _ = v.iter().filter(|x| **x > 1).count() > 2;
+    let n = 0;
+    let _ = v.iter().filter(|x| **x > 1).count() == n;
+
+    // `iter_count` and `chars_count_comparison` handle these
+    let _ = v.iter().count() == 0;
+    let _ = [1, 2].iter().count() > 0;
+    let _ = Vec::<i32>::new().into_iter().count() != 0;
+    let s = "hello";
+    let _ = s.chars().count() == 0;
+    let _ = s.bytes().count() > 0;
+}
@@ -0,0 +1,124 @@
+#![warn(clippy::linear_search_in_loop)]
+
+struct Filter {
+    blocked: Vec<String>,
+}
+
+impl Filter {
+    fn count_blocked(&self, names: &[String]) -> usize {
+        let mut count = 0;
+        for name in names {
+            if self.blocked.contains(name) {
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+fn common(a: &[u32], b: &[u32]) -> usize {
+    let b = b.to_vec();
+    let mut count = 0;
+    for x in a {
+        if b.contains(x) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn while_loop(b: &[u8], mut c: u8) {
+    let b = &b[1..];
+    while !b.iter().any(|&y| y == c) {
+        c += 1;
+    }
+}
+
+fn position(a: &[u64], b: &[u64]) {
+    let b = b.to_vec();
+    for x in a {
+        if let Some(i) = b.iter().position(|y| *y == *x) {
+            println!("{i}");
+        }
+    }
+}
+
+fn closures(a: &[u32], b: &[u32]) {
+    let b = b.to_vec();
+    let _ = a.iter().filter(|x| b.contains(x)).count();
+    let _: Vec<_> = a.iter().filter(|x| !b.iter().any(|y| *y == **x)).collect();
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Id(u32);
+
+fn ordered(a: &[Id], b: &[Id]) {
+    let b = &b[1..];
+    for x in a {
+        if b.contains(x) {
+            println!("found");
+        }
+    }
+}
+
+struct List<'a> {
+    items: &'a [u32],
+}
+
+fn no_lint(a: &[u32], b: &[u32], floats: &[f64]) {
+    let mut c = b.to_vec();
+    let floats = floats.to_vec();
+    let small = [1, 2, 3, 4];
+
+    // The searched `Vec` is modified in the loop
+    for x in a {
+        if !c.contains(x) {
+            c.push(*x);
+        }
+    }
+
+    // Declared inside the loop
+    for x in a {
+        let local = a.to_vec();
+        let _ = local.contains(x);
+    }
+
+    // Small arrays
+    for x in a {
+        let _ = small.contains(x);
+    }
+    for x in [1, 2, 3] {
+        let _ = c.contains(&x);
+    }
+    for x in 0..10 {
+        let _ = c.contains(&x);
+    }
+
+    // Floats can't be put in a set
+    for x in a {
+        let _ = floats.contains(&f64::from(*x));
+    }
+
+    // Not a search for equality
+    for x in a {
+        let _ = c.iter().any(|y| y > x);
+    }
+
+    // Function parameters
+    for x in a {
+        let _ = b.iter().position(|y| y == x);
+    }
+    let _ = a.iter().filter(|x| !b.contains(x)).count();
+
+    // Borrowed slices stored in fields
+    let list = List { items: a };
+    for x in a {
+        let _ = list.items.contains(x);
+    }
+
+    // Not inside a loop
+    let _ = c.contains(&1);
+    let f = || c.contains(&1);
+}
+
+fn main() {}
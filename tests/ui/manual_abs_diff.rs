@@ -0,0 +1,78 @@
+#![warn(clippy::manual_abs_diff)]
+#![allow(clippy::cast_abs_to_unsigned)]
+
+use std::cmp::{max, min};
+
+struct Span {
+    start: usize,
+    end: usize,
+}
+
+fn if_else(a: u32, b: u32, c: u32, span: &Span) {
+    let _ = if a > b { a - b } else { b - a };
+    //~^ ERROR: manual implementation of `abs_diff`
+    let _ = if a >= b { a - b } else { b - a };
+    //~^ ERROR: manual implementation of `abs_diff`
+    let _ = if a < b { b - a } else { a - b };
+    //~^ ERROR: manual implementation of `abs_diff`
+    let _ = if b <= a { a - b } else { b - a };
+    //~^ ERROR: manual implementation of `abs_diff`
+    let _ = if span.start > span.end {
+        //~^ ERROR: manual implementation of `abs_diff`
+        span.start - span.end
+    } else {
+        span.end - span.start
+    };
+
+    // The branches subtract different variables
+    let _ = if a > b { a - b } else { c - a };
+    let _ = if a > b { a - c } else { b - a };
+    let _ = if a > b { b - a } else { a - b };
+    // `else a.abs_diff(b)` wouldn't compile
+    let _ = if c == 0 { 0 } else if a > b { a - b } else { b - a };
+}
+
+fn max_min(a: u32, b: u32, c: u32) {
+    let _ = max(a, b) - min(a, b);
+    //~^ ERROR: manual implementation of `abs_diff`
+    let _ = a.max(b) - a.min(b);
+    //~^ ERROR: manual implementation of `abs_diff`
+    let _ = a.max(b) - b.min(a);
+    //~^ ERROR: manual implementation of `abs_diff`
+
+    let _ = max(a, b) - min(a, c);
+}
+
+fn widened(a: u32, b: u32, c: i16, d: i16, e: usize, f: usize) {
+    let _ = (a as i64 - b as i64).abs() as u32;
+    //~^ ERROR: manual implementation of `abs_diff`
+    let _ = (a as i64 - b as i64).abs() as u64;
+    //~^ ERROR: manual implementation of `abs_diff`
+    let _ = (c as i32 - d as i32).abs() as u16;
+    //~^ ERROR: manual implementation of `abs_diff`
+
+    // The subtraction can overflow in a type that isn't wider
+    let _ = (a as i32 - b as i32).abs() as u32;
+    // The result is narrower than the difference
+    let _ = (a as i64 - b as i64).abs() as u16;
+    // There's no `From<usize>` implementation for `u128`
+    let _ = (e as i128 - f as i128).abs() as u128;
+}
+
+fn signed(x: i32, y: i32) {
+    // The difference of signed integers is also signed
+    let _ = if x > y { x - y } else { y - x };
+}
+
+#[clippy::msrv = "1.59"]
+fn msrv_1_59(a: u32, b: u32) -> u32 {
+    if a > b { a - b } else { b - a }
+}
+
+#[clippy::msrv = "1.60"]
+fn msrv_1_60(a: u32, b: u32) -> u32 {
+    if a > b { a - b } else { b - a }
+    //~^ ERROR: manual implementation of `abs_diff`
+}
+
+fn main() {}
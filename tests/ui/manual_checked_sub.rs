@@ -0,0 +1,45 @@
+#![warn(clippy::manual_checked_sub, clippy::manual_saturating_sub)]
+#![allow(clippy::if_then_some_else_none)]
+
+struct Buf {
+    len: usize,
+    used: usize,
+}
+
+fn next(x: &mut u32) -> u32 {
+    *x += 1;
+    *x
+}
+
+fn main() {
+    let a = 10_u32;
+    let b = 3_u32;
+    let buf = Buf { len: 8, used: 2 };
+
+    let _ = if a >= b { Some(a - b) } else { None };
+    let _ = if b <= a { Some(a - b) } else { None };
+    let _ = if a < b { None } else { Some(a - b) };
+    let _ = if buf.len >= buf.used { Some(buf.len - buf.used) } else { None };
+
+    let _ = if a >= b { a - b } else { 0 };
+    let _ = if a > b { a - b } else { 0 };
+    let _ = if a < b { 0 } else { a - b };
+    let _ = if b >= a { 0 } else { a - b };
+    let _ = if buf.len > 1 { buf.len - 1 } else { 0 };
+
+    // Should not lint
+    let _ = if a > b { Some(a - b) } else { None };
+    let _ = if a > b { a - b - 1 } else { 0 };
+    let _ = if a >= b { Some(b - a) } else { None };
+    let _ = if a >= b { a - b } else { 1 };
+    let mut c = 0;
+    let _ = if next(&mut c) >= b { next(&mut c) - b } else { 0 };
+    let _ = if a == 0 { 1 } else if a >= b { a - b } else { 0 };
+
+    // For signed integers `checked_sub` and `saturating_sub` don't return `None` or zero
+    // for negative differences.
+    let x = 10_i32;
+    let y = -3_i32;
+    let _ = if x >= y { Some(x - y) } else { None };
+    let _ = if x >= y { x - y } else { 0 };
+}
@@ -346,9 +346,11 @@ fn const_main() {
     }
 
     // Flip the script, swap the places of min and max. Make sure this doesn't
-    // trigger when clamp would be guaranteed to panic.
+    // suggest clamp when it would be guaranteed to panic.
     let mut x33 = input;
     if x33 < CONST_MAX {
+        //~^ ERROR: clamp-like pattern without using clamp function
+        //~| NOTE: min is greater than max, so clamp would panic
         x33 = CONST_MAX;
     } else if x33 > CONST_MIN {
         x33 = CONST_MIN;
@@ -359,6 +361,8 @@ fn const_main() {
     {
         let mut x34 = input as f64;
         if x34 < f64::NAN {
+            //~^ ERROR: clamp-like pattern without using clamp function
+            //~| NOTE: min or max is NaN, so clamp would panic
             x34 = f64::NAN;
         } else if x34 > CONST_F64_MAX {
             x34 = CONST_F64_MAX;
@@ -558,3 +562,61 @@ const fn _const() {
         x = min;
     }
 }
+
+fn float_consts() {
+    const F32_MIN: f32 = -1.5;
+    const F32_MAX: f32 = 1.5;
+
+    let input = cmp_min_max(1) as f32;
+    // Bounds from associated consts
+    let _ = input.max(f32::EPSILON).min(F32_MAX);
+    //~^ ERROR: clamp-like pattern without using clamp function
+    //~| NOTE: clamp will panic if max < min, min.is_nan(), or max.is_nan()
+    // Float literals, in both orderings of the chain
+    let _ = if input < -1.5 {
+        //~^ ERROR: clamp-like pattern without using clamp function
+        //~| NOTE: clamp will panic if max < min, min.is_nan(), or max.is_nan()
+        -1.5
+    } else if input > 1.5 {
+        1.5
+    } else {
+        input
+    };
+    let _ = if input > 1.5 {
+        //~^ ERROR: clamp-like pattern without using clamp function
+        //~| NOTE: clamp will panic if max < min, min.is_nan(), or max.is_nan()
+        1.5
+    } else if input < -1.5 {
+        -1.5
+    } else {
+        input
+    };
+    // Reversed bounds
+    let _ = input.max(F32_MAX).min(F32_MIN);
+    //~^ ERROR: clamp-like pattern without using clamp function
+    //~| NOTE: min is greater than max, so clamp would panic
+
+    let input = input as f64;
+    let _ = if input < f64::EPSILON {
+        //~^ ERROR: clamp-like pattern without using clamp function
+        //~| NOTE: clamp will panic if max < min, min.is_nan(), or max.is_nan()
+        f64::EPSILON
+    } else if input > CONST_F64_MAX {
+        CONST_F64_MAX
+    } else {
+        input
+    };
+    let _ = if input > CONST_F64_MIN {
+        //~^ ERROR: clamp-like pattern without using clamp function
+        //~| NOTE: min is greater than max, so clamp would panic
+        CONST_F64_MIN
+    } else if input < CONST_F64_MAX {
+        CONST_F64_MAX
+    } else {
+        input
+    };
+    // The chain returns one of the bounds for a NaN input, whereas `clamp` returns NaN
+    let _ = f64::NAN.min(CONST_F64_MAX).max(CONST_F64_MIN);
+    //~^ ERROR: clamp-like pattern without using clamp function
+    //~| NOTE: clamp returns NaN if the input is NaN, unlike the `min` and `max` calls
+}
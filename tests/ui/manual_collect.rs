@@ -0,0 +1,107 @@
+#![warn(clippy::manual_collect)]
+#![allow(clippy::needless_collect, clippy::same_item_push, clippy::counted_push_loop)]
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+fn main() {
+    let words = ["a", "bb", "ccc"];
+    let numbers = vec![1, 2, 3];
+
+    let mut lengths = Vec::new();
+    for word in words {
+        lengths.push(word.len());
+    }
+
+    let mut copy = Vec::new();
+    copy.extend(numbers.iter().map(|x| x + 1));
+
+    let mut squares = Vec::new();
+    for x in 0..10 {
+        squares.push(x * x);
+    }
+    squares.push(100);
+
+    let mut all = Vec::new();
+    for x in 0..10 {
+        all.push(x);
+    }
+
+    let mut s = String::new();
+    for word in words {
+        s.push_str(word);
+    }
+
+    let mut index = HashMap::new();
+    for (i, word) in words.iter().enumerate() {
+        index.insert(*word, i);
+    }
+
+    let mut set: BTreeSet<u32> = BTreeSet::new();
+    set.extend([3, 1, 2]);
+
+    let mut evens = Vec::new();
+    for x in 0..10 {
+        if x % 2 == 0 {
+            evens.push(x);
+        }
+    }
+
+    let mut halves = Vec::new();
+    for x in numbers.clone() {
+        if x % 2 == 0 {
+            halves.push(x / 2);
+        }
+    }
+
+    let mut name_lengths = Vec::new();
+    for name in [String::from("a")] {
+        if !name.is_empty() {
+            name_lengths.push(name.len());
+        }
+    }
+
+    let mut queue = VecDeque::new();
+    for x in 0..3 {
+        queue.push_back(x);
+    }
+
+    let _ = (lengths, copy, squares, all, s, index, set, evens, halves, name_lengths, queue);
+
+    // Should not lint
+    let mut refs = Vec::new();
+    refs.extend(&numbers);
+
+    let mut used_before = Vec::new();
+    used_before.reserve(3);
+    for x in 0..3 {
+        used_before.push(x);
+    }
+
+    let mut two_pushes = Vec::new();
+    for x in 0..3 {
+        two_pushes.push(x);
+        two_pushes.push(x);
+    }
+
+    let mut with_continue = Vec::new();
+    for x in 0..3 {
+        with_continue.push(if x == 1 { continue } else { x });
+    }
+
+    let mut self_referencing = Vec::new();
+    for x in 0..3 {
+        self_referencing.push(x + self_referencing.len());
+    }
+
+    let mut derefs = String::new();
+    for word in &words {
+        derefs.push_str(word);
+    }
+
+    let mut with_capacity = Vec::with_capacity(3);
+    for x in 0..3 {
+        with_capacity.push(x);
+    }
+
+    let _ = (refs, used_before, two_pushes, with_continue, self_referencing, derefs, with_capacity);
+}
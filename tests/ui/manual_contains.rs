@@ -0,0 +1,61 @@
+#![warn(clippy::manual_contains)]
+#![allow(clippy::useless_vec, clippy::op_ref)]
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn slices(values: &[u32], needle: u32, needle_ref: &u32) {
+    let _ = values.iter().any(|v| *v == needle);
+    let _ = values.iter().any(|&v| v == needle);
+    let _ = values.iter().any(|v| needle == *v);
+    let _ = values.iter().any(|v| v == &needle);
+    let _ = values.iter().any(|v| v == needle_ref);
+    let _ = values.iter().any(|v| *v == *needle_ref);
+    let _ = values.iter().any(|v| *v == 10);
+
+    let array = [1, 2, 3];
+    let _ = array.iter().any(|v| *v == needle);
+    let vec = vec![1, 2, 3];
+    let _ = vec.iter().any(|&v| v == needle);
+    let strings = vec![String::from("a")];
+    let s = String::from("b");
+    let _ = strings.iter().any(|x| *x == s);
+}
+
+fn strings(s: &str, string: String, c: char, b: u8) {
+    let _ = s.chars().any(|x| x == c);
+    let _ = string.chars().any(|x| x == 'a');
+    let _ = s.bytes().any(|x| x == b);
+    let _ = string.bytes().any(|x| b'a' == x);
+}
+
+fn should_not_lint(values: &[u32], points: &[Point], strings: &[String], needle: u32, s: &str) {
+    // compares a projection of the element
+    let _ = points.iter().any(|p| p.x == needle);
+    // not an equality
+    let _ = values.iter().any(|v| *v != needle);
+    let _ = values.iter().any(|v| *v > needle);
+    // `needle + 1` could overflow even if `values` is empty
+    let _ = values.iter().any(|v| *v == needle + 1);
+    // the needle depends on the element
+    let _ = values.iter().any(|v| *v == *v + 1);
+    // different types, `contains` would need `&String`
+    let _ = strings.iter().any(|x| x == "a");
+    // the needle isn't loop-invariant
+    let mut n = 0;
+    let _ = values.iter().any(|v| {
+        *v == {
+            n += 1;
+            n
+        }
+    });
+    // other adapters in between
+    let _ = values.iter().copied().any(|v| v == needle);
+    // handled by `string_lit_chars_any`
+    let _ = "abc".chars().any(|x| x == 'a');
+    let _ = s.char_indices().any(|(_, x)| x == 'a');
+}
+
+fn main() {}
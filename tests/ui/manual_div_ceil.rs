@@ -0,0 +1,45 @@
+#![warn(clippy::manual_div_ceil)]
+
+struct Buf {
+    len: usize,
+}
+
+fn main() {
+    let x = 7_u32;
+    let y = 4_u32;
+    let buf = Buf { len: 100 };
+
+    let _ = (x + y - 1) / y;
+    let _ = (y + x - 1) / y;
+    let _ = (x + (y - 1)) / y;
+    let _ = ((y - 1) + x) / y;
+    let _ = (buf.len + 8 - 1) / 8;
+    let _ = x / y + if x % y != 0 { 1 } else { 0 };
+    let _ = if x % y != 0 { 1 } else { 0 } + x / y;
+
+    // Don't lint
+    let _ = (x + y - 1) / (y + 1);
+    let _ = (x + y - 2) / y;
+    let _ = (x + y) / y;
+    let _ = (x + 2 * y - 1) / y;
+    let _ = x / y + if x % y != 0 { 0 } else { 1 };
+    let _ = x / y + if x % (y + 1) != 0 { 1 } else { 0 };
+
+    let z = 7_i32;
+    let w = 4_i32;
+    let _ = (z + w - 1) / w;
+}
+
+fn side_effects(v: &mut Vec<u32>) -> u32 {
+    (7 + v.pop().unwrap() - 1) / v.pop().unwrap()
+}
+
+#[clippy::msrv = "1.72"]
+fn msrv_1_72(x: u32, y: u32) -> u32 {
+    (x + y - 1) / y
+}
+
+#[clippy::msrv = "1.73"]
+fn msrv_1_73(x: u32, y: u32) -> u32 {
+    (x + y - 1) / y
+}
@@ -184,3 +184,20 @@ fn main() {
         });
     }
 }
+
+#[clippy::msrv = "1.75"]
+fn msrv_1_75() {
+    // `Option::inspect` isn't available yet
+    let _ = Some(0).map(|x| {
+        println!("{}", x);
+        x
+    });
+
+    let _: Vec<_> = [0]
+        .into_iter()
+        .map(|x| {
+            println!("{}", x);
+            x
+        })
+        .collect();
+}
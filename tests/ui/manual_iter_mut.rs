@@ -0,0 +1,59 @@
+#![warn(clippy::manual_iter_mut)]
+#![allow(clippy::needless_range_loop, clippy::useless_vec)]
+
+struct Point(f64, f64);
+
+fn slice(s: &mut [i32]) {
+    for i in 0..s.len() {
+        s[i] = s[i].abs();
+    }
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    for i in 0..v.len() {
+        v[i] *= 2;
+    }
+
+    for i in 0..v.len() {
+        v[i] = v[i] * 2 + i;
+    }
+
+    for i in 1..v.len() {
+        v[i] += 1;
+    }
+
+    for i in 0..v.len() {
+        v[i] = v[i].max(2);
+    }
+
+    let x = 2;
+    for i in 0..v.len() {
+        v[i] *= x;
+    }
+
+    let mut points = [Point(0.0, 0.0), Point(1.0, 1.0)];
+    for i in 0..points.len() {
+        points[i].0 += points[i].1;
+    }
+
+    // Don't lint: other elements are read
+    for i in 1..v.len() {
+        v[i] += v[i - 1];
+    }
+    for i in 0..v.len() - 1 {
+        v[i] = v[i + 1];
+    }
+
+    // Don't lint: the elements are only read
+    let mut sum = 0;
+    for i in 0..v.len() {
+        sum += v[i];
+    }
+
+    // Don't lint: the loop variable is modified
+    for mut i in 0..v.len() {
+        i %= 2;
+        v[i] = 0;
+    }
+}
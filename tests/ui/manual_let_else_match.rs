@@ -178,3 +178,45 @@ fn issue11579() {
         _ => unreachable!("can't happen"),
     };
 }
+
+fn diverging_statements(opt: Option<u32>, pair: Option<(u32, u32)>) -> Result<u32, ()> {
+    let v = match opt {
+        //~^ ERROR: this could be rewritten as `let...else`
+        Some(v) => v,
+        None => {
+            println!("missing");
+            return Err(());
+        },
+    };
+
+    let v = match pair {
+        //~^ ERROR: this could be rewritten as `let...else`
+        Some((a, b)) => (a, b),
+        None => return Err(()),
+    };
+
+    let v = match opt {
+        //~^ ERROR: this could be rewritten as `let...else`
+        // The value must be there
+        Some(v) => v,
+        None => return Err(()),
+    };
+
+    // The tuple isn't made of the bindings in order
+    let v = match pair {
+        Some((a, b)) => (b, a),
+        None => return Err(()),
+    };
+
+    Ok(0)
+}
+
+fn by_reference(pair: &Option<(String, String)>) -> Result<(), ()> {
+    // The bindings are references, unlike the fields of the tuple in the pattern
+    let v = match pair {
+        Some((a, b)) => (a, b),
+        None => return Err(()),
+    };
+
+    Ok(())
+}
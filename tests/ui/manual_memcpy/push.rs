@@ -0,0 +1,85 @@
+#![warn(clippy::manual_memcpy)]
+#![allow(clippy::needless_range_loop, clippy::manual_iter_mut, clippy::manual_collect)]
+
+fn copy_items(src: &[u8], arr: [u8; 4], dst: &mut Vec<u8>, n: usize) {
+    for i in 0..src.len() {
+        dst.push(src[i]);
+    }
+
+    for i in 1..n {
+        dst.push(src[i]);
+    }
+
+    for i in 0..4 {
+        dst.push(arr[i]);
+    }
+
+    for x in src {
+        dst.push(*x);
+    }
+
+    for x in &arr {
+        dst.push(*x);
+    }
+
+    for &x in src.iter() {
+        dst.push(x);
+    }
+
+    for x in src.iter().copied() {
+        dst.push(x);
+    }
+}
+
+fn clone_items(strings: &[String], dst: &mut Vec<String>) {
+    for s in strings {
+        dst.push(s.clone());
+    }
+
+    for i in 0..strings.len() {
+        dst.push(strings[i].clone());
+    }
+}
+
+fn transformed_items<'a>(src: &'a [u8], dst: &mut Vec<u16>, refs: &mut Vec<&'a u8>) {
+    for x in src {
+        dst.push(u16::from(*x) + 1);
+    }
+
+    for x in src.iter().copied() {
+        dst.push(u16::from(x));
+    }
+
+    for i in 0..src.len() {
+        dst.push(u16::from(src[i]));
+    }
+
+    for x in src {
+        refs.push(x);
+    }
+}
+
+fn should_not_lint(src: &[u8], dst: &mut Vec<u8>, n: usize) {
+    // more than one statement
+    for x in src {
+        dst.push(*x);
+        dst.push(0);
+    }
+
+    // pushing an item of the `Vec` itself
+    for i in 0..n {
+        dst.push(dst[i]);
+    }
+
+    // the pushed value uses the `Vec`
+    for x in src {
+        dst.push(*x + dst.len() as u8);
+    }
+
+    // the pushed value doesn't depend on the loop variable
+    for _x in src {
+        dst.push(0);
+    }
+}
+
+fn main() {}
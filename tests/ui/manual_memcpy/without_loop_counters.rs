@@ -1,5 +1,5 @@
 #![warn(clippy::manual_memcpy)]
-#![allow(clippy::assigning_clones, clippy::useless_vec, clippy::needless_range_loop)]
+#![allow(clippy::assigning_clones, clippy::useless_vec, clippy::needless_range_loop, clippy::manual_iter_mut)]
 
 //@no-rustfix
 const LOOP_OFFSET: usize = 5000;
@@ -0,0 +1,67 @@
+#![feature(num_midpoint)]
+#![warn(clippy::manual_midpoint)]
+#![allow(clippy::integer_division)]
+
+struct Range {
+    start: u64,
+    end: u64,
+}
+
+fn next(x: u32) -> u32 {
+    x + 1
+}
+
+macro_rules! halve {
+    ($e:expr) => {
+        $e / 2
+    };
+}
+
+#[clippy::msrv = "1.87"]
+fn main() {
+    let (a, b) = (1u32, 2u32);
+    let _ = (a + b) / 2; //~ ERROR: manual implementation of `u32::midpoint`
+    let _ = a + (b - a) / 2; //~ ERROR: manual implementation of `u32::midpoint`
+    let _ = (b - a) / 2 + a; //~ ERROR: manual implementation of `u32::midpoint`
+
+    let (x, y) = (1i32, 2i32);
+    let _ = (x + y) / 2; //~ ERROR: manual implementation of `i32::midpoint`
+    // Rounds towards `x` instead of towards zero
+    let _ = x + (y - x) / 2;
+
+    let (f, g) = (1.0f64, 2.0f64);
+    let _ = (f + g) / 2.0; //~ ERROR: manual implementation of `f64::midpoint`
+    let _ = f + (g - f) / 2.0; //~ ERROR: manual implementation of `f64::midpoint`
+
+    let r = Range { start: 1, end: 5 };
+    let _ = (r.start + r.end) / 2; //~ ERROR: manual implementation of `u64::midpoint`
+
+    // Operands with side effects
+    let _ = (next(a) + b) / 2; //~ ERROR: manual implementation of `u32::midpoint`
+
+    // Not halving a sum or a difference
+    let _ = (a + b) / 3;
+    let _ = (a * b) / 2;
+    let _ = a + (b - x as u32) / 2;
+    let _ = halve!(a + b);
+}
+
+#[clippy::msrv = "1.84"]
+fn msrv_1_84() {
+    let (a, b) = (1u32, 2u32);
+    let _ = (a + b) / 2;
+}
+
+#[clippy::msrv = "1.85"]
+fn msrv_1_85() {
+    let (a, b) = (1u32, 2u32);
+    let _ = (a + b) / 2; //~ ERROR: manual implementation of `u32::midpoint`
+    let (x, y) = (1i32, 2i32);
+    let _ = (x + y) / 2;
+}
+
+// No MSRV is set, and `midpoint` isn't stable on all toolchains
+fn no_msrv() {
+    let (a, b) = (1u32, 2u32);
+    let _ = (a + b) / 2;
+}
@@ -0,0 +1,96 @@
+#![warn(clippy::manual_ok_err)]
+
+fn funcall() -> Result<u32, &'static str> {
+    todo!()
+}
+
+fn main() {
+    let _ = match funcall() {
+        Ok(v) => Some(v),
+        Err(_) => None,
+    };
+
+    let _ = match funcall() {
+        Err(v) => Some(v),
+        Ok(_) => None,
+    };
+
+    let _ = match funcall() {
+        Err(v) => Some(v),
+        _ => None,
+    };
+
+    let _ = if let Ok(v) = funcall() {
+        Some(v)
+    } else {
+        None
+    };
+
+    let _ = if let Err(v) = funcall() {
+        Some(v)
+    } else {
+        None
+    };
+
+    let r: Result<String, ()> = Ok(String::new());
+    let _ = match r {
+        Ok(ref s) => Some(s),
+        Err(_) => None,
+    };
+    let _ = match &r {
+        Ok(s) => Some(s),
+        Err(_) => None,
+    };
+
+    let _ = match funcall() {
+        Err(_) => None,
+        Ok(v) => Some(v),
+    };
+}
+
+fn no_lint() {
+    let _ = match funcall() {
+        Ok(v) if v > 3 => Some(v),
+        _ => None,
+    };
+
+    let _ = match funcall() {
+        Err(_) => None,
+        Ok(3) => None,
+        Ok(v) => Some(v),
+    };
+
+    let _ = match funcall() {
+        Ok(v) => Some(v),
+        Err("ignored") => None,
+        Err(_) => None,
+    };
+
+    let _ = match funcall() {
+        Ok(v) => Some(v + 1),
+        Err(_) => None,
+    };
+
+    let _ = match funcall() {
+        Ok(v) => {
+            println!("{v}");
+            Some(v)
+        },
+        Err(_) => None,
+    };
+
+    let _ = if let Ok(v) = funcall() {
+        Some(v)
+    } else {
+        println!("error");
+        None
+    };
+
+    let _ = match funcall() {
+        Ok(v) => Some(v),
+        Err(e) => {
+            println!("{e}");
+            None
+        },
+    };
+}
@@ -0,0 +1,33 @@
+#![feature(iter_repeat_n)]
+#![warn(clippy::manual_repeat_n)]
+
+use std::iter::repeat;
+
+fn main() {
+    let _ = repeat(10).take(3);
+    //~^ ERROR: this `repeat().take()` can be written more concisely
+
+    let _ = std::iter::repeat("x").take(3).collect::<Vec<_>>();
+    //~^ ERROR: this `repeat().take()` can be written more concisely
+
+    let n = 4;
+    let _: Vec<_> = repeat(vec![1, 2]).take(n * 2).collect();
+    //~^ ERROR: this `repeat().take()` can be written more concisely
+
+    // Intervening adapters
+    let _ = repeat(10).map(|x| x + 1).take(3);
+    let _ = repeat(10).skip(1).take(3);
+    // Not `iter::repeat`
+    let _ = (0..).take(3);
+}
+
+#[clippy::msrv = "1.81"]
+fn msrv_1_81() {
+    let _ = repeat(10).take(3);
+}
+
+#[clippy::msrv = "1.82"]
+fn msrv_1_82() {
+    let _ = repeat(10).take(3);
+    //~^ ERROR: this `repeat().take()` can be written more concisely
+}
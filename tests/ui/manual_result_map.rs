@@ -0,0 +1,116 @@
+#![warn(clippy::manual_result_map)]
+#![allow(dead_code)]
+
+#[derive(Debug)]
+struct ParseError;
+
+impl From<std::num::ParseIntError> for ParseError {
+    fn from(_: std::num::ParseIntError) -> Self {
+        ParseError
+    }
+}
+
+struct Legacy;
+struct Modern;
+
+#[allow(clippy::from_over_into)]
+impl Into<Modern> for Legacy {
+    fn into(self) -> Modern {
+        Modern
+    }
+}
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn map(r: Result<u32, &str>, rr: &Result<String, String>) {
+    let _ = match r {
+        Ok(v) => Ok(double(v)),
+        Err(e) => Err(e),
+    };
+    let _ = match r {
+        Err(e) => Err(e),
+        Ok(v) => Ok(v + 1),
+    };
+    let _ = match r {
+        Ok(v) => Ok(v),
+        Err(e) => Err(e.len()),
+    };
+    let _ = match rr {
+        Ok(s) => Ok(s.len()),
+        Err(e) => Err(e),
+    };
+}
+
+#[allow(clippy::manual_map)]
+fn map_option(o: Option<u32>) {
+    let _ = match o {
+        Some(v) => Some(double(v)),
+        None => None,
+    };
+}
+
+fn question_mark(r: Result<u32, String>) -> Result<u32, String> {
+    let v = match r {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    Ok(v)
+}
+
+fn question_mark_from(s: &str) -> Result<u32, ParseError> {
+    let v = match s.parse::<u32>() {
+        Ok(v) => v,
+        Err(e) => {
+            return Err(e.into());
+        },
+    };
+    Ok(v)
+}
+
+fn question_mark_option(o: Option<u32>) -> Option<u32> {
+    let v = match o {
+        Some(v) => v,
+        None => return None,
+    };
+    Some(v + 1)
+}
+
+fn no_lint(r: Result<u32, &str>) -> Result<u32, String> {
+    // Extra statements
+    let _ = match r {
+        Ok(v) => {
+            println!("{v}");
+            Ok(v + 1)
+        },
+        Err(e) => Err(e),
+    };
+    // The binding is used more than once
+    let _ = match r {
+        Ok(v) => Ok(v * v),
+        Err(e) => Err(e),
+    };
+    // Can't be moved into a closure
+    let _ = match r {
+        Ok(v) => Ok(v.checked_add(1).ok_or("overflow")?),
+        Err(e) => Err(e),
+    };
+    // Not the same error
+    let v = match r {
+        Ok(v) => v,
+        Err(_) => return Err(String::new()),
+    };
+    Ok(v)
+}
+
+fn no_from(r: Result<u32, Legacy>) -> Result<u32, Modern> {
+    // `?` needs `From`
+    let v = match r {
+        Ok(v) => v,
+        Err(e) => return Err(e.into()),
+    };
+    Ok(v)
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+#![warn(clippy::manual_rotate)]
+
+fn next() -> u32 {
+    1
+}
+
+fn rotate(a: u8, b: u32, c: u64, d: usize, t: (u32,), n: u32) {
+    let _ = (a << 3) | (a >> 5);
+    //~^ ERROR: manual implementation of `rotate_left`
+    let _ = (a >> 3) | (a << 5);
+    //~^ ERROR: manual implementation of `rotate_left`
+    let _ = (b << n) | (b >> (32 - n));
+    //~^ ERROR: manual implementation of `rotate_left`
+    let _ = (b >> (u32::BITS - n)) | (b << n);
+    //~^ ERROR: manual implementation of `rotate_left`
+    let _ = (b >> n) | (b << (u32::BITS - n));
+    //~^ ERROR: manual implementation of `rotate_right`
+    let _ = (c << 7) | (c >> (u64::BITS - 7));
+    //~^ ERROR: manual implementation of `rotate_left`
+    let _ = (c >> n) | (c << (64 - n));
+    //~^ ERROR: manual implementation of `rotate_right`
+    let _ = (d << n) | (d >> (usize::BITS - n));
+    //~^ ERROR: manual implementation of `rotate_left`
+    let _ = (t.0 << n) | (t.0 >> (32 - n));
+    //~^ ERROR: manual implementation of `rotate_left`
+}
+
+fn no_lint(a: u8, b: u32, c: u64, i: i32, n: u32, m: u32, s: usize) {
+    // The amounts don't add up to the bit width
+    let _ = (a << 3) | (a >> 6);
+    let _ = (b << n) | (b >> (31 - n));
+    let _ = (c << n) | (c >> (u32::BITS - n));
+    // Different amounts or values
+    let _ = (b << n) | (b >> (32 - m));
+    let _ = (b << n) | ((c as u32) >> (32 - n));
+    // Both shifts go in the same direction
+    let _ = (b << n) | (b << (32 - n));
+    // `x` has side effects
+    let _ = (next() << n) | (next() >> (32 - n));
+    // `>>` on signed integers isn't a rotation
+    let _ = (i << n) | (i >> (32 - n));
+    // The amount isn't a `u32`
+    let _ = (b << s) | (b >> (32 - s));
+}
+
+fn main() {}
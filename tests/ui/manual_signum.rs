@@ -0,0 +1,81 @@
+#![warn(clippy::manual_signum)]
+#![allow(clippy::comparison_chain)]
+
+use std::cmp::Ordering;
+
+const ZERO: i32 = 0;
+
+fn ints(x: i32, y: i64, v: &[i8]) {
+    let _ = if x > 0 { 1 } else if x < 0 { -1 } else { 0 };
+    //~^ ERROR: manual implementation of `signum`
+    let _ = if x < 0 { -1 } else if x > 0 { 1 } else { 0 };
+    //~^ ERROR: manual implementation of `signum`
+    let _ = if x == 0 { 0 } else if x > 0 { 1 } else { -1 };
+    //~^ ERROR: manual implementation of `signum`
+    let _: i64 = if 0 < y { 1 } else if y < i64::default() { -1 } else { 0 };
+    //~^ ERROR: manual implementation of `signum`
+    let _ = if x > ZERO { 1 } else if x < ZERO { -1 } else { ZERO };
+    //~^ ERROR: manual implementation of `signum`
+    let _: i8 = if v[0] > 0 { 1 } else if v[0] < 0 { -1 } else { 0 };
+    //~^ ERROR: manual implementation of `signum`
+    let _ = if x - 1 > 0 { 1 } else if x - 1 < 0 { -1 } else { 0 };
+    //~^ ERROR: manual implementation of `signum`
+}
+
+fn two_way(x: i32, y: i32) {
+    let _ = if x >= 0 { 1 } else { -1 };
+    //~^ ERROR: manual implementation of `signum`
+    let _ = if y < 0 { -1 } else { 1 };
+    //~^ ERROR: manual implementation of `signum`
+}
+
+fn floats(x: f64, y: f32) {
+    let _ = if x > 0.0 { 1.0 } else if x < 0.0 { -1.0 } else { 0.0 };
+    //~^ ERROR: manual implementation of `signum`
+    let _: f32 = if y < 0.0 { -1.0 } else { 1.0 };
+    //~^ ERROR: manual implementation of `signum`
+}
+
+fn cmp(x: i32, y: i64) {
+    let _ = match x.cmp(&0) {
+        //~^ ERROR: manual implementation of `signum`
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+    };
+    let _: i64 = match y.cmp(&0) {
+        //~^ ERROR: manual implementation of `signum`
+        Ordering::Equal => 0,
+        Ordering::Less => -1,
+        _ => 1,
+    };
+}
+
+fn no_lint(x: i32, y: i32) {
+    // Different expressions are compared
+    let _ = if x > 0 { 1 } else if y < 0 { -1 } else { 0 };
+    // The values don't match the comparisons
+    let _ = if x > 0 { -1 } else if x < 0 { 1 } else { 0 };
+    let _ = if x > 0 { 1 } else if x < 0 { -1 } else { 2 };
+    let _ = if x >= 0 { 1 } else if x <= 0 { -1 } else { 0 };
+    let _ = if x > 0 { 1 } else { 0 };
+    // Not compared with zero
+    let _ = if x > 1 { 1 } else if x < 1 { -1 } else { 0 };
+    // The result has a different type
+    let _: i64 = if x > 0 { 1 } else if x < 0 { -1 } else { 0 };
+    // `else x.signum()` wouldn't compile
+    let _ = if y == 1 { 2 } else if x > 0 { 1 } else if x < 0 { -1 } else { 0 };
+    // Guards and comparisons with other values
+    let _ = match x.cmp(&0) {
+        Ordering::Greater if y > 0 => 1,
+        Ordering::Less => -1,
+        _ => 0,
+    };
+    let _ = match x.cmp(&1) {
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+    };
+}
+
+fn main() {}
@@ -0,0 +1,108 @@
+#![warn(clippy::manual_slice_fill)]
+#![allow(clippy::needless_range_loop, clippy::manual_iter_mut)]
+
+use std::path::PathBuf;
+
+const VALUE: u8 = 7;
+
+fn copy_values(buf: &mut [u8], n: usize, val: u8) {
+    let mut arr = [0u32; 8];
+    let mut vec = vec![1u8; 16];
+
+    for x in &mut vec {
+        *x = 0;
+    }
+    vec.push(1);
+
+    for x in arr.iter_mut() {
+        *x = 1;
+    }
+
+    for i in 0..buf.len() {
+        buf[i] = val;
+    }
+
+    for i in 1..n {
+        buf[i] = VALUE;
+    }
+
+    for i in 0..=3 {
+        arr[i] = 2;
+    }
+
+    for x in buf {
+        *x = val;
+    }
+}
+
+fn non_copy_values(paths: &mut [PathBuf], template: PathBuf) {
+    for p in paths.iter_mut() {
+        *p = template.clone();
+    }
+
+    for p in paths.iter_mut() {
+        *p = PathBuf::new();
+    }
+}
+
+fn computed_values(buf: &mut [u32]) {
+    let mut counter = 0;
+    let mut next = || {
+        counter += 1;
+        counter
+    };
+    for x in buf.iter_mut() {
+        *x = next();
+    }
+    println!("{}", next());
+}
+
+fn should_not_lint(buf: &mut [usize], mut it: impl Iterator<Item = usize>) {
+    // uses the loop variable
+    for i in 0..buf.len() {
+        buf[i] = i;
+    }
+
+    // side effects on each iteration
+    for x in buf.iter_mut() {
+        *x = it.next().unwrap();
+    }
+
+    // reads from the filled slice
+    for i in 1..buf.len() {
+        buf[i] = buf[0];
+    }
+
+    // more than one statement
+    for x in buf.iter_mut() {
+        *x = 0;
+        println!("filled");
+    }
+
+    // not assigning to the element
+    let mut last = 0;
+    for x in buf.iter_mut() {
+        last = *x;
+    }
+    println!("{last}");
+}
+
+#[clippy::msrv = "1.49"]
+fn msrv_1_49(buf: &mut [u8]) {
+    for x in buf.iter_mut() {
+        *x = 0;
+    }
+}
+
+#[clippy::msrv = "1.50"]
+fn msrv_1_50(buf: &mut [u8], strings: &mut [String]) {
+    for x in buf.iter_mut() {
+        *x = 0;
+    }
+
+    for s in strings.iter_mut() {
+        *s = String::new();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,110 @@
+#![warn(clippy::manual_while_loop)]
+
+fn done() -> bool {
+    true
+}
+
+fn main() {
+    let mut x = 0;
+    loop {
+        if x >= 10 {
+            break;
+        }
+        x += 1;
+    }
+
+    loop {
+        if !done() {
+            break;
+        }
+        x -= 1;
+    }
+
+    loop {
+        if x == 3 || done() {
+            break;
+        }
+        if x % 2 == 0 {
+            x += 3;
+            continue;
+        }
+        x += 1;
+    }
+
+    'outer: loop {
+        if x > 100 {
+            break 'outer;
+        }
+        for i in 0..x {
+            if i == 5 {
+                continue 'outer;
+            }
+        }
+        x *= 2;
+    }
+
+    let mut y = 1.5f64;
+    loop {
+        if y < 0.5 {
+            break;
+        }
+        y /= 2.0;
+    }
+
+    // Don't lint
+
+    // The `if` isn't at the start of the loop
+    loop {
+        x += 1;
+        if x > 5 {
+            break;
+        }
+    }
+
+    // `else` branch
+    loop {
+        if x > 5 {
+            break;
+        } else {
+            x += 1;
+        }
+    }
+
+    // `break` with a value
+    let _ = loop {
+        if x > 5 {
+            break x;
+        }
+        x += 1;
+    };
+
+    // `break` to an outer loop
+    'a: loop {
+        loop {
+            if x > 5 {
+                break 'a;
+            }
+            x += 1;
+            if x == 3 {
+                break;
+            }
+        }
+    }
+
+    // More than a `break`
+    loop {
+        if x > 5 {
+            println!("done");
+            break;
+        }
+        x += 1;
+    }
+
+    // `if let` is linted by `while_let_loop`
+    let mut v = vec![1, 2];
+    loop {
+        if let Some(1) = v.pop() {
+            break;
+        }
+    }
+}
@@ -65,3 +65,30 @@ fn issue11764() {
     // no match ergonomics for `(i32, i32)`
     let _ = x.iter().copied().map(|(x, y)| (x, y));
 }
+
+#[allow(dead_code, unused_mut)]
+fn destructuring() {
+    struct Wrapper(u8);
+    struct Pair<T>(T, T);
+
+    let arrays = [[1, 2], [3, 4]];
+    // should lint
+    let _ = arrays.into_iter().map(|[x, y]| [x, y]);
+    let _ = [Wrapper(1)].into_iter().map(|Wrapper(inner)| Wrapper(inner));
+    let _: Option<[u8; 2]> = Some([1, 2]).map(|[a, b]| [a, b]);
+    let _: Option<Pair<u8>> = Some(Pair(1, 2)).map(|Pair(a, b)| Pair(a, b));
+    let _: Result<(u8, Wrapper), u8> = Ok((1, Wrapper(2))).map(|(a, Wrapper(b))| (a, Wrapper(b)));
+    let _: Result<u8, Wrapper> = Err(Wrapper(1)).map_err(|Wrapper(e)| Wrapper(e));
+
+    // should not lint
+    let _ = arrays.into_iter().map(|[x, y]| [y, x]);
+    let _ = arrays.into_iter().map(|[x, _]| [x, x]);
+    let _ = arrays.into_iter().map(|[mut x, y]| [x, y]);
+    let _ = Some(((1, 2), 3)).map(|(a @ (_, _), b)| (a, b));
+    let _ = arrays.iter().map(|[x, y]| [x, y]);
+    let _ = Some((1, 2)).map(|(ref a, b)| (*a, b));
+    let _ = [Wrapper(1)].into_iter().map(|Wrapper(inner)| Wrapper(inner + 1));
+    let _: Option<Wrapper> = Some(Pair(1, 2)).map(|Pair(a, _)| Wrapper(a));
+    let _ = Some(Pair(1, 2)).map(|Pair(a, b)| Pair(b, a));
+    let _: Result<u8, Pair<u8>> = Err(Pair(1, 2)).map_err(|Pair(a, b)| Pair(a + 1, b));
+}
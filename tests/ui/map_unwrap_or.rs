@@ -141,3 +141,24 @@ mod issue_10579 {
         println!("{y:?}");
     }
 }
+
+#[clippy::msrv = "1.81"]
+fn msrv_1_81() {
+    let opt: Option<i32> = Some(1);
+
+    let _ = opt.map(|x| x > 5).unwrap_or(true);
+}
+
+#[clippy::msrv = "1.82"]
+fn msrv_1_82() {
+    let opt: Option<i32> = Some(1);
+
+    let _ = opt.map(|x| x > 5).unwrap_or(true);
+}
+
+// `is_none_or` isn't stable on all toolchains, so it's only suggested with an explicit MSRV
+fn no_msrv() {
+    let opt: Option<i32> = Some(1);
+
+    let _ = opt.map(|x| x > 5).unwrap_or(true);
+}
@@ -55,3 +55,29 @@ fn main() {
     option_methods();
     result_methods();
 }
+
+const DEFAULT: i32 = 0;
+
+fn default_value() -> i32 {
+    0
+}
+
+#[rustfmt::skip]
+fn map_or_suggestions() {
+    let opt = Some(1);
+    let res: Result<i32, ()> = Ok(1);
+
+    let _ = opt.map(|x| x + 1).unwrap_or(0);
+    let _ = opt.map(|x| x + 1).unwrap_or(DEFAULT);
+    let _ = res.map(|x| x + 1).unwrap_or(0);
+    let _ = res.map(|x| x > 5).unwrap_or(false);
+    let _ = res.map(|x| x + 1).unwrap_or_else(|_| default_value());
+    let _ = opt.map(|x| x + 1).unwrap_or_else(default_value);
+
+    let fallback = 2;
+    let _ = opt.map(|x| x + fallback).unwrap_or_else(|| fallback);
+
+    // The default reads `count`, which is mutated by the `map` closure, so no suggestion is made.
+    let mut count = 0;
+    let _ = opt.map(|x| { count += x; x }).unwrap_or_else(|| count);
+}
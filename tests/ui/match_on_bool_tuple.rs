@@ -0,0 +1,50 @@
+#![warn(clippy::match_on_bool_tuple)]
+#![allow(clippy::single_match)]
+
+fn main() {}
+
+fn side_effect() -> bool {
+    println!("called");
+    true
+}
+
+fn match_on_bool_tuple(a: bool, b: bool, x: i32) {
+    let _ = match (a, b) {
+        //~^ ERROR: you seem to be trying to match on a tuple of boolean expressions
+        (true, true) => 1,
+        (true, false) => 2,
+        (false, _) => 3,
+    };
+
+    match (a, x > 0) {
+        //~^ ERROR: you seem to be trying to match on a tuple of boolean expressions
+        (false, true) => {
+            println!("positive");
+        },
+        _ => (),
+    };
+
+    let _ = match (a, b, x == 0) {
+        //~^ ERROR: you seem to be trying to match on a tuple of boolean expressions
+        (_, _, true) => 0,
+        (true, _, false) => 1,
+        _ => 2,
+    };
+
+    // No suggestion: the elements would be evaluated several times
+    let _ = match (a, side_effect()) {
+        //~^ ERROR: you seem to be trying to match on a tuple of boolean expressions
+        (true, true) => 1,
+        _ => 0,
+    };
+
+    // Don't lint: bindings and guards
+    let _ = match (a, b) {
+        (true, c) => c,
+        (false, _) => false,
+    };
+    let _ = match (a, b) {
+        (true, _) if x > 0 => 1,
+        _ => 0,
+    };
+}
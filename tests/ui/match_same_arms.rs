@@ -137,3 +137,18 @@ fn main() {
         _ => false,
     };
 }
+
+fn not_merged_automatically(x: Option<u8>, y: &Option<u8>) {
+    let _ = match x {
+        Some(1) | Some(2) => 1,
+        Some(3) => 1, //~ ERROR: this match arm has an identical body to another arm
+        _ => 0,
+    };
+
+    // `a` is bound by reference in both arms, but with a different binding mode
+    match (y, y) {
+        (Some(a), None) => println!("{a}"),
+        (&None, &Some(ref a)) => println!("{a}"), //~ ERROR: this match arm has an identical body to another arm
+        _ => {},
+    }
+}
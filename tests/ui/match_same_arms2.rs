@@ -280,3 +280,17 @@ mod with_lifetime {
         }
     }
 }
+
+fn keep_comments(x: Option<u8>) {
+    let _ = match x {
+        Some(0) => {
+            // nothing to see here
+            1
+        },
+        Some(42) => {
+            //~^ ERROR: this match arm has an identical body to another arm
+            1
+        },
+        _ => 0,
+    };
+}
@@ -0,0 +1,60 @@
+//@aux-build:non-exhaustive-enum.rs
+#![warn(clippy::match_wildcard_for_local_non_exhaustive)]
+#![allow(dead_code)]
+
+extern crate non_exhaustive_enum;
+
+use non_exhaustive_enum::ErrorKind;
+
+#[non_exhaustive]
+pub enum Event {
+    Start,
+    Stop,
+    Data(u8),
+    Error { code: i32 },
+}
+
+enum Exhaustive {
+    A,
+    B,
+    C,
+}
+
+fn consume(_: &Event) {}
+
+fn main() {
+    let e = Event::Start;
+    match e {
+        Event::Start => {},
+        _ => {},
+    }
+
+    match &e {
+        Event::Start | Event::Stop => {},
+        other => consume(other),
+    }
+
+    match &e {
+        Event::Data(0) => {},
+        Event::Error { .. } => {},
+        _ => {},
+    }
+
+    // All the variants are listed already
+    match e {
+        Event::Start | Event::Stop | Event::Data(_) => {},
+        Event::Error { .. } => {},
+    }
+
+    // Not `#[non_exhaustive]`
+    match Exhaustive::A {
+        Exhaustive::A => {},
+        _ => {},
+    }
+
+    // Defined in another crate, the wildcard is required
+    match ErrorKind::NotFound {
+        ErrorKind::NotFound => {},
+        _ => {},
+    }
+}
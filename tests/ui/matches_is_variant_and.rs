@@ -0,0 +1,49 @@
+#![warn(clippy::matches_is_variant_and)]
+#![allow(dead_code)]
+
+struct Wrapper {
+    name: Option<String>,
+    res: Result<u32, String>,
+}
+
+enum MyOption {
+    Some(u32),
+    None,
+}
+
+fn main() {
+    let opt = Some(5);
+    let res: Result<u32, &str> = Ok(5);
+
+    let _ = matches!(opt, Some(x) if x > 3);
+    let _ = matches!(res, Ok(x) if x % 2 == 0);
+    let _ = matches!(res, Err(e) if e.is_empty());
+    let _ = !matches!(opt, Some(x) if x > 3);
+    let _ = matches!(Some(5u8).map(u32::from), Some(x) if x > 3);
+
+    // Don't lint
+    let _ = matches!(opt, Some(3));
+    let _ = matches!(opt, Some(3 | 4));
+    let _ = matches!(MyOption::Some(5), MyOption::Some(x) if x > 3);
+}
+
+fn by_ref(w: &Wrapper, opt: &mut Option<String>) {
+    let _ = matches!(&w.name, Some(name) if name.is_empty());
+    let _ = matches!(w.name, Some(ref name) if name.len() > 3);
+    let _ = matches!(w.res, Ok(x) if x > 3);
+    let _ = matches!(opt, Some(s) if s.is_empty());
+}
+
+fn moved(w: Wrapper) {
+    let _ = matches!(w.name, Some(name) if name.is_empty());
+}
+
+#[clippy::msrv = "1.69"]
+fn msrv_1_69(opt: Option<u32>) {
+    let _ = matches!(opt, Some(x) if x > 3);
+}
+
+#[clippy::msrv = "1.70"]
+fn msrv_1_70(opt: Option<u32>) {
+    let _ = matches!(opt, Some(x) if x > 3);
+}
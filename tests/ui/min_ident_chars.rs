@@ -1,6 +1,6 @@
 //@aux-build:proc_macros.rs
 #![allow(irrefutable_let_patterns, nonstandard_style, unused)]
-#![allow(clippy::struct_field_names)]
+#![allow(clippy::struct_field_names, clippy::unused_enumerate_index)]
 #![warn(clippy::min_ident_chars)]
 
 extern crate proc_macros;
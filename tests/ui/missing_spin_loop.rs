@@ -2,7 +2,7 @@
 #![allow(clippy::bool_comparison)]
 #![allow(unused_braces)]
 
-use core::sync::atomic::{AtomicBool, Ordering};
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 fn main() {
     let b = AtomicBool::new(true);
@@ -23,5 +23,30 @@ fn main() {
     while b.load(Ordering::Acquire) {
         std::hint::spin_loop()
     }
-    // TODO: also match on loop+match or while let
+
+    let n = AtomicUsize::new(0);
+    while n.load(Ordering::Acquire) == 0 {}
+
+    // The body only does trivial work
+    let mut spins = 0;
+    while b.load(Ordering::Acquire) {
+        spins += 1;
+    }
+    loop {
+        spins += 1;
+        if !b.load(Ordering::Acquire) {
+            break;
+        }
+    }
+
+    // This is OK, as the loop calls functions
+    while b.load(Ordering::Acquire) {
+        std::thread::yield_now();
+    }
+    loop {
+        let cur = n.load(Ordering::Relaxed);
+        if n.compare_exchange(cur, cur + 1, Ordering::AcqRel, Ordering::Relaxed).is_ok() {
+            break;
+        }
+    }
 }
@@ -62,4 +62,17 @@ fn main() {
 
     // Should not lint!
     "foo".chars().any(|c| c.is_ascii());
+
+    "foo".bytes().all(|b| b.is_ascii());
+    //~^ ERROR: checking if a string is ascii using iterators
+    "foo".bytes().any(|b| !b.is_ascii());
+    //~^ ERROR: checking if a string is ascii using iterators
+    s.bytes().all(|b| u8::is_ascii(&b));
+    //~^ ERROR: checking if a string is ascii using iterators
+
+    // Should not lint!
+    "foo".chars().all(|c| c.is_ascii_digit());
+
+    // Should not lint!
+    "foo".bytes().all(|b| !b.is_ascii());
 }
@@ -0,0 +1,63 @@
+#![warn(clippy::needless_collect)]
+#![allow(clippy::iter_cloned_collect)]
+
+use std::collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
+
+fn main() {
+    let mut items = vec![3, 1, 2];
+    let names = vec![String::from("a"), String::from("b")];
+
+    for x in items.iter().map(|x| x * 2).collect::<Vec<_>>() {
+        println!("{x}");
+    }
+
+    for x in items.iter().copied().collect::<VecDeque<_>>() {
+        println!("{x}");
+    }
+
+    for x in items.iter().collect::<LinkedList<_>>() {
+        println!("{x}");
+    }
+
+    for _ in items.iter().filter(|&&x| x > 1).collect::<Vec<_>>() {}
+
+    'outer: for x in items.iter().map(|x| x + 1).collect::<Vec<_>>() {
+        for y in 0..x {
+            if y == 2 {
+                break 'outer;
+            }
+        }
+    }
+
+    for x in items.iter().collect::<BinaryHeap<_>>() {
+        println!("{x}");
+    }
+
+    for name in &names.iter().cloned().collect::<Vec<_>>() {
+        println!("{name}");
+    }
+
+    for x in &items.iter().map(|x| x * 2).collect::<Vec<_>>() {
+        println!("{x}");
+    }
+
+    // Don't lint
+
+    // Sets change the order and remove duplicates
+    for x in items.iter().collect::<HashSet<_>>() {
+        println!("{x}");
+    }
+    for x in items.iter().collect::<BTreeSet<_>>() {
+        println!("{x}");
+    }
+
+    // The body uses the source of the iterator
+    for x in items.iter().map(|x| x * 2).collect::<Vec<_>>() {
+        items.push(x);
+    }
+
+    // Not collected into a collection of items
+    for c in names.iter().map(String::as_str).collect::<String>().chars() {
+        println!("{c}");
+    }
+}
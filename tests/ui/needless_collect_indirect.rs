@@ -322,3 +322,47 @@ mod issue_8553 {
         w.len();
     }
 }
+
+fn into_iter_uses() {
+    let sample = [1; 5];
+    let mut other = Vec::new();
+
+    let in_for = sample.iter().map(|x| x + 1).collect::<Vec<_>>();
+    //~^ ERROR: avoid using `collect()` when not needed
+    for x in in_for {
+        other.push(x);
+    }
+
+    let extended = sample.iter().map(|x| x * 2).collect::<Vec<_>>();
+    //~^ ERROR: avoid using `collect()` when not needed
+    other.extend(extended);
+
+    let set = sample.iter().collect::<HashSet<_>>();
+    //~^ ERROR: avoid using `collect()` when not needed
+    set.contains(&&1);
+
+    let set = sample.iter().collect::<HashSet<_>>();
+    //~^ ERROR: avoid using `collect()` when not needed
+    set.is_empty();
+
+    // Do not lint, the set removes duplicates
+    let set = sample.iter().collect::<HashSet<_>>();
+    set.len();
+    let set = sample.iter().collect::<HashSet<_>>();
+    for x in set {
+        other.push(*x);
+    }
+
+    // Do not lint, the source is mutated before the collection is used
+    let mut source = vec![1, 2, 3];
+    let doubled = source.iter().map(|x| x * 2).collect::<Vec<_>>();
+    source.push(4);
+    for x in doubled {
+        other.push(x);
+    }
+
+    // Do not lint, the collection is used twice
+    let twice = sample.iter().map(|x| x + 1).collect::<Vec<_>>();
+    let len = twice.len();
+    other.extend(twice);
+}
@@ -1,5 +1,5 @@
 #![warn(clippy::needless_match)]
-#![allow(clippy::manual_map)]
+#![allow(clippy::manual_map, clippy::manual_result_map, clippy::if_let_chain_same_scrutinee)]
 #![allow(dead_code)]
 
 #[derive(Clone, Copy)]
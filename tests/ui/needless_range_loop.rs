@@ -1,5 +1,6 @@
 #![warn(clippy::needless_range_loop)]
 #![allow(
+    clippy::manual_iter_mut,
     clippy::uninlined_format_args,
     clippy::unnecessary_literal_unwrap,
     clippy::useless_vec
@@ -44,7 +45,7 @@ fn main() {
         println!("{} {}", vec[i], i);
     }
     for i in 0..vec.len() {
-        // not an error, indexing more than one variable
+        //~^ ERROR: the loop variable `i` is only used to index `vec` and `vec2`
         println!("{} {}", vec[i], vec2[i]);
     }
 
@@ -171,3 +172,32 @@ mod issue_2496 {
         unimplemented!()
     }
 }
+
+fn zip(a: &[i32], b: &[i32], c: Vec<i32>, mut out: Vec<i32>) -> i32 {
+    let mut sum = 0;
+    for i in 0..a.len().min(b.len()) {
+        //~^ ERROR: the loop variable `i` is only used to index `a` and `b`
+        sum += a[i] * b[i];
+    }
+
+    for i in 0..a.len().min(b.len()).min(c.len()) {
+        //~^ ERROR: the loop variable `i` is only used to index `a`, `b` and `c`
+        sum += a[i] * b[i] + c[i];
+    }
+
+    for i in 0..out.len() {
+        //~^ ERROR: the loop variable `i` is only used to index `out` and `a`
+        out[i] = a[i] * 2;
+    }
+
+    // not an error, `i` is also used outside of the indexing
+    for i in 0..a.len().min(b.len()) {
+        sum += a[i] * b[i] + i as i32;
+    }
+
+    // not an error, the loop doesn't end at the length of the indexed sequences
+    for i in 0..3 {
+        sum += a[i] * b[i];
+    }
+    sum
+}
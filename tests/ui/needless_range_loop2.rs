@@ -1,5 +1,5 @@
 #![warn(clippy::needless_range_loop)]
-#![allow(clippy::useless_vec)]
+#![allow(clippy::manual_iter_mut, clippy::useless_vec)]
 //@no-rustfix
 fn calc_idx(i: usize) -> usize {
     (i + i + 20) % 4
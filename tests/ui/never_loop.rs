@@ -4,7 +4,8 @@
     clippy::single_match,
     unused_assignments,
     unused_variables,
-    clippy::while_immutable_condition
+    clippy::while_immutable_condition,
+    clippy::manual_while_loop
 )]
 //@no-rustfix
 fn test1() {
@@ -27,6 +27,9 @@ fn main() {
         .filter(|o| o.is_some())
         //~^ ERROR: `filter` for `Some` followed by `unwrap`
         .map(|o| o.unwrap());
+
+    let _ = vec![Some(1)].into_iter().filter(|&o| o.is_some()).map(|o| o.unwrap());
+    //~^ ERROR: `filter` for `Some` followed by `unwrap`
 }
 
 fn odds_out(x: i32) -> Option<i32> {
@@ -319,3 +319,40 @@ fn host_effect() {
 }
 
 fn main() {}
+
+mod allocating_constructors {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn with_capacity(n: usize) {
+        let map: Option<HashMap<u32, u32>> = None;
+        let _ = map.unwrap_or(HashMap::with_capacity(16));
+        let opt: Option<String> = None;
+        let _ = opt.clone().unwrap_or(String::with_capacity(n));
+        let res: Result<String, ()> = Ok(String::new());
+        let _ = res.clone().unwrap_or(String::with_capacity(n));
+        let num: Result<u32, ()> = Ok(1);
+        let _ = num.map_or(String::with_capacity(n), |v| v.to_string());
+
+        // these don't allocate
+        let vec: Option<Vec<u32>> = None;
+        let _ = vec.unwrap_or(Vec::with_capacity(0));
+        let _ = opt.clone().unwrap_or(String::with_capacity(0));
+        let _ = res.clone().unwrap_or(String::with_capacity(0));
+    }
+
+    fn from_and_to_owned() {
+        let path: Option<PathBuf> = None;
+        let _ = path.unwrap_or(PathBuf::from("/tmp"));
+        let opt: Option<String> = None;
+        let _ = opt.clone().unwrap_or("foo".to_owned());
+        let _ = opt.clone().or(Some("foo".to_string()));
+        let _ = Some(1).ok_or("error".to_string());
+        let res: Result<String, ()> = Ok(String::new());
+        let _ = res.clone().or(Ok::<_, ()>("foo".to_string()));
+
+        // these don't allocate
+        let _ = opt.clone().unwrap_or("".to_string());
+        let _ = res.clone().unwrap_or("".to_owned());
+    }
+}
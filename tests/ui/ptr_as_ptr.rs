@@ -33,7 +33,7 @@ fn main() {
         let _ = *ptr_ptr as *const i32;
     }
 
-    // Changes in mutability. Do not lint this.
+    // Changes in mutability, `pointer::cast_mut` and `pointer::cast_const` follow the cast
     let _ = ptr as *mut i32;
     let _ = mut_ptr as *const i32;
 
@@ -186,3 +186,32 @@ mod null_entire_infer {
         core::ptr::null() as _
     }
 }
+
+fn cast_chains() {
+    let ptr: *const u32 = &42_u32;
+    let mut_ptr: *mut u32 = &mut 42_u32;
+
+    // Intermediate casts are collapsed into a single suggestion
+    let _ = ptr as *const u8 as *const i32;
+    let _ = ptr as *const u8 as *mut u8 as *mut i32;
+    let _ = mut_ptr as *const u32 as *const u8 as *mut i8;
+    unsafe {
+        *(ptr as *const u8 as *mut u8) = 0;
+        let _ = *(mut_ptr as *mut u8 as *const u16 as *const i16);
+    }
+
+    // The use site fixes the type of `cast_mut`, not the one of `cast`
+    let _: *mut i32 = ptr as *mut _;
+
+    // The pointee is only inferred from a later use
+    let inferred = ptr as *const _;
+    let _: *const i32 = inferred;
+}
+
+#[clippy::msrv = "1.64"]
+fn _msrv_1_64() {
+    let ptr: *const u32 = &42_u32;
+
+    // `pointer::cast_mut` was stabilized in 1.65. Do not lint this
+    let _ = ptr as *mut i32;
+}
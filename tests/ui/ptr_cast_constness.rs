@@ -64,3 +64,18 @@ fn _msrv_1_65() {
     let _ = ptr as *mut u32;
     let _ = mut_ptr as *const u32;
 }
+
+fn cast_chains() {
+    let ptr: *const u32 = &42_u32;
+    let mut_ptr: *mut u32 = &mut 42_u32;
+
+    // The pointee is the same at both ends of the chain
+    let _ = ptr as *const i32 as *mut u32;
+    let _ = mut_ptr as *const u32 as *const u8 as *const u32;
+    unsafe {
+        *(ptr as *mut u8 as *mut u32) = 0;
+    }
+
+    // `ptr_as_ptr` lints the whole chain if it is enabled, only the inner cast is linted here
+    let _ = ptr as *mut u32 as *mut i32;
+}
@@ -45,3 +45,11 @@ fn main() {
         println!("Neither will this");
     }
 }
+
+fn buf_read() {
+    use std::io::BufRead;
+
+    let mut input = String::new();
+    std::io::stdin().lock().read_line(&mut input).unwrap();
+    let _x: i32 = input.parse().unwrap();
+}
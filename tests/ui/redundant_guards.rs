@@ -317,3 +317,93 @@ mod issue12243 {
         }
     };
 }
+
+mod consts_and_ranges {
+    const MAX: u32 = 10;
+    const NAME: &str = "main";
+    const HALF: f32 = 0.5;
+
+    #[derive(PartialEq, Eq)]
+    struct Derived(u32);
+    const DERIVED: Derived = Derived(1);
+
+    struct Manual(u32);
+    impl PartialEq for Manual {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    const MANUAL: Manual = Manual(1);
+
+    trait Limit {
+        const LIMIT: u32;
+    }
+
+    fn consts<T: Limit>(x: Option<u32>, s: &str, d: Derived, f: f32, m: Manual) {
+        match x {
+            Some(x) if x == MAX => {},
+            //~^ ERROR: redundant guard
+            Some(x) if u32::MAX == x => {},
+            //~^ ERROR: redundant guard
+            // Don't lint: the constant depends on a generic parameter
+            Some(x) if x == T::LIMIT => {},
+            _ => {},
+        }
+        match (x, s) {
+            (Some(1 | 2), s) if s == NAME => {},
+            //~^ ERROR: redundant guard
+            _ => {},
+        }
+        match d {
+            d if d == DERIVED => {},
+            //~^ ERROR: redundant guard
+            _ => {},
+        }
+        // Don't lint: floats and types without structural equality can't be matched against
+        match f {
+            f if f == HALF => {},
+            _ => {},
+        }
+        match m {
+            m if m == MANUAL => {},
+            _ => {},
+        }
+    }
+
+    fn ranges(x: Option<u8>, c: char, r: &i32, f: f64) {
+        match x {
+            Some(x) if (1..=5).contains(&x) => {},
+            //~^ ERROR: redundant guard
+            Some(x) if (6..10).contains(&x) => {},
+            //~^ ERROR: redundant guard
+            // Don't lint: empty or unbounded ranges
+            Some(x) if (5..=1).contains(&x) => {},
+            Some(x) if (5..5).contains(&x) => {},
+            Some(x) if (10..).contains(&x) => {},
+            _ => {},
+        }
+        match c {
+            c if ('a'..='z').contains(&c) => {},
+            //~^ ERROR: redundant guard
+            _ => {},
+        }
+        match r {
+            &x if (-5..=5).contains(&x) => {},
+            //~^ ERROR: redundant guard
+            _ => {},
+        }
+        // Don't lint: float ranges
+        match f {
+            f if (0.0..=1.0).contains(&f) => {},
+            _ => {},
+        }
+    }
+
+    #[clippy::msrv = "1.79"]
+    fn exclusive_range_msrv(x: u8) {
+        match x {
+            x if (1..10).contains(&x) => {},
+            _ => {},
+        }
+    }
+}
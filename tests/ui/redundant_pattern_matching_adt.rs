@@ -0,0 +1,89 @@
+#![warn(clippy::redundant_pattern_matching)]
+#![allow(dead_code, clippy::needless_bool, clippy::equatable_if_let)]
+
+mod with_methods {
+    pub enum State {
+        Ready,
+        Pending(u32),
+        Done { code: i32 },
+    }
+
+    impl State {
+        pub fn is_ready(&self) -> bool {
+            matches!(self, State::Ready)
+        }
+
+        pub fn is_pending(&self) -> bool {
+            matches!(self, State::Pending(_))
+        }
+
+        // Not an `is_done(&self) -> bool` method
+        pub fn is_done(&self, code: i32) -> bool {
+            matches!(self, State::Done { code: c } if *c == code)
+        }
+    }
+
+    fn check(state: &State) {
+        let _ = match state {
+            State::Ready => true,
+            _ => false,
+        };
+        let _ = match *state {
+            State::Pending(_) => false,
+            _ => true,
+        };
+        let _ = if let State::Ready = state { true } else { false };
+        let _ = matches!(state, State::Pending(_));
+
+        // Only `match_like_matches_macro` lints these
+        let _ = match state {
+            State::Done { .. } => true,
+            _ => false,
+        };
+        let _ = match state {
+            State::Pending(1) => true,
+            _ => false,
+        };
+        let _ = match state {
+            State::Ready => true,
+            State::Pending(_) => true,
+            _ => false,
+        };
+    }
+}
+
+mod without_methods {
+    #![allow(clippy::match_like_matches_macro)]
+
+    enum Shape {
+        Circle(f64),
+        Square(f64),
+        Point,
+    }
+
+    fn check(shape: Shape) {
+        let _ = match shape {
+            Shape::Point => true,
+            _ => false,
+        };
+        let _ = match shape {
+            Shape::Circle(_) | Shape::Square(_) => false,
+            _ => true,
+        };
+        let _ = match shape {
+            Shape::Circle(r) if r > 1.0 => true,
+            _ => false,
+        };
+        let _ = if let Shape::Square(_) = shape { false } else { true };
+
+        // Already uses `matches!`
+        let _ = matches!(shape, Shape::Point);
+        // Not returning literals
+        let _ = match shape {
+            Shape::Circle(r) => r > 1.0,
+            _ => false,
+        };
+    }
+}
+
+fn main() {}
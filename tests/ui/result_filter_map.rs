@@ -32,4 +32,7 @@ fn main() {
         .filter(|o| o.is_ok())
         //~^ ERROR: `filter` for `Ok` followed by `unwrap`
         .map(|o| o.unwrap());
+
+    let _ = vec![Ok::<i32, ()>(1)].into_iter().filter(|&o| o.is_ok()).map(|o| o.unwrap());
+    //~^ ERROR: `filter` for `Ok` followed by `unwrap`
 }
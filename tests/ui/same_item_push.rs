@@ -1,5 +1,5 @@
 #![warn(clippy::same_item_push)]
-
+#![allow(clippy::counted_push_loop)]
 const VALUE: u8 = 7;
 
 fn mutate_increment(x: &mut u8) -> u8 {
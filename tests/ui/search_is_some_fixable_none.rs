@@ -1,4 +1,4 @@
-#![allow(dead_code, clippy::explicit_auto_deref, clippy::useless_vec)]
+#![allow(dead_code, clippy::explicit_auto_deref, clippy::useless_vec, clippy::manual_contains)]
 #![warn(clippy::search_is_some)]
 
 fn main() {
@@ -269,3 +269,20 @@ mod issue_11910 {
         let _ = s.find("world").is_none().then_some(0);
     }
 }
+
+fn filter_next_and_find_map() {
+    fn parse(s: &str) -> Option<i32> {
+        s.parse().ok()
+    }
+
+    let v = vec![3, 2, 1, 0, -1];
+    let strings = ["1", "a"];
+
+    // Check `filter().next().is_none()`
+    let _ = v.iter().filter(|&x| *x < 0).next().is_none();
+    let _ = v.iter().filter(|x| **x == 0).next().is_none();
+
+    // Check `find_map().is_none()`
+    let _ = v.iter().find_map(|x| x.checked_sub(1)).is_none();
+    let _ = strings.into_iter().find_map(parse).is_none();
+}
@@ -1,4 +1,4 @@
-#![allow(dead_code, clippy::explicit_auto_deref, clippy::useless_vec)]
+#![allow(dead_code, clippy::explicit_auto_deref, clippy::useless_vec, clippy::manual_contains)]
 #![warn(clippy::search_is_some)]
 
 fn main() {
@@ -248,3 +248,20 @@ mod issue9120 {
         let _ = v.iter().find(|x: &&u32| (*arg_no_deref_dyn)(x)).is_some();
     }
 }
+
+fn filter_next_and_find_map() {
+    fn parse(s: &str) -> Option<i32> {
+        s.parse().ok()
+    }
+
+    let v = vec![3, 2, 1, 0, -1];
+    let strings = ["1", "a"];
+
+    // Check `filter().next().is_some()`
+    let _ = v.iter().filter(|&x| *x < 0).next().is_some();
+    let _ = v.iter().filter(|x| **x == 0).next().is_some();
+
+    // Check `find_map().is_some()`
+    let _ = v.iter().find_map(|x| x.checked_sub(1)).is_some();
+    let _ = strings.into_iter().find_map(parse).is_some();
+}
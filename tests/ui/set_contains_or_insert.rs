@@ -0,0 +1,100 @@
+#![warn(clippy::set_contains_or_insert)]
+#![allow(clippy::map_entry)]
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+struct Visited {
+    seen: HashSet<u32>,
+}
+
+fn work(_: u32) {}
+
+fn hash_set(seen: &mut HashSet<u32>, names: &mut HashSet<String>, value: u32, name: String) {
+    if !seen.contains(&value) {
+        //~^ ERROR: usage of `HashSet::insert` after `HashSet::contains`
+        seen.insert(value);
+        work(value);
+    }
+
+    if seen.contains(&value) {
+        //~^ ERROR: usage of `HashSet::insert` after `HashSet::contains`
+        work(0);
+    } else {
+        seen.insert(value);
+        work(value);
+    }
+
+    if !seen.contains(&value) {
+        //~^ ERROR: usage of `HashSet::insert` after `HashSet::contains`
+        work(value);
+        seen.insert(value);
+    }
+
+    if !seen.contains(&value) {
+        //~^ ERROR: usage of `HashSet::insert` after `HashSet::contains`
+        seen.insert(value);
+    }
+
+    if !names.contains(&name) {
+        //~^ ERROR: usage of `HashSet::insert` after `HashSet::contains`
+        names.insert(name.clone());
+        work(name.len() as u32);
+    }
+}
+
+fn btree_set(seen: &mut BTreeSet<u32>, visited: &mut Visited, value: u32) {
+    if !seen.contains(&value) {
+        //~^ ERROR: usage of `BTreeSet::insert` after `BTreeSet::contains`
+        seen.insert(value);
+        work(value);
+    }
+
+    if !visited.seen.contains(&value) {
+        //~^ ERROR: usage of `HashSet::insert` after `HashSet::contains`
+        visited.seen.insert(value);
+        work(value);
+    }
+}
+
+fn no_lint(
+    seen: &mut HashSet<u32>,
+    other: &mut HashSet<u32>,
+    map: &mut HashMap<u32, u32>,
+    v: &mut Vec<u32>,
+    value: u32,
+) {
+    // Different values or sets
+    if !seen.contains(&value) {
+        seen.insert(value + 1);
+        work(value);
+    }
+    if !seen.contains(&value) {
+        other.insert(value);
+        work(value);
+    }
+    // The set is used before the value is inserted
+    if !seen.contains(&value) {
+        seen.remove(&0);
+        seen.insert(value);
+    }
+    // The value is inserted in the branch where the set already contains it
+    if seen.contains(&value) {
+        seen.insert(value);
+    }
+    // The value isn't always inserted
+    if !seen.contains(&value) {
+        if value > 1 {
+            seen.insert(value);
+        }
+        work(value);
+    }
+    // Not a set
+    if !v.contains(&value) {
+        v.push(value);
+    }
+    if !map.contains_key(&value) {
+        map.insert(value, 0);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+//@no-rustfix
+#![warn(clippy::set_contains_or_insert)]
+
+use std::collections::HashSet;
+
+fn work(_: u32) {}
+
+fn main() {
+    let mut names = HashSet::new();
+    let name = String::from("a");
+    // `name` is still used when the set already contains it
+    if !names.contains(&name) {
+        //~^ ERROR: usage of `HashSet::insert` after `HashSet::contains`
+        names.insert(name);
+        work(0);
+    } else {
+        println!("{name} was already seen");
+    }
+}
@@ -199,3 +199,49 @@ fn issue_10808(bar: Option<i32>) {
         },
     }
 }
+
+fn binding_else_arm(opt: Option<u32>) {
+    match opt {
+        Some(v) => println!("{v}"),
+        other => {
+            println!("no value");
+            println!("{other:?}");
+        },
+    }
+
+    match opt {
+        Some(v) => println!("{v}"),
+        _other => {
+            println!("no value");
+            println!("really no value");
+        },
+    }
+
+    let x = match opt {
+        Some(v) => v,
+        other => {
+            println!("{other:?}");
+            0
+        },
+    };
+
+    // Don't lint, `other` can't be bound again without evaluating the scrutinee twice
+    match opt.map(|v| v + 1) {
+        Some(v) => println!("{v}"),
+        other => {
+            println!("no value");
+            println!("{other:?}");
+        },
+    }
+}
+
+fn comment_between_arms(opt: Option<u32>) {
+    match opt {
+        Some(v) => println!("{v}"),
+        // nothing to print
+        None => {
+            println!("no value");
+            println!("really no value");
+        },
+    }
+}
@@ -0,0 +1,37 @@
+#![warn(clippy::sliced_string_as_bytes)]
+
+struct Message {
+    text: String,
+}
+
+fn main() {
+    let s = "Lorem ipsum";
+    let string = String::from("dolor sit amet");
+    let n = 5;
+
+    let bytes = s[1..5].as_bytes();
+    //~^ ERROR: calling `as_bytes` after slicing a string
+    let bytes = string[..n].as_bytes();
+    //~^ ERROR: calling `as_bytes` after slicing a string
+    let bytes = s[n..].as_bytes();
+    //~^ ERROR: calling `as_bytes` after slicing a string
+    let bytes = s[1..=n].as_bytes();
+    //~^ ERROR: calling `as_bytes` after slicing a string
+    let len = s[..n].as_bytes().len();
+    //~^ ERROR: calling `as_bytes` after slicing a string
+    let first = string[n..].as_bytes()[0];
+    //~^ ERROR: calling `as_bytes` after slicing a string
+
+    let msg = Message { text: string.clone() };
+    let bytes = msg.text[..n].as_bytes();
+    //~^ ERROR: calling `as_bytes` after slicing a string
+
+    // The bytes are turned back into a string
+    let slice = std::str::from_utf8(s[..n].as_bytes());
+    let slice = unsafe { std::str::from_utf8_unchecked(s[..n].as_bytes()) };
+
+    // Not slicing a string
+    let bytes = s.as_bytes();
+    let array = [s, s];
+    let bytes = array[0].as_bytes();
+}
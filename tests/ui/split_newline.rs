@@ -0,0 +1,33 @@
+#![warn(clippy::split_newline)]
+#![allow(clippy::single_char_pattern)]
+
+fn main() {
+    let s = "first\nsecond\n";
+    let string = String::from("first\nsecond\n");
+
+    let _ = s.split('\n');
+    let _ = s.split("\n");
+    let _ = string.split('\n');
+    let _ = s.rsplit('\n');
+    let _ = s.split_terminator('\n');
+    let _ = string.split_terminator("\n");
+
+    // `splitn` keeps the rest of the string in the last item
+    let _ = s.splitn(2, '\n');
+    let _ = s.rsplitn(2, '\n');
+
+    // not exactly a newline
+    let _ = s.split("\r\n");
+    let _ = s.split(' ');
+    let _ = s.split(['\n', ' ']);
+
+    // linted by `str_split_at_newline`
+    let _ = s.trim().split('\n');
+
+    // byte strings
+    let bytes = b"first\nsecond\n";
+    let _ = bytes.split(|b| *b == b'\n');
+    let _ = bytes.rsplit(|b| *b == b'\n');
+    let _ = b"first\nsecond\n".split(|&b| b == b'\n');
+    let _ = bytes.to_vec().split(|b| *b == b'\n');
+}
@@ -1,4 +1,4 @@
-#![allow(clippy::needless_if, dead_code, unused_must_use)]
+#![allow(clippy::needless_if, clippy::double_ended_iterator_last, dead_code, unused_must_use)]
 
 fn main() {}
 
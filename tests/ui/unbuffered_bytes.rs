@@ -0,0 +1,37 @@
+#![warn(clippy::unbuffered_bytes)]
+
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read, Stdin};
+use std::net::TcpStream;
+
+fn file_by_value(file: File) {
+    let _ = file.bytes();
+    //~^ ERROR: calling `bytes()` on an unbuffered reader reads a single byte at a time
+}
+
+fn file_by_mut_ref(file: &mut File) {
+    let _ = file.bytes();
+    //~^ ERROR: calling `bytes()` on an unbuffered reader reads a single byte at a time
+}
+
+fn other_unbuffered(stream: TcpStream, stdin: Stdin) {
+    let _ = stream.bytes();
+    //~^ ERROR: calling `bytes()` on an unbuffered reader reads a single byte at a time
+    let _ = stdin.bytes();
+    //~^ ERROR: calling `bytes()` on an unbuffered reader reads a single byte at a time
+}
+
+fn buffered(file: File, stdin: Stdin, slice: &[u8]) {
+    let _ = BufReader::new(file).bytes();
+    let _ = stdin.lock().bytes();
+    let _ = Cursor::new(vec![1u8, 2]).bytes();
+    let _ = slice.bytes();
+}
+
+fn generic<R: Read>(reader: R) {
+    let _ = reader.bytes();
+}
+
+fn main() {
+    let _ = "hello".bytes();
+}
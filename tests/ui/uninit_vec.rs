@@ -15,14 +15,14 @@ union MyOwnMaybeUninit {
 fn main() {
     // with_capacity() -> set_len() should be detected
     let mut vec: Vec<u8> = Vec::with_capacity(1000);
-    //~^ ERROR: calling `set_len()` immediately after reserving a buffer creates uninitial
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
     unsafe {
         vec.set_len(200);
     }
 
     // reserve() -> set_len() should be detected
     vec.reserve(1000);
-    //~^ ERROR: calling `set_len()` immediately after reserving a buffer creates uninitial
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
     unsafe {
         vec.set_len(200);
     }
@@ -50,16 +50,16 @@ fn main() {
     // test when both calls are enclosed in the same unsafe block
     unsafe {
         let mut vec: Vec<u8> = Vec::with_capacity(1000);
-        //~^ ERROR: calling `set_len()` immediately after reserving a buffer creates unini
+        //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
         vec.set_len(200);
 
         vec.reserve(1000);
-        //~^ ERROR: calling `set_len()` immediately after reserving a buffer creates unini
+        //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
         vec.set_len(200);
     }
 
     let mut vec: Vec<u8> = Vec::with_capacity(1000);
-    //~^ ERROR: calling `set_len()` immediately after reserving a buffer creates uninitial
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
     unsafe {
         // test the case where there are other statements in the following unsafe block
         vec.set_len(200);
@@ -69,13 +69,13 @@ fn main() {
     // handle vec stored in the field of a struct
     let mut my_vec = MyVec::default();
     my_vec.vec.reserve(1000);
-    //~^ ERROR: calling `set_len()` immediately after reserving a buffer creates uninitial
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
     unsafe {
         my_vec.vec.set_len(200);
     }
 
     my_vec.vec = Vec::with_capacity(1000);
-    //~^ ERROR: calling `set_len()` immediately after reserving a buffer creates uninitial
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
     unsafe {
         my_vec.vec.set_len(200);
     }
@@ -99,9 +99,11 @@ fn main() {
         vec.set_len(200);
     }
 
-    // known false negative
+    // interleaved initializations are followed separately
     let mut vec1: Vec<u8> = Vec::with_capacity(1000);
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
     let mut vec2: Vec<u8> = Vec::with_capacity(1000);
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
     unsafe {
         vec1.set_len(200);
         vec2.set_len(200);
@@ -130,7 +132,7 @@ fn main() {
     fn polymorphic<T>() {
         // We are conservative around polymorphic types.
         let mut vec: Vec<T> = Vec::with_capacity(1000);
-        //~^ ERROR: calling `set_len()` immediately after reserving a buffer creates unini
+        //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
         unsafe {
             vec.set_len(10);
         }
@@ -144,3 +146,75 @@ fn main() {
         }
     }
 }
+
+fn split_across_statements(n: usize) {
+    // only reads and unrelated statements in between
+    let mut vec: Vec<u8> = Vec::with_capacity(n);
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
+    let len = vec.len();
+    println!("{len} {}", vec.capacity());
+    unsafe {
+        vec.set_len(n);
+    }
+
+    // `set_len()` inside a loop
+    let mut vec: Vec<u8> = Vec::with_capacity(n);
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
+    for i in 0..n {
+        unsafe {
+            vec.set_len(i);
+        }
+    }
+
+    // allocated by a closure
+    let alloc = |n: usize| Vec::with_capacity(n * 2);
+    let mut vec: Vec<u8> = alloc(n);
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
+    unsafe {
+        vec.set_len(n);
+    }
+
+    // `reserve()` followed by `set_len()` with the old length plus the reserved length
+    let mut vec: Vec<u8> = Vec::new();
+    let old_len = vec.len();
+    vec.reserve(n);
+    //~^ ERROR: calling `set_len()` after reserving a buffer without initializing it creates
+    unsafe {
+        vec.set_len(old_len + n);
+    }
+}
+
+fn fill(vec: &mut Vec<u8>) {
+    vec.push(0);
+}
+
+fn initialized_in_between(n: usize, src: &[u8]) {
+    let mut vec: Vec<u8> = Vec::with_capacity(n);
+    vec.extend_from_slice(src);
+    unsafe {
+        vec.set_len(src.len());
+    }
+
+    let mut vec: Vec<u8> = Vec::with_capacity(n);
+    for _ in 0..n {
+        vec.push(0);
+    }
+    unsafe {
+        vec.set_len(n);
+    }
+
+    let mut vec: Vec<u8> = Vec::with_capacity(n);
+    fill(&mut vec);
+    unsafe {
+        vec.set_len(1);
+    }
+
+    // the spare capacity is initialized before `set_len()`
+    let mut vec: Vec<u8> = Vec::with_capacity(n);
+    for slot in vec.spare_capacity_mut() {
+        slot.write(0);
+    }
+    unsafe {
+        vec.set_len(n);
+    }
+}
@@ -0,0 +1,67 @@
+#![warn(clippy::unnecessary_clone_into_iter)]
+#![allow(clippy::redundant_clone, clippy::ptr_arg)]
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+fn make() -> Vec<String> {
+    vec![String::new()]
+}
+
+struct S {
+    items: Vec<String>,
+}
+
+fn main() {
+    let names = vec![String::from("a"), String::from("b")];
+    let _: Vec<(usize, String)> = names.clone().into_iter().enumerate().collect();
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+    println!("{names:?}");
+
+    let numbers = vec![1, 2, 3];
+    let _: u32 = numbers.clone().into_iter().sum();
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+    let _ = numbers.len();
+
+    let queue: VecDeque<String> = VecDeque::new();
+    for _ in queue.clone().into_iter().rev() {}
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+    let _ = queue.len();
+
+    let map: HashMap<u32, String> = HashMap::new();
+    let _: Vec<(u32, String)> = map.clone().into_iter().collect();
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+    let _ = map.len();
+
+    let s = S { items: make() };
+    let _: Vec<(String, i32)> = s.items.clone().into_iter().zip(0..).collect();
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+
+    // The originals aren't used afterwards
+    let last = vec![String::new()];
+    let _: Vec<String> = last.clone().into_iter().filter(|s| s.is_empty()).collect();
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+    for _ in make().clone().into_iter().rev() {}
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+
+    // Used again by the next iteration of the loop
+    let set: BTreeSet<u8> = BTreeSet::new();
+    for _ in 0..2 {
+        let _: Vec<u8> = set.clone().into_iter().collect();
+        //~^ ERROR: unnecessary clone before calling `into_iter`
+    }
+
+    // Used again by the next call of the closure
+    let words = vec![String::new()];
+    let f = || words.clone().into_iter().rev().collect::<Vec<_>>();
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+    f();
+}
+
+fn borrowed(v: &Vec<u32>, mut moved: Vec<u32>) {
+    let _: u32 = v.clone().into_iter().sum();
+    //~^ ERROR: unnecessary clone before calling `into_iter`
+
+    // Don't lint the `into_iter` call of the `for` loop desugaring
+    for _ in moved.clone() {}
+    moved.push(1);
+}
@@ -77,4 +77,58 @@ fn issue10000() {
     smoketest_map(HashMap::new());
 }
 
+fn fold_into_collection(values: &[u32]) {
+    use std::collections::{BTreeSet, HashMap};
+
+    let _ = values.iter().fold(Vec::new(), |mut acc, x| {
+        acc.push(x * 2);
+        acc
+    });
+    let _: Vec<u32> = values.iter().copied().fold(Vec::new(), |mut acc, x| {
+        acc.push(x);
+        acc
+    });
+    let _ = (0..3).fold(HashMap::new(), |mut acc, x| {
+        acc.insert(x, x.to_string());
+        acc
+    });
+    let _ = ['a', 'b'].into_iter().fold(String::new(), |mut acc, c| {
+        acc.push(c);
+        acc
+    });
+    let _ = (0u32..3).fold(BTreeSet::<u64>::new(), |mut acc, x| {
+        if x % 2 == 0 {
+            acc.insert(u64::from(x));
+        }
+        acc
+    });
+
+    // should not lint
+    let _ = values.iter().fold(Vec::new(), |mut acc, x| {
+        acc.push(x * 2);
+        acc.push(x * 3);
+        acc
+    });
+    let _ = values.iter().fold(Vec::with_capacity(3), |mut acc, x| {
+        acc.push(x * 2);
+        acc
+    });
+    let _ = values.iter().fold(Vec::new(), |mut acc, x| {
+        acc.push(acc.len() as u32 + x);
+        acc
+    });
+    let _ = values.iter().fold(Vec::new(), |mut acc, x| {
+        if *x > 1 {
+            acc.push(x * 2);
+        } else {
+            acc.push(0);
+        }
+        acc
+    });
+    let _ = values.iter().fold(vec![0], |mut acc, x| {
+        acc.push(x * 2);
+        acc
+    });
+}
+
 fn main() {}
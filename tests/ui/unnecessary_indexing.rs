@@ -0,0 +1,688 @@
+//@aux-build:proc_macros.rs
+#![allow(unused)]
+#![allow(dropping_copy_types)]
+#![allow(
+    clippy::const_is_empty,
+    clippy::get_first,
+    clippy::get_last_with_len,
+    clippy::len_zero
+)]
+#![warn(clippy::unnecessary_indexing)]
+
+extern crate proc_macros;
+use proc_macros::external;
+use std::collections::VecDeque;
+use std::ops::Index;
+
+const ONE: usize = 1;
+
+fn c(x: i32) -> i32 {
+    println!("{x}");
+    10
+}
+
+fn f(x: &mut i32) {
+    *x += 1;
+}
+
+fn g(x: &mut Vec<i32>) {
+    x.push(1);
+}
+
+macro_rules! first_of {
+    ($v:expr) => {
+        $v[0]
+    };
+}
+
+macro_rules! bump_first {
+    ($v:expr) => {
+        $v[0] += 1
+    };
+}
+
+macro_rules! first_or_zero {
+    ($v:ident) => {
+        if !$v.is_empty() { $v[0] } else { 0 }
+    };
+}
+
+struct Indexable(Vec<i32>);
+
+impl Indexable {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Index<usize> for Indexable {
+    type Output = i32;
+    fn index(&self, idx: usize) -> &i32 {
+        &self.0[idx]
+    }
+}
+
+struct Struct;
+impl Struct {
+    pub fn a(x: i32) -> i32 {
+        println!("{x}");
+        10
+    }
+}
+
+struct Inner {
+    items: Vec<i32>,
+}
+
+struct Container {
+    items: Vec<i32>,
+    other: Vec<i32>,
+    inner: Inner,
+}
+
+impl Container {
+    fn items(&self) -> &[i32] {
+        &self.items
+    }
+
+    fn first_item(&self) -> i32 {
+        // lint on field receivers
+        if !self.items.is_empty() {
+            //~^ ERROR: condition can be simplified with `if..let` syntax
+            return self.items[0];
+        }
+        0
+    }
+
+    fn first_inner_item(&self) -> i32 {
+        // lint on nested field receivers
+        if !self.inner.items.is_empty() {
+            //~^ ERROR: condition can be simplified with `if..let` syntax
+            return c(self.inner.items[0]);
+        }
+        0
+    }
+
+    fn bump_first_item(&mut self) {
+        if !self.items.is_empty() {
+            //~^ ERROR: condition can be simplified with `if..let` syntax
+            self.items[0] += 1;
+        }
+    }
+
+    fn first_other_item(&self) -> i32 {
+        // don't lint when another field is indexed
+        if !self.items.is_empty() {
+            return self.other[0] + self.inner.items[0];
+        }
+        0
+    }
+
+    fn first_item_through_call(&self) -> i32 {
+        // don't lint when the receiver is not a place
+        if !self.items().is_empty() {
+            return self.items()[0];
+        }
+        0
+    }
+}
+
+// lint on early-return guards, suggesting `let..else`
+fn head(a: &[i32]) -> Option<i32> {
+    if a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        return None;
+    }
+    Some(a[0])
+}
+
+fn tail(a: &[String]) -> usize {
+    if a.len() == 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        panic!("empty");
+    }
+    let b: &String = &a[a.len() - 1];
+    b.len()
+}
+
+fn sum_heads(a: &[Vec<i32>]) -> i32 {
+    let mut sum = 0;
+    for v in a {
+        if v.is_empty() {
+            //~^ ERROR: condition can be simplified with `if..let` syntax
+            continue;
+        }
+        sum += v[0];
+    }
+    sum
+}
+
+fn heads(a: &[i32], b: &[i32]) -> Option<i32> {
+    if a.is_empty() || b.len() == 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        return None;
+    }
+    Some(a[0] + b[0])
+}
+
+// don't lint on guards from macro expansions
+fn head_asserted(a: &[i32]) -> i32 {
+    assert!(!a.is_empty());
+    a[0]
+}
+
+// don't lint when the guard doesn't diverge
+fn head_or_zero(a: &[i32]) -> i32 {
+    if a.is_empty() {
+        drop(0);
+    }
+    a[0]
+}
+
+// don't lint when the receiver is mutated between the guard and the indexing
+fn push_then_head(mut a: Vec<i32>) -> Option<i32> {
+    if a.is_empty() {
+        return None;
+    }
+    a.push(1);
+    Some(a[0])
+}
+
+// don't lint when the other operands can't be kept in a `let..else`
+fn head_if(a: &[i32], flag: bool) -> Option<i32> {
+    if a.is_empty() || flag {
+        return None;
+    }
+    Some(a[0])
+}
+
+#[clippy::msrv = "1.64"]
+fn head_msrv(a: &[i32]) -> Option<i32> {
+    if a.is_empty() {
+        return None;
+    }
+    Some(a[0])
+}
+
+#[clippy::msrv = "1.65"]
+fn head_msrv_let_else(a: &[i32]) -> Option<i32> {
+    if a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        return None;
+    }
+    Some(a[0])
+}
+
+fn main() {
+    // lint on vecs with a call
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    // lint on vecs with a method call
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = Struct::a(a[0]);
+    }
+
+    // lint on arrays with a call
+    let a: &[i32] = &[1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    // lint on arrays with a method call
+    let a: &[i32] = &[1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = Struct::a(a[0]);
+    }
+
+    // lint on a borrow of the first element
+    let a: Vec<String> = vec![String::new()];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b: &String = &a[0];
+        let len = a[0].len();
+    }
+
+    // lint when the first element is accessed in the else branch
+    let a: &[i32] = &[1];
+    if a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        drop(0);
+    } else {
+        let b = a[0];
+    }
+
+    // lint on `get(0).unwrap()` and `get(0).expect(..)`
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = a.get(0).unwrap();
+        let c = a.get(0).expect("not empty");
+    }
+
+    // lint on a bare `get(0)`
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b: Option<&i32> = a.get(0);
+    }
+
+    // lint on mixed indexing and `get(0)`
+    let a: &[i32] = &[1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = a[0];
+        let c = a.get(0).unwrap();
+    }
+
+    // lint on `len()` compared against zero
+    let a: Vec<i32> = vec![1];
+    if a.len() > 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    let a: Vec<i32> = vec![1];
+    if a.len() != 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    let a: &[i32] = &[1];
+    if 0 < a.len() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = a.get(0).unwrap();
+    }
+
+    let a: &[i32] = &[1];
+    if a.len() >= 1 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    let a: Vec<i32> = vec![1];
+    if a.len() == 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        drop(0);
+    } else {
+        let b = c(a[0]);
+    }
+
+    // don't lint when `len()` is compared against something else
+    let a: Vec<i32> = vec![1, 2];
+    if a.len() > 1 {
+        let b = a[0];
+    }
+
+    let a: Vec<i32> = vec![1, 2];
+    if a.len() == 2 {
+        let b = a[0];
+    }
+
+    // lint on mutable uses of the first element, suggesting `first_mut()`
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        a[0] += 1;
+    }
+
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        f(&mut a[0]);
+    }
+
+    let mut a: Vec<String> = vec![String::new()];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        a[0].clear();
+    }
+
+    // lint on mixed mutable and immutable uses with a single `first_mut()`
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+        a[0] = b;
+        let c: &i32 = &a[0];
+    }
+
+    // don't lint when the receiver is mutated or moved in the block
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        a.clear();
+        let b = a[0];
+    }
+
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        a.push(2);
+        let b = a[0];
+    }
+
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        let b = a.remove(0);
+        let b = a[0];
+    }
+
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        a = vec![2];
+        let b = a[0];
+    }
+
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        g(&mut a);
+        let b = a[0];
+    }
+
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        let b = a[0];
+        drop(a);
+    }
+
+    // don't lint when the mutable borrow would conflict with other uses
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        a[0] = a.len() as i32;
+    }
+
+    // lint on the last element, suggesting `last()`
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[a.len() - 1]);
+    }
+
+    let a: &[i32] = &[1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[a.len() - ONE]);
+        let c = a.get(a.len() - 1).unwrap();
+    }
+
+    let a: &mut [i32] = &mut [1];
+    if a.len() != 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        a[a.len() - 1] = 0;
+    }
+
+    // don't lint when both the first and the last element are accessed
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        let b = a[0] + a[a.len() - 1];
+    }
+
+    // don't lint on other offsets from the end
+    let a: Vec<i32> = vec![1, 2];
+    if !a.is_empty() {
+        let b = a[a.len() - 2];
+    }
+
+    // don't lint when the first element is not accessed
+    let a: &[i32] = &[1];
+    if !a.is_empty() {
+        let b = c(1);
+    }
+
+    // don't lint when another element is accessed
+    let a: Vec<i32> = vec![1, 2];
+    if !a.is_empty() {
+        let b = a[1];
+        let c = a.get(1).unwrap();
+    }
+
+    // don't lint when the first element is only accessed in the empty branch
+    let a: Vec<i32> = vec![1];
+    if a.is_empty() {
+        let b = a.get(0);
+    }
+
+    // don't lint when the first element of another sequence is accessed
+    let a: Vec<i32> = vec![1];
+    let d: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        let b = d[0];
+    }
+
+    // lint on conditions joined with `&&`, keeping the other operands in a nested `if`
+    let flag = true;
+    let other = false;
+    let third = true;
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() && flag {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    let a: Vec<i32> = vec![1];
+    if flag && a.len() > 0 && (other || third) {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() && a.len() > 3 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = a[0];
+    }
+
+    // don't lint when the other operands can't be kept without let-chains
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() && flag {
+        let b = a[0];
+    } else {
+        drop(0);
+    }
+
+    let a: &[i32] = &[1];
+    if a.is_empty() || flag {
+        drop(0);
+    } else {
+        let b = a[0];
+    }
+
+    // don't lint when a call comes before the emptiness check
+    let a: Vec<i32> = vec![1];
+    if c(0) > 3 && !a.is_empty() {
+        let b = a[0];
+    }
+
+    // don't lint when the emptiness check doesn't imply the branch is taken
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() || flag {
+        let b = a[0];
+    }
+
+    let a: Vec<i32> = vec![1];
+    if a.is_empty() && flag {
+        drop(0);
+    } else {
+        let b = a[0];
+    }
+
+    // lint with a binding name that doesn't clash with the names used in the block
+    let x = 1;
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let first = c(a[0]);
+        let b = first + x;
+    }
+
+    let a: Vec<i32> = vec![1];
+    let first = 1;
+    if !a.is_empty() && first > 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let a_first = first;
+        let b = c(a[a.len() - 1]) + a_first;
+        let f = |last: i32| last + 1;
+    }
+
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let a_first = first;
+        let b = c(a[0]) + a_first;
+    }
+
+    // lint once on several sequences checked in one condition, binding them together
+    let a: Vec<i32> = vec![1];
+    let b: Vec<i32> = vec![1];
+    if !a.is_empty() && !b.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let d = c(a[0]) + c(b[0]);
+    }
+
+    let a: Vec<i32> = vec![1];
+    let b: &[i32] = &[1];
+    if !a.is_empty() && flag && b.len() > 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let d = c(a[0]) + b[b.len() - 1];
+    }
+
+    let a: &[i32] = &[1];
+    let b: &[i32] = &[1];
+    if a.is_empty() || b.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        drop(0);
+    } else {
+        let d = a[0] + b[0];
+    }
+
+    // lint on the sequence whose element is accessed, keeping the other emptiness check
+    let a: Vec<i32> = vec![1];
+    let b: Vec<i32> = vec![1];
+    if !a.is_empty() && !b.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let d = c(b[0]);
+    }
+
+    // only bind the sequences whose emptiness check comes before any call
+    let a: Vec<i32> = vec![1];
+    let b: Vec<i32> = vec![1];
+    if !a.is_empty() && c(0) > 3 && !b.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let d = c(a[0]) + c(b[0]);
+    }
+
+    // lint when an access comes from a macro expansion, leaving it as it is
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]) + first_of!(a);
+    }
+
+    // don't lint when all the accesses come from macro expansions
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        let b = first_of!(a);
+    }
+
+    // don't lint when a macro expansion mutates the element
+    let mut a: Vec<i32> = vec![1];
+    if !a.is_empty() {
+        bump_first!(a);
+        let b = a[0];
+    }
+
+    // don't lint when the `if` comes from a macro expansion
+    let a: Vec<i32> = vec![1];
+    let b = first_or_zero!(a);
+
+    external! {
+        let a: Vec<i32> = vec![1];
+        if !a.is_empty() {
+            let b = a[0];
+        }
+    }
+
+    // lint on `VecDeque`s, suggesting `front()` and `back()`
+    let a: VecDeque<i32> = VecDeque::from([1]);
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    let mut a: VecDeque<i32> = VecDeque::from([1]);
+    if a.len() > 0 {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        a[0] += 1;
+    }
+
+    let a: VecDeque<i32> = VecDeque::from([1]);
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[a.len() - 1]);
+    }
+
+    // lint on arrays
+    let a: [i32; 3] = [1, 2, 3];
+    if !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    // don't lint on other types implementing `Index<usize>`
+    let a = Indexable(vec![1]);
+    if !a.is_empty() {
+        let b = a[0];
+    }
+
+    // lint on a `match` on the emptiness check, matching on the element instead
+    let a: Vec<i32> = vec![1];
+    let b = match a.is_empty() {
+        //~^ ERROR: this `match` can be simplified by matching on the element
+        false => c(a[0]),
+        true => 0,
+    };
+
+    let a: &[i32] = &[1];
+    let b = match a.len() == 0 {
+        //~^ ERROR: this `match` can be simplified by matching on the element
+        true => 0,
+        false => a[a.len() - 1],
+    };
+
+    let mut a: Vec<i32> = vec![1];
+    match !a.is_empty() {
+        //~^ ERROR: this `match` can be simplified by matching on the element
+        true => a[0] += 1,
+        false => drop(0),
+    }
+
+    // don't lint on a `match` with guards or or-patterns
+    let a: Vec<i32> = vec![1];
+    let b = match a.is_empty() {
+        false if flag => a[0],
+        _ => 0,
+    };
+
+    let a: Vec<i32> = vec![1];
+    let b = match a.is_empty() {
+        true | false => a[0],
+    };
+
+    // don't lint on a `match` on a condition chain
+    let a: Vec<i32> = vec![1];
+    let b = match !a.is_empty() && flag {
+        true => a[0],
+        false => 0,
+    };
+}
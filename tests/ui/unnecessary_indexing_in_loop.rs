@@ -0,0 +1,50 @@
+#![allow(unused, clippy::while_immutable_condition, clippy::len_zero, clippy::get_first)]
+#![warn(clippy::unnecessary_indexing_in_loop)]
+
+use std::collections::VecDeque;
+
+fn c(x: i32) -> i32 {
+    println!("{x}");
+    10
+}
+
+fn main() {
+    // lint when the body doesn't mutate the sequence
+    let a: Vec<i32> = vec![1];
+    while !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `while..let` syntax
+        if c(a[0]) > 3 {
+            break;
+        }
+    }
+
+    let a: &[i32] = &[1];
+    while a.len() > 0 {
+        //~^ ERROR: condition can be simplified with `while..let` syntax
+        if c(a[a.len() - 1]) > 3 {
+            break;
+        }
+    }
+
+    let mut a: Vec<i32> = vec![1];
+    while !a.is_empty() {
+        //~^ ERROR: condition can be simplified with `while..let` syntax
+        a[0] += 1;
+        if a[0] > 3 {
+            break;
+        }
+    }
+
+    // don't lint on other conditions
+    let flag = true;
+    let mut a: Vec<i32> = vec![1, 2];
+    while !a.is_empty() && flag {
+        let b = c(a[0]);
+        a.remove(0);
+    }
+
+    let a: Vec<i32> = vec![1];
+    while a.is_empty() {
+        let b = a.get(0);
+    }
+}
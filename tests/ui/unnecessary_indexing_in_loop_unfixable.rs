@@ -0,0 +1,60 @@
+//@no-rustfix
+#![allow(unused, clippy::get_first)]
+#![warn(clippy::unnecessary_indexing_in_loop)]
+
+use std::collections::VecDeque;
+
+fn c(x: i32) -> i32 {
+    println!("{x}");
+    10
+}
+
+fn main() {
+    // lint when the first element is removed at the end of the body
+    let mut a: VecDeque<i32> = VecDeque::from([1, 2]);
+    while !a.is_empty() {
+        //~^ ERROR: the first element is indexed, then removed at the end of each iteration
+        let b = c(a[0]);
+        a.pop_front();
+    }
+
+    let mut a: Vec<i32> = vec![1, 2];
+    while !a.is_empty() {
+        //~^ ERROR: the first element is indexed, then removed at the end of each iteration
+        let b = c(a[0]);
+        a.remove(0);
+    }
+
+    // don't lint when the sequence is mutated in some other way
+    let mut a: Vec<i32> = vec![1, 2];
+    while !a.is_empty() {
+        let b = c(a[0]);
+        a.remove(1);
+    }
+
+    let mut a: Vec<i32> = vec![1, 2];
+    while !a.is_empty() {
+        a.remove(0);
+        let b = c(a[0]);
+    }
+
+    let mut a: Vec<i32> = vec![1, 2];
+    while !a.is_empty() {
+        let b = c(a[0]);
+        a.pop();
+    }
+
+    let mut a: Vec<i32> = vec![1, 2];
+    while !a.is_empty() {
+        let b = c(a[0]);
+        a.push(3);
+        a.remove(0);
+    }
+
+    // don't lint when the last element is accessed before removing the first one
+    let mut a: VecDeque<i32> = VecDeque::from([1, 2]);
+    while !a.is_empty() {
+        let b = c(a[a.len() - 1]);
+        a.pop_front();
+    }
+}
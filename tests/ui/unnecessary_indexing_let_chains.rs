@@ -0,0 +1,63 @@
+#![feature(let_chains)]
+#![allow(unused)]
+#![allow(dropping_copy_types)]
+#![warn(clippy::unnecessary_indexing)]
+
+fn c(x: i32) -> i32 {
+    println!("{x}");
+    10
+}
+
+fn main() {
+    let flag = true;
+    let other = false;
+    let third = true;
+
+    // lint on conditions joined with `&&`, keeping the other operands in a let-chain
+    let a: Vec<i32> = vec![1];
+    if !a.is_empty() && flag {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    } else {
+        drop(0);
+    }
+
+    let a: Vec<i32> = vec![1];
+    if flag && !a.is_empty() && (other || third) {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let b = c(a[0]);
+    }
+
+    // lint on conditions joined with `||`, negating the other operands
+    let a: &[i32] = &[1];
+    if a.is_empty() || flag {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        drop(0);
+    } else {
+        let b = a[0];
+    }
+
+    // don't lint when a call comes before the emptiness check
+    let a: Vec<i32> = vec![1];
+    if c(0) > 3 && !a.is_empty() {
+        let b = a[0];
+    }
+    // lint once on several sequences checked in one condition, binding them in a let-chain
+    let a: Vec<i32> = vec![1];
+    let b: &[i32] = &[1];
+    if !a.is_empty() && flag && !b.is_empty() {
+        //~^ ERROR: condition can be simplified with `if..let` syntax
+        let d = c(a[0]) + b[b.len() - 1];
+    }
+
+    // don't lint when an indexing or an overloaded operator comes before the emptiness check
+    let a: Vec<i32> = vec![1];
+    let v = [1, 2];
+    if v[1] > 0 && !a.is_empty() {
+        let b = a[0];
+    }
+    let w = std::num::Wrapping(1);
+    if w + w > w && !a.is_empty() {
+        let b = a[0];
+    }
+}
@@ -20,7 +20,7 @@ fn main() {
         .join("");
     println!("{}", output);
 
-    // should not be linted
+    // should not be linted, there's no replacement in `std` for non-empty separators
     let vector = vec!["hello", "world"];
     let output = vector
         .iter()
@@ -29,8 +29,27 @@ fn main() {
         .join("\n");
     println!("{}", output);
 
+    // should be linted
+    let vector = vec!["hello", "world"];
+    let output = vector.iter().map(|item| item.to_uppercase()).collect::<Vec<String>>().concat();
+    println!("{}", output);
+
+    // should be linted
+    let output: String = vector.iter().map(|item| item.trim()).collect::<Vec<&str>>().join("");
+    println!("{}", output);
+
+    // should be linted
+    const EMPTY: &str = "";
+    let output: String = vector.iter().copied().collect::<Vec<_>>().join(EMPTY);
+    println!("{}", output);
+
     // should not be linted
     let vector = vec!["hello", "world"];
     let output = vector.iter().map(|item| item.to_uppercase()).collect::<String>();
     println!("{}", output);
+
+    // should not be linted
+    let bytes = vec![vec![1u8], vec![2]];
+    let output = bytes.into_iter().collect::<Vec<_>>().concat();
+    println!("{:?}", output);
 }
@@ -0,0 +1,15 @@
+#![warn(clippy::unnecessary_join)]
+#![allow(unused_imports, clippy::uninlined_format_args, clippy::useless_vec)]
+
+use itertools::Itertools;
+
+fn main() {
+    // should be linted, but there's no suggestion for non-empty separators
+    let vector = vec!["hello", "world"];
+    let output = vector
+        .iter()
+        .map(|item| item.to_uppercase())
+        .collect::<Vec<String>>()
+        .join("\n");
+    println!("{}", output);
+}
@@ -281,3 +281,37 @@ fn panicky_arithmetic_ops(x: usize, y: isize) {
     let _x = false.then(|| f1 + f2);
     //~^ ERROR: unnecessary closure used with `bool::then`
 }
+
+fn const_fn_calls() {
+    use std::time::Duration;
+
+    enum MyError {
+        NotFound,
+        Code(u32),
+    }
+
+    const fn make_code(code: u32) -> MyError {
+        MyError::Code(code)
+    }
+
+    let opt: Option<Duration> = None;
+    let _ = opt.unwrap_or_else(|| Duration::from_secs(0));
+    //~^ ERROR: unnecessary closure used to substitute value for `Option::None`
+    let _ = opt.unwrap_or_else(|| Duration::from_secs(u64::MAX));
+    //~^ ERROR: unnecessary closure used to substitute value for `Option::None`
+    let _ = opt.unwrap_or_else(|| Duration::from_secs(1).saturating_add(Duration::from_millis(500)));
+    //~^ ERROR: unnecessary closure used to substitute value for `Option::None`
+    let _: Result<u32, MyError> = Some(1).ok_or_else(|| make_code(404));
+    //~^ ERROR: unnecessary closure used to substitute value for `Option::None`
+    let _: Result<u32, MyError> = Some(1).ok_or_else(|| MyError::Code(404));
+    //~^ ERROR: unnecessary closure used to substitute value for `Option::None`
+    // The call panics, which the closure only did when called, so the suggestion may be incorrect
+    let _ = opt.unwrap_or_else(|| Duration::new(u64::MAX, 1_000_000_000));
+    //~^ ERROR: unnecessary closure used to substitute value for `Option::None`
+
+    // Should not lint
+    let secs = 5;
+    let _ = opt.unwrap_or_else(|| Duration::from_secs(secs));
+    let _ = opt.unwrap_or_else(|| Duration::from_secs(10 / 2));
+    let _ = opt.unwrap_or_else(|| Duration::from_secs([1, 2][0]));
+}
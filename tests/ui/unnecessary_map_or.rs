@@ -0,0 +1,39 @@
+#![warn(clippy::unnecessary_map_or)]
+#![feature(is_none_or)]
+
+#[clippy::msrv = "1.82"]
+fn main() {
+    let opt = Some(5);
+    let _ = opt.map_or(true, |x| x > 3);
+    let _ = opt.map_or(true, |x| {
+        let y = x * 2;
+        y % 3 == 0
+    });
+    let r = &Some(String::new());
+    let _ = r.as_ref().map_or(true, |s| s.is_empty());
+    let f = |x: i32| x > 0;
+    let _ = opt.map_or(true, f);
+
+    // Don't lint
+    let _ = opt.map_or(false, |x| x > 3);
+    let _ = opt.map_or(1, |x| x + 1);
+    let res: Result<i32, ()> = Ok(5);
+    let _ = res.map_or(true, |x| x > 3);
+    let b = true;
+    let _ = opt.map_or(b, |x| x > 3);
+}
+
+#[clippy::msrv = "1.81"]
+fn msrv_1_81(opt: Option<i32>) -> bool {
+    opt.map_or(true, |x| x > 3)
+}
+
+#[clippy::msrv = "1.82"]
+fn msrv_1_82(opt: Option<i32>) -> bool {
+    opt.map_or(true, |x| x > 3)
+}
+
+// `is_none_or` isn't stable on all toolchains, so it's only suggested with an explicit MSRV
+fn no_msrv(opt: Option<i32>) -> bool {
+    opt.map_or(true, |x| x > 3)
+}
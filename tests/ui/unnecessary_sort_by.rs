@@ -99,3 +99,27 @@ fn main() {
     issue_5754::test();
     issue_6001::test();
 }
+
+fn key_projections() {
+    struct Item {
+        id: u32,
+        name: String,
+    }
+
+    let mut items: Vec<Item> = vec![];
+    items.sort_by(|a, b| a.id.cmp(&b.id));
+    items.sort_unstable_by(|a, b| b.id.cmp(&a.id));
+    let _ = items.iter().max_by(|a, b| a.id.cmp(&b.id));
+    let _ = items.iter().min_by(|a, b| a.id.cmp(&b.id));
+    let _ = items.iter().max_by(|a, b| b.id.cmp(&a.id));
+    let _ = items.iter().map(|item| item.id).min_by(|a, b| a.cmp(b));
+    let _ = items.binary_search_by(|item| item.id.cmp(&3));
+
+    // The keys can't be moved out of or borrow from the items, don't lint
+    items.sort_by(|a, b| a.name.cmp(&b.name));
+    let _ = items.iter().max_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+    let _ = items.binary_search_by(|item| item.name.as_str().cmp("foo"));
+    // Not a projection of the element
+    let target = 3;
+    let _ = items.binary_search_by(|_| target.cmp(&3));
+}
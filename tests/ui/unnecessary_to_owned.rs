@@ -566,3 +566,51 @@ fn borrow_checks() {
     HashSet::<i32>::new().foo::<&str>(&"".to_owned());
     HashSet::<String>::new().get(&1.to_string());
 }
+
+fn into_iterator_params() {
+    fn sum<I: IntoIterator<Item = u32>>(iter: I) -> u32 {
+        iter.into_iter().sum()
+    }
+    fn count<I: IntoIterator>(iter: I) -> usize {
+        iter.into_iter().count()
+    }
+
+    let array = [1u32, 2, 3];
+    let vec = vec![1u32, 2, 3];
+    let slice = &vec[..];
+
+    sum(array.to_vec()); //~ ERROR: unnecessary use of `to_vec`
+    sum(vec.to_vec()); //~ ERROR: unnecessary use of `to_vec`
+    sum(slice.to_owned()); //~ ERROR: unnecessary use of `to_owned`
+    count(array.to_vec()); //~ ERROR: unnecessary use of `to_vec`
+    count(vec.to_vec()); //~ ERROR: unnecessary use of `to_vec`
+    count(slice.to_vec()); //~ ERROR: unnecessary use of `to_vec`
+
+    // Should not lint: the items aren't `Copy`.
+    fn concat<I: IntoIterator<Item = String>>(iter: I) -> String {
+        iter.into_iter().collect()
+    }
+    let strings = vec![String::from("a")];
+    concat(strings.to_vec());
+
+    // Should not lint: the argument must be `'static`.
+    fn boxed<I: IntoIterator<Item = u32> + 'static>(iter: I) -> Box<dyn Iterator<Item = u32>> {
+        Box::new(iter.into_iter())
+    }
+    let _ = boxed(vec.to_vec());
+
+    // Should not lint: the items are bounded as well.
+    fn widen<I: IntoIterator>(iter: I) -> u64
+    where
+        I::Item: Into<u64>,
+    {
+        iter.into_iter().map(Into::into).sum()
+    }
+    widen(vec.to_vec());
+
+    // Should not lint: the parameter has other bounds.
+    fn sum_twice<I: IntoIterator<Item = u32> + Clone>(iter: I) -> u32 {
+        iter.clone().into_iter().chain(iter).sum()
+    }
+    sum_twice(vec.to_vec());
+}
@@ -103,4 +103,24 @@ fn main() {
     let v = [1, 2, 3].iter().copied().enumerate();
     let x = v.map(|(_, x)| x).sum::<i32>();
     assert_eq!(x, 6);
+
+    // The index is unused even though it has a name.
+    let map = std::collections::HashMap::from([("a", 1)]);
+    for (idx, (name, value)) in map.iter().enumerate() {
+        println!("{name}: {value}");
+    }
+
+    for (_, (name, value)) in map.iter().enumerate() {
+        println!("{name}: {value}");
+    }
+
+    [1, 2, 3].iter().enumerate().for_each(|(_idx, item)| println!("{item}"));
+
+    let _ = [(1, 2)].iter().enumerate().map(|(i, (a, b))| a + b);
+
+    // This shouldn't trigger the lint. The function expects the tuple.
+    fn second((_, x): (usize, &i32)) -> i32 {
+        *x
+    }
+    let _ = [1, 2, 3].iter().enumerate().map(second);
 }
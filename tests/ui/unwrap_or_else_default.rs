@@ -1,6 +1,7 @@
 #![warn(clippy::unwrap_or_default)]
 #![allow(dead_code)]
 #![allow(clippy::unnecessary_wraps, clippy::unnecessary_literal_unwrap)]
+#![allow(clippy::redundant_closure)]
 
 /// Checks implementation of the `UNWRAP_OR_DEFAULT` lint.
 fn unwrap_or_else_default() {
@@ -160,4 +161,30 @@ fn missing_suggested_method() {
     s.unwrap_or(Default::default());
 }
 
+fn entry_api() {
+    use std::collections::{BTreeMap, HashMap};
+    use std::fmt::Debug;
+
+    let mut map = HashMap::<u32, Vec<u32>>::new();
+    map.entry(1).or_insert_with(Vec::new).push(1);
+    map.entry(2).or_insert_with(Default::default).push(2);
+    map.entry(3).or_insert(Vec::new()).push(3);
+    map.entry(4).or_insert_with(|| Vec::new()).push(4);
+    map.entry(5).or_insert_with(|| Default::default()).push(5);
+
+    let mut strings = HashMap::<u32, String>::new();
+    strings.entry(1).or_insert_with(String::new).push('a');
+    strings.entry(2).or_insert_with(|| "".to_string()).push('b');
+
+    let mut btree = BTreeMap::<u32, Vec<u32>>::new();
+    btree.entry(1).or_insert_with(Vec::new).push(1);
+    btree.entry(2).or_insert(Default::default()).push(2);
+    btree.entry(3).or_insert_with(|| Vec::new()).push(3);
+
+    // should not be changed: `Box<dyn Debug>` doesn't implement `Default`
+    let mut boxed = HashMap::<u32, Box<dyn Debug>>::new();
+    boxed.entry(1).or_insert(Box::<Vec<u8>>::default());
+    boxed.entry(2).or_insert_with(|| Box::<Vec<u8>>::default());
+}
+
 fn main() {}
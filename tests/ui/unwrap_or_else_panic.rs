@@ -0,0 +1,64 @@
+#![feature(lint_reasons)]
+#![warn(clippy::unwrap_or_else_panic)]
+
+struct NoDebug;
+
+fn option(opt: Option<u32>) {
+    let _ = opt.unwrap_or_else(|| panic!("no value"));
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+    let _ = opt.unwrap_or_else(|| unreachable!("always `Some`"));
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+    let _ = opt.unwrap_or_else(|| {
+        //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+        todo!();
+    });
+    let _ = opt.unwrap_or_else(|| unimplemented!());
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+    let _ = opt.map_or_else(|| panic!("no value"), |x| x + 1);
+    //~^ ERROR: called `map_or_else` with a closure that always panics
+
+    // The message is formatted, keep the closure
+    let id = 1;
+    let _ = opt.unwrap_or_else(|| panic!("no value for {id}"));
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+    let _ = opt.unwrap_or_else(|| panic!("no value for {}", id));
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+    let _ = opt.unwrap_or_else(|| panic!("{{no value}}"));
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+    let _ = opt.or_else(|| panic!("no value"));
+    //~^ ERROR: called `or_else` with a closure that always panics
+
+    // Don't lint
+    let _ = opt.unwrap_or_else(|| {
+        println!("no value");
+        panic!()
+    });
+    let _ = opt.unwrap_or_else(|| 0);
+    let _ = opt.map_or_else(|| 0, |x| x + 1);
+}
+
+fn result(res: Result<u32, String>, no_debug: Result<u32, NoDebug>) {
+    let _ = res.clone().unwrap_or_else(|_| panic!("no value"));
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+    let _ = res.clone().map_or_else(|_| unreachable!(), |x| x + 1);
+    //~^ ERROR: called `map_or_else` with a closure that always panics
+    let _ = res.unwrap_or_else(|e| panic!("no value: {e}"));
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+
+    // `expect` needs the error to implement `Debug`
+    let _ = no_debug.unwrap_or_else(|_| panic!("no value"));
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+}
+
+#[expect(clippy::unwrap_or_else_panic)]
+fn expected(opt: Option<u32>) -> u32 {
+    opt.unwrap_or_else(|| panic!("no value"))
+}
+
+#[allow(clippy::panic)]
+fn panic_allowed(opt: Option<u32>) -> u32 {
+    opt.unwrap_or_else(|| panic!("no value"))
+    //~^ ERROR: called `unwrap_or_else` with a closure that always panics
+}
+
+fn main() {}
@@ -0,0 +1,96 @@
+#![warn(clippy::while_index_loop)]
+#![allow(clippy::useless_vec)]
+
+fn slice(s: &mut [i32]) {
+    let mut i = 0;
+    while i < s.len() {
+        s[i] = s[i].abs();
+        i += 1;
+    }
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+
+    let mut i = 0;
+    while i < v.len() {
+        println!("{}", v[i]);
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < v.len() {
+        println!("{i}: {}", v[i]);
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < v.len() {
+        v[i] *= 2;
+        i += 1;
+    }
+
+    let mut idx = 0;
+    'outer: while idx < v.len() {
+        if v[idx] == 4 {
+            break 'outer;
+        }
+        idx += 1;
+    }
+
+    // Don't lint: elements are skipped
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 2 {
+            i += 1;
+        }
+        i += 1;
+    }
+
+    // Don't lint: the increment is skipped
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 2 {
+            continue;
+        }
+        i += 1;
+    }
+
+    // Don't lint: the length of `v` changes
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] > 0 {
+            v.push(0);
+        }
+        i += 1;
+    }
+
+    // Don't lint: other elements are used
+    let mut i = 0;
+    while i < v.len() - 1 {
+        v[i] = v[i + 1];
+        i += 1;
+    }
+    let mut i = 0;
+    while i < v.len() {
+        println!("{}", v[i + 1]);
+        i += 1;
+    }
+
+    // Don't lint: the counter is used after the loop
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 2 {
+            break;
+        }
+        i += 1;
+    }
+    println!("{i}");
+
+    // Don't lint: the counter doesn't start at zero
+    let mut i = 1;
+    while i < v.len() {
+        println!("{}", v[i]);
+        i += 1;
+    }
+}
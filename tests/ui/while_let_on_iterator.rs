@@ -456,6 +456,50 @@ fn fn_once_closure() {
     });
 }
 
+#[allow(clippy::needless_borrow)]
+fn field_and_reborrow() {
+    struct Parser<I> {
+        tokens: I,
+        count: usize,
+    }
+    impl<I: Iterator<Item = u32>> Parser<I> {
+        fn skip_to(&mut self, target: u32) -> Option<u32> {
+            // Field used after the loop, needs `by_ref`
+            while let Some(token) = self.tokens.next() {
+                if token == target {
+                    break;
+                }
+            }
+            self.tokens.next()
+        }
+
+        fn consume(&mut self) {
+            // Field not used after the loop
+            while let Some(token) = self.tokens.next() {
+                self.count += token as usize;
+            }
+        }
+
+        fn capture(&mut self) {
+            // Don't lint, `self` is captured by a closure in the loop
+            while let Some(token) = self.tokens.next() {
+                let f = || self.count;
+                if f() == token as usize {
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut it = 0..10;
+    while let Some(x) = (&mut it).next() {
+        if x == 5 {
+            break;
+        }
+    }
+    println!("{:?}", it.next());
+}
+
 fn main() {
     let mut it = 0..20;
     while let Some(..) = it.next() {
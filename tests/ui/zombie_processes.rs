@@ -0,0 +1,97 @@
+#![warn(clippy::zombie_processes)]
+
+use std::process::{Child, Command};
+
+fn main() {
+    let _ = Command::new("").spawn().unwrap();
+    //~^ ERROR: spawned process is never `wait()`ed on
+    Command::new("").spawn().unwrap();
+    //~^ ERROR: spawned process is never `wait()`ed on
+    let _child = Command::new("").spawn().unwrap();
+    //~^ ERROR: spawned process is never `wait()`ed on
+    let _stdout = Command::new("").spawn().unwrap().stdout;
+    //~^ ERROR: spawned process is never `wait()`ed on
+
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        //~^ ERROR: spawned process is never `wait()`ed on
+        x.kill().unwrap();
+        let _stdout = x.stdout.take();
+    }
+
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        //~^ ERROR: spawned process is never `wait()`ed on
+        // A single `try_wait` doesn't reap the child if it hasn't exited yet
+        let _ = x.try_wait();
+    }
+
+    {
+        let x = Command::new("").spawn().unwrap();
+        //~^ ERROR: spawned process is never `wait()`ed on
+        let _y = x;
+    }
+
+    // Don't lint:
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        x.wait().unwrap();
+    }
+
+    {
+        let x = Command::new("").spawn().unwrap();
+        x.wait_with_output().unwrap();
+    }
+
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        x.kill().unwrap();
+        x.wait().unwrap();
+    }
+
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        while x.try_wait().unwrap().is_none() {}
+    }
+
+    {
+        let x = Command::new("").spawn().unwrap();
+        let mut y = x;
+        y.wait().unwrap();
+    }
+
+    {
+        let x = Command::new("").spawn().unwrap();
+        process_child(x);
+    }
+
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        std::thread::spawn(move || {
+            x.wait().unwrap();
+        });
+    }
+
+    Command::new("").spawn().unwrap().wait().unwrap();
+}
+
+fn process_child(c: Child) {
+    let _ = c;
+}
+
+fn spawn_and_return() -> Option<Child> {
+    let x = Command::new("").spawn().unwrap();
+    Some(x)
+}
+
+fn spawn_tail() -> Child {
+    Command::new("").spawn().unwrap()
+}
+
+fn try_operator() -> std::io::Result<()> {
+    let _x = Command::new("").spawn()?;
+    //~^ ERROR: spawned process is never `wait()`ed on
+    let mut y = Command::new("").spawn()?;
+    y.wait()?;
+    Ok(())
+}